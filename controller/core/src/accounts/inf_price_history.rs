@@ -0,0 +1,304 @@
+//! Optional ring buffer PDA of INF price samples
+//! for computing time-weighted average prices.
+//!
+//! Samples are appended at most once every [`INF_PRICE_HISTORY_MIN_SLOT_INTERVAL`] slots
+//! by the permissionless `CrankInfPriceHistory` instruction only.
+//! Swaps and `SyncSolValue` do not sample, so the TWAP is only as fresh
+//! as the latest crank.
+
+use core::mem::size_of;
+
+use crate::{
+    internal_utils::{impl_cast_from_acc_data, impl_cast_to_acc_data},
+    svc::InfCalc,
+};
+
+/// Max number of samples held by the buffer
+pub const INF_PRICE_HISTORY_CAP: usize = 256;
+
+/// Min number of slots between 2 consecutive samples
+pub const INF_PRICE_HISTORY_MIN_SLOT_INTERVAL: u64 = 150;
+
+/// INF has 9 decimals
+pub const ONE_INF: u64 = 1_000_000_000;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InfPriceSample {
+    pub slot: u64,
+
+    /// SOL value of [`ONE_INF`], in lamports
+    pub sol_per_inf: u64,
+
+    pub total_sol_value: u64,
+}
+
+impl InfPriceSample {
+    /// `calc` should have already had pending yield release applied.
+    ///
+    /// # Returns
+    /// `None` if pool is insolvent for LPers
+    #[inline]
+    pub const fn from_inf_calc(slot: u64, calc: &InfCalc) -> Option<Self> {
+        let sol_per_inf = match calc.inf_to_sol(ONE_INF) {
+            None => return None,
+            Some(x) => x,
+        };
+        Some(Self {
+            slot,
+            sol_per_inf,
+            total_sol_value: *calc.pool_lamports.total(),
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InfPriceSamplePacked {
+    slot: [u8; 8],
+    sol_per_inf: [u8; 8],
+    total_sol_value: [u8; 8],
+}
+
+impl InfPriceSamplePacked {
+    #[inline]
+    pub const fn into_inf_price_sample(self) -> InfPriceSample {
+        let Self {
+            slot,
+            sol_per_inf,
+            total_sol_value,
+        } = self;
+        InfPriceSample {
+            slot: u64::from_le_bytes(slot),
+            sol_per_inf: u64::from_le_bytes(sol_per_inf),
+            total_sol_value: u64::from_le_bytes(total_sol_value),
+        }
+    }
+
+    #[inline]
+    pub const fn from_inf_price_sample(
+        InfPriceSample {
+            slot,
+            sol_per_inf,
+            total_sol_value,
+        }: &InfPriceSample,
+    ) -> Self {
+        Self {
+            slot: slot.to_le_bytes(),
+            sol_per_inf: sol_per_inf.to_le_bytes(),
+            total_sol_value: total_sol_value.to_le_bytes(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InfPriceHistory {
+    /// Index in `samples` that the next sample will be written to
+    head: [u8; 4],
+
+    /// Number of valid samples, saturates at [`INF_PRICE_HISTORY_CAP`]
+    len: [u8; 4],
+
+    samples: [InfPriceSamplePacked; INF_PRICE_HISTORY_CAP],
+}
+impl_cast_from_acc_data!(InfPriceHistory, packed);
+impl_cast_to_acc_data!(InfPriceHistory, packed);
+
+pub const INF_PRICE_HISTORY_ACC_LEN: usize = size_of::<InfPriceHistory>();
+
+impl Default for InfPriceHistory {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            head: [0; 4],
+            len: [0; 4],
+            samples: [InfPriceSamplePacked::default(); INF_PRICE_HISTORY_CAP],
+        }
+    }
+}
+
+/// Time-weighted averages over a window of slots
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InfTwap {
+    /// SOL value of [`ONE_INF`], in lamports
+    pub sol_per_inf: u64,
+
+    pub total_sol_value: u64,
+}
+
+impl InfPriceHistory {
+    #[inline]
+    pub const fn len(&self) -> usize {
+        u32::from_le_bytes(self.len) as usize
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    const fn head(&self) -> usize {
+        u32::from_le_bytes(self.head) as usize
+    }
+
+    /// `i = 0` is the latest sample
+    #[inline]
+    pub const fn nth_latest(&self, i: usize) -> Option<InfPriceSample> {
+        if i >= self.len() {
+            return None;
+        }
+        let idx = (self.head() + INF_PRICE_HISTORY_CAP - 1 - i) % INF_PRICE_HISTORY_CAP;
+        Some(self.samples[idx].into_inf_price_sample())
+    }
+
+    #[inline]
+    pub const fn latest(&self) -> Option<InfPriceSample> {
+        self.nth_latest(0)
+    }
+
+    /// Samples from latest to oldest
+    #[inline]
+    pub fn iter_latest_first(&self) -> impl Iterator<Item = InfPriceSample> + '_ {
+        (0..self.len()).filter_map(|i| self.nth_latest(i))
+    }
+
+    /// Whether a new sample should be appended at `curr_slot`
+    #[inline]
+    pub const fn should_sample(&self, curr_slot: u64) -> bool {
+        match self.latest() {
+            None => true,
+            Some(InfPriceSample { slot, .. }) => {
+                curr_slot.saturating_sub(slot) >= INF_PRICE_HISTORY_MIN_SLOT_INTERVAL
+            }
+        }
+    }
+
+    /// Appends `sample`, overwriting the oldest sample if the buffer is full.
+    ///
+    /// Does not check [`Self::should_sample`]
+    #[inline]
+    pub const fn push(&mut self, sample: &InfPriceSample) {
+        let head = self.head();
+        self.samples[head] = InfPriceSamplePacked::from_inf_price_sample(sample);
+        self.head = (((head + 1) % INF_PRICE_HISTORY_CAP) as u32).to_le_bytes();
+        if self.len() < INF_PRICE_HISTORY_CAP {
+            self.len = ((self.len() + 1) as u32).to_le_bytes();
+        }
+    }
+
+    /// Time-weighted averages over the `window` slots before `curr_slot`,
+    /// where each sample's values are taken to hold from its slot
+    /// until the next sample's slot, and the latest sample's values until `curr_slot`.
+    ///
+    /// # Returns
+    /// `None` if
+    /// - `window = 0`
+    /// - the oldest sample is more recent than `curr_slot - window`
+    ///   i.e. the history does not cover the entire window
+    /// - the latest sample is more recent than `curr_slot`
+    #[inline]
+    pub fn twap(&self, curr_slot: u64, window: u64) -> Option<InfTwap> {
+        if window == 0 {
+            return None;
+        }
+        let start = curr_slot.checked_sub(window)?;
+
+        let mut end = curr_slot;
+        let mut sums = [0u128; 2];
+        for InfPriceSample {
+            slot,
+            sol_per_inf,
+            total_sol_value,
+        } in self.iter_latest_first()
+        {
+            if slot > end {
+                return None;
+            }
+            let seg_start = slot.max(start);
+            let dur = u128::from(end - seg_start);
+            sums[0] += u128::from(sol_per_inf) * dur;
+            sums[1] += u128::from(total_sol_value) * dur;
+            if slot <= start {
+                // unwrap-safety: average of u64s always fits in u64
+                let [sol_per_inf, total_sol_value] =
+                    sums.map(|s| u64::try_from(s / u128::from(window)).unwrap());
+                return Some(InfTwap {
+                    sol_per_inf,
+                    total_sol_value,
+                });
+            }
+            end = slot;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn sample(slot: u64, sol_per_inf: u64) -> InfPriceSample {
+        InfPriceSample {
+            slot,
+            sol_per_inf,
+            total_sol_value: sol_per_inf,
+        }
+    }
+
+    #[test]
+    fn twap_basic() {
+        let mut h = InfPriceHistory::default();
+        assert_eq!(h.twap(1_000, 100), None);
+
+        h.push(&sample(0, 1_000));
+        h.push(&sample(300, 2_000));
+
+        // window entirely after latest sample
+        assert_eq!(h.twap(500, 100).unwrap().sol_per_inf, 2_000);
+        // half before, half after latest sample
+        assert_eq!(h.twap(400, 200).unwrap().sol_per_inf, 1_500);
+        // window goes back before oldest sample
+        assert_eq!(h.twap(400, 401), None);
+        assert_eq!(h.twap(400, 400).unwrap().sol_per_inf, 1_250);
+        // latest sample in the future
+        assert_eq!(h.twap(299, 10), None);
+    }
+
+    proptest! {
+        #[test]
+        fn push_wraps_around(n in 0..3 * INF_PRICE_HISTORY_CAP) {
+            let mut h = InfPriceHistory::default();
+            (0..n as u64).for_each(|i| h.push(&sample(i, i)));
+
+            prop_assert_eq!(h.len(), n.min(INF_PRICE_HISTORY_CAP));
+            let slots: Vec<_> = h.iter_latest_first().map(|s| s.slot).collect();
+            let expected: Vec<_> = (0..n as u64).rev().take(INF_PRICE_HISTORY_CAP).collect();
+            prop_assert_eq!(slots, expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn twap_of_constant_is_constant(
+            price: u64,
+            slots in proptest::collection::btree_set(0..1_000_000u64, 1..INF_PRICE_HISTORY_CAP),
+            extra in 0..1_000u64,
+            window in 1..1_000_000u64,
+        ) {
+            let mut h = InfPriceHistory::default();
+            slots.iter().for_each(|s| h.push(&sample(*s, price)));
+
+            let oldest = *slots.first().unwrap();
+            let curr_slot = *slots.last().unwrap() + extra;
+            let res = h.twap(curr_slot, window);
+            if curr_slot.checked_sub(window).is_some_and(|start| start >= oldest) {
+                prop_assert_eq!(res, Some(InfTwap { sol_per_inf: price, total_sol_value: price }));
+            } else {
+                prop_assert_eq!(res, None);
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{
+    accounts::packed_list::{PackedList, PackedListMut},
+    typedefs::lst_calc_backing::LstCalcBacking,
+};
+
+/// Companion list to the LstStateList, keyed by mint.
+///
+/// Entries are not removed on `RemoveLst`, so this list may contain
+/// stale entries for mints that are no longer in the pool.
+/// `AddLst` overwrites any such stale entry if the LST is added again.
+pub type LstCalcBackingList<'a> = PackedList<'a, LstCalcBacking>;
+
+pub type LstCalcBackingListMut<'a> = PackedListMut<'a, LstCalcBacking>;
+
+impl LstCalcBackingList<'_> {
+    #[inline]
+    pub fn find_by_mint(&self, mint: &[u8; 32]) -> Option<&LstCalcBacking> {
+        self.0.iter().find(|s| s.mint == *mint)
+    }
+}
+
+impl LstCalcBackingListMut<'_> {
+    #[inline]
+    pub fn find_by_mint(&mut self, mint: &[u8; 32]) -> Option<&mut LstCalcBacking> {
+        self.0.iter_mut().find(|s| s.mint == *mint)
+    }
+}
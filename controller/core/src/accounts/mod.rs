@@ -1,5 +1,11 @@
 pub mod disable_pool_authority_list;
+pub mod inf_price_history;
+pub mod lst_calc_backing_list;
 pub mod lst_state_list;
 pub mod packed_list;
+pub mod pending_admin_action;
+pub mod pool_config;
 pub mod pool_state;
+pub mod protocol_fee_beneficiary_list;
 pub mod rebalance_record;
+pub mod referral_config;
@@ -0,0 +1,39 @@
+//! Singleton PDA recording the timelocked admin action currently queued.
+//!
+//! Only exists while an action is pending; closed on execution or cancellation.
+
+use crate::internal_utils::{impl_cast_from_acc_data, impl_cast_to_acc_data};
+
+pub const PENDING_ADMIN_ACTION_IX_DATA_LEN: usize = 16;
+
+pub const PENDING_ADMIN_ACTION_KEYS_LEN: usize = 3;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PendingAdminAction {
+    /// Earliest slot at which the action may be executed.
+    ///
+    /// Fixed on queue using the admin action delay at that time,
+    /// so later changes to the delay do not affect it.
+    pub earliest_execution_slot: u64,
+
+    /// Accounts of the queued instruction that the action is bound to,
+    /// see [`crate::instructions::admin::timelock::TimelockedIx::bound_keys_len`].
+    ///
+    /// Unused entries are zeroed.
+    pub keys: [[u8; 32]; PENDING_ADMIN_ACTION_KEYS_LEN],
+
+    /// Instruction data of the queued instruction, starting with its discriminant.
+    ///
+    /// Zero-padded.
+    pub ix_data: [u8; PENDING_ADMIN_ACTION_IX_DATA_LEN],
+}
+impl_cast_from_acc_data!(PendingAdminAction);
+impl_cast_to_acc_data!(PendingAdminAction);
+
+impl PendingAdminAction {
+    #[inline]
+    pub const fn ix_discm(&self) -> u8 {
+        self.ix_data[0]
+    }
+}
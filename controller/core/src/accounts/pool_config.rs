@@ -0,0 +1,57 @@
+//! Optional singleton PDA holding pool-level parameters
+//! that do not fit in `PoolState`'s fixed layout.
+//!
+//! All parameters take their default value if this PDA has not been created.
+
+use core::mem::size_of;
+
+use crate::internal_utils::{impl_cast_from_acc_data, impl_cast_to_acc_data};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PoolConfig {
+    /// Number of slots a timelocked admin action must wait between
+    /// being queued and being executed.
+    ///
+    /// Timelocked admin instructions may be called directly if this is 0.
+    pub admin_action_delay_slots: u64,
+
+    /// Maximum SOL value, in lamports, the pool may lose in a single rebalance.
+    /// The loss is absorbed from `withheld_lamports`
+    pub rebalance_max_loss_lamports: u64,
+}
+impl_cast_from_acc_data!(PoolConfig);
+impl_cast_to_acc_data!(PoolConfig);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PoolConfigPacked {
+    admin_action_delay_slots: [u8; 8],
+    rebalance_max_loss_lamports: [u8; 8],
+}
+impl_cast_from_acc_data!(PoolConfigPacked, packed);
+impl_cast_to_acc_data!(PoolConfigPacked, packed);
+
+impl PoolConfigPacked {
+    #[inline]
+    pub const fn into_pool_config(self) -> PoolConfig {
+        let Self {
+            admin_action_delay_slots,
+            rebalance_max_loss_lamports,
+        } = self;
+        PoolConfig {
+            admin_action_delay_slots: u64::from_le_bytes(admin_action_delay_slots),
+            rebalance_max_loss_lamports: u64::from_le_bytes(rebalance_max_loss_lamports),
+        }
+    }
+}
+
+impl From<PoolConfigPacked> for PoolConfig {
+    #[inline]
+    fn from(value: PoolConfigPacked) -> Self {
+        value.into_pool_config()
+    }
+}
+
+const _ASSERT_PACKED_UNPACKED_SIZES_EQ: () =
+    assert!(size_of::<PoolConfig>() == size_of::<PoolConfigPacked>());
@@ -9,6 +9,7 @@ use crate::{
         impl_cast_from_acc_data, impl_cast_to_acc_data, impl_gas_memset, impl_verify_vers,
     },
     typedefs::{
+        disable_modes::DisableModes,
         fee_nanos::{FeeNanos, FeeNanosTooLargeErr},
         rps::Rps,
         uq0f63::UQ0F63,
@@ -27,7 +28,11 @@ pub struct PoolStateV2 {
     pub version: u8,
     pub is_disabled: u8,
     pub is_rebalancing: u8,
-    pub padding: [u8; 1],
+    /// Bitfield of individually disabled operations,
+    /// see [`crate::typedefs::disable_modes`].
+    ///
+    /// Occupies what was `padding` in v1
+    pub disable_modes: u8,
     pub admin: [u8; 32],
     pub rebalance_authority: [u8; 32],
     pub protocol_fee_beneficiary: [u8; 32],
@@ -40,11 +45,31 @@ pub struct PoolStateV2 {
     pub withheld_lamports: u64,
     pub protocol_fee_lamports: u64,
     pub last_release_slot: u64,
+
+    /// Minimum fee, as a proportion of the input SOL value, that
+    /// swaps must charge regardless of the pricing program's output
+    pub min_fee_nanos: u32,
+
+    /// Max change in an LST's per-token SOL value a single sync may make
+    /// before input of the LST is automatically disabled,
+    /// relative to its previous value, in nanos.
+    ///
+    /// 0 means the circuit breaker is off.
+    pub sol_value_circuit_breaker_nanos: u32,
 }
 impl_cast_from_acc_data!(PoolStateV2);
 impl_cast_to_acc_data!(PoolStateV2);
 impl_verify_vers!(PoolStateV2, 2);
 
+/// Account data length of v2 pool states created before `min_fee_nanos`
+/// was appended.
+///
+/// Such accounts are extended to `size_of::<PoolStateV2>()`, with the
+/// appended bytes zeroed, by the same idempotent migration that migrates v1.
+pub const POOL_STATE_V2_INITIAL_LEN: usize = core::mem::offset_of!(PoolStateV2, min_fee_nanos);
+
+const _ASSERT_POOL_STATE_V2_INITIAL_LEN: () = assert!(POOL_STATE_V2_INITIAL_LEN == 240);
+
 impl PoolStateV2 {
     #[inline]
     pub const fn rps_checked(&self) -> Result<Rps, RpsOobErr> {
@@ -62,6 +87,30 @@ impl PoolStateV2 {
     pub const fn protocol_fee_nanos_checked(&self) -> Result<FeeNanos, FeeNanosTooLargeErr> {
         FeeNanos::new(self.protocol_fee_nanos)
     }
+
+    #[inline]
+    pub const fn disable_modes(&self) -> DisableModes {
+        DisableModes::new_truncated(self.disable_modes)
+    }
+
+    #[inline]
+    pub const fn min_fee_nanos_checked(&self) -> Result<FeeNanos, FeeNanosTooLargeErr> {
+        FeeNanos::new(self.min_fee_nanos)
+    }
+
+    /// # Returns
+    /// The SOL value the pool may currently lose in a single rebalance,
+    /// which is `max_loss_lamports`, the pool config's
+    /// `rebalance_max_loss_lamports`, capped at `withheld_lamports`
+    /// so that the loss never comes out of protocol fees or LPs
+    #[inline]
+    pub const fn rebalance_max_loss(&self, max_loss_lamports: u64) -> u64 {
+        if max_loss_lamports < self.withheld_lamports {
+            max_loss_lamports
+        } else {
+            self.withheld_lamports
+        }
+    }
 }
 
 #[repr(C)]
@@ -72,7 +121,7 @@ pub struct PoolStateV2Packed {
     version: u8,
     is_disabled: u8,
     is_rebalancing: u8,
-    padding: [u8; 1],
+    disable_modes: u8,
     admin: [u8; 32],
     rebalance_authority: [u8; 32],
     protocol_fee_beneficiary: [u8; 32],
@@ -83,12 +132,34 @@ pub struct PoolStateV2Packed {
     withheld_lamports: [u8; 8],
     protocol_fee_lamports: [u8; 8],
     last_release_slot: [u8; 8],
+    min_fee_nanos: [u8; 4],
+    sol_value_circuit_breaker_nanos: [u8; 4],
 }
 impl_cast_from_acc_data!(PoolStateV2Packed, packed);
 impl_cast_to_acc_data!(PoolStateV2Packed, packed);
 impl_verify_vers!(PoolStateV2Packed, 2);
 
 impl PoolStateV2Packed {
+    /// Reads a v2 pool state account that has not yet been extended,
+    /// with all fields appended after [`POOL_STATE_V2_INITIAL_LEN`] zeroed,
+    /// which is what the onchain migration sets them to.
+    ///
+    /// # Returns
+    /// `None` if `acc_data` is not [`POOL_STATE_V2_INITIAL_LEN`] long
+    #[inline]
+    pub const fn of_initial_acc_data(acc_data: &[u8]) -> Option<Self> {
+        if acc_data.len() != POOL_STATE_V2_INITIAL_LEN {
+            return None;
+        }
+        let mut buf = [0u8; size_of::<Self>()];
+        let mut i = 0;
+        while i < POOL_STATE_V2_INITIAL_LEN {
+            buf[i] = acc_data[i];
+            i += 1;
+        }
+        Some(*Self::of_acc_data_arr(&buf))
+    }
+
     #[inline]
     pub const fn into_pool_state_v2(self) -> PoolStateV2 {
         let Self {
@@ -97,7 +168,7 @@ impl PoolStateV2Packed {
             version,
             is_disabled,
             is_rebalancing,
-            padding,
+            disable_modes,
             admin,
             rebalance_authority,
             protocol_fee_beneficiary,
@@ -108,6 +179,8 @@ impl PoolStateV2Packed {
             last_release_slot,
             rps,
             rps_authority,
+            min_fee_nanos,
+            sol_value_circuit_breaker_nanos,
         } = self;
         PoolStateV2 {
             total_sol_value: u64::from_le_bytes(total_sol_value),
@@ -115,7 +188,7 @@ impl PoolStateV2Packed {
             version,
             is_disabled,
             is_rebalancing,
-            padding,
+            disable_modes,
             admin,
             rebalance_authority,
             protocol_fee_beneficiary,
@@ -126,6 +199,8 @@ impl PoolStateV2Packed {
             last_release_slot: u64::from_le_bytes(last_release_slot),
             rps: u64::from_le_bytes(rps),
             rps_authority,
+            min_fee_nanos: u32::from_le_bytes(min_fee_nanos),
+            sol_value_circuit_breaker_nanos: u32::from_le_bytes(sol_value_circuit_breaker_nanos),
         }
     }
 
@@ -195,15 +270,18 @@ impl_gas_memset!(PoolStateV2U8Bools, POOL_STATE_V2U8_BOOLS_LEN);
 // conversion functions
 /// Field-Type Aggregations
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct PoolStateV2Fta<A, U, V, W, X> {
+pub struct PoolStateV2Fta<A, U, V, W, X, Y, Z> {
     pub addrs: PoolStateV2Addrs<A>,
     pub u64s: PoolStateV2U64s<U>,
     pub u8_bools: PoolStateV2U8Bools<V>,
     pub protocol_fee_nanos: W,
     pub rps: X,
+    pub disable_modes: Y,
+    pub min_fee_nanos: W,
+    pub sol_value_circuit_breaker_nanos: Z,
 }
 
-pub type PoolStateV2FtaVals = PoolStateV2Fta<[u8; 32], u64, u8, FeeNanos, Rps>;
+pub type PoolStateV2FtaVals = PoolStateV2Fta<[u8; 32], u64, u8, FeeNanos, Rps, u8, u32>;
 
 impl PoolStateV2FtaVals {
     #[inline]
@@ -214,6 +292,9 @@ impl PoolStateV2FtaVals {
             u8_bools,
             protocol_fee_nanos,
             rps,
+            disable_modes,
+            min_fee_nanos,
+            sol_value_circuit_breaker_nanos,
         } = self;
         PoolStateV2 {
             total_sol_value: *u64s.total_sol_value(),
@@ -221,7 +302,7 @@ impl PoolStateV2FtaVals {
             version: 2u8,
             is_disabled: *u8_bools.is_disabled(),
             is_rebalancing: *u8_bools.is_rebalancing(),
-            padding: [0u8],
+            disable_modes,
             admin: *addrs.admin(),
             rebalance_authority: *addrs.rebalance_authority(),
             protocol_fee_beneficiary: *addrs.protocol_fee_beneficiary(),
@@ -232,6 +313,8 @@ impl PoolStateV2FtaVals {
             withheld_lamports: *u64s.withheld_lamports(),
             protocol_fee_lamports: *u64s.protocol_fee_lamports(),
             last_release_slot: *u64s.last_release_slot(),
+            min_fee_nanos: min_fee_nanos.get(),
+            sol_value_circuit_breaker_nanos,
         }
     }
 
@@ -243,6 +326,7 @@ impl PoolStateV2FtaVals {
             total_sol_value,
             is_disabled,
             is_rebalancing,
+            disable_modes,
             admin,
             rebalance_authority,
             protocol_fee_beneficiary,
@@ -252,11 +336,12 @@ impl PoolStateV2FtaVals {
             withheld_lamports,
             protocol_fee_lamports,
             last_release_slot,
+            sol_value_circuit_breaker_nanos,
             // explicitly list out unused fields to make sure we didnt miss any
             protocol_fee_nanos: _,
             version: _,
-            padding: _,
             rps: _,
+            min_fee_nanos: _,
         } = ps;
         Ok(Self {
             addrs: PoolStateV2Addrs::memset([0; 32])
@@ -282,6 +367,12 @@ impl PoolStateV2FtaVals {
                 Err(e) => return Err(InvalidPoolStateDataErrV2::Rps(e)),
                 Ok(x) => x,
             },
+            disable_modes,
+            min_fee_nanos: match ps.min_fee_nanos_checked() {
+                Err(e) => return Err(InvalidPoolStateDataErrV2::MinFeeNanos(e)),
+                Ok(x) => x,
+            },
+            sol_value_circuit_breaker_nanos,
         })
     }
 }
@@ -323,7 +414,11 @@ aou!(_TOTAL_SOL_VALUE, total_sol_value);
 aou!(_VERSION, version);
 aou!(_IS_DISABLED, is_disabled);
 aou!(_IS_REBALANCING, is_rebalancing);
-aou!(_PADDING, padding);
+
+const _DISABLE_MODES_IN_V1_PADDING: () = assert!(
+    core::mem::offset_of!(PoolStateV2, disable_modes) == core::mem::offset_of!(PoolState, padding)
+);
+
 aou!(_ADMIN, admin);
 aou!(_REBALANCE_AUTH, rebalance_authority);
 aou!(_PROTOCOL_FEE_BENEFICIARY, protocol_fee_beneficiary);
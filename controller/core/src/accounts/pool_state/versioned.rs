@@ -1,12 +1,19 @@
 use crate::{
     accounts::pool_state::{PoolState, PoolStatePacked, PoolStateV2, PoolStateV2Packed},
-    typedefs::{fee_nanos::NANOS_DENOM, rps::Rps, versioned::V1_2},
+    typedefs::{
+        disable_modes::DisableModes,
+        fee_nanos::{FeeNanos, FeeNanosTooLargeErr, NANOS_DENOM},
+        rps::Rps,
+        versioned::V1_2,
+    },
     v1_2_each_field, v1_2_each_field_mut, v1_2_each_meth,
 };
 
 pub type VerPoolState = V1_2<PoolState, PoolStateV2>;
 
 impl VerPoolState {
+    /// V2 accounts of [`super::POOL_STATE_V2_INITIAL_LEN`] that have not yet been
+    /// extended are read with their appended fields zeroed
     #[inline]
     pub const fn try_from_acc_data(data: &[u8]) -> Option<Self> {
         if let Some(p) = PoolStatePacked::of_acc_data(data) {
@@ -14,7 +21,10 @@ impl VerPoolState {
         } else {
             match PoolStateV2Packed::of_acc_data(data) {
                 Some(p) => Some(Self::V2(p.into_pool_state_v2())),
-                None => None,
+                None => match PoolStateV2Packed::of_initial_acc_data(data) {
+                    Some(p) => Some(Self::V2(p.into_pool_state_v2())),
+                    None => None,
+                },
             }
         }
     }
@@ -39,6 +49,48 @@ impl VerPoolState {
         v1_2_each_field!(self, rebalance_authority)
     }
 
+    #[inline]
+    pub const fn protocol_fee_beneficiary(&self) -> &[u8; 32] {
+        v1_2_each_field!(self, protocol_fee_beneficiary)
+    }
+
+    #[inline]
+    pub const fn is_rebalancing(&self) -> &u8 {
+        v1_2_each_field!(self, is_rebalancing)
+    }
+
+    #[inline]
+    pub const fn is_disabled(&self) -> &u8 {
+        v1_2_each_field!(self, is_disabled)
+    }
+
+    /// V1 pool states have no disable modes, its padding byte is ignored
+    #[inline]
+    pub const fn disable_modes(&self) -> DisableModes {
+        match self {
+            Self::V1(_) => DisableModes::NONE,
+            Self::V2(p) => p.disable_modes(),
+        }
+    }
+
+    /// V1 pool states have no minimum fee
+    #[inline]
+    pub const fn min_fee_nanos_checked(&self) -> Result<FeeNanos, FeeNanosTooLargeErr> {
+        match self {
+            Self::V1(_) => Ok(FeeNanos::ZERO),
+            Self::V2(p) => p.min_fee_nanos_checked(),
+        }
+    }
+
+    /// V1 pool states have no withheld lamports to absorb rebalance losses from
+    #[inline]
+    pub const fn rebalance_max_loss(&self, max_loss_lamports: u64) -> u64 {
+        match self {
+            Self::V1(_) => 0,
+            Self::V2(p) => p.rebalance_max_loss(max_loss_lamports),
+        }
+    }
+
     #[inline]
     pub const fn is_rebalancing_mut(&mut self) -> &mut u8 {
         v1_2_each_field_mut!(self, is_rebalancing)
@@ -64,18 +116,18 @@ impl VerPoolState {
                 lp_protocol_fee_bps,
                 is_disabled,
                 is_rebalancing,
-                padding,
                 admin,
                 rebalance_authority,
                 protocol_fee_beneficiary,
                 pricing_program,
                 lp_token_mint,
                 version: _,
+                padding: _,
             }) => PoolStateV2 {
                 total_sol_value,
                 is_disabled,
                 is_rebalancing,
-                padding,
+                disable_modes: 0,
                 admin,
                 rebalance_authority,
                 protocol_fee_beneficiary,
@@ -91,6 +143,8 @@ impl VerPoolState {
                 version: 2,
                 withheld_lamports: 0,
                 protocol_fee_lamports: 0,
+                min_fee_nanos: 0,
+                sol_value_circuit_breaker_nanos: 0,
             },
         }
     }
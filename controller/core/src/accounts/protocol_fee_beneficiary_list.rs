@@ -0,0 +1,125 @@
+//! List of protocol fee beneficiaries that protocol fees are
+//! split between with `WithdrawProtocolFeesSplit`.
+//!
+//! While the list has any entries, `PoolStateV2.protocol_fee_beneficiary`
+//! can no longer withdraw all protocol fees to itself with
+//! `WithdrawProtocolFeesV2`.
+
+use crate::{
+    accounts::packed_list::{PackedList, PackedListMut},
+    typedefs::protocol_fee_beneficiary_share::ProtocolFeeBeneficiaryShare,
+};
+
+/// Max number of entries the list can hold so that all beneficiaries'
+/// INF token accounts fit in a single `WithdrawProtocolFeesSplit` instruction
+pub const MAX_PROTOCOL_FEE_BENEFICIARIES: usize = 16;
+
+pub type ProtocolFeeBeneficiaryList<'a> = PackedList<'a, ProtocolFeeBeneficiaryShare>;
+
+pub type ProtocolFeeBeneficiaryListMut<'a> = PackedListMut<'a, ProtocolFeeBeneficiaryShare>;
+
+impl ProtocolFeeBeneficiaryList<'_> {
+    #[inline]
+    pub fn find_by_beneficiary(
+        &self,
+        beneficiary: &[u8; 32],
+    ) -> Option<&ProtocolFeeBeneficiaryShare> {
+        self.0.iter().find(|s| s.beneficiary == *beneficiary)
+    }
+
+    #[inline]
+    pub fn total_weight(&self) -> u64 {
+        // cannot overflow: list would need > u32::MAX entries
+        self.0.iter().map(|s| u64::from(s.weight())).sum()
+    }
+
+    /// Splits `amt` between the entries of this list in proportion to their weights,
+    /// returning each entry's share in list order.
+    ///
+    /// Each share is rounded down. The rounding dust, which is always < list length,
+    /// is given to the first entry.
+    ///
+    /// Returns `None` if the total weight of the list is 0
+    #[inline]
+    pub fn split(&self, amt: u64) -> Option<impl Iterator<Item = u64> + '_> {
+        let total = self.total_weight();
+        if total == 0 {
+            return None;
+        }
+        // cannot underflow: sum of floored shares <= amt
+        let dust = amt
+            - self
+                .0
+                .iter()
+                .map(|s| share_floor(amt, s.weight(), total))
+                .sum::<u64>();
+        Some(self.0.iter().enumerate().map(move |(i, s)| {
+            let share = share_floor(amt, s.weight(), total);
+            if i == 0 {
+                share + dust
+            } else {
+                share
+            }
+        }))
+    }
+}
+
+/// `floor(amt * weight / total)`
+///
+/// # Params
+/// - `total` must be nonzero and >= `weight`
+#[inline]
+const fn share_floor(amt: u64, weight: u32, total: u64) -> u64 {
+    // as-safety: result <= amt since weight <= total
+    ((amt as u128 * weight as u128) / total as u128) as u64
+}
+
+impl ProtocolFeeBeneficiaryListMut<'_> {
+    #[inline]
+    pub fn find_by_beneficiary(
+        &mut self,
+        beneficiary: &[u8; 32],
+    ) -> Option<&mut ProtocolFeeBeneficiaryShare> {
+        self.0.iter_mut().find(|s| s.beneficiary == *beneficiary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn list_strat() -> impl Strategy<Value = Vec<ProtocolFeeBeneficiaryShare>> {
+        prop::collection::vec(
+            (any::<[u8; 32]>(), any::<u32>())
+                .prop_map(|(b, w)| ProtocolFeeBeneficiaryShare::new(b, w)),
+            1..=16,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn split_sums_to_amt_and_dust_to_first(amt: u64, entries in list_strat()) {
+            let list = PackedList(entries.as_slice());
+            let total = list.total_weight();
+            match list.split(amt) {
+                None => prop_assert_eq!(total, 0),
+                Some(shares) => {
+                    let shares: Vec<_> = shares.collect();
+                    prop_assert_eq!(shares.len(), entries.len());
+                    prop_assert_eq!(shares.iter().map(|s| u128::from(*s)).sum::<u128>(), u128::from(amt));
+                    for (i, (share, entry)) in shares.iter().zip(&entries).enumerate() {
+                        let floor = share_floor(amt, entry.weight(), total);
+                        if i == 0 {
+                            prop_assert!(*share >= floor);
+                            prop_assert!(*share - floor < entries.len() as u64);
+                        } else {
+                            prop_assert_eq!(*share, floor);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -8,6 +8,9 @@ pub struct RebalanceRecord {
     pub old_total_sol_value: u64,
     pub inp_lst_index: u32,
     pub padding: [u8; 4],
+
+    /// The pool config's `rebalance_max_loss_lamports` at StartRebalance
+    pub rebalance_max_loss_lamports: u64,
 }
 impl_cast_from_acc_data!(RebalanceRecord);
 impl_cast_to_acc_data!(RebalanceRecord);
@@ -18,6 +21,7 @@ pub struct RebalanceRecordPacked {
     old_total_sol_value: [u8; 8],
     inp_lst_index: [u8; 4],
     padding: [u8; 4],
+    rebalance_max_loss_lamports: [u8; 8],
 }
 impl_cast_from_acc_data!(RebalanceRecordPacked, packed);
 impl_cast_to_acc_data!(RebalanceRecordPacked, packed);
@@ -29,11 +33,13 @@ impl RebalanceRecordPacked {
             old_total_sol_value,
             inp_lst_index,
             padding,
+            rebalance_max_loss_lamports,
         } = self;
         RebalanceRecord {
             old_total_sol_value: u64::from_le_bytes(old_total_sol_value),
             inp_lst_index: u32::from_le_bytes(inp_lst_index),
             padding,
+            rebalance_max_loss_lamports: u64::from_le_bytes(rebalance_max_loss_lamports),
         }
     }
 
@@ -0,0 +1,24 @@
+//! Optional singleton PDA configuring the share of V2 swap fees
+//! paid to referrers. Referrers are paid nothing if this PDA
+//! has not been created.
+
+use crate::{
+    internal_utils::{impl_cast_from_acc_data, impl_cast_to_acc_data},
+    typedefs::fee_nanos::{FeeNanos, FeeNanosTooLargeErr},
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReferralConfig {
+    /// Share of a swap's fee paid to its referrer
+    pub referral_fee_nanos: u32,
+}
+impl_cast_from_acc_data!(ReferralConfig);
+impl_cast_to_acc_data!(ReferralConfig);
+
+impl ReferralConfig {
+    #[inline]
+    pub const fn referral_fee_nanos_checked(&self) -> Result<FeeNanos, FeeNanosTooLargeErr> {
+        FeeNanos::new(self.referral_fee_nanos)
+    }
+}
@@ -1,7 +1,8 @@
 use core::{error::Error, fmt::Display};
 
 use crate::typedefs::{
-    fee_nanos::FeeNanosTooLargeErr, rps::RpsTooSmallErr, uq0f63::UQ0F63TooLargeErr,
+    disable_modes::DisableMode, fee_nanos::FeeNanosTooLargeErr, rps::RpsTooSmallErr,
+    uq0f63::UQ0F63TooLargeErr,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -51,6 +52,22 @@ pub enum Inf1CtlErr {
     InvalidPoolStateDataV2(InvalidPoolStateDataErrV2),
     TimeWentBackwards,
     UnauthorizedSetRpsAuthoritySigner,
+    InvalidInfPriceHistoryData,
+    InvalidReferralConfigData,
+    InvalidProtocolFeeBeneficiaryListData,
+    InvalidProtocolFeeBeneficiaryIndex,
+    TooManyProtocolFeeBeneficiaries,
+    PoolOperationDisabled(DisableMode),
+    AdminActionTimelocked,
+    AdminActionNotReady,
+    AdminActionAlreadyQueued,
+    InvalidPendingAdminActionData,
+    InvalidPoolConfigData,
+    LstSolValueCircuitBreakerTripped,
+    FeeBelowMin,
+    InvalidLstCalcBackingListData,
+    RemoveLiquidityReferral,
+    ProtocolFeeBeneficiaryListNotEmpty,
 }
 
 impl Display for Inf1CtlErr {
@@ -97,9 +114,27 @@ impl Display for Inf1CtlErr {
             | SwapSameLst
             | DuplicateDisablePoolAuthority
             | UnauthorizedSetRpsAuthoritySigner
+            | InvalidInfPriceHistoryData
+            | InvalidReferralConfigData
+            | InvalidProtocolFeeBeneficiaryListData
+            | InvalidProtocolFeeBeneficiaryIndex
+            | TooManyProtocolFeeBeneficiaries
+            | AdminActionTimelocked
+            | AdminActionNotReady
+            | AdminActionAlreadyQueued
+            | InvalidPendingAdminActionData
+            | InvalidPoolConfigData
+            | LstSolValueCircuitBreakerTripped
+            | FeeBelowMin
+            | InvalidLstCalcBackingListData
+            | RemoveLiquidityReferral
+            | ProtocolFeeBeneficiaryListNotEmpty
             | TimeWentBackwards => core::fmt::Debug::fmt(self, f),
             WrongPoolStateVers(e) => f.write_fmt(format_args!("WrongPoolStateVers. {e}")),
             InvalidPoolStateDataV2(e) => e.fmt(f),
+            PoolOperationDisabled(m) => {
+                f.write_fmt(format_args!("PoolOperationDisabled. {}", m.as_str()))
+            }
         }
     }
 }
@@ -128,6 +163,7 @@ impl Error for WrongVersErr {}
 pub enum InvalidPoolStateDataErrV2 {
     Rps(RpsOobErr),
     ProtocolFeeNanos(FeeNanosTooLargeErr),
+    MinFeeNanos(FeeNanosTooLargeErr),
 }
 
 impl Display for InvalidPoolStateDataErrV2 {
@@ -136,6 +172,7 @@ impl Display for InvalidPoolStateDataErrV2 {
         match self {
             Self::Rps(e) => f.write_fmt(format_args!("RpsOob. {e}")),
             Self::ProtocolFeeNanos(e) => f.write_fmt(format_args!("ProtocolFeeNanosOob. {e}")),
+            Self::MinFeeNanos(e) => f.write_fmt(format_args!("MinFeeNanosOob. {e}")),
         }
     }
 }
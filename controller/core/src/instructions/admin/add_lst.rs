@@ -43,6 +43,13 @@ pub struct AddLstIxAccs<T> {
 
     /// Token program of the new LST to add
     pub lst_token_program: T,
+
+    /// Dynamic list PDA of the accounts backing each LST's SOL value calculator
+    pub lst_calc_backing_list: T,
+
+    /// Account backing the LST's SOL value calculator,
+    /// e.g. its stake pool account for SPL LSTs
+    pub sol_value_calculator_backing: T,
 }
 
 impl<T: Copy> AddLstIxAccs<T> {
@@ -69,7 +76,8 @@ pub const ADD_LST_IX_IS_WRITER: AddLstIxAccFlags = AddLstIxAccFlags::memset(fals
     .const_with_payer(true)
     .const_with_pool_reserves(true)
     .const_with_protocol_fee_accumulator(true)
-    .const_with_lst_state_list(true);
+    .const_with_lst_state_list(true)
+    .const_with_lst_calc_backing_list(true);
 
 pub const ADD_LST_IX_IS_SIGNER: AddLstIxAccFlags = AddLstIxAccFlags::memset(false)
     .const_with_admin(true)
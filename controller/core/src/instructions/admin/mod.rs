@@ -2,5 +2,8 @@ pub mod add_lst;
 pub mod lst_input;
 pub mod remove_lst;
 pub mod set_admin;
+pub mod set_min_fee;
 pub mod set_pricing_prog;
 pub mod set_sol_value_calculator;
+pub mod set_sol_value_circuit_breaker;
+pub mod timelock;
@@ -16,6 +16,12 @@ pub struct SetAdminIxAccs<T> {
 
     /// The pool's state singleton PDA
     pub pool_state: T,
+
+    /// The pool config PDA. May not yet exist.
+    ///
+    /// This instruction may only be called directly if the admin action delay is 0,
+    /// otherwise it must go through the admin action timelock
+    pub pool_config: T,
 }
 
 impl<T: Copy> SetAdminIxAccs<T> {
@@ -34,8 +40,9 @@ pub type SetAdminIxAccFlags = SetAdminIxAccs<bool>;
 pub const SET_ADMIN_IX_IS_WRITER: SetAdminIxAccFlags =
     SetAdminIxAccFlags::memset(false).const_with_pool_state(true);
 
-pub const SET_ADMIN_IX_IS_SIGNER: SetAdminIxAccFlags =
-    SetAdminIxAccFlags::memset(true).const_with_pool_state(false);
+pub const SET_ADMIN_IX_IS_SIGNER: SetAdminIxAccFlags = SetAdminIxAccFlags::memset(true)
+    .const_with_pool_state(false)
+    .const_with_pool_config(false);
 
 // Data
 
@@ -0,0 +1,50 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::generic::U32IxData;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetMinFeeIxAccs<T> {
+    /// The pool's admin
+    pub admin: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The pool config PDA. May not yet exist.
+    ///
+    /// This instruction may only be called directly if the admin action delay is 0,
+    /// otherwise it must go through the admin action timelock
+    pub pool_config: T,
+}
+
+impl<T: Copy> SetMinFeeIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; SET_MIN_FEE_IX_ACCS_LEN])
+    }
+}
+
+pub type SetMinFeeIxKeys<'a> = SetMinFeeIxAccs<&'a [u8; 32]>;
+
+pub type SetMinFeeIxKeysOwned = SetMinFeeIxAccs<[u8; 32]>;
+
+pub type SetMinFeeIxAccFlags = SetMinFeeIxAccs<bool>;
+
+pub const SET_MIN_FEE_IX_IS_WRITER: SetMinFeeIxAccFlags =
+    SetMinFeeIxAccFlags::memset(false).const_with_pool_state(true);
+
+pub const SET_MIN_FEE_IX_IS_SIGNER: SetMinFeeIxAccFlags =
+    SetMinFeeIxAccFlags::memset(false).const_with_admin(true);
+
+// Data
+
+pub const SET_MIN_FEE_IX_DISCM: u8 = 41;
+
+/// Arg is the new `pool_state.min_fee_nanos`
+pub type SetMinFeeIxData = U32IxData<SET_MIN_FEE_IX_DISCM>;
+
+pub const SET_MIN_FEE_IX_DATA_LEN: usize = SetMinFeeIxData::DATA_LEN;
@@ -16,6 +16,12 @@ pub struct SetPricingProgIxAccs<T> {
 
     /// The pool's state singleton PDA
     pub pool_state: T,
+
+    /// The pool config PDA. May not yet exist.
+    ///
+    /// This instruction may only be called directly if the admin action delay is 0,
+    /// otherwise it must go through the admin action timelock
+    pub pool_config: T,
 }
 
 impl<T: Copy> SetPricingProgIxAccs<T> {
@@ -8,7 +8,9 @@ use crate::instructions::generic::U32IxData;
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct SetSolValueCalculatorIxPreAccs<T> {
-    /// The pool's admin
+    /// The pool's admin.
+    ///
+    /// Pays for rent if `lst_calc_backing_list` needs to be extended
     pub admin: T,
 
     /// Mint of the LST to set SOL value calculator for
@@ -18,12 +20,28 @@ pub struct SetSolValueCalculatorIxPreAccs<T> {
     pub pool_state: T,
 
     /// LST reserves token account of the pool.
-    ///
-    /// The LST's SOL value calculator program suffix accounts follow.
     pub pool_reserves: T,
 
     /// Dynamic list PDA of LstStates for each LST in the pool
     pub lst_state_list: T,
+
+    /// Dynamic list PDA of the accounts backing each LST's SOL value calculator
+    pub lst_calc_backing_list: T,
+
+    /// Account backing the LST's new SOL value calculator,
+    /// e.g. its stake pool account for SPL LSTs
+    pub sol_value_calculator_backing: T,
+
+    /// System program
+    pub system_program: T,
+
+    /// The pool config PDA. May not yet exist.
+    ///
+    /// This instruction may only be called directly if the admin action delay is 0,
+    /// otherwise it must go through the admin action timelock.
+    ///
+    /// The LST's SOL value calculator program suffix accounts follow.
+    pub pool_config: T,
 }
 
 impl<T: Copy> SetSolValueCalculatorIxPreAccs<T> {
@@ -48,8 +66,10 @@ impl<T> AsRef<[T]> for SetSolValueCalculatorIxPreAccs<T> {
 
 pub const SET_SOL_VALUE_CALC_IX_PRE_IS_WRITER: SetSolValueCalculatorIxPreAccFlags =
     SetSolValueCalculatorIxPreAccFlags::memset(false)
+        .const_with_admin(true)
         .const_with_pool_state(true)
-        .const_with_lst_state_list(true);
+        .const_with_lst_state_list(true)
+        .const_with_lst_calc_backing_list(true);
 
 pub const SET_SOL_VALUE_CALC_IX_PRE_IS_SIGNER: SetSolValueCalculatorIxPreAccFlags =
     SetSolValueCalculatorIxPreAccFlags::memset(false).const_with_admin(true);
@@ -0,0 +1,46 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::generic::U32IxData;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetSolValueCircuitBreakerIxAccs<T> {
+    /// The pool's admin
+    pub admin: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+}
+
+impl<T: Copy> SetSolValueCircuitBreakerIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; SET_SOL_VALUE_CIRCUIT_BREAKER_IX_ACCS_LEN])
+    }
+}
+
+pub type SetSolValueCircuitBreakerIxKeys<'a> = SetSolValueCircuitBreakerIxAccs<&'a [u8; 32]>;
+
+pub type SetSolValueCircuitBreakerIxKeysOwned = SetSolValueCircuitBreakerIxAccs<[u8; 32]>;
+
+pub type SetSolValueCircuitBreakerIxAccFlags = SetSolValueCircuitBreakerIxAccs<bool>;
+
+pub const SET_SOL_VALUE_CIRCUIT_BREAKER_IX_IS_WRITER: SetSolValueCircuitBreakerIxAccFlags =
+    SetSolValueCircuitBreakerIxAccFlags::memset(false).const_with_pool_state(true);
+
+pub const SET_SOL_VALUE_CIRCUIT_BREAKER_IX_IS_SIGNER: SetSolValueCircuitBreakerIxAccFlags =
+    SetSolValueCircuitBreakerIxAccFlags::memset(false).const_with_admin(true);
+
+// Data
+
+pub const SET_SOL_VALUE_CIRCUIT_BREAKER_IX_DISCM: u8 = 40;
+
+/// Arg is the new `pool_state.sol_value_circuit_breaker_nanos`.
+/// 0 turns the circuit breaker off.
+pub type SetSolValueCircuitBreakerIxData = U32IxData<SET_SOL_VALUE_CIRCUIT_BREAKER_IX_DISCM>;
+
+pub const SET_SOL_VALUE_CIRCUIT_BREAKER_IX_DATA_LEN: usize =
+    SetSolValueCircuitBreakerIxData::DATA_LEN;
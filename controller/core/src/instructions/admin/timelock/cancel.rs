@@ -0,0 +1,51 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::generic::DiscmOnlyIxData;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct CancelAdminActionIxAccs<T> {
+    /// The pool's admin
+    pub admin: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The pending admin action PDA. Closed on success
+    pub pending_admin_action: T,
+
+    /// Account to refund the pending admin action PDA's rent to
+    pub refund_rent_to: T,
+}
+
+impl<T: Copy> CancelAdminActionIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; CANCEL_ADMIN_ACTION_IX_ACCS_LEN])
+    }
+}
+
+pub type CancelAdminActionIxKeys<'a> = CancelAdminActionIxAccs<&'a [u8; 32]>;
+
+pub type CancelAdminActionIxKeysOwned = CancelAdminActionIxAccs<[u8; 32]>;
+
+pub type CancelAdminActionIxAccFlags = CancelAdminActionIxAccs<bool>;
+
+pub const CANCEL_ADMIN_ACTION_IX_IS_WRITER: CancelAdminActionIxAccFlags =
+    CancelAdminActionIxAccFlags::memset(false)
+        .const_with_pending_admin_action(true)
+        .const_with_refund_rent_to(true);
+
+pub const CANCEL_ADMIN_ACTION_IX_IS_SIGNER: CancelAdminActionIxAccFlags =
+    CancelAdminActionIxAccFlags::memset(false).const_with_admin(true);
+
+// Data
+
+pub const CANCEL_ADMIN_ACTION_IX_DISCM: u8 = 38;
+
+pub type CancelAdminActionIxData = DiscmOnlyIxData<CANCEL_ADMIN_ACTION_IX_DISCM>;
+
+pub const CANCEL_ADMIN_ACTION_IX_DATA_LEN: usize = CancelAdminActionIxData::DATA_LEN;
@@ -0,0 +1,53 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::generic::DiscmOnlyIxData;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ExecuteAdminActionIxPreAccs<T> {
+    /// The pending admin action PDA. Closed on success
+    pub pending_admin_action: T,
+
+    /// Account to refund the pending admin action PDA's rent to.
+    ///
+    /// The queued instruction's accounts follow, exactly as if it
+    /// were called directly.
+    pub refund_rent_to: T,
+}
+
+impl<T: Copy> ExecuteAdminActionIxPreAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; EXECUTE_ADMIN_ACTION_IX_PRE_ACCS_LEN])
+    }
+}
+
+impl<T> AsRef<[T]> for ExecuteAdminActionIxPreAccs<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+pub type ExecuteAdminActionIxPreKeys<'a> = ExecuteAdminActionIxPreAccs<&'a [u8; 32]>;
+
+pub type ExecuteAdminActionIxPreKeysOwned = ExecuteAdminActionIxPreAccs<[u8; 32]>;
+
+pub type ExecuteAdminActionIxPreAccFlags = ExecuteAdminActionIxPreAccs<bool>;
+
+pub const EXECUTE_ADMIN_ACTION_IX_PRE_IS_WRITER: ExecuteAdminActionIxPreAccFlags =
+    ExecuteAdminActionIxPreAccFlags::memset(true);
+
+pub const EXECUTE_ADMIN_ACTION_IX_PRE_IS_SIGNER: ExecuteAdminActionIxPreAccFlags =
+    ExecuteAdminActionIxPreAccFlags::memset(false);
+
+// Data
+
+pub const EXECUTE_ADMIN_ACTION_IX_DISCM: u8 = 37;
+
+pub type ExecuteAdminActionIxData = DiscmOnlyIxData<EXECUTE_ADMIN_ACTION_IX_DISCM>;
+
+pub const EXECUTE_ADMIN_ACTION_IX_DATA_LEN: usize = ExecuteAdminActionIxData::DATA_LEN;
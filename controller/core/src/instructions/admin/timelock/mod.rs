@@ -0,0 +1,99 @@
+//! Queue-then-execute mechanism for admin instructions that
+//! change how the pool prices or values its assets, or who controls it.
+//!
+//! While [`crate::accounts::pool_config::PoolConfig::admin_action_delay_slots`]
+//! is nonzero, the instructions in [`TimelockedIx`] cannot be called directly
+//! and must instead be queued with [`queue`], then executed with [`execute`]
+//! once the delay has elapsed.
+
+use crate::instructions::{
+    admin::{
+        set_admin::{SET_ADMIN_IX_DATA_LEN, SET_ADMIN_IX_DISCM},
+        set_min_fee::{SET_MIN_FEE_IX_DATA_LEN, SET_MIN_FEE_IX_DISCM},
+        set_pricing_prog::{SET_PRICING_PROG_IX_DATA_LEN, SET_PRICING_PROG_IX_DISCM},
+        set_sol_value_calculator::{SET_SOL_VALUE_CALC_IX_DATA_LEN, SET_SOL_VALUE_CALC_IX_DISCM},
+    },
+    protocol_fee::set_protocol_fee::{SET_PROTOCOL_FEE_IX_DATA_LEN, SET_PROTOCOL_FEE_IX_DISCM},
+};
+
+pub mod cancel;
+pub mod execute;
+pub mod queue;
+pub mod set_admin_action_delay;
+
+use set_admin_action_delay::{SET_ADMIN_ACTION_DELAY_IX_DATA_LEN, SET_ADMIN_ACTION_DELAY_IX_DISCM};
+
+/// An instruction subject to the admin action timelock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum TimelockedIx {
+    SetSolValueCalculator = SET_SOL_VALUE_CALC_IX_DISCM,
+    SetAdmin = SET_ADMIN_IX_DISCM,
+    SetProtocolFee = SET_PROTOCOL_FEE_IX_DISCM,
+    SetPricingProg = SET_PRICING_PROG_IX_DISCM,
+    SetMinFee = SET_MIN_FEE_IX_DISCM,
+
+    /// Only when decreasing the delay
+    SetAdminActionDelay = SET_ADMIN_ACTION_DELAY_IX_DISCM,
+}
+
+impl TimelockedIx {
+    #[inline]
+    pub const fn from_discm(discm: u8) -> Option<Self> {
+        Some(match discm {
+            SET_SOL_VALUE_CALC_IX_DISCM => Self::SetSolValueCalculator,
+            SET_ADMIN_IX_DISCM => Self::SetAdmin,
+            SET_PROTOCOL_FEE_IX_DISCM => Self::SetProtocolFee,
+            SET_PRICING_PROG_IX_DISCM => Self::SetPricingProg,
+            SET_MIN_FEE_IX_DISCM => Self::SetMinFee,
+            SET_ADMIN_ACTION_DELAY_IX_DISCM => Self::SetAdminActionDelay,
+            _ => return None,
+        })
+    }
+
+    #[inline]
+    pub const fn discm(self) -> u8 {
+        self as u8
+    }
+
+    /// Length of the instruction's data, including discriminant
+    #[inline]
+    pub const fn ix_data_len(self) -> usize {
+        match self {
+            Self::SetSolValueCalculator => SET_SOL_VALUE_CALC_IX_DATA_LEN,
+            Self::SetAdmin => SET_ADMIN_IX_DATA_LEN,
+            Self::SetProtocolFee => SET_PROTOCOL_FEE_IX_DATA_LEN,
+            Self::SetPricingProg => SET_PRICING_PROG_IX_DATA_LEN,
+            Self::SetMinFee => SET_MIN_FEE_IX_DATA_LEN,
+            Self::SetAdminActionDelay => SET_ADMIN_ACTION_DELAY_IX_DATA_LEN,
+        }
+    }
+
+    /// Number of the instruction's accounts that a queued action is bound to,
+    /// stored in order at the start of
+    /// [`crate::accounts::pending_admin_action::PendingAdminAction::keys`]:
+    /// - `SetSolValueCalculator`: `[lst_mint, sol_value_calculator_backing, calculator program]`
+    /// - `SetAdmin`: `[new]`
+    /// - `SetPricingProg`: `[new]`
+    /// - others: none, everything is in instruction data
+    #[inline]
+    pub const fn bound_keys_len(self) -> usize {
+        match self {
+            Self::SetSolValueCalculator => 3,
+            Self::SetAdmin | Self::SetPricingProg => 1,
+            Self::SetProtocolFee | Self::SetMinFee | Self::SetAdminActionDelay => 0,
+        }
+    }
+
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::SetSolValueCalculator => "SetSolValueCalculator",
+            Self::SetAdmin => "SetAdmin",
+            Self::SetProtocolFee => "SetProtocolFee",
+            Self::SetPricingProg => "SetPricingProg",
+            Self::SetMinFee => "SetMinFee",
+            Self::SetAdminActionDelay => "SetAdminActionDelay",
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::{
+    accounts::pending_admin_action::{
+        PENDING_ADMIN_ACTION_IX_DATA_LEN, PENDING_ADMIN_ACTION_KEYS_LEN,
+    },
+    instructions::{
+        admin::timelock::TimelockedIx,
+        internal_utils::{caba, csba},
+    },
+};
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct QueueAdminActionIxAccs<T> {
+    /// The pool's admin
+    pub admin: T,
+
+    /// Pays for the pending admin action PDA's rent
+    pub payer: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The pool config PDA. May not yet exist
+    pub pool_config: T,
+
+    /// The pending admin action PDA. Must not yet exist
+    pub pending_admin_action: T,
+
+    /// System program
+    pub system_program: T,
+}
+
+impl<T: Copy> QueueAdminActionIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; QUEUE_ADMIN_ACTION_IX_ACCS_LEN])
+    }
+}
+
+pub type QueueAdminActionIxKeys<'a> = QueueAdminActionIxAccs<&'a [u8; 32]>;
+
+pub type QueueAdminActionIxKeysOwned = QueueAdminActionIxAccs<[u8; 32]>;
+
+pub type QueueAdminActionIxAccFlags = QueueAdminActionIxAccs<bool>;
+
+pub const QUEUE_ADMIN_ACTION_IX_IS_WRITER: QueueAdminActionIxAccFlags =
+    QueueAdminActionIxAccFlags::memset(false)
+        .const_with_payer(true)
+        .const_with_pending_admin_action(true);
+
+pub const QUEUE_ADMIN_ACTION_IX_IS_SIGNER: QueueAdminActionIxAccFlags =
+    QueueAdminActionIxAccFlags::memset(false)
+        .const_with_admin(true)
+        .const_with_payer(true);
+
+// Data
+
+pub const QUEUE_ADMIN_ACTION_IX_DISCM: u8 = 36;
+
+pub const QUEUE_ADMIN_ACTION_IX_DATA_LEN: usize =
+    1 + PENDING_ADMIN_ACTION_IX_DATA_LEN + 32 * PENDING_ADMIN_ACTION_KEYS_LEN;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QueueAdminActionIxArgs {
+    /// Instruction data of the instruction to queue, starting with its discriminant.
+    ///
+    /// Zero-padded.
+    pub ix_data: [u8; PENDING_ADMIN_ACTION_IX_DATA_LEN],
+
+    /// Accounts of the instruction to queue that the action is bound to.
+    ///
+    /// Unused entries must be zeroed.
+    pub keys: [[u8; 32]; PENDING_ADMIN_ACTION_KEYS_LEN],
+}
+
+impl QueueAdminActionIxArgs {
+    /// # Returns
+    /// `None` if `ix_data` or `keys` are too long
+    #[inline]
+    pub fn new(ix_data: &[u8], keys: &[[u8; 32]]) -> Option<Self> {
+        let mut res = Self::default();
+        res.ix_data
+            .get_mut(..ix_data.len())?
+            .copy_from_slice(ix_data);
+        res.keys.get_mut(..keys.len())?.copy_from_slice(keys);
+        Some(res)
+    }
+
+    /// # Returns
+    /// The instruction to queue.
+    ///
+    /// `None` if `ix_data` does not start with the discriminant of a [`TimelockedIx`]
+    /// or `ix_data` or `keys` are not correctly zero-padded for it
+    #[inline]
+    pub fn timelocked_ix(&self) -> Option<TimelockedIx> {
+        let ix = TimelockedIx::from_discm(self.ix_data[0])?;
+        let data_padded = self.ix_data[ix.ix_data_len()..].iter().all(|b| *b == 0);
+        let keys_padded = self.keys[ix.bound_keys_len()..]
+            .iter()
+            .all(|k| *k == [0u8; 32]);
+        (data_padded && keys_padded).then_some(ix)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct QueueAdminActionIxData([u8; QUEUE_ADMIN_ACTION_IX_DATA_LEN]);
+
+impl QueueAdminActionIxData {
+    #[inline]
+    pub const fn new(
+        QueueAdminActionIxArgs {
+            ix_data,
+            keys: [k0, k1, k2],
+        }: &QueueAdminActionIxArgs,
+    ) -> Self {
+        const A: usize = QUEUE_ADMIN_ACTION_IX_DATA_LEN;
+
+        let mut d = [0u8; A];
+
+        d = caba::<A, 0, 1>(d, &[QUEUE_ADMIN_ACTION_IX_DISCM]);
+        d = caba::<A, 1, 16>(d, ix_data);
+        d = caba::<A, 17, 32>(d, k0);
+        d = caba::<A, 49, 32>(d, k1);
+        d = caba::<A, 81, 32>(d, k2);
+
+        Self(d)
+    }
+
+    #[inline]
+    pub const fn as_buf(&self) -> &[u8; QUEUE_ADMIN_ACTION_IX_DATA_LEN] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn parse_no_discm(
+        data: &[u8; QUEUE_ADMIN_ACTION_IX_DATA_LEN - 1],
+    ) -> QueueAdminActionIxArgs {
+        let (ix_data, rest) = csba::<112, 16, 96>(data);
+        let (k0, rest) = csba::<96, 32, 64>(rest);
+        let (k1, rest) = csba::<64, 32, 32>(rest);
+        let (k2, _) = csba::<32, 32, 0>(rest);
+
+        QueueAdminActionIxArgs {
+            ix_data: *ix_data,
+            keys: [*k0, *k1, *k2],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::instructions::admin::set_admin::SET_ADMIN_IX_DISCM;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_round_trip(
+            ix_data: [u8; PENDING_ADMIN_ACTION_IX_DATA_LEN],
+            keys: [[u8; 32]; PENDING_ADMIN_ACTION_KEYS_LEN],
+        ) {
+            let args = QueueAdminActionIxArgs { ix_data, keys };
+            let data = QueueAdminActionIxData::new(&args);
+            prop_assert_eq!(data.as_buf()[0], QUEUE_ADMIN_ACTION_IX_DISCM);
+            prop_assert_eq!(
+                QueueAdminActionIxData::parse_no_discm(data.as_buf()[1..].try_into().unwrap()),
+                args
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn timelocked_ix_rejects_bad_padding(
+            trailing in 1..=u8::MAX,
+            idx in 1..PENDING_ADMIN_ACTION_IX_DATA_LEN,
+            new_admin: [u8; 32],
+            extra_key in any::<[u8; 32]>().prop_filter("nonzero", |k| *k != [0u8; 32]),
+        ) {
+            let args = QueueAdminActionIxArgs::new(&[SET_ADMIN_IX_DISCM], &[new_admin]).unwrap();
+            prop_assert_eq!(args.timelocked_ix(), Some(TimelockedIx::SetAdmin));
+
+            let mut bad_data = args;
+            bad_data.ix_data[idx] = trailing;
+            prop_assert_eq!(bad_data.timelocked_ix(), None);
+
+            let mut bad_keys = args;
+            bad_keys.keys[1] = extra_key;
+            prop_assert_eq!(bad_keys.timelocked_ix(), None);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::internal_utils::caba;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetAdminActionDelayIxAccs<T> {
+    /// The pool's admin
+    pub admin: T,
+
+    /// Pays for the pool config PDA's rent if it has not yet been created
+    pub payer: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The pool config PDA, created if it does not yet exist
+    pub pool_config: T,
+
+    /// System program
+    pub system_program: T,
+}
+
+impl<T: Copy> SetAdminActionDelayIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; SET_ADMIN_ACTION_DELAY_IX_ACCS_LEN])
+    }
+}
+
+pub type SetAdminActionDelayIxKeys<'a> = SetAdminActionDelayIxAccs<&'a [u8; 32]>;
+
+pub type SetAdminActionDelayIxKeysOwned = SetAdminActionDelayIxAccs<[u8; 32]>;
+
+pub type SetAdminActionDelayIxAccFlags = SetAdminActionDelayIxAccs<bool>;
+
+pub const SET_ADMIN_ACTION_DELAY_IX_IS_WRITER: SetAdminActionDelayIxAccFlags =
+    SetAdminActionDelayIxAccFlags::memset(false)
+        .const_with_payer(true)
+        .const_with_pool_config(true);
+
+pub const SET_ADMIN_ACTION_DELAY_IX_IS_SIGNER: SetAdminActionDelayIxAccFlags =
+    SetAdminActionDelayIxAccFlags::memset(false)
+        .const_with_admin(true)
+        .const_with_payer(true);
+
+// Data
+
+pub const SET_ADMIN_ACTION_DELAY_IX_DISCM: u8 = 39;
+
+pub const SET_ADMIN_ACTION_DELAY_IX_DATA_LEN: usize = 9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetAdminActionDelayIxData([u8; SET_ADMIN_ACTION_DELAY_IX_DATA_LEN]);
+
+impl SetAdminActionDelayIxData {
+    #[inline]
+    pub const fn new(admin_action_delay_slots: u64) -> Self {
+        const A: usize = SET_ADMIN_ACTION_DELAY_IX_DATA_LEN;
+
+        let mut d = [0u8; A];
+
+        d = caba::<A, 0, 1>(d, &[SET_ADMIN_ACTION_DELAY_IX_DISCM]);
+        d = caba::<A, 1, 8>(d, &admin_action_delay_slots.to_le_bytes());
+
+        Self(d)
+    }
+
+    #[inline]
+    pub const fn as_buf(&self) -> &[u8; SET_ADMIN_ACTION_DELAY_IX_DATA_LEN] {
+        &self.0
+    }
+
+    /// Returns `admin_action_delay_slots` arg
+    #[inline]
+    pub const fn parse_no_discm(data: &[u8; 8]) -> u64 {
+        u64::from_le_bytes(*data)
+    }
+}
@@ -0,0 +1,54 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::generic::DiscmOnlyIxData;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct CrankInfPriceHistoryIxAccs<T> {
+    /// Pays for the INF price history PDA's rent if it has not yet been created
+    pub payer: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The pool's LP token mint (INF)
+    pub lp_token_mint: T,
+
+    /// The INF price history PDA, created if it does not yet exist
+    pub inf_price_history: T,
+
+    /// System program
+    pub system_program: T,
+}
+
+impl<T: Copy> CrankInfPriceHistoryIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; CRANK_INF_PRICE_HISTORY_IX_ACCS_LEN])
+    }
+}
+
+pub type CrankInfPriceHistoryIxKeys<'a> = CrankInfPriceHistoryIxAccs<&'a [u8; 32]>;
+
+pub type CrankInfPriceHistoryIxKeysOwned = CrankInfPriceHistoryIxAccs<[u8; 32]>;
+
+pub type CrankInfPriceHistoryIxAccFlags = CrankInfPriceHistoryIxAccs<bool>;
+
+pub const CRANK_INF_PRICE_HISTORY_IX_IS_WRITER: CrankInfPriceHistoryIxAccFlags =
+    CrankInfPriceHistoryIxAccFlags::memset(false)
+        .const_with_payer(true)
+        .const_with_inf_price_history(true);
+
+pub const CRANK_INF_PRICE_HISTORY_IX_IS_SIGNER: CrankInfPriceHistoryIxAccFlags =
+    CrankInfPriceHistoryIxAccFlags::memset(false).const_with_payer(true);
+
+// Data
+
+pub const CRANK_INF_PRICE_HISTORY_IX_DISCM: u8 = 29;
+
+pub type CrankInfPriceHistoryIxData = DiscmOnlyIxData<CRANK_INF_PRICE_HISTORY_IX_DISCM>;
+
+pub const CRANK_INF_PRICE_HISTORY_IX_DATA_LEN: usize = CrankInfPriceHistoryIxData::DATA_LEN;
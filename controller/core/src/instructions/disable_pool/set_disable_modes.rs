@@ -0,0 +1,78 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::{instructions::internal_utils::caba, typedefs::disable_modes::DisableModes};
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetDisableModesIxAccs<T> {
+    /// Either pool admin or
+    /// a disable pool authority.
+    ///
+    /// Only the pool admin may re-enable a disabled mode
+    pub signer: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The DisablePoolAuthority list singleton PDA
+    pub disable_pool_auth_list: T,
+}
+
+impl<T: Copy> SetDisableModesIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; SET_DISABLE_MODES_IX_ACCS_LEN])
+    }
+}
+
+pub type SetDisableModesIxKeys<'a> = SetDisableModesIxAccs<&'a [u8; 32]>;
+
+pub type SetDisableModesIxKeysOwned = SetDisableModesIxAccs<[u8; 32]>;
+
+pub type SetDisableModesIxAccFlags = SetDisableModesIxAccs<bool>;
+
+pub const SET_DISABLE_MODES_IX_IS_WRITER: SetDisableModesIxAccFlags =
+    SetDisableModesIxAccFlags::memset(false).const_with_pool_state(true);
+
+pub const SET_DISABLE_MODES_IX_IS_SIGNER: SetDisableModesIxAccFlags =
+    SetDisableModesIxAccFlags::memset(false).const_with_signer(true);
+
+// Data
+
+pub const SET_DISABLE_MODES_IX_DISCM: u8 = 35;
+
+pub const SET_DISABLE_MODES_IX_DATA_LEN: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetDisableModesIxData([u8; SET_DISABLE_MODES_IX_DATA_LEN]);
+
+impl SetDisableModesIxData {
+    #[inline]
+    pub const fn new(disable_modes: DisableModes) -> Self {
+        const A: usize = SET_DISABLE_MODES_IX_DATA_LEN;
+
+        let mut d = [0u8; A];
+
+        d = caba::<A, 0, 1>(d, &[SET_DISABLE_MODES_IX_DISCM]);
+        d = caba::<A, 1, 1>(d, &[disable_modes.get()]);
+
+        Self(d)
+    }
+
+    #[inline]
+    pub const fn as_buf(&self) -> &[u8; SET_DISABLE_MODES_IX_DATA_LEN] {
+        &self.0
+    }
+
+    /// Returns `disable_modes` arg, the new disable modes to set to.
+    ///
+    /// `None` if any unknown bits are set
+    #[inline]
+    pub const fn parse_no_discm([disable_modes]: &[u8; 1]) -> Option<DisableModes> {
+        DisableModes::new(*disable_modes)
+    }
+}
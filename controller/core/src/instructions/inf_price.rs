@@ -0,0 +1,130 @@
+//! Read-only instruction that returns INF's redemption rate
+//! with pending yield release applied via return data.
+//!
+//! Does not write to any account, so it can be CPI'd or simulated by anyone.
+
+use generic_array_struct::generic_array_struct;
+use sanctum_u64_ratio::Ratio;
+
+use crate::{
+    instructions::{
+        generic::DiscmOnlyIxData,
+        internal_utils::{caba, csba},
+    },
+    svc::InfCalc,
+};
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct InfPriceIxAccs<T> {
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The pool's LP token mint (INF)
+    pub lp_token_mint: T,
+}
+
+impl<T: Copy> InfPriceIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; INF_PRICE_IX_ACCS_LEN])
+    }
+}
+
+pub type InfPriceIxKeys<'a> = InfPriceIxAccs<&'a [u8; 32]>;
+
+pub type InfPriceIxKeysOwned = InfPriceIxAccs<[u8; 32]>;
+
+pub type InfPriceIxAccFlags = InfPriceIxAccs<bool>;
+
+pub const INF_PRICE_IX_IS_WRITER: InfPriceIxAccFlags = InfPriceIxAccFlags::memset(false);
+
+pub const INF_PRICE_IX_IS_SIGNER: InfPriceIxAccFlags = InfPriceIxAccFlags::memset(false);
+
+// Data
+
+pub const INF_PRICE_IX_DISCM: u8 = 28;
+
+pub type InfPriceIxData = DiscmOnlyIxData<INF_PRICE_IX_DISCM>;
+
+pub const INF_PRICE_IX_DATA_LEN: usize = InfPriceIxData::DATA_LEN;
+
+// Return data
+
+pub const INF_PRICE_RET_LEN: usize = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InfPriceRet {
+    /// Floor this ratio to convert an amount of INF to its SOL value,
+    /// exactly like [`InfCalc::inf_to_sol`]
+    pub inf_to_sol: Ratio<u64, u64>,
+
+    /// Pool's `withheld_lamports` remaining after yield release
+    pub withheld_lamports: u64,
+}
+
+impl InfPriceRet {
+    /// `calc` should have already had pending yield release applied
+    /// via [`InfCalc::lookahead`].
+    ///
+    /// # Returns
+    /// `None` if pool is insolvent for LPers
+    #[inline]
+    pub const fn from_inf_calc(calc: &InfCalc) -> Option<Self> {
+        let inf_to_sol = match calc.lp_due_over_supply() {
+            None => return None,
+            Some(r) => r.0,
+        };
+        Some(Self {
+            inf_to_sol,
+            withheld_lamports: *calc.pool_lamports.withheld(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct InfPriceRetData([u8; INF_PRICE_RET_LEN]);
+
+impl InfPriceRetData {
+    #[inline]
+    pub const fn new(
+        InfPriceRet {
+            inf_to_sol,
+            withheld_lamports,
+        }: &InfPriceRet,
+    ) -> Self {
+        const A: usize = INF_PRICE_RET_LEN;
+
+        let mut d = [0u8; A];
+
+        d = caba::<A, 0, 8>(d, &inf_to_sol.n.to_le_bytes());
+        d = caba::<A, 8, 8>(d, &inf_to_sol.d.to_le_bytes());
+        d = caba::<A, 16, 8>(d, &withheld_lamports.to_le_bytes());
+
+        Self(d)
+    }
+
+    #[inline]
+    pub const fn as_buf(&self) -> &[u8; INF_PRICE_RET_LEN] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn parse(data: &[u8; INF_PRICE_RET_LEN]) -> InfPriceRet {
+        let (n, rest) = csba::<24, 8, 16>(data);
+        let (d, rest) = csba::<16, 8, 8>(rest);
+        let (withheld_lamports, _) = csba::<8, 8, 0>(rest);
+
+        InfPriceRet {
+            inf_to_sol: Ratio {
+                n: u64::from_le_bytes(*n),
+                d: u64::from_le_bytes(*d),
+            },
+            withheld_lamports: u64::from_le_bytes(*withheld_lamports),
+        }
+    }
+}
@@ -1,11 +1,14 @@
 mod internal_utils;
 
 pub mod admin;
+pub mod crank_inf_price_history;
 pub mod disable_pool;
 pub mod generic;
+pub mod inf_price;
 pub mod liquidity;
 pub mod protocol_fee;
 pub mod rebalance;
+pub mod referral;
 pub mod rps;
 pub mod swap;
 pub mod sync_sol_value;
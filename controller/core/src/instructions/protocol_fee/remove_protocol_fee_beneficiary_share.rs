@@ -0,0 +1,65 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::generic::U32IxData;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct RemoveProtocolFeeBeneficiaryShareIxAccs<T> {
+    /// Account receiving lamports in excess of rent-exemption of
+    /// ProtocolFeeBeneficiaryList after shrinkage
+    pub refund_rent_to: T,
+
+    /// Pool's admin
+    pub admin: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The beneficiary to remove.
+    ///
+    /// This is here to ensure that the index argument matches up.
+    pub remove: T,
+
+    /// The ProtocolFeeBeneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: T,
+}
+
+impl<T: Copy> RemoveProtocolFeeBeneficiaryShareIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_ACCS_LEN])
+    }
+}
+
+pub type RemoveProtocolFeeBeneficiaryShareIxKeys<'a> =
+    RemoveProtocolFeeBeneficiaryShareIxAccs<&'a [u8; 32]>;
+
+pub type RemoveProtocolFeeBeneficiaryShareIxKeysOwned =
+    RemoveProtocolFeeBeneficiaryShareIxAccs<[u8; 32]>;
+
+pub type RemoveProtocolFeeBeneficiaryShareIxAccFlags =
+    RemoveProtocolFeeBeneficiaryShareIxAccs<bool>;
+
+pub const REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_WRITER:
+    RemoveProtocolFeeBeneficiaryShareIxAccFlags =
+    RemoveProtocolFeeBeneficiaryShareIxAccFlags::memset(false)
+        .const_with_refund_rent_to(true)
+        .const_with_protocol_fee_beneficiary_list(true);
+
+pub const REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_SIGNER:
+    RemoveProtocolFeeBeneficiaryShareIxAccFlags =
+    RemoveProtocolFeeBeneficiaryShareIxAccFlags::memset(false).const_with_admin(true);
+
+// Data
+
+pub const REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_DISCM: u8 = 32;
+
+/// u32 arg is the index of the beneficiary to remove in the list
+pub type RemoveProtocolFeeBeneficiaryShareIxData =
+    U32IxData<REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_DISCM>;
+
+pub const REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_DATA_LEN: usize =
+    RemoveProtocolFeeBeneficiaryShareIxData::DATA_LEN;
@@ -13,6 +13,12 @@ pub struct SetProtocolFeeIxAccs<T> {
 
     /// The pool's state singleton PDA
     pub pool_state: T,
+
+    /// The pool config PDA. May not yet exist.
+    ///
+    /// This instruction may only be called directly if the admin action delay is 0,
+    /// otherwise it must go through the admin action timelock
+    pub pool_config: T,
 }
 
 impl<T: Copy> SetProtocolFeeIxAccs<T> {
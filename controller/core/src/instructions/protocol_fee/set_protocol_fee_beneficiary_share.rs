@@ -0,0 +1,67 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::generic::U32IxData;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetProtocolFeeBeneficiaryShareIxAccs<T> {
+    /// Account paying for additional rent
+    pub payer: T,
+
+    /// Pool's admin
+    pub admin: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// Beneficiary to set the share weight of.
+    ///
+    /// Added to the list if not already in it.
+    pub beneficiary: T,
+
+    /// The ProtocolFeeBeneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: T,
+
+    /// System program
+    pub system_program: T,
+}
+
+impl<T: Copy> SetProtocolFeeBeneficiaryShareIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_ACCS_LEN])
+    }
+}
+
+pub type SetProtocolFeeBeneficiaryShareIxKeys<'a> =
+    SetProtocolFeeBeneficiaryShareIxAccs<&'a [u8; 32]>;
+
+pub type SetProtocolFeeBeneficiaryShareIxKeysOwned = SetProtocolFeeBeneficiaryShareIxAccs<[u8; 32]>;
+
+pub type SetProtocolFeeBeneficiaryShareIxAccFlags = SetProtocolFeeBeneficiaryShareIxAccs<bool>;
+
+pub const SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_WRITER:
+    SetProtocolFeeBeneficiaryShareIxAccFlags =
+    SetProtocolFeeBeneficiaryShareIxAccFlags::memset(false)
+        .const_with_payer(true)
+        .const_with_protocol_fee_beneficiary_list(true);
+
+pub const SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_SIGNER:
+    SetProtocolFeeBeneficiaryShareIxAccFlags =
+    SetProtocolFeeBeneficiaryShareIxAccFlags::memset(false)
+        .const_with_payer(true)
+        .const_with_admin(true);
+
+// Data
+
+pub const SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_DISCM: u8 = 31;
+
+/// u32 arg is the beneficiary's new share weight
+pub type SetProtocolFeeBeneficiaryShareIxData =
+    U32IxData<SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_DISCM>;
+
+pub const SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_DATA_LEN: usize =
+    SetProtocolFeeBeneficiaryShareIxData::DATA_LEN;
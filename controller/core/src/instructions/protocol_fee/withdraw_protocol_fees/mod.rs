@@ -1,2 +1,4 @@
+pub mod split;
+pub mod sweep;
 pub mod v1;
 pub mod v2;
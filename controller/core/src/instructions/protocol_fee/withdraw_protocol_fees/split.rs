@@ -0,0 +1,60 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::generic::DiscmOnlyIxData;
+
+// Accounts
+
+/// Followed by a suffix of the INF token accounts to mint each beneficiary's share to,
+/// one for each entry of the ProtocolFeeBeneficiary list, in the same order.
+///
+/// Each INF token account's authority must be its entry's beneficiary.
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct WithdrawProtocolFeesSplitIxAccs<T> {
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The ProtocolFeeBeneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: T,
+
+    /// INF token mint
+    pub inf_mint: T,
+
+    /// INF token program
+    pub token_program: T,
+}
+
+impl<T: Copy> WithdrawProtocolFeesSplitIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; WITHDRAW_PROTOCOL_FEES_SPLIT_IX_ACCS_LEN])
+    }
+}
+
+pub type WithdrawProtocolFeesSplitIxKeys<'a> = WithdrawProtocolFeesSplitIxAccs<&'a [u8; 32]>;
+
+pub type WithdrawProtocolFeesSplitIxKeysOwned = WithdrawProtocolFeesSplitIxAccs<[u8; 32]>;
+
+pub type WithdrawProtocolFeesSplitIxAccFlags = WithdrawProtocolFeesSplitIxAccs<bool>;
+
+/// Suffix INF token accounts are all writable
+pub const WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_WRITER: WithdrawProtocolFeesSplitIxAccFlags =
+    WithdrawProtocolFeesSplitIxAccFlags::memset(false)
+        .const_with_pool_state(true)
+        .const_with_inf_mint(true);
+
+/// Permissionless: shares can only be minted to the beneficiaries' INF token accounts.
+///
+/// Suffix INF token accounts are all non-signers
+pub const WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_SIGNER: WithdrawProtocolFeesSplitIxAccFlags =
+    WithdrawProtocolFeesSplitIxAccFlags::memset(false);
+
+// Data
+
+pub const WITHDRAW_PROTOCOL_FEES_SPLIT_IX_DISCM: u8 = 33;
+
+pub type WithdrawProtocolFeesSplitIxData = DiscmOnlyIxData<WITHDRAW_PROTOCOL_FEES_SPLIT_IX_DISCM>;
+
+pub const WITHDRAW_PROTOCOL_FEES_SPLIT_IX_DATA_LEN: usize =
+    WithdrawProtocolFeesSplitIxData::DATA_LEN;
@@ -0,0 +1,66 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::generic::DiscmOnlyIxData;
+
+// Accounts
+
+/// Withdraws the entire remaining balance of a legacy v1 LST protocol fee accumulator
+/// token account and closes it.
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct WithdrawProtocolFeesSweepIxAccs<T> {
+    /// The pool's protocol fee beneficiary
+    pub beneficiary: T,
+
+    /// Token account to withdraw the accumulator's remaining balance to
+    pub withdraw_to: T,
+
+    /// LST protocol fee accmulator token account to close
+    pub protocol_fee_accumulator: T,
+
+    /// The protocol fee accumulator token account authority PDA. PDA ["protocol_fee"]
+    pub protocol_fee_accumulator_auth: T,
+
+    /// Token program of the LST
+    pub token_program: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    pub lst_mint: T,
+
+    /// Account to refund the accumulator's rent lamports to
+    pub refund_rent_to: T,
+}
+
+impl<T: Copy> WithdrawProtocolFeesSweepIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; WITHDRAW_PROTOCOL_FEES_SWEEP_IX_ACCS_LEN])
+    }
+}
+
+pub type WithdrawProtocolFeesSweepIxKeys<'a> = WithdrawProtocolFeesSweepIxAccs<&'a [u8; 32]>;
+
+pub type WithdrawProtocolFeesSweepIxKeysOwned = WithdrawProtocolFeesSweepIxAccs<[u8; 32]>;
+
+pub type WithdrawProtocolFeesSweepIxAccFlags = WithdrawProtocolFeesSweepIxAccs<bool>;
+
+pub const WITHDRAW_PROTOCOL_FEES_SWEEP_IX_IS_WRITER: WithdrawProtocolFeesSweepIxAccFlags =
+    WithdrawProtocolFeesSweepIxAccFlags::memset(false)
+        .const_with_withdraw_to(true)
+        .const_with_protocol_fee_accumulator(true)
+        .const_with_refund_rent_to(true);
+
+pub const WITHDRAW_PROTOCOL_FEES_SWEEP_IX_IS_SIGNER: WithdrawProtocolFeesSweepIxAccFlags =
+    WithdrawProtocolFeesSweepIxAccFlags::memset(false).const_with_beneficiary(true);
+
+// Data
+
+pub const WITHDRAW_PROTOCOL_FEES_SWEEP_IX_DISCM: u8 = 34;
+
+pub type WithdrawProtocolFeesSweepIxData = DiscmOnlyIxData<WITHDRAW_PROTOCOL_FEES_SWEEP_IX_DISCM>;
+
+pub const WITHDRAW_PROTOCOL_FEES_SWEEP_IX_DATA_LEN: usize =
+    WithdrawProtocolFeesSweepIxData::DATA_LEN;
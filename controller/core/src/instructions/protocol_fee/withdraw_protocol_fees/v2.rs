@@ -22,6 +22,11 @@ pub struct WithdrawProtocolFeesV2IxAccs<T> {
 
     /// INF token program
     pub token_program: T,
+
+    /// The protocol fee beneficiary list PDA.
+    /// Must be empty or not yet created, otherwise protocol fees
+    /// can only be withdrawn with `WithdrawProtocolFeesSplit`
+    pub protocol_fee_beneficiary_list: T,
 }
 
 impl<T: Copy> WithdrawProtocolFeesV2IxAccs<T> {
@@ -1,3 +1,4 @@
 pub mod end;
 pub mod set_rebal_auth;
+pub mod set_rebal_max_loss;
 pub mod start;
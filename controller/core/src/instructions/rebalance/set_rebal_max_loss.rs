@@ -0,0 +1,83 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::internal_utils::caba;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetRebalMaxLossIxAccs<T> {
+    /// The pool's admin
+    pub admin: T,
+
+    /// Pays for the pool config PDA's rent if it has not yet been created
+    pub payer: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The pool config PDA, created if it does not yet exist
+    pub pool_config: T,
+
+    /// System program
+    pub system_program: T,
+}
+
+impl<T: Copy> SetRebalMaxLossIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; SET_REBAL_MAX_LOSS_IX_ACCS_LEN])
+    }
+}
+
+pub type SetRebalMaxLossIxKeys<'a> = SetRebalMaxLossIxAccs<&'a [u8; 32]>;
+
+pub type SetRebalMaxLossIxKeysOwned = SetRebalMaxLossIxAccs<[u8; 32]>;
+
+pub type SetRebalMaxLossIxAccFlags = SetRebalMaxLossIxAccs<bool>;
+
+pub const SET_REBAL_MAX_LOSS_IX_IS_WRITER: SetRebalMaxLossIxAccFlags =
+    SetRebalMaxLossIxAccFlags::memset(false)
+        .const_with_payer(true)
+        .const_with_pool_config(true);
+
+pub const SET_REBAL_MAX_LOSS_IX_IS_SIGNER: SetRebalMaxLossIxAccFlags =
+    SetRebalMaxLossIxAccFlags::memset(false)
+        .const_with_admin(true)
+        .const_with_payer(true);
+
+// Data
+
+pub const SET_REBAL_MAX_LOSS_IX_DISCM: u8 = 42;
+
+pub const SET_REBAL_MAX_LOSS_IX_DATA_LEN: usize = 9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetRebalMaxLossIxData([u8; SET_REBAL_MAX_LOSS_IX_DATA_LEN]);
+
+impl SetRebalMaxLossIxData {
+    #[inline]
+    pub const fn new(rebalance_max_loss_lamports: u64) -> Self {
+        const A: usize = SET_REBAL_MAX_LOSS_IX_DATA_LEN;
+
+        let mut d = [0u8; A];
+
+        d = caba::<A, 0, 1>(d, &[SET_REBAL_MAX_LOSS_IX_DISCM]);
+        d = caba::<A, 1, 8>(d, &rebalance_max_loss_lamports.to_le_bytes());
+
+        Self(d)
+    }
+
+    #[inline]
+    pub const fn as_buf(&self) -> &[u8; SET_REBAL_MAX_LOSS_IX_DATA_LEN] {
+        &self.0
+    }
+
+    /// Returns the new `pool_config.rebalance_max_loss_lamports`
+    #[inline]
+    pub const fn parse_no_discm(data: &[u8; 8]) -> u64 {
+        u64::from_le_bytes(*data)
+    }
+}
@@ -20,6 +20,11 @@ pub struct StartRebalanceIxPreAccs<T> {
     pub instructions: T,
     pub system_program: T,
     pub out_lst_token_program: T,
+
+    /// The pool config PDA. May not yet exist.
+    ///
+    /// Its `rebalance_max_loss_lamports` is recorded for EndRebalance
+    pub pool_config: T,
 }
 
 impl<T: Copy> StartRebalanceIxPreAccs<T> {
@@ -49,7 +54,8 @@ pub const START_REBALANCE_IX_PRE_IS_WRITER: StartRebalanceIxPreAccFlags =
         .const_with_out_lst_mint(false)
         .const_with_instructions(false)
         .const_with_system_program(false)
-        .const_with_out_lst_token_program(false);
+        .const_with_out_lst_token_program(false)
+        .const_with_pool_config(false);
 
 pub const START_REBALANCE_IX_PRE_IS_SIGNER: StartRebalanceIxPreAccFlags =
     StartRebalanceIxPreAccFlags::memset(false).const_with_rebalance_auth(true);
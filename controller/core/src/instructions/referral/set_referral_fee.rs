@@ -0,0 +1,56 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::generic::U32IxData;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetReferralFeeIxAccs<T> {
+    /// The pool's admin
+    pub admin: T,
+
+    /// Pays for the referral config PDA's rent if it has not yet been created
+    pub payer: T,
+
+    /// The pool's state singleton PDA
+    pub pool_state: T,
+
+    /// The referral config PDA, created if it does not yet exist
+    pub referral_config: T,
+
+    /// System program
+    pub system_program: T,
+}
+
+impl<T: Copy> SetReferralFeeIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; SET_REFERRAL_FEE_IX_ACCS_LEN])
+    }
+}
+
+pub type SetReferralFeeIxKeys<'a> = SetReferralFeeIxAccs<&'a [u8; 32]>;
+
+pub type SetReferralFeeIxKeysOwned = SetReferralFeeIxAccs<[u8; 32]>;
+
+pub type SetReferralFeeIxAccFlags = SetReferralFeeIxAccs<bool>;
+
+pub const SET_REFERRAL_FEE_IX_IS_WRITER: SetReferralFeeIxAccFlags =
+    SetReferralFeeIxAccFlags::memset(false)
+        .const_with_payer(true)
+        .const_with_referral_config(true);
+
+pub const SET_REFERRAL_FEE_IX_IS_SIGNER: SetReferralFeeIxAccFlags =
+    SetReferralFeeIxAccFlags::memset(false)
+        .const_with_admin(true)
+        .const_with_payer(true);
+
+// Data
+
+pub const SET_REFERRAL_FEE_IX_DISCM: u8 = 30;
+
+pub type SetReferralFeeIxData = U32IxData<SET_REFERRAL_FEE_IX_DISCM>;
+
+pub const SET_REFERRAL_FEE_IX_DATA_LEN: usize = SetReferralFeeIxData::DATA_LEN;
@@ -2,6 +2,8 @@ use generic_array_struct::generic_array_struct;
 
 pub mod exact_in;
 pub mod exact_out;
+pub mod referral;
+pub mod ret;
 
 // Accounts
 
@@ -0,0 +1,139 @@
+//! Optional referral accounts suffix for `SwapExactInV2` and `SwapExactOutV2`.
+//!
+//! If present, the referral accounts come after all other accounts
+//! and the instruction data has a single trailing [`REFERRAL_FLAG`] byte
+//! so that the pricing program accounts suffix, which takes up all remaining
+//! accounts otherwise, can be told apart from them.
+//!
+//! Referrals are rejected for RemoveLiquidity since the input token is INF.
+//! Self-referral is allowed; it amounts to a fee discount capped by the
+//! admin-set referral fee.
+
+use generic_array_struct::generic_array_struct;
+
+use crate::instructions::{
+    internal_utils::caba,
+    swap::{new_swap_ix_data, parse_swap_ix_args, IxArgs, IX_DATA_LEN},
+};
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ReferralSufAccs<T> {
+    /// The referral config PDA. Need not have been created,
+    /// in which case the referrer is paid nothing.
+    pub referral_config: T,
+
+    /// The referrer's token account of the input mint,
+    /// which receives the referral portion of the fee
+    pub referrer_acc: T,
+}
+
+impl<T: Copy> ReferralSufAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; REFERRAL_SUF_ACCS_LEN])
+    }
+}
+
+pub type ReferralSufKeys<'a> = ReferralSufAccs<&'a [u8; 32]>;
+
+pub type ReferralSufKeysOwned = ReferralSufAccs<[u8; 32]>;
+
+pub type ReferralSufAccFlags = ReferralSufAccs<bool>;
+
+impl<T> AsRef<[T]> for ReferralSufAccs<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+pub const REFERRAL_SUF_IS_WRITER: ReferralSufAccFlags =
+    ReferralSufAccFlags::memset(false).const_with_referrer_acc(true);
+
+pub const REFERRAL_SUF_IS_SIGNER: ReferralSufAccFlags = ReferralSufAccFlags::memset(false);
+
+// Data
+
+pub const REFERRAL_FLAG: u8 = 1;
+
+pub const IX_DATA_WITH_REFERRAL_LEN: usize = IX_DATA_LEN + 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct IxDataWithReferral<const DISCM: u8>([u8; IX_DATA_WITH_REFERRAL_LEN]);
+
+impl<const DISCM: u8> IxDataWithReferral<DISCM> {
+    #[inline]
+    pub const fn new(args: &IxArgs) -> Self {
+        const A: usize = IX_DATA_WITH_REFERRAL_LEN;
+
+        let mut d = [0u8; A];
+        d = caba::<A, 0, IX_DATA_LEN>(d, &new_swap_ix_data(DISCM, args));
+        d = caba::<A, IX_DATA_LEN, 1>(d, &[REFERRAL_FLAG]);
+
+        Self(d)
+    }
+
+    #[inline]
+    pub const fn as_buf(&self) -> &[u8; IX_DATA_WITH_REFERRAL_LEN] {
+        &self.0
+    }
+}
+
+/// Parses V2 swap instruction data, excluding the discriminant,
+/// with or without the trailing [`REFERRAL_FLAG`].
+///
+/// # Returns
+/// `(args, has_referral)`, `None` if data is of an invalid length or has an invalid trailing byte
+#[inline]
+pub const fn parse_swap_v2_ix_args(data: &[u8]) -> Option<(IxArgs, bool)> {
+    const N: usize = IX_DATA_LEN - 1;
+
+    match data.split_first_chunk::<N>() {
+        Some((args, [])) => Some((parse_swap_ix_args(args), false)),
+        Some((args, [REFERRAL_FLAG])) => Some((parse_swap_ix_args(args), true)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::instructions::swap::IxData;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_round_trip(
+            inp_lst_value_calc_accs: u8,
+            out_lst_value_calc_accs: u8,
+            inp_lst_index: u32,
+            out_lst_index: u32,
+            limit: u64,
+            amount: u64,
+        ) {
+            const DISCM: u8 = 23;
+            let args = IxArgs {
+                inp_lst_value_calc_accs,
+                out_lst_value_calc_accs,
+                inp_lst_index,
+                out_lst_index,
+                limit,
+                amount,
+            };
+            prop_assert_eq!(
+                parse_swap_v2_ix_args(&IxData::<DISCM>::new(&args).as_buf()[1..]),
+                Some((args, false))
+            );
+            let with_ref = IxDataWithReferral::<DISCM>::new(&args);
+            prop_assert_eq!(with_ref.as_buf()[0], DISCM);
+            prop_assert_eq!(parse_swap_v2_ix_args(&with_ref.as_buf()[1..]), Some((args, true)));
+        }
+    }
+}
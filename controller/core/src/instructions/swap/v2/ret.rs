@@ -0,0 +1,133 @@
+//! Return data set by `SwapExactInV2` and `SwapExactOutV2`.
+//!
+//! Prefixed with a version byte so that fields can be added in the future
+//! without breaking programs that decode it.
+
+use crate::instructions::internal_utils::{caba, csba};
+
+pub const SWAP_V2_RET_V1_VERS: u8 = 1;
+
+pub const SWAP_V2_RET_V1_LEN: usize = 97;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SwapV2RetV1 {
+    /// Amount of input tokens that left the user's wallet
+    pub inp: u64,
+
+    /// Amount of output tokens that entered the user's wallet
+    pub out: u64,
+
+    /// The amount of fee accrued to the pool,
+    /// in terms of sol value (lamports)
+    pub fee: u64,
+
+    /// SOL value of `inp` input tokens
+    pub inp_sol_val: u64,
+
+    /// This is INF for RemoveLiquidity
+    pub inp_mint: [u8; 32],
+
+    /// This is INF for AddLiquidity
+    pub out_mint: [u8; 32],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SwapV2RetV1Data([u8; SWAP_V2_RET_V1_LEN]);
+
+impl SwapV2RetV1Data {
+    #[inline]
+    pub const fn new(
+        SwapV2RetV1 {
+            inp,
+            out,
+            fee,
+            inp_sol_val,
+            inp_mint,
+            out_mint,
+        }: &SwapV2RetV1,
+    ) -> Self {
+        const A: usize = SWAP_V2_RET_V1_LEN;
+
+        let mut d = [0u8; A];
+
+        d = caba::<A, 0, 1>(d, &[SWAP_V2_RET_V1_VERS]);
+        d = caba::<A, 1, 8>(d, &inp.to_le_bytes());
+        d = caba::<A, 9, 8>(d, &out.to_le_bytes());
+        d = caba::<A, 17, 8>(d, &fee.to_le_bytes());
+        d = caba::<A, 25, 8>(d, &inp_sol_val.to_le_bytes());
+        d = caba::<A, 33, 32>(d, inp_mint);
+        d = caba::<A, 65, 32>(d, out_mint);
+
+        Self(d)
+    }
+
+    #[inline]
+    pub const fn as_buf(&self) -> &[u8; SWAP_V2_RET_V1_LEN] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn parse_no_vers(data: &[u8; SWAP_V2_RET_V1_LEN - 1]) -> SwapV2RetV1 {
+        let (inp, rest) = csba::<96, 8, 88>(data);
+        let (out, rest) = csba::<88, 8, 80>(rest);
+        let (fee, rest) = csba::<80, 8, 72>(rest);
+        let (inp_sol_val, rest) = csba::<72, 8, 64>(rest);
+        let (inp_mint, rest) = csba::<64, 32, 32>(rest);
+        let (out_mint, _) = csba::<32, 32, 0>(rest);
+
+        SwapV2RetV1 {
+            inp: u64::from_le_bytes(*inp),
+            out: u64::from_le_bytes(*out),
+            fee: u64::from_le_bytes(*fee),
+            inp_sol_val: u64::from_le_bytes(*inp_sol_val),
+            inp_mint: *inp_mint,
+            out_mint: *out_mint,
+        }
+    }
+}
+
+/// Versioned swap return data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwapV2Ret {
+    V1(SwapV2RetV1),
+}
+
+impl SwapV2Ret {
+    /// Returns `None` if `data` is not of any known version
+    /// or has the wrong length for its version
+    #[inline]
+    pub const fn parse(data: &[u8]) -> Option<Self> {
+        match data.split_first() {
+            Some((&SWAP_V2_RET_V1_VERS, rest)) => match rest.split_first_chunk() {
+                Some((v1, &[])) => Some(Self::V1(SwapV2RetV1Data::parse_no_vers(v1))),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(
+            inp: u64,
+            out: u64,
+            fee: u64,
+            inp_sol_val: u64,
+            inp_mint: [u8; 32],
+            out_mint: [u8; 32],
+        ) {
+            let ret = SwapV2RetV1 { inp, out, fee, inp_sol_val, inp_mint, out_mint };
+            let data = SwapV2RetV1Data::new(&ret);
+            prop_assert_eq!(SwapV2Ret::parse(data.as_buf()), Some(SwapV2Ret::V1(ret)));
+            prop_assert_eq!(SwapV2Ret::parse(&data.as_buf()[..SWAP_V2_RET_V1_LEN - 1]), None);
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::pda::{
-    const_find_disable_pool_authority_list, const_find_lst_state_list, const_find_pool_state,
-    const_find_protocol_fee, const_find_rebalance_record,
+    const_find_disable_pool_authority_list, const_find_inf_price_history,
+    const_find_lst_calc_backing_list, const_find_lst_state_list, const_find_pending_admin_action,
+    const_find_pool_config, const_find_pool_state, const_find_protocol_fee,
+    const_find_protocol_fee_beneficiary_list, const_find_rebalance_record,
+    const_find_referral_config,
 };
 
 macro_rules! id_str {
@@ -90,6 +93,54 @@ const_pda!(
     const_find_disable_pool_authority_list
 );
 
+const_pda!(
+    LST_CALC_BACKING_LIST,
+    LST_CALC_BACKING_LIST_ID_STR,
+    LST_CALC_BACKING_LIST_ID,
+    LST_CALC_BACKING_LIST_BUMP,
+    const_find_lst_calc_backing_list
+);
+
+const_pda!(
+    INF_PRICE_HISTORY,
+    INF_PRICE_HISTORY_ID_STR,
+    INF_PRICE_HISTORY_ID,
+    INF_PRICE_HISTORY_BUMP,
+    const_find_inf_price_history
+);
+
+const_pda!(
+    REFERRAL_CONFIG,
+    REFERRAL_CONFIG_ID_STR,
+    REFERRAL_CONFIG_ID,
+    REFERRAL_CONFIG_BUMP,
+    const_find_referral_config
+);
+
+const_pda!(
+    PROTOCOL_FEE_BENEFICIARY_LIST,
+    PROTOCOL_FEE_BENEFICIARY_LIST_ID_STR,
+    PROTOCOL_FEE_BENEFICIARY_LIST_ID,
+    PROTOCOL_FEE_BENEFICIARY_LIST_BUMP,
+    const_find_protocol_fee_beneficiary_list
+);
+
+const_pda!(
+    POOL_CONFIG,
+    POOL_CONFIG_ID_STR,
+    POOL_CONFIG_ID,
+    POOL_CONFIG_BUMP,
+    const_find_pool_config
+);
+
+const_pda!(
+    PENDING_ADMIN_ACTION,
+    PENDING_ADMIN_ACTION_ID_STR,
+    PENDING_ADMIN_ACTION_ID,
+    PENDING_ADMIN_ACTION_BUMP,
+    const_find_pending_admin_action
+);
+
 #[cfg(test)]
 mod tests {
     use expect_test::expect;
@@ -119,6 +170,30 @@ mod tests {
                 expect!["FJc6b3iyYaD5p24aKQ2FcM7WVATapPGq65LhY1MDKXzG"],
                 DISABLE_POOL_AUTHORITY_LIST_ID_STR,
             ),
+            (
+                expect!["Ab25pzhXRQpJ7znZDFXoWwurxuAJow9pFTQVvHK27iCP"],
+                LST_CALC_BACKING_LIST_ID_STR,
+            ),
+            (
+                expect!["HZmVfeFacEgWtfWuS1vfg6Enwr4mmVaTVPktL15LeFCQ"],
+                INF_PRICE_HISTORY_ID_STR,
+            ),
+            (
+                expect!["7zE1vQ9ujuoRBtCxFRXACsLz3wtu93cs1nw6w6ec5Lni"],
+                REFERRAL_CONFIG_ID_STR,
+            ),
+            (
+                expect!["8UcTmvbVooKQn2ijHNkvC3smfKKqLejhZZkmYu5CG4B8"],
+                PROTOCOL_FEE_BENEFICIARY_LIST_ID_STR,
+            ),
+            (
+                expect!["CPsQtXHtKz9PFv3wodKxKtDnkSKytf1XezaSGzweg9y7"],
+                POOL_CONFIG_ID_STR,
+            ),
+            (
+                expect!["9NSYm75tA7ffvNkUHMvJJb1xt8Cx4mxrqwhckb9Xwfmj"],
+                PENDING_ADMIN_ACTION_ID_STR,
+            ),
         ]
         .into_iter()
         .for_each(|(e, s)| e.assert_eq(s));
@@ -12,6 +12,18 @@ pub const REBALANCE_RECORD_SEED: [u8; 16] = *b"rebalance-record";
 
 pub const DISABLE_POOL_AUTHORITY_LIST_SEED: [u8; 27] = *b"disable-pool-authority-list";
 
+pub const LST_CALC_BACKING_LIST_SEED: [u8; 21] = *b"lst-calc-backing-list";
+
+pub const INF_PRICE_HISTORY_SEED: [u8; 17] = *b"inf-price-history";
+
+pub const REFERRAL_CONFIG_SEED: [u8; 15] = *b"referral-config";
+
+pub const PROTOCOL_FEE_BENEFICIARY_LIST_SEED: [u8; 29] = *b"protocol-fee-beneficiary-list";
+
+pub const POOL_CONFIG_SEED: [u8; 11] = *b"pool-config";
+
+pub const PENDING_ADMIN_ACTION_SEED: [u8; 20] = *b"pending-admin-action";
+
 pub const fn const_find_pool_state(prog_id: &[u8; 32]) -> ([u8; 32], u8) {
     derive_program_address(&[&POOL_STATE_SEED], prog_id)
 }
@@ -32,6 +44,30 @@ pub const fn const_find_disable_pool_authority_list(prog_id: &[u8; 32]) -> ([u8;
     derive_program_address(&[&DISABLE_POOL_AUTHORITY_LIST_SEED], prog_id)
 }
 
+pub const fn const_find_lst_calc_backing_list(prog_id: &[u8; 32]) -> ([u8; 32], u8) {
+    derive_program_address(&[&LST_CALC_BACKING_LIST_SEED], prog_id)
+}
+
+pub const fn const_find_inf_price_history(prog_id: &[u8; 32]) -> ([u8; 32], u8) {
+    derive_program_address(&[&INF_PRICE_HISTORY_SEED], prog_id)
+}
+
+pub const fn const_find_referral_config(prog_id: &[u8; 32]) -> ([u8; 32], u8) {
+    derive_program_address(&[&REFERRAL_CONFIG_SEED], prog_id)
+}
+
+pub const fn const_find_protocol_fee_beneficiary_list(prog_id: &[u8; 32]) -> ([u8; 32], u8) {
+    derive_program_address(&[&PROTOCOL_FEE_BENEFICIARY_LIST_SEED], prog_id)
+}
+
+pub const fn const_find_pool_config(prog_id: &[u8; 32]) -> ([u8; 32], u8) {
+    derive_program_address(&[&POOL_CONFIG_SEED], prog_id)
+}
+
+pub const fn const_find_pending_admin_action(prog_id: &[u8; 32]) -> ([u8; 32], u8) {
+    derive_program_address(&[&PENDING_ADMIN_ACTION_SEED], prog_id)
+}
+
 /// PDA seeds to use with ATA program to find pool reserves ATA
 pub const fn pool_reserves_ata_seeds<'a>(
     token_program: &'a [u8; 32],
@@ -2,7 +2,7 @@
 
 use core::{error::Error, fmt::Display, ops::RangeInclusive};
 
-use inf1_svc_core::traits::{SolValCalc, SolValCalcAccs};
+use inf1_svc_core::traits::{SolValCalc, SolValCalcAccs, Staleness};
 use sanctum_u64_ratio::{Floor, Ratio};
 
 use crate::{
@@ -150,6 +150,13 @@ impl From<InfCalcErr> for Inf1CtlErr {
 
 /// SolValCalc traits const adapters
 impl InfCalc {
+    /// Pending yield release is accounted for via [`Self::lookahead`],
+    /// so the calculator is never considered stale.
+    #[inline]
+    pub const fn svc_is_stale(&self) -> bool {
+        false
+    }
+
     #[inline]
     pub const fn svc_lst_to_sol(&self, inf: u64) -> Result<RangeInclusive<u64>, InfCalcErr> {
         match self.inf_to_sol(inf) {
@@ -184,6 +191,13 @@ impl SolValCalc for InfCalc {
     }
 }
 
+impl Staleness for InfCalc {
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.svc_is_stale()
+    }
+}
+
 /// The INF program does NOT implement the SOL value calculator program interface.
 ///
 /// We also do not need to pass in any additional calc accounts for getting its sol value
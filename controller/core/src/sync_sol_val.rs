@@ -1,6 +1,7 @@
 use crate::{
     accounts::pool_state::PoolStateV2,
     typedefs::{
+        fee_nanos::NANOS_DENOM,
         pool_sv::{PoolSvLamports, PoolSvMutRefs},
         snap::SnapU64,
     },
@@ -31,6 +32,51 @@ impl SyncSolVal {
         };
         sub_old.checked_add(*lst_sol_val.new())
     }
+
+    /// Checks the change in the LST's per-token SOL value,
+    /// `lst_sol_val / reserves_balance`, against the pool's circuit breaker,
+    /// see [`crate::accounts::pool_state::PoolStateV2::sol_value_circuit_breaker_nanos`].
+    ///
+    /// Comparing per-token SOL values means changes in the reserves balance,
+    /// e.g. tokens transferred directly to the pool reserves, do not trip it.
+    ///
+    /// # Returns
+    /// `true` if `|new_rate - old_rate| / old_rate > circuit_breaker_nanos / 1_000_000_000`
+    /// where `rate = lst_sol_val / reserves_balance`.
+    ///
+    /// Always `false` if `circuit_breaker_nanos == 0` (circuit breaker off)
+    /// or any of old SOL value, old balance or new balance is 0 (no rate to compare)
+    #[inline]
+    pub const fn trips_circuit_breaker(
+        &self,
+        reserves_balance: &SnapU64,
+        circuit_breaker_nanos: u32,
+    ) -> bool {
+        let [old_sv, new_sv] = [*self.lst_sol_val.old(), *self.lst_sol_val.new()];
+        let [old_bal, new_bal] = [*reserves_balance.old(), *reserves_balance.new()];
+        if circuit_breaker_nanos == 0 || old_sv == 0 || old_bal == 0 || new_bal == 0 {
+            return false;
+        }
+        // new_rate / old_rate = (new_sv * old_bal) / (old_sv * new_bal)
+        //
+        // cannot overflow: u64 * u64 < u128
+        let scaled_new = new_sv as u128 * old_bal as u128;
+        let scaled_old = old_sv as u128 * new_bal as u128;
+        let change = scaled_new.abs_diff(scaled_old);
+
+        // trips if change > scaled_old * circuit_breaker_nanos / NANOS_DENOM.
+        // change is an integer so comparing against the floor of the rhs is exact.
+        // Split scaled_old into quotient and remainder of NANOS_DENOM to avoid overflow.
+        let denom = NANOS_DENOM as u128;
+        let nanos = circuit_breaker_nanos as u128;
+        let max_change = match (scaled_old / denom).checked_mul(nanos) {
+            // threshold exceeds max possible change
+            None => return false,
+            // cannot overflow: rem * nanos < 10^9 * 2^32
+            Some(q) => q.saturating_add((scaled_old % denom) * nanos / denom),
+        };
+        change > max_change
+    }
 }
 
 impl PoolSvLamports {
@@ -62,3 +108,136 @@ impl PoolStateV2 {
         Some(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::typedefs::{compact_u64::CompactU64, snap::NewSnapBuilder};
+
+    use super::*;
+
+    fn snap(old: u64, new: u64) -> SnapU64 {
+        NewSnapBuilder::start().with_old(old).with_new(new).build()
+    }
+
+    fn ssv(old: u64, new: u64) -> SyncSolVal {
+        SyncSolVal {
+            lst_sol_val: snap(old, new),
+        }
+    }
+
+    /// Largest change from `old` that does not trip a `circuit_breaker_nanos` circuit breaker
+    /// if reserves balance is unchanged
+    fn max_untripped_change(old: u64, circuit_breaker_nanos: u32) -> u128 {
+        old as u128 * circuit_breaker_nanos as u128 / NANOS_DENOM as u128
+    }
+
+    proptest! {
+        #[test]
+        fn off_or_zero_never_trips(
+            old: u64,
+            new: u64,
+            old_bal: u64,
+            new_bal: u64,
+            circuit_breaker_nanos: u32,
+        ) {
+            let bal = snap(old_bal, new_bal);
+            prop_assert!(!ssv(old, new).trips_circuit_breaker(&bal, 0));
+            prop_assert!(!ssv(0, new).trips_circuit_breaker(&bal, circuit_breaker_nanos));
+            for bal in [snap(0, new_bal), snap(old_bal, 0)] {
+                prop_assert!(!ssv(old, new).trips_circuit_breaker(&bal, circuit_breaker_nanos));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn unchanged_rate_never_trips(
+            val: u64,
+            bal: u64,
+            (small_val, small_bal, mul) in (any::<u32>(), any::<u32>(), any::<u32>()),
+            circuit_breaker_nanos: u32,
+        ) {
+            prop_assert!(!ssv(val, val).trips_circuit_breaker(&snap(bal, bal), circuit_breaker_nanos));
+
+            // balance and SOL value both scaled by the same factor
+            let [small_val, small_bal, mul] = [small_val, small_bal, mul].map(u64::from);
+            prop_assert!(!ssv(small_val, small_val * mul)
+                .trips_circuit_breaker(&snap(small_bal, small_bal * mul), circuit_breaker_nanos));
+        }
+    }
+
+    /// LST exchange rate `(num, den)` between 1.0 and 2.0
+    fn rate_strat() -> impl Strategy<Value = (u64, u64)> {
+        (1..=NANOS_DENOM as u64).prop_flat_map(|den| (den..=2 * den, Just(den)))
+    }
+
+    fn sol_val(bal: u64, (num, den): (u64, u64)) -> u64 {
+        (bal as u128 * num as u128 / den as u128)
+            .try_into()
+            .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn donation_never_trips(
+            rate in rate_strat(),
+            old_bal in NANOS_DENOM as u64..=1_000_000_000_000_000,
+            donation in 1..=1_000_000_000_000_000u64,
+            // leeway for rounding of SOL values and the stored reserves balance
+            circuit_breaker_nanos in 10..=u32::MAX,
+        ) {
+            let new_bal = old_bal + donation;
+            let s = ssv(sol_val(old_bal, rate), sol_val(new_bal, rate));
+            let synced_bal = CompactU64::new(old_bal).get();
+            prop_assert!(
+                !s.trips_circuit_breaker(&snap(synced_bal, new_bal), circuit_breaker_nanos)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn trips_exactly_above_threshold(
+            old in 1..=u64::MAX,
+            bal in 1..=u64::MAX,
+            circuit_breaker_nanos in 1..=u32::MAX,
+            is_increase: bool,
+        ) {
+            let bal = snap(bal, bal);
+            let max = max_untripped_change(old, circuit_breaker_nanos);
+            let apply = |change: u128| {
+                let change = u64::try_from(change).ok()?;
+                if is_increase {
+                    old.checked_add(change)
+                } else {
+                    old.checked_sub(change)
+                }
+            };
+            if let Some(new) = apply(max) {
+                prop_assert!(!ssv(old, new).trips_circuit_breaker(&bal, circuit_breaker_nanos));
+            }
+            if let Some(new) = apply(max + 1) {
+                prop_assert!(ssv(old, new).trips_circuit_breaker(&bal, circuit_breaker_nanos));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn tighter_threshold_also_trips(
+            old: u64,
+            new: u64,
+            old_bal: u64,
+            new_bal: u64,
+            (tighter, looser) in (1..=u32::MAX).prop_flat_map(|l| (1..=l, Just(l))),
+        ) {
+            let s = ssv(old, new);
+            let bal = snap(old_bal, new_bal);
+            if s.trips_circuit_breaker(&bal, looser) {
+                prop_assert!(s.trips_circuit_breaker(&bal, tighter));
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+/// A u64 stored in 5 bytes as a u32 mantissa and a u8 shift,
+/// `value = mantissa << shift`, for fitting into existing padding.
+///
+/// Exact for values `<= u32::MAX`. Larger values are rounded down,
+/// with relative error `< 2^-31`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CompactU64([u8; 5]);
+
+impl CompactU64 {
+    pub const ZERO: Self = Self([0; 5]);
+
+    const MAX_SHIFT: u8 = (u64::BITS - u32::BITS) as u8;
+
+    #[inline]
+    pub const fn new(val: u64) -> Self {
+        let shift = (u64::BITS - val.leading_zeros()).saturating_sub(u32::BITS);
+        // as-safety: val >> shift fits in u32 by construction of shift
+        let [a, b, c, d] = ((val >> shift) as u32).to_le_bytes();
+        Self([a, b, c, d, shift as u8])
+    }
+
+    /// Returns 0 for an invalid shift, which is only possible
+    /// for bytes that were not created by [`Self::new`]
+    #[inline]
+    pub const fn get(&self) -> u64 {
+        let [a, b, c, d, shift] = self.0;
+        if shift > Self::MAX_SHIFT {
+            return 0;
+        }
+        (u32::from_le_bytes([a, b, c, d]) as u64) << shift
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn exact_up_to_u32_max(val: u32) {
+            prop_assert_eq!(CompactU64::new(val.into()).get(), u64::from(val));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn rounds_down_within_bound(val: u64) {
+            let got = CompactU64::new(val).get();
+            prop_assert!(got <= val);
+            // (val - got) / val < 2^-31
+            prop_assert!(u128::from(val - got) << 31 < u128::from(val.max(1)));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn monotonic(a: u64, b: u64) {
+            let [lo, hi] = if a <= b { [a, b] } else { [b, a] };
+            prop_assert!(CompactU64::new(lo).get() <= CompactU64::new(hi).get());
+        }
+    }
+
+    #[test]
+    fn invalid_shift_is_zero() {
+        assert_eq!(CompactU64([u8::MAX; 5]).get(), 0);
+        assert_eq!(CompactU64::new(u64::MAX).get() >> 32, u64::from(u32::MAX));
+    }
+}
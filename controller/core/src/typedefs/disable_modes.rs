@@ -0,0 +1,93 @@
+//! Bitfield of pool operations that are individually disabled.
+//!
+//! Unlike `is_disabled`, which blocks all operations, this allows
+//! e.g. blocking deposits and swaps while still letting LPs exit.
+
+/// A single pool operation that can be disabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum DisableMode {
+    /// LST -> LST swaps
+    Swap = 1 << 0,
+
+    /// LST -> INF
+    AddLiquidity = 1 << 1,
+
+    /// INF -> LST
+    RemoveLiquidity = 1 << 2,
+
+    /// StartRebalance
+    Rebalance = 1 << 3,
+}
+
+impl DisableMode {
+    #[inline]
+    pub const fn bit(self) -> u8 {
+        self as u8
+    }
+
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Swap => "swap",
+            Self::AddLiquidity => "add liquidity",
+            Self::RemoveLiquidity => "remove liquidity",
+            Self::Rebalance => "rebalance",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct DisableModes(u8);
+
+impl DisableModes {
+    /// Nothing disabled
+    pub const NONE: Self = Self(0);
+
+    /// All [`DisableMode`]s disabled
+    pub const ALL: Self = Self(
+        DisableMode::Swap.bit()
+            | DisableMode::AddLiquidity.bit()
+            | DisableMode::RemoveLiquidity.bit()
+            | DisableMode::Rebalance.bit(),
+    );
+
+    /// Returns `None` if `bits` has bits set that do not correspond to any [`DisableMode`]
+    #[inline]
+    pub const fn new(bits: u8) -> Option<Self> {
+        if bits & !Self::ALL.0 != 0 {
+            None
+        } else {
+            Some(Self(bits))
+        }
+    }
+
+    /// Ignores bits that do not correspond to any [`DisableMode`]
+    #[inline]
+    pub const fn new_truncated(bits: u8) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    #[inline]
+    pub const fn get(&self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_disabled(&self, mode: DisableMode) -> bool {
+        self.0 & mode.bit() != 0
+    }
+
+    #[inline]
+    pub const fn with(self, mode: DisableMode) -> Self {
+        Self(self.0 | mode.bit())
+    }
+
+    /// Returns true if `self` has any mode disabled that `other` does not,
+    /// i.e. going from `self` to `other` would re-enable some operation
+    #[inline]
+    pub const fn has_any_not_in(&self, other: &Self) -> bool {
+        self.0 & !other.0 != 0
+    }
+}
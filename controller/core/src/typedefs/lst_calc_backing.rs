@@ -0,0 +1,18 @@
+use crate::internal_utils::{impl_cast_from_acc_data, impl_cast_to_acc_data};
+
+/// Entry of the [`crate::accounts::lst_calc_backing_list::LstCalcBackingList`]
+/// recording the account that backs a LST's SOL value calculator,
+/// e.g. the stake pool account for SPL LSTs.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LstCalcBacking {
+    pub mint: [u8; 32],
+
+    /// Arbitrary admin-specified account.
+    ///
+    /// Set to the LST's stake pool account for calculator programs that
+    /// wrap a stake pool program. Unused for other calculator programs.
+    pub backing: [u8; 32],
+}
+impl_cast_from_acc_data!(LstCalcBacking, packed);
+impl_cast_to_acc_data!(LstCalcBacking, packed);
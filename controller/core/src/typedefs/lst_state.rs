@@ -1,6 +1,9 @@
 use core::mem::size_of;
 
-use crate::internal_utils::{impl_cast_from_acc_data, impl_cast_to_acc_data};
+use crate::{
+    internal_utils::{impl_cast_from_acc_data, impl_cast_to_acc_data},
+    typedefs::compact_u64::CompactU64,
+};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -8,7 +11,16 @@ pub struct LstState {
     pub is_input_disabled: u8,
     pub pool_reserves_bump: u8,
     pub protocol_fee_accumulator_bump: u8,
-    pub padding: [u8; 5],
+
+    /// Pool reserves balance of the LST as of the last update of `sol_value`,
+    /// for the SOL value circuit breaker to compare per-token SOL values with,
+    /// see [`crate::sync_sol_val::SyncSolVal::trips_circuit_breaker`].
+    ///
+    /// Zero for LSTs that have not been synced since this field was added.
+    ///
+    /// Occupies what was `padding` in v1
+    pub synced_reserves_balance: CompactU64,
+
     pub sol_value: u64,
     pub mint: [u8; 32],
     pub sol_value_calculator: [u8; 32],
@@ -22,7 +34,7 @@ pub struct LstStatePacked {
     pub(crate) is_input_disabled: u8,
     pub(crate) pool_reserves_bump: u8,
     pub(crate) protocol_fee_accumulator_bump: u8,
-    pub(crate) padding: [u8; 5],
+    pub(crate) synced_reserves_balance: CompactU64,
     pub(crate) sol_value: [u8; 8],
     pub(crate) mint: [u8; 32],
     pub(crate) sol_value_calculator: [u8; 32],
@@ -37,7 +49,7 @@ impl LstStatePacked {
             is_input_disabled,
             pool_reserves_bump,
             protocol_fee_accumulator_bump,
-            padding,
+            synced_reserves_balance,
             sol_value,
             mint,
             sol_value_calculator,
@@ -46,7 +58,7 @@ impl LstStatePacked {
             is_input_disabled,
             pool_reserves_bump,
             protocol_fee_accumulator_bump,
-            padding,
+            synced_reserves_balance,
             sol_value: u64::from_le_bytes(sol_value),
             mint,
             sol_value_calculator,
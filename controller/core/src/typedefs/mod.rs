@@ -1,6 +1,10 @@
+pub mod compact_u64;
+pub mod disable_modes;
 pub mod fee_nanos;
+pub mod lst_calc_backing;
 pub mod lst_state;
 pub mod pool_sv;
+pub mod protocol_fee_beneficiary_share;
 pub mod rps;
 pub mod snap;
 pub mod u8bool;
@@ -0,0 +1,33 @@
+use crate::internal_utils::{impl_cast_from_acc_data, impl_cast_to_acc_data};
+
+/// Entry of the
+/// [`crate::accounts::protocol_fee_beneficiary_list::ProtocolFeeBeneficiaryList`]
+/// recording a recipient of protocol fees and its relative share of them.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ProtocolFeeBeneficiaryShare {
+    /// Authority of the INF token account this entry's share is minted to
+    pub beneficiary: [u8; 32],
+
+    /// Little-endian u32.
+    ///
+    /// This entry receives `weight / sum of all weights` of withdrawn protocol fees.
+    pub weight: [u8; 4],
+}
+impl_cast_from_acc_data!(ProtocolFeeBeneficiaryShare, packed);
+impl_cast_to_acc_data!(ProtocolFeeBeneficiaryShare, packed);
+
+impl ProtocolFeeBeneficiaryShare {
+    #[inline]
+    pub const fn new(beneficiary: [u8; 32], weight: u32) -> Self {
+        Self {
+            beneficiary,
+            weight: weight.to_le_bytes(),
+        }
+    }
+
+    #[inline]
+    pub const fn weight(&self) -> u32 {
+        u32::from_le_bytes(self.weight)
+    }
+}
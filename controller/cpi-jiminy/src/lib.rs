@@ -0,0 +1,23 @@
+//! Helpers for other jiminy programs to CPI into the INF controller program
+
+#![cfg_attr(not(test), no_std)]
+
+use inf1_core::inf1_ctl_core;
+use jiminy_return_data::{get_return_data, ReturnData};
+
+// Re-exports
+pub use inf1_core::*;
+
+pub mod swap;
+pub mod sync_sol_value;
+
+/// Max length of return data that can be set by a program
+pub const MAX_RETURN_DATA: usize = 1024;
+
+pub type CtlReturnData = ReturnData<MAX_RETURN_DATA>;
+
+/// Return data of the last CPI, if it was set by the controller program
+#[inline]
+pub fn ctl_return_data() -> Option<CtlReturnData> {
+    get_return_data::<MAX_RETURN_DATA>().filter(|d| *d.program_id() == inf1_ctl_core::ID)
+}
@@ -0,0 +1,421 @@
+//! `SwapExactInV2` and `SwapExactOutV2`.
+//!
+//! To add or remove liquidity, convert
+//! [`AddLiquidityIxArgs`](inf1_core::instructions::swap::v2::liquidity::AddLiquidityIxArgs)
+//! or [`RemoveLiquidityIxArgs`](inf1_core::instructions::swap::v2::liquidity::RemoveLiquidityIxArgs)
+//! with `into_swap_args()`.
+
+use inf1_core::{
+    inf1_ctl_core::{
+        self,
+        instructions::swap::{
+            v2::{
+                exact_in::SwapExactInIxData, exact_out::SwapExactOutIxData, ret::SwapV2Ret,
+                IxPreAccs,
+            },
+            IX_DATA_LEN,
+        },
+    },
+    instructions::swap::{IxAccs, IxArgs},
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::{ProgramError, BORSH_IO_ERROR, INVALID_ARGUMENT},
+    Cpi, CpiBuilder,
+};
+
+use crate::ctl_return_data;
+
+pub type SwapV2IxPreAccountHandles<'a> = IxPreAccs<AccountHandle<'a>>;
+
+/// `C, D, P: AsRef<[AccountHandle]>`, the suffix accounts of the
+/// input SOL value calculator, output SOL value calculator and pricing program respectively
+pub type SwapV2IxAccountHandles<'a, C, D, P> =
+    IxAccs<AccountHandle<'a>, SwapV2IxPreAccountHandles<'a>, C, D, P>;
+
+pub type SwapV2IxArgsHandles<'a, C, D, P> =
+    IxArgs<AccountHandle<'a>, SwapV2IxPreAccountHandles<'a>, C, D, P>;
+
+/// Accounts are forwarded with the same permissions they were passed to the calling program with.
+///
+/// Returns the swap result set by the controller program as return data,
+/// failing with [`BORSH_IO_ERROR`] if it is missing or malformed
+#[inline]
+pub fn cpi_swap_exact_in_v2<'cpi, 'accounts, const MAX_CPI_ACCS: usize>(
+    cpi: &'cpi mut Cpi<MAX_CPI_ACCS>,
+    abr: &'cpi mut Abr,
+    args: &SwapV2IxArgsHandles<
+        'accounts,
+        impl AsRef<[AccountHandle<'accounts>]>,
+        impl AsRef<[AccountHandle<'accounts>]>,
+        impl AsRef<[AccountHandle<'accounts>]>,
+    >,
+) -> Result<SwapV2Ret, ProgramError> {
+    let ix_data = swap_exact_in_v2_ix_data(args)?;
+    prepare(cpi, abr, ix_data.as_buf(), &args.accs).and_then(invoke)
+}
+
+/// Accounts are forwarded with the same permissions they were passed to the calling program with.
+///
+/// Returns the swap result set by the controller program as return data,
+/// failing with [`BORSH_IO_ERROR`] if it is missing or malformed
+#[inline]
+pub fn cpi_swap_exact_out_v2<'cpi, 'accounts, const MAX_CPI_ACCS: usize>(
+    cpi: &'cpi mut Cpi<MAX_CPI_ACCS>,
+    abr: &'cpi mut Abr,
+    args: &SwapV2IxArgsHandles<
+        'accounts,
+        impl AsRef<[AccountHandle<'accounts>]>,
+        impl AsRef<[AccountHandle<'accounts>]>,
+        impl AsRef<[AccountHandle<'accounts>]>,
+    >,
+) -> Result<SwapV2Ret, ProgramError> {
+    let ix_data = swap_exact_out_v2_ix_data(args)?;
+    prepare(cpi, abr, ix_data.as_buf(), &args.accs).and_then(invoke)
+}
+
+#[inline]
+fn swap_exact_in_v2_ix_data<T, I, C: AsRef<[T]>, D: AsRef<[T]>, P>(
+    args: &IxArgs<T, I, C, D, P>,
+) -> Result<SwapExactInIxData, ProgramError> {
+    args.to_full_from_accs()
+        .map(|full| SwapExactInIxData::new(&full))
+        .ok_or(INVALID_ARGUMENT)
+}
+
+#[inline]
+fn swap_exact_out_v2_ix_data<T, I, C: AsRef<[T]>, D: AsRef<[T]>, P>(
+    args: &IxArgs<T, I, C, D, P>,
+) -> Result<SwapExactOutIxData, ProgramError> {
+    args.to_full_from_accs()
+        .map(|full| SwapExactOutIxData::new(&full))
+        .ok_or(INVALID_ARGUMENT)
+}
+
+#[inline]
+fn prepare<'cpi, 'accounts, const MAX_CPI_ACCS: usize>(
+    cpi: &'cpi mut Cpi<MAX_CPI_ACCS>,
+    abr: &'cpi mut Abr,
+    ix_data: &'cpi [u8; IX_DATA_LEN],
+    accs: &SwapV2IxAccountHandles<
+        'accounts,
+        impl AsRef<[AccountHandle<'accounts>]>,
+        impl AsRef<[AccountHandle<'accounts>]>,
+        impl AsRef<[AccountHandle<'accounts>]>,
+    >,
+) -> Result<CpiBuilder<'cpi, MAX_CPI_ACCS, true>, ProgramError> {
+    CpiBuilder::new(cpi, abr)
+        .with_prog_id(&inf1_ctl_core::ID)
+        .with_ix_data(ix_data)
+        .with_accounts_fwd(accs.seq().copied())
+}
+
+#[inline]
+fn invoke<const MAX_CPI_ACCS: usize>(
+    cpi: CpiBuilder<'_, MAX_CPI_ACCS, true>,
+) -> Result<SwapV2Ret, ProgramError> {
+    cpi.invoke()?;
+    swap_ret(ctl_return_data().as_ref().map(|d| d.data()))
+}
+
+#[inline]
+fn swap_ret(data: Option<&[u8]>) -> Result<SwapV2Ret, ProgramError> {
+    data.and_then(SwapV2Ret::parse).ok_or(BORSH_IO_ERROR)
+}
+
+/// [`AccountHandle`]s can only be obtained from the program's entrypoint,
+/// so these tests use keys in their place.
+/// Handles are forwarded in [`IxAccs::seq`] order with the permissions they were passed with.
+#[cfg(test)]
+mod tests {
+    use core::array;
+
+    use inf1_core::{
+        inf1_ctl_core::{
+            instructions::swap::v2::{
+                ret::{SwapV2RetV1, SwapV2RetV1Data},
+                IxPreKeysOwned,
+            },
+            svc::InfDummyCalcAccs,
+        },
+        inf1_pp_core::traits::main::{PriceExactInAccs, PriceExactOutAccs},
+        inf1_svc_core::traits::SolValCalcAccs,
+        instructions::swap::v2::{
+            exact_in::{
+                swap_exact_in_v2_ix_is_signer, swap_exact_in_v2_ix_is_writer,
+                swap_exact_in_v2_ix_keys_owned,
+            },
+            exact_out::{
+                swap_exact_out_v2_ix_is_signer, swap_exact_out_v2_ix_is_writer,
+                swap_exact_out_v2_ix_keys_owned,
+            },
+            liquidity::{
+                AddLiquidityIxAccs, AddLiquidityIxArgs, RemoveLiquidityIxAccs,
+                RemoveLiquidityIxArgs, LP_LST_INDEX,
+            },
+        },
+    };
+    use inf1_pp_flatfee_core::instructions::pricing::price::{
+        FlatFeePriceAccs, IxSufKeysOwned as FlatFeeSufKeysOwned,
+    };
+    use inf1_svc_spl_core::instructions::sol_val_calc::SplCalcAccs;
+
+    use super::*;
+
+    type KeysArgs<C, D> = IxArgs<[u8; 32], IxPreKeysOwned, C, D, FlatFeePriceAccs>;
+
+    const INP_SPL: SplCalcAccs = SplCalcAccs {
+        stake_pool_addr: [30; 32],
+    };
+
+    const OUT_SPL: SplCalcAccs = SplCalcAccs {
+        stake_pool_addr: [31; 32],
+    };
+
+    const PRICING: FlatFeePriceAccs =
+        FlatFeePriceAccs(FlatFeeSufKeysOwned::memset([32; 32]).const_with_output_fee([33; 32]));
+
+    fn keys_args<C, D>(
+        inp_lst_index: u32,
+        inp_calc: C,
+        out_lst_index: u32,
+        out_calc: D,
+    ) -> KeysArgs<C, D> {
+        IxArgs {
+            inp_lst_index,
+            out_lst_index,
+            limit: 3,
+            amount: 4,
+            accs: IxAccs {
+                ix_prefix: IxPreAccs::new(array::from_fn(|i| [i as u8 + 1; 32])),
+                inp_calc_prog: [20; 32],
+                inp_calc,
+                out_calc_prog: [21; 32],
+                out_calc,
+                pricing_prog: [22; 32],
+                pricing: PRICING,
+            },
+        }
+    }
+
+    /// `keys` with its SOL value calculator and pricing program suffixes
+    /// replaced by the accounts themselves, like [`SwapV2IxArgsHandles`]
+    fn fwd_args<C: SolValCalcAccs, D: SolValCalcAccs>(
+        keys: &KeysArgs<C, D>,
+    ) -> IxArgs<[u8; 32], IxPreKeysOwned, C::KeysOwned, D::KeysOwned, FlatFeeSufKeysOwned> {
+        let IxAccs {
+            ix_prefix,
+            inp_calc_prog,
+            inp_calc,
+            out_calc_prog,
+            out_calc,
+            pricing_prog,
+            pricing,
+        } = &keys.accs;
+        IxArgs {
+            inp_lst_index: keys.inp_lst_index,
+            out_lst_index: keys.out_lst_index,
+            limit: keys.limit,
+            amount: keys.amount,
+            accs: IxAccs {
+                ix_prefix: *ix_prefix,
+                inp_calc_prog: *inp_calc_prog,
+                inp_calc: inp_calc.suf_keys_owned(),
+                out_calc_prog: *out_calc_prog,
+                out_calc: out_calc.suf_keys_owned(),
+                pricing_prog: *pricing_prog,
+                pricing: PriceExactInAccs::suf_keys_owned(pricing),
+            },
+        }
+    }
+
+    fn assert_exact_in_round_trip<C: SolValCalcAccs, D: SolValCalcAccs>(
+        keys: &KeysArgs<C, D>,
+        args: &IxArgs<
+            [u8; 32],
+            IxPreKeysOwned,
+            impl AsRef<[[u8; 32]]>,
+            impl AsRef<[[u8; 32]]>,
+            impl AsRef<[[u8; 32]]>,
+        >,
+    ) {
+        assert_eq!(
+            swap_exact_in_v2_ix_data(args),
+            Ok(SwapExactInIxData::new(&keys.to_full()))
+        );
+        assert!(args
+            .accs
+            .seq()
+            .eq(swap_exact_in_v2_ix_keys_owned(&keys.accs).seq()));
+        [
+            swap_exact_in_v2_ix_is_signer(&keys.accs),
+            swap_exact_in_v2_ix_is_writer(&keys.accs),
+        ]
+        .iter()
+        .for_each(|flags| assert_eq!(flags.seq().count(), args.accs.seq().count()));
+    }
+
+    fn assert_exact_out_round_trip<C: SolValCalcAccs, D: SolValCalcAccs>(
+        keys: &KeysArgs<C, D>,
+        args: &IxArgs<
+            [u8; 32],
+            IxPreKeysOwned,
+            impl AsRef<[[u8; 32]]>,
+            impl AsRef<[[u8; 32]]>,
+            impl AsRef<[[u8; 32]]>,
+        >,
+    ) {
+        assert_eq!(
+            swap_exact_out_v2_ix_data(args),
+            Ok(SwapExactOutIxData::new(&keys.to_full()))
+        );
+        assert!(args
+            .accs
+            .seq()
+            .eq(swap_exact_out_v2_ix_keys_owned(&keys.accs).seq()));
+        [
+            swap_exact_out_v2_ix_is_signer(&keys.accs),
+            swap_exact_out_v2_ix_is_writer(&keys.accs),
+        ]
+        .iter()
+        .for_each(|flags| assert_eq!(flags.seq().count(), args.accs.seq().count()));
+        // same pricing program suffix for both directions
+        assert_eq!(
+            PriceExactOutAccs::suf_keys_owned(&keys.accs.pricing),
+            PriceExactInAccs::suf_keys_owned(&keys.accs.pricing)
+        );
+    }
+
+    #[test]
+    fn swap_exact_in_v2_round_trip() {
+        let keys = keys_args(1, INP_SPL, 2, OUT_SPL);
+        assert_exact_in_round_trip(&keys, &fwd_args(&keys));
+    }
+
+    #[test]
+    fn swap_exact_out_v2_round_trip() {
+        let keys = keys_args(1, INP_SPL, 2, OUT_SPL);
+        assert_exact_out_round_trip(&keys, &fwd_args(&keys));
+    }
+
+    #[test]
+    fn add_liquidity_round_trip() {
+        let keys = keys_args(1, INP_SPL, LP_LST_INDEX, InfDummyCalcAccs);
+        let IxAccs {
+            ix_prefix,
+            inp_calc_prog,
+            inp_calc,
+            out_calc_prog,
+            pricing_prog,
+            pricing,
+            ..
+        } = fwd_args(&keys).accs;
+        let args = AddLiquidityIxArgs {
+            inp_lst_index: 1,
+            limit: 3,
+            amount: 4,
+            accs: AddLiquidityIxAccs {
+                ix_prefix,
+                inp_calc_prog,
+                inp_calc,
+                lp_calc_prog: out_calc_prog,
+                pricing_prog,
+                pricing,
+            },
+        }
+        .into_swap_args();
+        assert_exact_in_round_trip(&keys, &args);
+        assert_exact_out_round_trip(&keys, &args);
+    }
+
+    #[test]
+    fn remove_liquidity_round_trip() {
+        let keys = keys_args(LP_LST_INDEX, InfDummyCalcAccs, 2, OUT_SPL);
+        let IxAccs {
+            ix_prefix,
+            inp_calc_prog,
+            out_calc_prog,
+            out_calc,
+            pricing_prog,
+            pricing,
+            ..
+        } = fwd_args(&keys).accs;
+        let args = RemoveLiquidityIxArgs {
+            out_lst_index: 2,
+            limit: 3,
+            amount: 4,
+            accs: RemoveLiquidityIxAccs {
+                ix_prefix,
+                lp_calc_prog: inp_calc_prog,
+                out_calc_prog,
+                out_calc,
+                pricing_prog,
+                pricing,
+            },
+        }
+        .into_swap_args();
+        assert_exact_in_round_trip(&keys, &args);
+        assert_exact_out_round_trip(&keys, &args);
+    }
+
+    #[test]
+    fn suffix_too_long_rejected() {
+        let keys = keys_args(1, INP_SPL, 2, OUT_SPL);
+        let IxArgs {
+            inp_lst_index,
+            out_lst_index,
+            limit,
+            amount,
+            accs:
+                IxAccs {
+                    ix_prefix,
+                    inp_calc_prog,
+                    out_calc_prog,
+                    out_calc,
+                    pricing_prog,
+                    pricing,
+                    ..
+                },
+        } = fwd_args(&keys);
+        let args = IxArgs {
+            inp_lst_index,
+            out_lst_index,
+            limit,
+            amount,
+            accs: IxAccs {
+                ix_prefix,
+                inp_calc_prog,
+                inp_calc: vec![[0u8; 32]; 256],
+                out_calc_prog,
+                out_calc,
+                pricing_prog,
+                pricing,
+            },
+        };
+        assert_eq!(
+            swap_exact_in_v2_ix_data(&args).err(),
+            Some(INVALID_ARGUMENT)
+        );
+        assert_eq!(
+            swap_exact_out_v2_ix_data(&args).err(),
+            Some(INVALID_ARGUMENT)
+        );
+    }
+
+    #[test]
+    fn swap_ret_parse() {
+        let ret = SwapV2RetV1 {
+            inp: 1,
+            out: 2,
+            fee: 3,
+            inp_sol_val: 4,
+            inp_mint: [5; 32],
+            out_mint: [6; 32],
+        };
+        let data = SwapV2RetV1Data::new(&ret);
+        assert_eq!(swap_ret(Some(data.as_buf())), Ok(SwapV2Ret::V1(ret)));
+        [None, Some(&data.as_buf()[1..]), Some(&[][..])]
+            .into_iter()
+            .for_each(|d| assert_eq!(swap_ret(d), Err(BORSH_IO_ERROR)));
+    }
+}
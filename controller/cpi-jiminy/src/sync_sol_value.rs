@@ -0,0 +1,78 @@
+use inf1_core::{
+    inf1_ctl_core::{
+        self,
+        instructions::sync_sol_value::{SyncSolValueIxData, SyncSolValueIxPreAccs},
+    },
+    instructions::sync_sol_value::SyncSolValueIxAccs,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+    Cpi, CpiBuilder,
+};
+
+pub type SyncSolValueIxPreAccountHandles<'a> = SyncSolValueIxPreAccs<AccountHandle<'a>>;
+
+/// `C: AsRef<[AccountHandle]>`, the LST's SOL value calculator suffix accounts
+pub type SyncSolValueIxAccountHandles<'a, C> =
+    SyncSolValueIxAccs<AccountHandle<'a>, SyncSolValueIxPreAccountHandles<'a>, C>;
+
+/// Accounts are forwarded with the same permissions they were passed to the calling program with.
+#[inline]
+pub fn cpi_sync_sol_value<'cpi, 'accounts, const MAX_CPI_ACCS: usize>(
+    cpi: &'cpi mut Cpi<MAX_CPI_ACCS>,
+    abr: &'cpi mut Abr,
+    lst_index: u32,
+    accs: &SyncSolValueIxAccountHandles<'accounts, impl AsRef<[AccountHandle<'accounts>]>>,
+) -> Result<(), ProgramError> {
+    CpiBuilder::new(cpi, abr)
+        .with_prog_id(&inf1_ctl_core::ID)
+        .with_ix_data(SyncSolValueIxData::new(lst_index).as_buf())
+        .with_accounts_fwd(accs.seq().copied())?
+        .invoke()
+}
+
+/// [`AccountHandle`]s can only be obtained from the program's entrypoint,
+/// so these tests use keys in their place.
+#[cfg(test)]
+mod tests {
+    use inf1_core::{
+        inf1_ctl_core::instructions::sync_sol_value::NewSyncSolValueIxPreAccsBuilder,
+        inf1_svc_core::traits::SolValCalcAccs,
+        instructions::sync_sol_value::{
+            sync_sol_value_ix_is_signer, sync_sol_value_ix_is_writer, sync_sol_value_ix_keys_owned,
+        },
+    };
+    use inf1_svc_spl_core::instructions::sol_val_calc::SplCalcAccs;
+
+    use super::*;
+
+    #[test]
+    fn sync_sol_value_round_trip() {
+        let keys = SyncSolValueIxAccs {
+            ix_prefix: NewSyncSolValueIxPreAccsBuilder::start()
+                .with_lst_mint([1; 32])
+                .with_pool_state([2; 32])
+                .with_lst_state_list([3; 32])
+                .with_pool_reserves([4; 32])
+                .build(),
+            calc_prog: [5; 32],
+            calc: SplCalcAccs {
+                stake_pool_addr: [6; 32],
+            },
+        };
+        let fwd = SyncSolValueIxAccs {
+            ix_prefix: keys.ix_prefix,
+            calc_prog: keys.calc_prog,
+            calc: keys.calc.suf_keys_owned(),
+        };
+
+        assert!(fwd.seq().eq(sync_sol_value_ix_keys_owned(&keys).seq()));
+        [
+            sync_sol_value_ix_is_signer(&keys),
+            sync_sol_value_ix_is_writer(&keys),
+        ]
+        .iter()
+        .for_each(|flags| assert_eq!(flags.seq().count(), fwd.seq().count()));
+    }
+}
@@ -0,0 +1,119 @@
+//! Helpers for other `solana-program` programs to CPI into the INF controller program
+
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use inf1_core::inf1_ctl_core;
+use solana_account_info::AccountInfo;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::ProgramResult;
+use solana_pubkey::Pubkey;
+
+// Re-exports
+pub use inf1_core::*;
+
+pub mod swap;
+pub mod sync_sol_value;
+
+/// Return data of the last CPI, if it was set by the controller program
+#[inline]
+pub fn ctl_return_data() -> Option<Vec<u8>> {
+    solana_cpi::get_return_data()
+        .and_then(|(prog, data)| (prog.to_bytes() == inf1_ctl_core::ID).then_some(data))
+}
+
+/// Invokes the controller program with `accs`,
+/// forwarding them with the same permissions they were passed to the calling program with.
+#[inline]
+fn invoke_fwd<'a, 'info: 'a>(
+    ix_data: &[u8],
+    accs: impl Iterator<Item = &'a AccountInfo<'info>> + Clone,
+) -> ProgramResult {
+    let ix = fwd_ix(ix_data, accs.clone());
+    let infos: Vec<AccountInfo<'info>> = accs.cloned().collect();
+    solana_cpi::invoke(&ix, &infos)
+}
+
+#[inline]
+fn fwd_ix<'a, 'info: 'a>(
+    ix_data: &[u8],
+    accs: impl Iterator<Item = &'a AccountInfo<'info>>,
+) -> Instruction {
+    Instruction {
+        program_id: Pubkey::new_from_array(inf1_ctl_core::ID),
+        accounts: accs
+            .map(|a| AccountMeta {
+                pubkey: *a.key,
+                is_signer: a.is_signer,
+                is_writable: a.is_writable,
+            })
+            .collect(),
+        data: ix_data.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    /// Backing storage for [`AccountInfo`]s that are passed to the calling program
+    /// with the given keys and permissions
+    pub struct AccsStore {
+        metas: Vec<AccountMeta>,
+        lamports: Vec<u64>,
+    }
+
+    impl AccsStore {
+        pub fn new<'a>(
+            keys: impl Iterator<Item = &'a [u8; 32]>,
+            is_signer: impl Iterator<Item = &'a bool>,
+            is_writer: impl Iterator<Item = &'a bool>,
+        ) -> Self {
+            let metas: Vec<_> = keys
+                .zip(is_signer)
+                .zip(is_writer)
+                .map(|((k, s), w)| AccountMeta {
+                    pubkey: Pubkey::new_from_array(*k),
+                    is_signer: *s,
+                    is_writable: *w,
+                })
+                .collect();
+            Self {
+                lamports: vec![0; metas.len()],
+                metas,
+            }
+        }
+
+        /// The instruction that should be invoked if all accounts are forwarded in order
+        pub fn expected_ix(&self, ix_data: &[u8]) -> Instruction {
+            Instruction {
+                program_id: Pubkey::new_from_array(inf1_ctl_core::ID),
+                accounts: self.metas.clone(),
+                data: ix_data.to_vec(),
+            }
+        }
+
+        pub fn infos(&mut self) -> Vec<AccountInfo<'_>> {
+            const OWNER: Pubkey = Pubkey::new_from_array([0; 32]);
+
+            self.metas
+                .iter()
+                .zip(self.lamports.iter_mut())
+                .map(|(m, l)| {
+                    AccountInfo::new(
+                        &m.pubkey,
+                        m.is_signer,
+                        m.is_writable,
+                        l,
+                        &mut [],
+                        &OWNER,
+                        false,
+                    )
+                })
+                .collect()
+        }
+    }
+}
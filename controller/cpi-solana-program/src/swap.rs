@@ -0,0 +1,401 @@
+//! `SwapExactInV2` and `SwapExactOutV2`.
+//!
+//! To add or remove liquidity, convert
+//! [`AddLiquidityIxArgs`](inf1_core::instructions::swap::v2::liquidity::AddLiquidityIxArgs)
+//! or [`RemoveLiquidityIxArgs`](inf1_core::instructions::swap::v2::liquidity::RemoveLiquidityIxArgs)
+//! with `into_swap_args()`.
+
+use inf1_core::{
+    inf1_ctl_core::instructions::swap::v2::{
+        exact_in::SwapExactInIxData, exact_out::SwapExactOutIxData, ret::SwapV2Ret, IxPreAccs,
+    },
+    instructions::swap::{IxAccs, IxArgs},
+};
+use solana_account_info::AccountInfo;
+use solana_program_error::ProgramError;
+
+use crate::{ctl_return_data, invoke_fwd};
+
+pub type SwapV2IxPreAccountInfos<'a, 'info> = IxPreAccs<&'a AccountInfo<'info>>;
+
+/// `C, D, P: AsRef<[&AccountInfo]>`, the suffix accounts of the
+/// input SOL value calculator, output SOL value calculator and pricing program respectively
+pub type SwapV2IxAccountInfos<'a, 'info, C, D, P> =
+    IxAccs<&'a AccountInfo<'info>, SwapV2IxPreAccountInfos<'a, 'info>, C, D, P>;
+
+pub type SwapV2IxArgsInfos<'a, 'info, C, D, P> =
+    IxArgs<&'a AccountInfo<'info>, SwapV2IxPreAccountInfos<'a, 'info>, C, D, P>;
+
+/// Accounts are forwarded with the same permissions they were passed to the calling program with.
+///
+/// Returns the swap result set by the controller program as return data,
+/// failing with `BorshIoError` if it is missing or malformed
+#[inline]
+pub fn cpi_swap_exact_in_v2<'a, 'info>(
+    args: &SwapV2IxArgsInfos<
+        'a,
+        'info,
+        impl AsRef<[&'a AccountInfo<'info>]>,
+        impl AsRef<[&'a AccountInfo<'info>]>,
+        impl AsRef<[&'a AccountInfo<'info>]>,
+    >,
+) -> Result<SwapV2Ret, ProgramError> {
+    let ix_data = swap_exact_in_v2_ix_data(args)?;
+    invoke_fwd(ix_data.as_buf(), args.accs.seq().copied())?;
+    swap_ret(ctl_return_data().as_deref())
+}
+
+#[inline]
+fn swap_exact_in_v2_ix_data<T, I, C: AsRef<[T]>, D: AsRef<[T]>, P>(
+    args: &IxArgs<T, I, C, D, P>,
+) -> Result<SwapExactInIxData, ProgramError> {
+    args.to_full_from_accs()
+        .map(|full| SwapExactInIxData::new(&full))
+        .ok_or(ProgramError::InvalidArgument)
+}
+
+/// Accounts are forwarded with the same permissions they were passed to the calling program with.
+///
+/// Returns the swap result set by the controller program as return data,
+/// failing with `BorshIoError` if it is missing or malformed
+#[inline]
+pub fn cpi_swap_exact_out_v2<'a, 'info>(
+    args: &SwapV2IxArgsInfos<
+        'a,
+        'info,
+        impl AsRef<[&'a AccountInfo<'info>]>,
+        impl AsRef<[&'a AccountInfo<'info>]>,
+        impl AsRef<[&'a AccountInfo<'info>]>,
+    >,
+) -> Result<SwapV2Ret, ProgramError> {
+    let ix_data = swap_exact_out_v2_ix_data(args)?;
+    invoke_fwd(ix_data.as_buf(), args.accs.seq().copied())?;
+    swap_ret(ctl_return_data().as_deref())
+}
+
+#[inline]
+fn swap_exact_out_v2_ix_data<T, I, C: AsRef<[T]>, D: AsRef<[T]>, P>(
+    args: &IxArgs<T, I, C, D, P>,
+) -> Result<SwapExactOutIxData, ProgramError> {
+    args.to_full_from_accs()
+        .map(|full| SwapExactOutIxData::new(&full))
+        .ok_or(ProgramError::InvalidArgument)
+}
+
+#[inline]
+fn swap_ret(data: Option<&[u8]>) -> Result<SwapV2Ret, ProgramError> {
+    data.and_then(SwapV2Ret::parse)
+        .ok_or(ProgramError::BorshIoError)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::array;
+
+    use inf1_core::{
+        inf1_ctl_core::{
+            instructions::swap::v2::{
+                ret::{SwapV2RetV1, SwapV2RetV1Data},
+                IxPreKeysOwned,
+            },
+            svc::InfDummyCalcAccs,
+        },
+        inf1_svc_core::traits::SolValCalcAccs,
+        instructions::swap::v2::{
+            exact_in::{
+                swap_exact_in_v2_ix_is_signer, swap_exact_in_v2_ix_is_writer,
+                swap_exact_in_v2_ix_keys_owned,
+            },
+            exact_out::{
+                swap_exact_out_v2_ix_is_signer, swap_exact_out_v2_ix_is_writer,
+                swap_exact_out_v2_ix_keys_owned,
+            },
+            liquidity::{
+                AddLiquidityIxAccs, AddLiquidityIxArgs, RemoveLiquidityIxAccs,
+                RemoveLiquidityIxArgs, LP_LST_INDEX,
+            },
+        },
+    };
+    use inf1_pp_flatfee_core::instructions::pricing::price::{
+        FlatFeePriceAccs, IxSufKeysOwned as FlatFeeSufKeysOwned,
+    };
+    use inf1_svc_spl_core::instructions::sol_val_calc::SplCalcAccs;
+    use solana_instruction::Instruction;
+
+    use crate::{fwd_ix, test_utils::AccsStore};
+
+    use super::*;
+
+    type KeysArgs<C, D> = IxArgs<[u8; 32], IxPreKeysOwned, C, D, FlatFeePriceAccs>;
+
+    type InfoVec<'a, 'info> = Vec<&'a AccountInfo<'info>>;
+
+    const SPL_SUF_LEN: usize = 4;
+
+    const INP_SPL: SplCalcAccs = SplCalcAccs {
+        stake_pool_addr: [30; 32],
+    };
+
+    const OUT_SPL: SplCalcAccs = SplCalcAccs {
+        stake_pool_addr: [31; 32],
+    };
+
+    const PRICING: FlatFeePriceAccs =
+        FlatFeePriceAccs(FlatFeeSufKeysOwned::memset([32; 32]).const_with_output_fee([33; 32]));
+
+    fn keys_args<C, D>(
+        inp_lst_index: u32,
+        inp_calc: C,
+        out_lst_index: u32,
+        out_calc: D,
+    ) -> KeysArgs<C, D> {
+        IxArgs {
+            inp_lst_index,
+            out_lst_index,
+            limit: 3,
+            amount: 4,
+            accs: IxAccs {
+                ix_prefix: IxPreAccs::new(array::from_fn(|i| [i as u8 + 1; 32])),
+                inp_calc_prog: [20; 32],
+                inp_calc,
+                out_calc_prog: [21; 32],
+                out_calc,
+                pricing_prog: [22; 32],
+                pricing: PRICING,
+            },
+        }
+    }
+
+    /// Splits `infos`, which are in [`IxAccs::seq`] order, back into swap accounts
+    fn info_accs<'a, 'info>(
+        infos: &'a [AccountInfo<'info>],
+        inp_suf_len: usize,
+        out_suf_len: usize,
+    ) -> SwapV2IxAccountInfos<'a, 'info, InfoVec<'a, 'info>, InfoVec<'a, 'info>, InfoVec<'a, 'info>>
+    {
+        let mut it = infos.iter();
+        let ix_prefix = IxPreAccs::new(array::from_fn(|_| it.next().unwrap()));
+        let inp_calc_prog = it.next().unwrap();
+        let inp_calc = it.by_ref().take(inp_suf_len).collect();
+        let out_calc_prog = it.next().unwrap();
+        let out_calc = it.by_ref().take(out_suf_len).collect();
+        let pricing_prog = it.next().unwrap();
+        IxAccs {
+            ix_prefix,
+            inp_calc_prog,
+            inp_calc,
+            out_calc_prog,
+            out_calc,
+            pricing_prog,
+            pricing: it.collect(),
+        }
+    }
+
+    fn info_args<'a, 'info, C, D, P>(
+        keys: &KeysArgs<impl SolValCalcAccs, impl SolValCalcAccs>,
+        accs: IxAccs<&'a AccountInfo<'info>, SwapV2IxPreAccountInfos<'a, 'info>, C, D, P>,
+    ) -> SwapV2IxArgsInfos<'a, 'info, C, D, P> {
+        IxArgs {
+            inp_lst_index: keys.inp_lst_index,
+            out_lst_index: keys.out_lst_index,
+            limit: keys.limit,
+            amount: keys.amount,
+            accs,
+        }
+    }
+
+    /// Same as [`cpi_swap_exact_in_v2`] without the invoke
+    fn exact_in_ix<'a, 'info>(
+        args: &SwapV2IxArgsInfos<
+            'a,
+            'info,
+            impl AsRef<[&'a AccountInfo<'info>]>,
+            impl AsRef<[&'a AccountInfo<'info>]>,
+            impl AsRef<[&'a AccountInfo<'info>]>,
+        >,
+    ) -> Instruction {
+        fwd_ix(
+            swap_exact_in_v2_ix_data(args).unwrap().as_buf(),
+            args.accs.seq().copied(),
+        )
+    }
+
+    /// Same as [`cpi_swap_exact_out_v2`] without the invoke
+    fn exact_out_ix<'a, 'info>(
+        args: &SwapV2IxArgsInfos<
+            'a,
+            'info,
+            impl AsRef<[&'a AccountInfo<'info>]>,
+            impl AsRef<[&'a AccountInfo<'info>]>,
+            impl AsRef<[&'a AccountInfo<'info>]>,
+        >,
+    ) -> Instruction {
+        fwd_ix(
+            swap_exact_out_v2_ix_data(args).unwrap().as_buf(),
+            args.accs.seq().copied(),
+        )
+    }
+
+    fn assert_exact_in_round_trip<C: SolValCalcAccs, D: SolValCalcAccs>(
+        keys: &KeysArgs<C, D>,
+        ix: impl FnOnce(&[AccountInfo]) -> Instruction,
+    ) {
+        let mut store = AccsStore::new(
+            swap_exact_in_v2_ix_keys_owned(&keys.accs).seq(),
+            swap_exact_in_v2_ix_is_signer(&keys.accs).seq(),
+            swap_exact_in_v2_ix_is_writer(&keys.accs).seq(),
+        );
+        let expected = store.expected_ix(SwapExactInIxData::new(&keys.to_full()).as_buf());
+        assert_eq!(ix(&store.infos()), expected);
+    }
+
+    fn assert_exact_out_round_trip<C: SolValCalcAccs, D: SolValCalcAccs>(
+        keys: &KeysArgs<C, D>,
+        ix: impl FnOnce(&[AccountInfo]) -> Instruction,
+    ) {
+        let mut store = AccsStore::new(
+            swap_exact_out_v2_ix_keys_owned(&keys.accs).seq(),
+            swap_exact_out_v2_ix_is_signer(&keys.accs).seq(),
+            swap_exact_out_v2_ix_is_writer(&keys.accs).seq(),
+        );
+        let expected = store.expected_ix(SwapExactOutIxData::new(&keys.to_full()).as_buf());
+        assert_eq!(ix(&store.infos()), expected);
+    }
+
+    fn add_liq_args<'a, 'info>(
+        keys: &KeysArgs<SplCalcAccs, InfDummyCalcAccs>,
+        infos: &'a [AccountInfo<'info>],
+    ) -> AddLiquidityIxArgs<&'a AccountInfo<'info>, InfoVec<'a, 'info>, InfoVec<'a, 'info>> {
+        let IxAccs {
+            ix_prefix,
+            inp_calc_prog,
+            inp_calc,
+            out_calc_prog,
+            pricing_prog,
+            pricing,
+            ..
+        } = info_accs(infos, SPL_SUF_LEN, 0);
+        AddLiquidityIxArgs {
+            inp_lst_index: keys.inp_lst_index,
+            limit: keys.limit,
+            amount: keys.amount,
+            accs: AddLiquidityIxAccs {
+                ix_prefix,
+                inp_calc_prog,
+                inp_calc,
+                lp_calc_prog: out_calc_prog,
+                pricing_prog,
+                pricing,
+            },
+        }
+    }
+
+    fn remove_liq_args<'a, 'info>(
+        keys: &KeysArgs<InfDummyCalcAccs, SplCalcAccs>,
+        infos: &'a [AccountInfo<'info>],
+    ) -> RemoveLiquidityIxArgs<&'a AccountInfo<'info>, InfoVec<'a, 'info>, InfoVec<'a, 'info>> {
+        let IxAccs {
+            ix_prefix,
+            inp_calc_prog,
+            out_calc_prog,
+            out_calc,
+            pricing_prog,
+            pricing,
+            ..
+        } = info_accs(infos, 0, SPL_SUF_LEN);
+        RemoveLiquidityIxArgs {
+            out_lst_index: keys.out_lst_index,
+            limit: keys.limit,
+            amount: keys.amount,
+            accs: RemoveLiquidityIxAccs {
+                ix_prefix,
+                lp_calc_prog: inp_calc_prog,
+                out_calc_prog,
+                out_calc,
+                pricing_prog,
+                pricing,
+            },
+        }
+    }
+
+    #[test]
+    fn swap_exact_in_v2_round_trip() {
+        let keys = keys_args(1, INP_SPL, 2, OUT_SPL);
+        assert_exact_in_round_trip(&keys, |infos| {
+            exact_in_ix(&info_args(
+                &keys,
+                info_accs(infos, SPL_SUF_LEN, SPL_SUF_LEN),
+            ))
+        });
+    }
+
+    #[test]
+    fn swap_exact_out_v2_round_trip() {
+        let keys = keys_args(1, INP_SPL, 2, OUT_SPL);
+        assert_exact_out_round_trip(&keys, |infos| {
+            exact_out_ix(&info_args(
+                &keys,
+                info_accs(infos, SPL_SUF_LEN, SPL_SUF_LEN),
+            ))
+        });
+    }
+
+    #[test]
+    fn add_liquidity_round_trip() {
+        let keys = keys_args(1, INP_SPL, LP_LST_INDEX, InfDummyCalcAccs);
+        assert_exact_in_round_trip(&keys, |infos| {
+            exact_in_ix(&add_liq_args(&keys, infos).into_swap_args())
+        });
+        assert_exact_out_round_trip(&keys, |infos| {
+            exact_out_ix(&add_liq_args(&keys, infos).into_swap_args())
+        });
+    }
+
+    #[test]
+    fn remove_liquidity_round_trip() {
+        let keys = keys_args(LP_LST_INDEX, InfDummyCalcAccs, 2, OUT_SPL);
+        assert_exact_in_round_trip(&keys, |infos| {
+            exact_in_ix(&remove_liq_args(&keys, infos).into_swap_args())
+        });
+        assert_exact_out_round_trip(&keys, |infos| {
+            exact_out_ix(&remove_liq_args(&keys, infos).into_swap_args())
+        });
+    }
+
+    #[test]
+    fn swap_ret_parse() {
+        let ret = SwapV2RetV1 {
+            inp: 1,
+            out: 2,
+            fee: 3,
+            inp_sol_val: 4,
+            inp_mint: [5; 32],
+            out_mint: [6; 32],
+        };
+        let data = SwapV2RetV1Data::new(&ret);
+        assert_eq!(swap_ret(Some(data.as_buf())), Ok(SwapV2Ret::V1(ret)));
+        [None, Some(&data.as_buf()[1..]), Some(&[][..])]
+            .into_iter()
+            .for_each(|d| assert_eq!(swap_ret(d), Err(ProgramError::BorshIoError)));
+    }
+
+    #[test]
+    fn suffix_too_long_rejected() {
+        let keys = keys_args(1, INP_SPL, 2, OUT_SPL);
+        let mut store = AccsStore::new(
+            core::iter::repeat_n(&[0u8; 32], 300),
+            core::iter::repeat_n(&false, 300),
+            core::iter::repeat_n(&false, 300),
+        );
+        let infos = store.infos();
+        let args = info_args(&keys, info_accs(&infos, 256, 0));
+        assert_eq!(
+            swap_exact_in_v2_ix_data(&args).err(),
+            Some(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            swap_exact_out_v2_ix_data(&args).err(),
+            Some(ProgramError::InvalidArgument)
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use inf1_core::{
+    inf1_ctl_core::instructions::sync_sol_value::{SyncSolValueIxData, SyncSolValueIxPreAccs},
+    instructions::sync_sol_value::SyncSolValueIxAccs,
+};
+use solana_account_info::AccountInfo;
+use solana_program_error::ProgramResult;
+
+use crate::invoke_fwd;
+
+pub type SyncSolValueIxPreAccountInfos<'a, 'info> = SyncSolValueIxPreAccs<&'a AccountInfo<'info>>;
+
+/// `C: AsRef<[&AccountInfo]>`, the LST's SOL value calculator suffix accounts
+pub type SyncSolValueIxAccountInfos<'a, 'info, C> =
+    SyncSolValueIxAccs<&'a AccountInfo<'info>, SyncSolValueIxPreAccountInfos<'a, 'info>, C>;
+
+/// Accounts are forwarded with the same permissions they were passed to the calling program with.
+#[inline]
+pub fn cpi_sync_sol_value<'a, 'info>(
+    lst_index: u32,
+    accs: &SyncSolValueIxAccountInfos<'a, 'info, impl AsRef<[&'a AccountInfo<'info>]>>,
+) -> ProgramResult {
+    invoke_fwd(
+        SyncSolValueIxData::new(lst_index).as_buf(),
+        accs.seq().copied(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use inf1_core::{
+        inf1_ctl_core::instructions::sync_sol_value::NewSyncSolValueIxPreAccsBuilder,
+        instructions::sync_sol_value::{
+            sync_sol_value_ix_is_signer, sync_sol_value_ix_is_writer, sync_sol_value_ix_keys_owned,
+        },
+    };
+    use inf1_svc_spl_core::instructions::sol_val_calc::SplCalcAccs;
+
+    use crate::{fwd_ix, test_utils::AccsStore};
+
+    use super::*;
+
+    #[test]
+    fn sync_sol_value_round_trip() {
+        let keys = SyncSolValueIxAccs {
+            ix_prefix: NewSyncSolValueIxPreAccsBuilder::start()
+                .with_lst_mint([1; 32])
+                .with_pool_state([2; 32])
+                .with_lst_state_list([3; 32])
+                .with_pool_reserves([4; 32])
+                .build(),
+            calc_prog: [5; 32],
+            calc: SplCalcAccs {
+                stake_pool_addr: [6; 32],
+            },
+        };
+        let mut store = AccsStore::new(
+            sync_sol_value_ix_keys_owned(&keys).seq(),
+            sync_sol_value_ix_is_signer(&keys).seq(),
+            sync_sol_value_ix_is_writer(&keys).seq(),
+        );
+        let ix_data = SyncSolValueIxData::new(7);
+        let expected = store.expected_ix(ix_data.as_buf());
+
+        let infos = store.infos();
+        let mut it = infos.iter();
+        let accs = SyncSolValueIxAccs {
+            ix_prefix: NewSyncSolValueIxPreAccsBuilder::start()
+                .with_lst_mint(it.next().unwrap())
+                .with_pool_state(it.next().unwrap())
+                .with_lst_state_list(it.next().unwrap())
+                .with_pool_reserves(it.next().unwrap())
+                .build(),
+            calc_prog: it.next().unwrap(),
+            calc: it.collect::<Vec<_>>(),
+        };
+        let accs: &SyncSolValueIxAccountInfos<_> = &accs;
+
+        assert_eq!(fwd_ix(ix_data.as_buf(), accs.seq().copied()), expected);
+    }
+}
@@ -1,13 +1,21 @@
 use inf1_ctl_core::{
     accounts::{
         disable_pool_authority_list::{DisablePoolAuthorityList, DisablePoolAuthorityListMut},
+        inf_price_history::InfPriceHistory,
+        lst_calc_backing_list::{LstCalcBackingList, LstCalcBackingListMut},
         lst_state_list::{LstStateList, LstStateListMut},
         packed_list::{PackedList, PackedListMut},
+        pending_admin_action::PendingAdminAction,
+        pool_config::PoolConfig,
         pool_state::{PoolState, PoolStateV2},
+        protocol_fee_beneficiary_list::{
+            ProtocolFeeBeneficiaryList, ProtocolFeeBeneficiaryListMut,
+        },
         rebalance_record::RebalanceRecord,
+        referral_config::ReferralConfig,
     },
     err::Inf1CtlErr,
-    typedefs::lst_state::LstState,
+    typedefs::{lst_state::LstState, protocol_fee_beneficiary_share::ProtocolFeeBeneficiaryShare},
 };
 use jiminy_cpi::account::Account;
 
@@ -160,6 +168,38 @@ pub fn disable_pool_auth_list_get(
     ))
 }
 
+#[inline]
+pub fn lst_calc_backing_list_checked(
+    acc: &Account,
+) -> Result<LstCalcBackingList<'_>, Inf1CtlCustomProgErr> {
+    PackedList::of_acc_data(acc.data()).ok_or(Inf1CtlCustomProgErr(
+        Inf1CtlErr::InvalidLstCalcBackingListData,
+    ))
+}
+
+#[inline]
+pub fn lst_calc_backing_list_checked_mut(
+    acc: &mut Account,
+) -> Result<LstCalcBackingListMut<'_>, Inf1CtlCustomProgErr> {
+    PackedListMut::of_acc_data(acc.data_mut()).ok_or(Inf1CtlCustomProgErr(
+        Inf1CtlErr::InvalidLstCalcBackingListData,
+    ))
+}
+
+#[inline]
+pub fn inf_price_history_checked(acc: &Account) -> Result<&InfPriceHistory, Inf1CtlCustomProgErr> {
+    InfPriceHistory::of_acc_data(acc.data())
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidInfPriceHistoryData))
+}
+
+#[inline]
+pub fn inf_price_history_checked_mut(
+    acc: &mut Account,
+) -> Result<&mut InfPriceHistory, Inf1CtlCustomProgErr> {
+    InfPriceHistory::of_acc_data_mut(acc.data_mut())
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidInfPriceHistoryData))
+}
+
 const _REBALANCE_RECORD_ALIGN_CHECK: () =
     assert!(core::mem::align_of::<RebalanceRecord>() <= _ACC_DATA_ALIGN);
 
@@ -178,3 +218,112 @@ pub fn rebalance_record_checked_mut(
     unsafe { RebalanceRecord::of_acc_data_mut(acc.data_mut()) }
         .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidRebalanceRecordData))
 }
+
+const _REFERRAL_CONFIG_ALIGN_CHECK: () =
+    assert!(core::mem::align_of::<ReferralConfig>() <= _ACC_DATA_ALIGN);
+
+#[inline]
+pub fn referral_config_checked(acc: &Account) -> Result<&ReferralConfig, Inf1CtlCustomProgErr> {
+    // safety: account data is 8-byte aligned
+    unsafe { ReferralConfig::of_acc_data(acc.data()) }
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidReferralConfigData))
+}
+
+#[inline]
+pub fn referral_config_checked_mut(
+    acc: &mut Account,
+) -> Result<&mut ReferralConfig, Inf1CtlCustomProgErr> {
+    // safety: account data is 8-byte aligned
+    unsafe { ReferralConfig::of_acc_data_mut(acc.data_mut()) }
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidReferralConfigData))
+}
+
+const _POOL_CONFIG_ALIGN_CHECK: () =
+    assert!(core::mem::align_of::<PoolConfig>() <= _ACC_DATA_ALIGN);
+
+#[inline]
+pub fn pool_config_checked(acc: &Account) -> Result<&PoolConfig, Inf1CtlCustomProgErr> {
+    // safety: account data is 8-byte aligned
+    unsafe { PoolConfig::of_acc_data(acc.data()) }
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidPoolConfigData))
+}
+
+#[inline]
+pub fn pool_config_checked_mut(acc: &mut Account) -> Result<&mut PoolConfig, Inf1CtlCustomProgErr> {
+    // safety: account data is 8-byte aligned
+    unsafe { PoolConfig::of_acc_data_mut(acc.data_mut()) }
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidPoolConfigData))
+}
+
+/// Returns the pool's admin action delay,
+/// which is 0 if the pool config PDA has not been created
+#[inline]
+pub fn pool_config_admin_action_delay_slots(acc: &Account) -> Result<u64, Inf1CtlCustomProgErr> {
+    if acc.data_len() == 0 {
+        Ok(0)
+    } else {
+        pool_config_checked(acc).map(|c| c.admin_action_delay_slots)
+    }
+}
+
+/// Returns the pool's rebalance loss tolerance,
+/// which is 0 if the pool config PDA has not been created
+#[inline]
+pub fn pool_config_rebalance_max_loss_lamports(acc: &Account) -> Result<u64, Inf1CtlCustomProgErr> {
+    if acc.data_len() == 0 {
+        Ok(0)
+    } else {
+        pool_config_checked(acc).map(|c| c.rebalance_max_loss_lamports)
+    }
+}
+
+const _PENDING_ADMIN_ACTION_ALIGN_CHECK: () =
+    assert!(core::mem::align_of::<PendingAdminAction>() <= _ACC_DATA_ALIGN);
+
+#[inline]
+pub fn pending_admin_action_checked(
+    acc: &Account,
+) -> Result<&PendingAdminAction, Inf1CtlCustomProgErr> {
+    // safety: account data is 8-byte aligned
+    unsafe { PendingAdminAction::of_acc_data(acc.data()) }.ok_or(Inf1CtlCustomProgErr(
+        Inf1CtlErr::InvalidPendingAdminActionData,
+    ))
+}
+
+#[inline]
+pub fn pending_admin_action_checked_mut(
+    acc: &mut Account,
+) -> Result<&mut PendingAdminAction, Inf1CtlCustomProgErr> {
+    // safety: account data is 8-byte aligned
+    unsafe { PendingAdminAction::of_acc_data_mut(acc.data_mut()) }.ok_or(Inf1CtlCustomProgErr(
+        Inf1CtlErr::InvalidPendingAdminActionData,
+    ))
+}
+
+#[inline]
+pub fn protocol_fee_beneficiary_list_checked(
+    acc: &Account,
+) -> Result<ProtocolFeeBeneficiaryList<'_>, Inf1CtlCustomProgErr> {
+    PackedList::of_acc_data(acc.data()).ok_or(Inf1CtlCustomProgErr(
+        Inf1CtlErr::InvalidProtocolFeeBeneficiaryListData,
+    ))
+}
+
+#[inline]
+pub fn protocol_fee_beneficiary_list_checked_mut(
+    acc: &mut Account,
+) -> Result<ProtocolFeeBeneficiaryListMut<'_>, Inf1CtlCustomProgErr> {
+    PackedListMut::of_acc_data(acc.data_mut()).ok_or(Inf1CtlCustomProgErr(
+        Inf1CtlErr::InvalidProtocolFeeBeneficiaryListData,
+    ))
+}
+
+#[inline]
+pub fn protocol_fee_beneficiary_list_get(
+    list: ProtocolFeeBeneficiaryList<'_>,
+    idx: usize,
+) -> Result<&ProtocolFeeBeneficiaryShare, Inf1CtlCustomProgErr> {
+    list.0.get(idx).ok_or(Inf1CtlCustomProgErr(
+        Inf1CtlErr::InvalidProtocolFeeBeneficiaryIndex,
+    ))
+}
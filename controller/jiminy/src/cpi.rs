@@ -37,7 +37,7 @@ pub type EndRebalanceIxPreAccountHandles<'account> = EndRebalanceIxPreAccs<Accou
 
 pub type SwapIxPreAccountHandles<'account> = SwapIxPreAccs<AccountHandle<'account>>;
 
-// TODO: make invoke() helpers for client programs
+// invoke() helpers for client programs are in `inf1-ctl-cpi-jiminy`
 
 /// Wrapper for the return value from CPI call to `sol-val-calc` program
 ///
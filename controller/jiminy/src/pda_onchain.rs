@@ -1,12 +1,16 @@
 use inf1_ctl_core::{
     keys::{
-        ATOKEN_ID, DISABLE_POOL_AUTHORITY_LIST_BUMP, LST_STATE_LIST_BUMP, POOL_STATE_BUMP,
-        PROTOCOL_FEE_BUMP, REBALANCE_RECORD_BUMP,
+        ATOKEN_ID, DISABLE_POOL_AUTHORITY_LIST_BUMP, INF_PRICE_HISTORY_BUMP,
+        LST_CALC_BACKING_LIST_BUMP, LST_STATE_LIST_BUMP, PENDING_ADMIN_ACTION_BUMP,
+        POOL_CONFIG_BUMP, POOL_STATE_BUMP, PROTOCOL_FEE_BENEFICIARY_LIST_BUMP, PROTOCOL_FEE_BUMP,
+        REBALANCE_RECORD_BUMP, REFERRAL_CONFIG_BUMP,
     },
     pda::{
         pool_reserves_ata_seeds, protocol_fee_accumulator_ata_seeds,
-        DISABLE_POOL_AUTHORITY_LIST_SEED, LST_STATE_LIST_SEED, POOL_STATE_SEED, PROTOCOL_FEE_SEED,
-        REBALANCE_RECORD_SEED,
+        DISABLE_POOL_AUTHORITY_LIST_SEED, INF_PRICE_HISTORY_SEED, LST_CALC_BACKING_LIST_SEED,
+        LST_STATE_LIST_SEED, PENDING_ADMIN_ACTION_SEED, POOL_CONFIG_SEED, POOL_STATE_SEED,
+        PROTOCOL_FEE_BENEFICIARY_LIST_SEED, PROTOCOL_FEE_SEED, REBALANCE_RECORD_SEED,
+        REFERRAL_CONFIG_SEED,
     },
 };
 use jiminy_pda::{
@@ -32,11 +36,37 @@ const_1seed_signer!(
     DISABLE_POOL_AUTHORITY_LIST_SEED,
     DISABLE_POOL_AUTHORITY_LIST_BUMP
 );
+const_1seed_signer!(
+    LST_CALC_BACKING_LIST_SIGNER,
+    LST_CALC_BACKING_LIST_SEED,
+    LST_CALC_BACKING_LIST_BUMP
+);
+const_1seed_signer!(
+    INF_PRICE_HISTORY_SIGNER,
+    INF_PRICE_HISTORY_SEED,
+    INF_PRICE_HISTORY_BUMP
+);
 const_1seed_signer!(
     REBALANCE_RECORD_SIGNER,
     REBALANCE_RECORD_SEED,
     REBALANCE_RECORD_BUMP
 );
+const_1seed_signer!(
+    REFERRAL_CONFIG_SIGNER,
+    REFERRAL_CONFIG_SEED,
+    REFERRAL_CONFIG_BUMP
+);
+const_1seed_signer!(
+    PROTOCOL_FEE_BENEFICIARY_LIST_SIGNER,
+    PROTOCOL_FEE_BENEFICIARY_LIST_SEED,
+    PROTOCOL_FEE_BENEFICIARY_LIST_BUMP
+);
+const_1seed_signer!(POOL_CONFIG_SIGNER, POOL_CONFIG_SEED, POOL_CONFIG_BUMP);
+const_1seed_signer!(
+    PENDING_ADMIN_ACTION_SIGNER,
+    PENDING_ADMIN_ACTION_SEED,
+    PENDING_ADMIN_ACTION_BUMP
+);
 
 #[inline]
 pub fn create_raw_pool_reserves_addr(
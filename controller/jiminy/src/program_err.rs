@@ -100,6 +100,22 @@ seqerr!(
     InvalidPoolStateDataV2(_),
     TimeWentBackwards,
     UnauthorizedSetRpsAuthoritySigner,
+    InvalidInfPriceHistoryData,
+    InvalidReferralConfigData,
+    InvalidProtocolFeeBeneficiaryListData,
+    InvalidProtocolFeeBeneficiaryIndex,
+    TooManyProtocolFeeBeneficiaries,
+    PoolOperationDisabled(_),
+    AdminActionTimelocked,
+    AdminActionNotReady,
+    AdminActionAlreadyQueued,
+    InvalidPendingAdminActionData,
+    InvalidPoolConfigData,
+    LstSolValueCircuitBreakerTripped,
+    FeeBelowMin,
+    InvalidLstCalcBackingListData,
+    RemoveLiquidityReferral,
+    ProtocolFeeBeneficiaryListNotEmpty,
 );
 
 pub struct Inf1CtlCustomProgErr(pub Inf1CtlErr);
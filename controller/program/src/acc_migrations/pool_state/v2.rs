@@ -1,8 +1,8 @@
 use core::cmp::max;
 
 use inf1_ctl_jiminy::{
-    account_utils::{pool_state_checked, pool_state_v2_checked},
-    accounts::pool_state::{PoolState, PoolStateV2},
+    account_utils::{pool_state_checked, pool_state_v2_checked, pool_state_v2_checked_mut},
+    accounts::pool_state::{PoolState, PoolStateV2, POOL_STATE_V2_INITIAL_LEN},
     err::Inf1CtlErr,
     keys::POOL_STATE_ID,
     program_err::Inf1CtlCustomProgErr,
@@ -13,6 +13,9 @@ use jiminy_sysvar_clock::Clock;
 
 const BPS_TO_NANOS_MULTIPLE: u32 = NANOS_DENOM / 10_000;
 
+/// Migrates v1 pool states and extends v2 pool states of
+/// [`POOL_STATE_V2_INITIAL_LEN`] to the current length.
+///
 /// Also verifies identity of `pool_state_acc_unchecked`
 ///
 /// # Prerequisites
@@ -31,6 +34,20 @@ pub fn migrate_idmpt(
         return Ok(());
     }
 
+    // v2 accounts created before fields were appended to PoolStateV2
+    if pool_state_acc_unchecked.data_len() == POOL_STATE_V2_INITIAL_LEN {
+        pool_state_acc_unchecked.realloc(core::mem::size_of::<PoolStateV2>(), false)?;
+        // version checked here
+        let PoolStateV2 {
+            min_fee_nanos,
+            sol_value_circuit_breaker_nanos,
+            ..
+        } = pool_state_v2_checked_mut(pool_state_acc_unchecked)?;
+        *min_fee_nanos = 0;
+        *sol_value_circuit_breaker_nanos = 0;
+        return Ok(());
+    }
+
     // this also ensures pool_state is v1 at this point
     let init_protocol_fee_nanos = pool_state_checked(pool_state_acc_unchecked).map(
         |PoolState {
@@ -47,23 +64,30 @@ pub fn migrate_idmpt(
     let PoolStateV2 {
         protocol_fee_nanos,
         version,
+        disable_modes,
         admin,
         rps_authority,
         rps,
         withheld_lamports,
         protocol_fee_lamports,
         last_release_slot,
+        min_fee_nanos,
+        sol_value_circuit_breaker_nanos,
         ..
     } = unsafe { PoolStateV2::of_acc_data_mut(pool_state_acc_unchecked.data_mut()) }
         .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidPoolStateData))?;
 
     *version = 2;
     *protocol_fee_nanos = init_protocol_fee_nanos;
+    // v1 padding, not guaranteed to be zeroed
+    *disable_modes = 0;
     *rps_authority = *admin;
     *withheld_lamports = 0;
     *protocol_fee_lamports = 0;
     *rps = *Rps::DEFAULT.as_raw();
     *last_release_slot = clock.slot;
+    *min_fee_nanos = 0;
+    *sol_value_circuit_breaker_nanos = 0;
 
     Ok(())
 }
@@ -11,8 +11,10 @@ pub fn quote_err_to_inf1_ctl_err(e: QuoteErr<Infallible, Infallible, Infallible>
             unreachable!()
         }
         QuoteErr::InpDisabled => Inf1CtlErr::LstInputDisabled,
+        QuoteErr::Disabled(m) => Inf1CtlErr::PoolOperationDisabled(m),
         QuoteErr::NotEnoughLiquidity(_) => Inf1CtlErr::NotEnoughLiquidity,
         QuoteErr::PoolLoss => Inf1CtlErr::PoolWouldLoseSolValue,
         QuoteErr::ZeroValue => Inf1CtlErr::ZeroValue,
+        QuoteErr::FeeBelowMin => Inf1CtlErr::FeeBelowMin,
     }
 }
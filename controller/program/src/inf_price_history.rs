@@ -0,0 +1,63 @@
+use inf1_ctl_jiminy::{
+    account_utils::{
+        inf_price_history_checked, inf_price_history_checked_mut, pool_state_v2_checked,
+    },
+    accounts::{inf_price_history::InfPriceSample, pool_state::PoolStateV2},
+    err::Inf1CtlErr,
+    program_err::Inf1CtlCustomProgErr,
+    svc::InfCalc,
+    yields::release::ReleaseYieldParams,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+};
+use jiminy_sysvar_clock::Clock;
+
+use crate::token::checked_mint_of;
+
+/// INF's SOL value with pending yield release applied,
+/// without writing to pool state
+#[inline]
+pub fn inf_calc_lookahead(
+    pool: &PoolStateV2,
+    inf_mint_supply: u64,
+    curr_slot: u64,
+) -> Result<InfCalc, ProgramError> {
+    let params = ReleaseYieldParams::new(pool, curr_slot).map_err(Inf1CtlCustomProgErr)?;
+    Ok(InfCalc::new(pool, inf_mint_supply)
+        .lookahead(params)
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::MathError))?)
+}
+
+/// Appends a sample to the INF price history PDA if one is due.
+///
+/// No-op if the PDA has not yet been created.
+///
+/// Accounts must have been verified by caller.
+#[inline]
+pub fn sample_inf_price_history(
+    abr: &mut Abr,
+    pool_state: AccountHandle,
+    lp_token_mint: AccountHandle,
+    inf_price_history: AccountHandle,
+    clock: &Clock,
+) -> Result<(), ProgramError> {
+    if abr.get(inf_price_history).data_len() == 0
+        || !inf_price_history_checked(abr.get(inf_price_history))?.should_sample(clock.slot)
+    {
+        return Ok(());
+    }
+
+    let pool = pool_state_v2_checked(abr.get(pool_state))?;
+    let inf_mint_supply = checked_mint_of(abr.get(lp_token_mint))?.supply();
+    let sample = InfPriceSample::from_inf_calc(
+        clock.slot,
+        &inf_calc_lookahead(pool, inf_mint_supply, clock.slot)?,
+    )
+    .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::MathError))?;
+
+    inf_price_history_checked_mut(abr.get_mut(inf_price_history))?.push(&sample);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::{
-    utils::{accs_split_first_chunk, extend_lst_state_list},
+    utils::{accs_split_first_chunk, extend_lst_state_list, upsert_lst_calc_backing},
     verify::{
         verify_lst_state_list_no_dup, verify_not_rebalancing_and_not_disabled, verify_pks,
         verify_signers, verify_sol_value_calculator_is_program, verify_tokenkeg_or_22_mint,
@@ -10,10 +10,13 @@ use inf1_ctl_jiminy::{
     account_utils::{lst_state_list_checked, lst_state_list_checked_mut, pool_state_v2_checked},
     err::Inf1CtlErr,
     instructions::admin::add_lst::{AddLstIxAccs, NewAddLstIxAccsBuilder, ADD_LST_IX_IS_SIGNER},
-    keys::{ATOKEN_ID, LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID, SYS_PROG_ID},
+    keys::{
+        ATOKEN_ID, LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID,
+        SYS_PROG_ID,
+    },
     pda_onchain::{find_pool_reserves, find_protocol_fee_accumulator},
     program_err::Inf1CtlCustomProgErr,
-    typedefs::lst_state::LstState,
+    typedefs::{compact_u64::CompactU64, lst_calc_backing::LstCalcBacking, lst_state::LstState},
 };
 use jiminy_cpi::{
     account::{Abr, AccountHandle},
@@ -60,10 +63,13 @@ pub fn process_add_lst(
         .with_associated_token_program(&ATOKEN_ID)
         .with_system_program(&SYS_PROG_ID)
         .with_lst_token_program(token_prog)
+        .with_lst_calc_backing_list(&LST_CALC_BACKING_LIST_ID)
         // Free account - payer can be any account with sufficient lamports for ATA rent
         .with_payer(abr.get(*accs.payer()).key())
         // Free account - admin can specify any sol value calculator program
         .with_sol_value_calculator(abr.get(*accs.sol_value_calculator()).key())
+        // Free account - admin is responsible for specifying the correct backing account
+        .with_sol_value_calculator_backing(abr.get(*accs.sol_value_calculator_backing()).key())
         .build();
 
     verify_pks(abr, &accs.0, &expected_pks.0)?;
@@ -125,6 +131,7 @@ pub fn process_add_lst(
 
     // Add lst state to lst state list
     let sol_value_calculator = *abr.get(*accs.sol_value_calculator()).key();
+    let backing = *abr.get(*accs.sol_value_calculator_backing()).key();
 
     let list = lst_state_list_checked_mut(abr.get_mut(*accs.lst_state_list()))?;
     let new_lst_state = list
@@ -136,11 +143,23 @@ pub fn process_add_lst(
         is_input_disabled: 0,
         pool_reserves_bump,
         protocol_fee_accumulator_bump,
-        padding: [0u8; 5],
+        synced_reserves_balance: CompactU64::ZERO,
         sol_value: 0,
         mint,
         sol_value_calculator,
     };
 
+    // Overwrites any stale entry left behind by a previous RemoveLst of the same mint
+    upsert_lst_calc_backing(
+        abr,
+        cpi,
+        &NewTransferIxAccsBuilder::start()
+            .with_from(*accs.payer())
+            .with_to(*accs.lst_calc_backing_list())
+            .build(),
+        rent,
+        LstCalcBacking { mint, backing },
+    )?;
+
     Ok(())
 }
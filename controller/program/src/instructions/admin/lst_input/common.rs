@@ -26,11 +26,21 @@ pub fn set_lst_input_checked<'acc>(
     accs: &[AccountHandle<'acc>],
     data_no_discm: &[u8],
 ) -> Result<(SetLstInputIxAccs<AccountHandle<'acc>>, usize), ProgramError> {
+    let idx = u32_ix_data_parse_no_discm(ix_data_as_arr(data_no_discm)?) as usize;
+    let accs = set_lst_input_accs_checked(abr, accs, idx)?;
+    Ok((accs, idx))
+}
+
+/// Also used by other admin instructions that take [`SetLstInputIxAccs`]
+#[inline]
+pub fn set_lst_input_accs_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+    idx: usize,
+) -> Result<SetLstInputIxAccs<AccountHandle<'acc>>, ProgramError> {
     let (accs, _) = accs_split_first_chunk(accs)?;
     let accs = SetLstInputIxAccs(*accs);
 
-    let idx = u32_ix_data_parse_no_discm(ix_data_as_arr(data_no_discm)?) as usize;
-
     let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
     let list = lst_state_list_checked(abr.get(*accs.lst_state_list()))?;
     let LstState { mint, .. } = lst_state_list_get(list, idx)?;
@@ -47,5 +57,5 @@ pub fn set_lst_input_checked<'acc>(
 
     verify_not_rebalancing_and_not_disabled(pool)?;
 
-    Ok((accs, idx))
+    Ok(accs)
 }
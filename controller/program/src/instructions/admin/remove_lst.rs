@@ -80,10 +80,15 @@ pub fn process_remove_lst(
 
     verify_not_rebalancing_and_not_disabled(pool)?;
 
-    let [l, p] = [accs.pool_reserves(), accs.protocol_fee_accumulator()]
-        .map(|h| get_token_account_amount(abr.get(*h)));
-    let lst_balance = l?;
-    let protocol_fee_accumulator_balance = p?;
+    let lst_balance = get_token_account_amount(abr.get(*accs.pool_reserves()))?;
+    // legacy protocol fee accumulator may have already been closed by WithdrawProtocolFeesSweep
+    let protocol_fee_accumulator = abr.get(*accs.protocol_fee_accumulator());
+    let is_protocol_fee_accumulator_open = !protocol_fee_accumulator.data().is_empty();
+    let protocol_fee_accumulator_balance = if is_protocol_fee_accumulator_open {
+        get_token_account_amount(protocol_fee_accumulator)?
+    } else {
+        0
+    };
 
     if lst_state.sol_value != 0 || lst_balance != 0 || protocol_fee_accumulator_balance != 0 {
         return Err(Inf1CtlCustomProgErr(Inf1CtlErr::LstStillHasValue).into());
@@ -91,14 +96,15 @@ pub fn process_remove_lst(
 
     // Close protocol fee accumulator and pool reserves ATAs
     [
-        (
+        is_protocol_fee_accumulator_open.then_some((
             *accs.protocol_fee_accumulator(),
             *accs.protocol_fee_accumulator_auth(),
             PROTOCOL_FEE_SIGNER,
-        ),
-        (*accs.pool_reserves(), *accs.pool_state(), POOL_STATE_SIGNER),
+        )),
+        Some((*accs.pool_reserves(), *accs.pool_state(), POOL_STATE_SIGNER)),
     ]
     .into_iter()
+    .flatten()
     .try_for_each(|(close, auth, signer)| -> Result<(), ProgramError> {
         cpi.invoke_signed(
             abr,
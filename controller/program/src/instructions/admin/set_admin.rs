@@ -3,7 +3,7 @@ use inf1_ctl_jiminy::{
     instructions::admin::set_admin::{
         NewSetAdminIxAccsBuilder, SetAdminIxAccs, SET_ADMIN_IX_IS_SIGNER,
     },
-    keys::POOL_STATE_ID,
+    keys::{POOL_CONFIG_ID, POOL_STATE_ID},
 };
 use jiminy_cpi::{
     account::{Abr, AccountHandle},
@@ -29,6 +29,7 @@ pub fn set_admin_accs_checked<'acc>(
 
     let expected_pks = NewSetAdminIxAccsBuilder::start()
         .with_pool_state(&POOL_STATE_ID)
+        .with_pool_config(&POOL_CONFIG_ID)
         .with_curr(&pool.admin)
         // Free: current admin is free to set new admin to whatever pk as pleased
         .with_new(abr.get(*accs.new()).key())
@@ -0,0 +1,72 @@
+use inf1_ctl_jiminy::{
+    account_utils::{pool_state_v2_checked, pool_state_v2_checked_mut},
+    accounts::pool_state::PoolStateV2,
+    err::Inf1CtlErr,
+    instructions::admin::set_min_fee::{
+        NewSetMinFeeIxAccsBuilder, SetMinFeeIxAccs, SetMinFeeIxData, SET_MIN_FEE_IX_IS_SIGNER,
+    },
+    keys::{POOL_CONFIG_ID, POOL_STATE_ID},
+    program_err::Inf1CtlCustomProgErr,
+    typedefs::fee_nanos::FeeNanos,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::{ProgramError, INVALID_INSTRUCTION_DATA},
+};
+
+use crate::{
+    utils::accs_split_first_chunk,
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pks, verify_signers},
+};
+
+type SetMinFeeIxAccounts<'acc> = SetMinFeeIxAccs<AccountHandle<'acc>>;
+
+#[inline]
+pub fn set_min_fee_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+    ix_data_no_discm: &[u8],
+) -> Result<(SetMinFeeIxAccounts<'acc>, FeeNanos), ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = SetMinFeeIxAccs(*accs);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewSetMinFeeIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_pool_config(&POOL_CONFIG_ID)
+        .with_admin(&pool.admin)
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &SET_MIN_FEE_IX_IS_SIGNER.0)?;
+
+    verify_not_rebalancing_and_not_disabled(pool)?;
+
+    let min_fee_nanos_raw = SetMinFeeIxData::parse_no_discm(
+        ix_data_no_discm
+            .first_chunk()
+            .ok_or(INVALID_INSTRUCTION_DATA)?,
+    );
+
+    let min_fee_nanos = FeeNanos::new(min_fee_nanos_raw)
+        .map_err(|_| Inf1CtlCustomProgErr(Inf1CtlErr::FeeTooHigh))?;
+
+    Ok((accs, min_fee_nanos))
+}
+
+#[inline]
+pub fn process_set_min_fee(
+    abr: &mut Abr,
+    accs: &SetMinFeeIxAccounts,
+    min_fee_nanos: FeeNanos,
+) -> Result<(), ProgramError> {
+    let PoolStateV2 {
+        min_fee_nanos: pool_min_fee_nanos,
+        ..
+    } = pool_state_v2_checked_mut(abr.get_mut(*accs.pool_state()))?;
+
+    *pool_min_fee_nanos = min_fee_nanos.get();
+
+    Ok(())
+}
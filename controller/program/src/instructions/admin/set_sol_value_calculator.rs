@@ -12,9 +12,12 @@ use inf1_ctl_jiminy::{
         },
         sync_sol_value::NewSyncSolValueIxPreAccsBuilder,
     },
-    keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    keys::{
+        LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID, SYS_PROG_ID,
+    },
     pda_onchain::create_raw_pool_reserves_addr,
     program_err::Inf1CtlCustomProgErr,
+    typedefs::lst_calc_backing::LstCalcBacking,
 };
 use jiminy_cpi::{
     account::{Abr, AccountHandle},
@@ -24,10 +27,12 @@ use jiminy_cpi::{
 use inf1_core::instructions::admin::set_sol_value_calculator::SetSolValueCalculatorIxAccs;
 use inf1_core::instructions::sync_sol_value::SyncSolValueIxAccs;
 use jiminy_sysvar_clock::Clock;
+use jiminy_sysvar_rent::Rent;
+use sanctum_system_jiminy::sanctum_system_core::instructions::transfer::NewTransferIxAccsBuilder;
 
 use crate::{
-    svc::lst_ssv_uy,
-    utils::{accs_split_first_chunk, split_suf_accs},
+    svc::lst_ssv_uy_trips_circuit_breaker,
+    utils::{accs_split_first_chunk, split_suf_accs, upsert_lst_calc_backing},
     verify::{
         verify_not_rebalancing_and_not_disabled, verify_pks, verify_signers,
         verify_sol_value_calculator_is_program,
@@ -64,6 +69,7 @@ pub fn set_sol_value_calculator_accs_checked<'a, 'acc>(
             .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidReserves))?;
 
     let pool = pool_state_v2_checked(abr.get(*ix_prefix.pool_state()))?;
+    let backing = abr.get(*ix_prefix.sol_value_calculator_backing());
 
     let expected_pks = NewSetSolValueCalculatorIxPreAccsBuilder::start()
         .with_admin(&pool.admin)
@@ -71,6 +77,11 @@ pub fn set_sol_value_calculator_accs_checked<'a, 'acc>(
         .with_lst_state_list(&LST_STATE_LIST_ID)
         .with_pool_reserves(&expected_reserves)
         .with_pool_state(&POOL_STATE_ID)
+        .with_lst_calc_backing_list(&LST_CALC_BACKING_LIST_ID)
+        .with_system_program(&SYS_PROG_ID)
+        .with_pool_config(&POOL_CONFIG_ID)
+        // Free account - admin is responsible for specifying the correct backing account
+        .with_sol_value_calculator_backing(backing.key())
         .build();
     verify_pks(abr, &ix_prefix.0, &expected_pks.0)?;
 
@@ -99,12 +110,14 @@ pub fn process_set_sol_value_calculator(
     }: &SetSolValueCalculatorIxAccounts,
     lst_idx: usize,
     clock: &Clock,
+    rent: &Rent,
 ) -> Result<(), ProgramError> {
     pool_state_v2_checked_mut(abr.get_mut(*ix_prefix.pool_state()))?
         .release_yield(clock.slot)
         .map_err(Inf1CtlCustomProgErr)?;
 
     let new_calc_prog = *abr.get(*calc_prog).key();
+    let backing = *abr.get(*ix_prefix.sol_value_calculator_backing()).key();
 
     let list = lst_state_list_checked_mut(abr.get_mut(*ix_prefix.lst_state_list()))?;
     let lst_state = list
@@ -113,8 +126,22 @@ pub fn process_set_sol_value_calculator(
         .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidLstIndex))?;
 
     lst_state.sol_value_calculator = new_calc_prog;
+    let mint = lst_state.mint;
+
+    upsert_lst_calc_backing(
+        abr,
+        cpi,
+        &NewTransferIxAccsBuilder::start()
+            .with_from(*ix_prefix.admin())
+            .with_to(*ix_prefix.lst_calc_backing_list())
+            .build(),
+        rent,
+        LstCalcBacking { mint, backing },
+    )?;
 
-    lst_ssv_uy(
+    // admin is deliberately changing how the LST is valued,
+    // so a resulting SOL value jump should not trip the circuit breaker
+    lst_ssv_uy_trips_circuit_breaker(
         abr,
         cpi,
         &SyncSolValueIxAccs {
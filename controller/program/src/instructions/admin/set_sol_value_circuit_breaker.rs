@@ -0,0 +1,64 @@
+use inf1_ctl_jiminy::{
+    account_utils::{pool_state_v2_checked, pool_state_v2_checked_mut},
+    accounts::pool_state::PoolStateV2,
+    instructions::admin::set_sol_value_circuit_breaker::{
+        NewSetSolValueCircuitBreakerIxAccsBuilder, SetSolValueCircuitBreakerIxAccs,
+        SetSolValueCircuitBreakerIxData, SET_SOL_VALUE_CIRCUIT_BREAKER_IX_IS_SIGNER,
+    },
+    keys::POOL_STATE_ID,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+};
+
+use crate::{
+    utils::{accs_split_first_chunk, ix_data_as_arr},
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pks, verify_signers},
+};
+
+type SetSolValueCircuitBreakerIxAccounts<'acc> =
+    SetSolValueCircuitBreakerIxAccs<AccountHandle<'acc>>;
+
+#[inline]
+pub fn set_sol_value_circuit_breaker_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+    ix_data_no_discm: &[u8],
+) -> Result<(SetSolValueCircuitBreakerIxAccounts<'acc>, u32), ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = SetSolValueCircuitBreakerIxAccs(*accs);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewSetSolValueCircuitBreakerIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_admin(&pool.admin)
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &SET_SOL_VALUE_CIRCUIT_BREAKER_IX_IS_SIGNER.0)?;
+
+    verify_not_rebalancing_and_not_disabled(pool)?;
+
+    let circuit_breaker_nanos =
+        SetSolValueCircuitBreakerIxData::parse_no_discm(ix_data_as_arr(ix_data_no_discm)?);
+
+    Ok((accs, circuit_breaker_nanos))
+}
+
+#[inline]
+pub fn process_set_sol_value_circuit_breaker(
+    abr: &mut Abr,
+    accs: &SetSolValueCircuitBreakerIxAccounts,
+    circuit_breaker_nanos: u32,
+) -> Result<(), ProgramError> {
+    let PoolStateV2 {
+        sol_value_circuit_breaker_nanos,
+        ..
+    } = pool_state_v2_checked_mut(abr.get_mut(*accs.pool_state()))?;
+
+    *sol_value_circuit_breaker_nanos = circuit_breaker_nanos;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use inf1_ctl_jiminy::{
+    account_utils::{pending_admin_action_checked, pool_state_v2_checked},
+    instructions::admin::timelock::cancel::{
+        CancelAdminActionIxAccs, NewCancelAdminActionIxAccsBuilder,
+        CANCEL_ADMIN_ACTION_IX_IS_SIGNER,
+    },
+    keys::{PENDING_ADMIN_ACTION_ID, POOL_STATE_ID},
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+};
+
+use crate::{
+    utils::accs_split_first_chunk,
+    verify::{verify_pks, verify_signers},
+};
+
+type CancelAdminActionIxAccounts<'acc> = CancelAdminActionIxAccs<AccountHandle<'acc>>;
+
+#[inline]
+pub fn cancel_admin_action_accs_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+) -> Result<CancelAdminActionIxAccounts<'acc>, ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = CancelAdminActionIxAccs(*accs);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewCancelAdminActionIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_pending_admin_action(&PENDING_ADMIN_ACTION_ID)
+        .with_admin(&pool.admin)
+        // Free: admin is free to refund rent to whichever account as pleased
+        .with_refund_rent_to(abr.get(*accs.refund_rent_to()).key())
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &CANCEL_ADMIN_ACTION_IX_IS_SIGNER.0)?;
+
+    // check that there is an action to cancel
+    pending_admin_action_checked(abr.get(*accs.pending_admin_action()))?;
+
+    Ok(accs)
+}
+
+#[inline]
+pub fn process_cancel_admin_action(
+    abr: &mut Abr,
+    accs: &CancelAdminActionIxAccounts,
+) -> Result<(), ProgramError> {
+    abr.close(*accs.pending_admin_action(), *accs.refund_rent_to())?;
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use inf1_ctl_jiminy::{
+    account_utils::pending_admin_action_checked,
+    accounts::pending_admin_action::PendingAdminAction,
+    err::Inf1CtlErr,
+    instructions::admin::{
+        set_sol_value_calculator::SetSolValueCalculatorIxData,
+        timelock::{
+            execute::{ExecuteAdminActionIxPreAccs, NewExecuteAdminActionIxPreAccsBuilder},
+            TimelockedIx,
+        },
+    },
+    keys::PENDING_ADMIN_ACTION_ID,
+    program_err::Inf1CtlCustomProgErr,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+};
+use jiminy_sysvar_clock::Clock;
+use jiminy_sysvar_rent::Rent;
+
+use crate::{
+    instructions::{
+        admin::{
+            set_admin::{process_set_admin, set_admin_accs_checked},
+            set_min_fee::{process_set_min_fee, set_min_fee_checked},
+            set_pricing_prog::{process_set_pricing_prog, set_pricing_prog_accs_checked},
+            set_sol_value_calculator::{
+                process_set_sol_value_calculator, set_sol_value_calculator_accs_checked,
+            },
+            timelock::set_admin_action_delay::{
+                process_set_admin_action_delay, set_admin_action_delay_checked,
+            },
+        },
+        protocol_fee::set_protocol_fee::{process_set_protocol_fee, set_protocol_fee_checked},
+    },
+    utils::{accs_split_first_chunk, ix_data_as_arr},
+    verify::verify_pks,
+    Cpi,
+};
+
+/// Runs the queued instruction with the accounts following the prefix,
+/// exactly as if it were called directly but without the
+/// `AdminActionTimelocked` check, then closes the pending admin action PDA.
+#[inline]
+pub fn process_execute_admin_action(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accounts: &[AccountHandle],
+    clock: &Clock,
+    rent: &Rent,
+) -> Result<(), ProgramError> {
+    let (ix_prefix, wrapped) = accs_split_first_chunk(accounts)?;
+    let ix_prefix = ExecuteAdminActionIxPreAccs(*ix_prefix);
+
+    let expected_pks = NewExecuteAdminActionIxPreAccsBuilder::start()
+        .with_pending_admin_action(&PENDING_ADMIN_ACTION_ID)
+        // Free: the queued instruction requires the admin's signature
+        .with_refund_rent_to(abr.get(*ix_prefix.refund_rent_to()).key())
+        .build();
+    verify_pks(abr, &ix_prefix.0, &expected_pks.0)?;
+
+    let PendingAdminAction {
+        earliest_execution_slot,
+        keys,
+        ix_data,
+    } = *pending_admin_action_checked(abr.get(*ix_prefix.pending_admin_action()))?;
+
+    if clock.slot < earliest_execution_slot {
+        return Err(Inf1CtlCustomProgErr(Inf1CtlErr::AdminActionNotReady).into());
+    }
+
+    let ix = TimelockedIx::from_discm(ix_data[0]).ok_or(Inf1CtlCustomProgErr(
+        Inf1CtlErr::InvalidPendingAdminActionData,
+    ))?;
+    let data_no_discm = &ix_data[1..ix.ix_data_len()];
+
+    // Unused `keys` entries are verified to be zeroed on queue,
+    // so only the bound accounts of the queued instruction need to be checked
+    match ix {
+        TimelockedIx::SetSolValueCalculator => {
+            let lst_idx =
+                SetSolValueCalculatorIxData::parse_no_discm(ix_data_as_arr(data_no_discm)?)
+                    as usize;
+            let accs = set_sol_value_calculator_accs_checked(abr, wrapped, lst_idx)?;
+            let [k0, k1, k2] = &keys;
+            verify_pks(
+                abr,
+                &[
+                    *accs.ix_prefix.lst_mint(),
+                    *accs.ix_prefix.sol_value_calculator_backing(),
+                    accs.calc_prog,
+                ],
+                &[k0, k1, k2],
+            )?;
+            process_set_sol_value_calculator(abr, cpi, &accs, lst_idx, clock, rent)?;
+        }
+        TimelockedIx::SetAdmin => {
+            let accs = set_admin_accs_checked(abr, wrapped)?;
+            verify_pks(abr, &[*accs.new()], &[&keys[0]])?;
+            process_set_admin(abr, &accs)?;
+        }
+        TimelockedIx::SetProtocolFee => {
+            let (accs, protocol_fee_nanos) = set_protocol_fee_checked(abr, wrapped, data_no_discm)?;
+            process_set_protocol_fee(abr, &accs, protocol_fee_nanos)?;
+        }
+        TimelockedIx::SetPricingProg => {
+            let accs = set_pricing_prog_accs_checked(abr, wrapped)?;
+            verify_pks(abr, &[*accs.new()], &[&keys[0]])?;
+            process_set_pricing_prog(abr, &accs)?;
+        }
+        TimelockedIx::SetMinFee => {
+            let (accs, min_fee_nanos) = set_min_fee_checked(abr, wrapped, data_no_discm)?;
+            process_set_min_fee(abr, &accs, min_fee_nanos)?;
+        }
+        TimelockedIx::SetAdminActionDelay => {
+            let (accs, delay) = set_admin_action_delay_checked(abr, wrapped, data_no_discm)?;
+            process_set_admin_action_delay(abr, cpi, &accs, delay, rent)?;
+        }
+    }
+
+    abr.close(
+        *ix_prefix.pending_admin_action(),
+        *ix_prefix.refund_rent_to(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,4 @@
+pub mod cancel;
+pub mod execute;
+pub mod queue;
+pub mod set_admin_action_delay;
@@ -0,0 +1,103 @@
+use core::mem::size_of;
+
+use inf1_ctl_jiminy::{
+    account_utils::{
+        pending_admin_action_checked_mut, pool_config_admin_action_delay_slots,
+        pool_state_v2_checked,
+    },
+    accounts::pending_admin_action::PendingAdminAction,
+    err::Inf1CtlErr,
+    instructions::admin::timelock::queue::{
+        NewQueueAdminActionIxAccsBuilder, QueueAdminActionIxAccs, QueueAdminActionIxArgs,
+        QueueAdminActionIxData, QUEUE_ADMIN_ACTION_IX_IS_SIGNER,
+    },
+    keys::{PENDING_ADMIN_ACTION_ID, POOL_CONFIG_ID, POOL_STATE_ID, SYS_PROG_ID},
+    pda_onchain::PENDING_ADMIN_ACTION_SIGNER,
+    program_err::Inf1CtlCustomProgErr,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::{ProgramError, INVALID_INSTRUCTION_DATA},
+};
+use jiminy_sysvar_clock::Clock;
+use jiminy_sysvar_rent::Rent;
+use sanctum_system_jiminy::sanctum_system_core::instructions::transfer::NewTransferIxAccsBuilder;
+
+use crate::{
+    utils::{accs_split_first_chunk, create_pda_idmpt, ix_data_as_arr},
+    verify::{verify_pks, verify_signers},
+    Cpi,
+};
+
+type QueueAdminActionIxAccounts<'acc> = QueueAdminActionIxAccs<AccountHandle<'acc>>;
+
+#[inline]
+pub fn queue_admin_action_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+    ix_data_no_discm: &[u8],
+) -> Result<(QueueAdminActionIxAccounts<'acc>, QueueAdminActionIxArgs), ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = QueueAdminActionIxAccs(*accs);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewQueueAdminActionIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_pool_config(&POOL_CONFIG_ID)
+        .with_pending_admin_action(&PENDING_ADMIN_ACTION_ID)
+        .with_system_program(&SYS_PROG_ID)
+        .with_admin(&pool.admin)
+        // Free: payer can be any signing pubkey with funds
+        .with_payer(abr.get(*accs.payer()).key())
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &QUEUE_ADMIN_ACTION_IX_IS_SIGNER.0)?;
+
+    let args = QueueAdminActionIxData::parse_no_discm(ix_data_as_arr(ix_data_no_discm)?);
+    args.timelocked_ix().ok_or(INVALID_INSTRUCTION_DATA)?;
+
+    if abr.get(*accs.pending_admin_action()).data_len() != 0 {
+        return Err(Inf1CtlCustomProgErr(Inf1CtlErr::AdminActionAlreadyQueued).into());
+    }
+
+    Ok((accs, args))
+}
+
+#[inline]
+pub fn process_queue_admin_action(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accs: &QueueAdminActionIxAccounts,
+    QueueAdminActionIxArgs { ix_data, keys }: QueueAdminActionIxArgs,
+    clock: &Clock,
+    rent: &Rent,
+) -> Result<(), ProgramError> {
+    let delay = pool_config_admin_action_delay_slots(abr.get(*accs.pool_config()))?;
+    let earliest_execution_slot = clock
+        .slot
+        .checked_add(delay)
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::MathError))?;
+
+    create_pda_idmpt(
+        abr,
+        cpi,
+        &NewTransferIxAccsBuilder::start()
+            .with_from(*accs.payer())
+            .with_to(*accs.pending_admin_action())
+            .build(),
+        rent,
+        PENDING_ADMIN_ACTION_SIGNER,
+        size_of::<PendingAdminAction>(),
+    )?;
+
+    *pending_admin_action_checked_mut(abr.get_mut(*accs.pending_admin_action()))? =
+        PendingAdminAction {
+            earliest_execution_slot,
+            keys,
+            ix_data,
+        };
+
+    Ok(())
+}
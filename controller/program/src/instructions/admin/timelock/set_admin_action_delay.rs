@@ -0,0 +1,103 @@
+use core::mem::size_of;
+
+use inf1_ctl_jiminy::{
+    account_utils::{
+        pool_config_admin_action_delay_slots, pool_config_checked_mut, pool_state_v2_checked,
+    },
+    accounts::pool_config::PoolConfig,
+    err::Inf1CtlErr,
+    instructions::admin::timelock::set_admin_action_delay::{
+        NewSetAdminActionDelayIxAccsBuilder, SetAdminActionDelayIxAccs, SetAdminActionDelayIxData,
+        SET_ADMIN_ACTION_DELAY_IX_IS_SIGNER,
+    },
+    keys::{POOL_CONFIG_ID, POOL_STATE_ID, SYS_PROG_ID},
+    pda_onchain::POOL_CONFIG_SIGNER,
+    program_err::Inf1CtlCustomProgErr,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+};
+use jiminy_sysvar_rent::Rent;
+use sanctum_system_jiminy::sanctum_system_core::instructions::transfer::NewTransferIxAccsBuilder;
+
+use crate::{
+    utils::{accs_split_first_chunk, create_pda_idmpt, ix_data_as_arr},
+    verify::{verify_pks, verify_signers},
+    Cpi,
+};
+
+pub type SetAdminActionDelayIxAccounts<'acc> = SetAdminActionDelayIxAccs<AccountHandle<'acc>>;
+
+#[inline]
+pub fn set_admin_action_delay_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+    ix_data_no_discm: &[u8],
+) -> Result<(SetAdminActionDelayIxAccounts<'acc>, u64), ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = SetAdminActionDelayIxAccs(*accs);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewSetAdminActionDelayIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_pool_config(&POOL_CONFIG_ID)
+        .with_system_program(&SYS_PROG_ID)
+        .with_admin(&pool.admin)
+        // Free: payer can be any signing pubkey with funds
+        .with_payer(abr.get(*accs.payer()).key())
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &SET_ADMIN_ACTION_DELAY_IX_IS_SIGNER.0)?;
+
+    let delay = SetAdminActionDelayIxData::parse_no_discm(ix_data_as_arr(ix_data_no_discm)?);
+
+    Ok((accs, delay))
+}
+
+/// Increasing the delay takes effect immediately,
+/// but decreasing it must go through the timelock
+#[inline]
+pub fn verify_admin_action_delay_not_decreased(
+    abr: &Abr,
+    accs: &SetAdminActionDelayIxAccounts,
+    new_delay: u64,
+) -> Result<(), ProgramError> {
+    if new_delay < pool_config_admin_action_delay_slots(abr.get(*accs.pool_config()))? {
+        return Err(Inf1CtlCustomProgErr(Inf1CtlErr::AdminActionTimelocked).into());
+    }
+    Ok(())
+}
+
+/// Creates the pool config PDA if it does not yet exist
+#[inline]
+pub fn process_set_admin_action_delay(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accs: &SetAdminActionDelayIxAccounts,
+    delay: u64,
+    rent: &Rent,
+) -> Result<(), ProgramError> {
+    create_pda_idmpt(
+        abr,
+        cpi,
+        &NewTransferIxAccsBuilder::start()
+            .with_from(*accs.payer())
+            .with_to(*accs.pool_config())
+            .build(),
+        rent,
+        POOL_CONFIG_SIGNER,
+        size_of::<PoolConfig>(),
+    )?;
+
+    let PoolConfig {
+        admin_action_delay_slots,
+        ..
+    } = pool_config_checked_mut(abr.get_mut(*accs.pool_config()))?;
+
+    *admin_action_delay_slots = delay;
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use inf1_ctl_jiminy::{
+    account_utils::pool_state_v2_checked,
+    accounts::inf_price_history::INF_PRICE_HISTORY_ACC_LEN,
+    instructions::crank_inf_price_history::{
+        CrankInfPriceHistoryIxAccs, NewCrankInfPriceHistoryIxAccsBuilder,
+        CRANK_INF_PRICE_HISTORY_IX_IS_SIGNER,
+    },
+    keys::{INF_PRICE_HISTORY_ID, POOL_STATE_ID, SYS_PROG_ID},
+    pda_onchain::INF_PRICE_HISTORY_SIGNER,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+};
+use jiminy_sysvar_clock::Clock;
+use jiminy_sysvar_rent::Rent;
+use sanctum_system_jiminy::sanctum_system_core::instructions::transfer::NewTransferIxAccsBuilder;
+
+use crate::{
+    inf_price_history::sample_inf_price_history,
+    utils::{accs_split_first_chunk, create_pda_idmpt},
+    verify::{verify_pks, verify_signers},
+    Cpi,
+};
+
+type CrankInfPriceHistoryIxAccounts<'acc> = CrankInfPriceHistoryIxAccs<AccountHandle<'acc>>;
+
+/// Does not perform the PoolState v1 -> v2 migration
+/// since this instruction does not write to pool state
+#[inline]
+pub fn crank_inf_price_history_accs_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+) -> Result<CrankInfPriceHistoryIxAccounts<'acc>, ProgramError> {
+    let (ix_prefix, _) = accs_split_first_chunk(accs)?;
+    let accs = CrankInfPriceHistoryIxAccs(*ix_prefix);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewCrankInfPriceHistoryIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_lp_token_mint(&pool.lp_token_mint)
+        .with_inf_price_history(&INF_PRICE_HISTORY_ID)
+        .with_system_program(&SYS_PROG_ID)
+        // Free: payer can be any signing pubkey with funds
+        .with_payer(abr.get(*accs.payer()).key())
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &CRANK_INF_PRICE_HISTORY_IX_IS_SIGNER.0)?;
+
+    Ok(accs)
+}
+
+/// Permissionless.
+///
+/// Creates the INF price history PDA if it does not yet exist,
+/// then appends a sample if one is due. No-op otherwise.
+#[inline]
+pub fn process_crank_inf_price_history(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accs: &CrankInfPriceHistoryIxAccounts,
+    clock: &Clock,
+    rent: &Rent,
+) -> Result<(), ProgramError> {
+    let history = *accs.inf_price_history();
+
+    create_pda_idmpt(
+        abr,
+        cpi,
+        &NewTransferIxAccsBuilder::start()
+            .with_from(*accs.payer())
+            .with_to(history)
+            .build(),
+        rent,
+        INF_PRICE_HISTORY_SIGNER,
+        INF_PRICE_HISTORY_ACC_LEN,
+    )?;
+
+    sample_inf_price_history(
+        abr,
+        *accs.pool_state(),
+        *accs.lp_token_mint(),
+        history,
+        clock,
+    )
+}
@@ -2,3 +2,4 @@ pub mod add_disable_pool_auth;
 pub mod disable;
 pub mod enable;
 pub mod remove_disable_pool_auth;
+pub mod set_disable_modes;
@@ -0,0 +1,80 @@
+use inf1_ctl_jiminy::{
+    account_utils::{
+        disable_pool_auth_list_checked, pool_state_v2_checked, pool_state_v2_checked_mut,
+    },
+    accounts::{packed_list::PackedList, pool_state::PoolStateV2},
+    err::Inf1CtlErr,
+    instructions::disable_pool::set_disable_modes::{
+        NewSetDisableModesIxAccsBuilder, SetDisableModesIxAccs, SetDisableModesIxData,
+        SET_DISABLE_MODES_IX_IS_SIGNER,
+    },
+    keys::{DISABLE_POOL_AUTHORITY_LIST_ID, POOL_STATE_ID},
+    program_err::Inf1CtlCustomProgErr,
+    typedefs::disable_modes::DisableModes,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::{ProgramError, INVALID_INSTRUCTION_DATA},
+};
+
+use crate::{
+    utils::{accs_split_first_chunk, ix_data_as_arr},
+    verify::{verify_pks, verify_signers},
+};
+
+type SetDisableModesIxAccounts<'acc> = SetDisableModesIxAccs<AccountHandle<'acc>>;
+
+/// Does not check that the pool is not disabled or rebalancing
+/// so that modes can be prepared before `EnablePool`
+#[inline]
+pub fn set_disable_modes_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+    ix_data_no_discm: &[u8],
+) -> Result<(SetDisableModesIxAccounts<'acc>, DisableModes), ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = SetDisableModesIxAccs(*accs);
+
+    let new_modes = SetDisableModesIxData::parse_no_discm(ix_data_as_arr(ix_data_no_discm)?)
+        .ok_or(INVALID_INSTRUCTION_DATA)?;
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+    let signer_pk = abr.get(*accs.signer()).key();
+    let is_reenabling = pool.disable_modes().has_any_not_in(&new_modes);
+
+    let expected_pks = NewSetDisableModesIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_disable_pool_auth_list(&DISABLE_POOL_AUTHORITY_LIST_ID)
+        // Free if only disabling more modes: either admin or disable pool auth checked below
+        .with_signer(if is_reenabling {
+            &pool.admin
+        } else {
+            signer_pk
+        })
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &SET_DISABLE_MODES_IX_IS_SIGNER.0)?;
+
+    let PackedList(auths) =
+        disable_pool_auth_list_checked(abr.get(*accs.disable_pool_auth_list()))?;
+    if *signer_pk != pool.admin && !auths.iter().any(|pk| pk == signer_pk) {
+        return Err(
+            Inf1CtlCustomProgErr(Inf1CtlErr::UnauthorizedDisablePoolAuthoritySigner).into(),
+        );
+    }
+
+    Ok((accs, new_modes))
+}
+
+#[inline]
+pub fn process_set_disable_modes(
+    abr: &mut Abr,
+    accs: &SetDisableModesIxAccounts,
+    new_modes: DisableModes,
+) -> Result<(), ProgramError> {
+    let PoolStateV2 { disable_modes, .. } =
+        pool_state_v2_checked_mut(abr.get_mut(*accs.pool_state()))?;
+    *disable_modes = new_modes.get();
+    Ok(())
+}
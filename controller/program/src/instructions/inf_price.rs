@@ -0,0 +1,62 @@
+use inf1_ctl_jiminy::{
+    account_utils::pool_state_v2_checked,
+    err::Inf1CtlErr,
+    instructions::inf_price::{
+        InfPriceIxAccs, InfPriceRet, InfPriceRetData, NewInfPriceIxAccsBuilder,
+    },
+    keys::POOL_STATE_ID,
+    program_err::Inf1CtlCustomProgErr,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+};
+use jiminy_return_data::set_return_data;
+use jiminy_sysvar_clock::Clock;
+
+use crate::{
+    inf_price_history::inf_calc_lookahead, token::checked_mint_of, utils::accs_split_first_chunk,
+    verify::verify_pks,
+};
+
+type InfPriceIxAccounts<'acc> = InfPriceIxAccs<AccountHandle<'acc>>;
+
+/// Does not perform the PoolState v1 -> v2 migration
+/// since this instruction does not write to any account
+#[inline]
+pub fn inf_price_accs_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+) -> Result<InfPriceIxAccounts<'acc>, ProgramError> {
+    let (ix_prefix, _) = accs_split_first_chunk(accs)?;
+    let accs = InfPriceIxAccs(*ix_prefix);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewInfPriceIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_lp_token_mint(&pool.lp_token_mint)
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    Ok(accs)
+}
+
+/// Runs the same yield release math as `release_yield()`
+/// without writing the results to pool state
+#[inline]
+pub fn process_inf_price(
+    abr: &Abr,
+    accs: &InfPriceIxAccounts,
+    clock: &Clock,
+) -> Result<(), ProgramError> {
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+    let mint_supply = checked_mint_of(abr.get(*accs.lp_token_mint()))?.supply();
+
+    let ret = InfPriceRet::from_inf_calc(&inf_calc_lookahead(pool, mint_supply, clock.slot)?)
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::MathError))?;
+
+    set_return_data(InfPriceRetData::new(&ret).as_buf());
+
+    Ok(())
+}
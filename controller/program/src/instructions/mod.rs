@@ -1,7 +1,10 @@
 pub mod admin;
+pub mod crank_inf_price_history;
 pub mod disable_pool;
+pub mod inf_price;
 pub mod protocol_fee;
 pub mod rebalance;
+pub mod referral;
 pub mod rps;
 pub mod swap;
 pub mod sync_sol_value;
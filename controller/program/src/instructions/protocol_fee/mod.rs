@@ -1,3 +1,5 @@
+pub mod remove_protocol_fee_beneficiary_share;
 pub mod set_protocol_fee;
 pub mod set_protocol_fee_beneficiary;
+pub mod set_protocol_fee_beneficiary_share;
 pub mod withdraw_protocol_fees;
@@ -0,0 +1,84 @@
+use inf1_ctl_jiminy::{
+    account_utils::{
+        pool_state_v2_checked, protocol_fee_beneficiary_list_checked,
+        protocol_fee_beneficiary_list_get,
+    },
+    accounts::pool_state::PoolStateV2,
+    instructions::protocol_fee::remove_protocol_fee_beneficiary_share::{
+        NewRemoveProtocolFeeBeneficiaryShareIxAccsBuilder, RemoveProtocolFeeBeneficiaryShareIxAccs,
+        RemoveProtocolFeeBeneficiaryShareIxData,
+        REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_SIGNER,
+    },
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_BENEFICIARY_LIST_ID},
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::{ProgramError, INVALID_INSTRUCTION_DATA},
+};
+use jiminy_sysvar_rent::Rent;
+use sanctum_system_jiminy::sanctum_system_core::instructions::transfer::NewTransferIxAccsBuilder;
+
+use crate::{
+    utils::{accs_split_first_chunk, shrink_protocol_fee_beneficiary_list},
+    verify::{verify_pks, verify_signers},
+};
+
+type RemoveProtocolFeeBeneficiaryShareAccounts<'acc> =
+    RemoveProtocolFeeBeneficiaryShareIxAccs<AccountHandle<'acc>>;
+
+#[inline]
+pub fn remove_protocol_fee_beneficiary_share_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+    data_no_discm: &[u8],
+) -> Result<(RemoveProtocolFeeBeneficiaryShareAccounts<'acc>, usize), ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = RemoveProtocolFeeBeneficiaryShareIxAccs(*accs);
+
+    let idx = RemoveProtocolFeeBeneficiaryShareIxData::parse_no_discm(
+        data_no_discm
+            .try_into()
+            .map_err(|_| INVALID_INSTRUCTION_DATA)?,
+    ) as usize;
+
+    let list =
+        protocol_fee_beneficiary_list_checked(abr.get(*accs.protocol_fee_beneficiary_list()))?;
+    let expected_remove = protocol_fee_beneficiary_list_get(list, idx)?;
+    let PoolStateV2 { admin, .. } = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewRemoveProtocolFeeBeneficiaryShareIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_protocol_fee_beneficiary_list(&PROTOCOL_FEE_BENEFICIARY_LIST_ID)
+        .with_remove(&expected_remove.beneficiary)
+        .with_admin(admin)
+        // Free: rent refund destination can be set to anything admin wants
+        .with_refund_rent_to(abr.get(*accs.refund_rent_to()).key())
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(
+        abr,
+        &accs.0,
+        &REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_SIGNER.0,
+    )?;
+
+    Ok((accs, idx))
+}
+
+#[inline]
+pub fn process_remove_protocol_fee_beneficiary_share(
+    abr: &mut Abr,
+    accs: &RemoveProtocolFeeBeneficiaryShareAccounts,
+    idx: usize,
+    rent: &Rent,
+) -> Result<(), ProgramError> {
+    shrink_protocol_fee_beneficiary_list(
+        abr,
+        &NewTransferIxAccsBuilder::start()
+            .with_from(*accs.protocol_fee_beneficiary_list())
+            .with_to(*accs.refund_rent_to())
+            .build(),
+        rent,
+        idx,
+    )
+}
@@ -6,7 +6,7 @@ use inf1_ctl_jiminy::{
         NewSetProtocolFeeIxAccsBuilder, SetProtocolFeeIxAccs, SetProtocolFeeIxData,
         SET_PROTOCOL_FEE_IX_IS_SIGNER,
     },
-    keys::POOL_STATE_ID,
+    keys::{POOL_CONFIG_ID, POOL_STATE_ID},
     program_err::Inf1CtlCustomProgErr,
     typedefs::fee_nanos::FeeNanos,
 };
@@ -35,6 +35,7 @@ pub fn set_protocol_fee_checked<'acc>(
 
     let expected_pks = NewSetProtocolFeeIxAccsBuilder::start()
         .with_pool_state(&POOL_STATE_ID)
+        .with_pool_config(&POOL_CONFIG_ID)
         .with_admin(&pool.admin)
         .build();
     verify_pks(abr, &accs.0, &expected_pks.0)?;
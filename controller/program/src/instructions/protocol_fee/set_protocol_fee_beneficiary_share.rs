@@ -0,0 +1,84 @@
+use inf1_ctl_jiminy::{
+    account_utils::pool_state_v2_checked,
+    instructions::protocol_fee::set_protocol_fee_beneficiary_share::{
+        NewSetProtocolFeeBeneficiaryShareIxAccsBuilder, SetProtocolFeeBeneficiaryShareIxAccs,
+        SetProtocolFeeBeneficiaryShareIxData, SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_SIGNER,
+    },
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_BENEFICIARY_LIST_ID, SYS_PROG_ID},
+    typedefs::protocol_fee_beneficiary_share::ProtocolFeeBeneficiaryShare,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::{ProgramError, INVALID_INSTRUCTION_DATA},
+    Cpi,
+};
+use jiminy_sysvar_rent::Rent;
+use sanctum_system_jiminy::sanctum_system_core::instructions::transfer::NewTransferIxAccsBuilder;
+
+use crate::{
+    utils::{accs_split_first_chunk, upsert_protocol_fee_beneficiary_share},
+    verify::{verify_pks, verify_signers},
+};
+
+type SetProtocolFeeBeneficiaryShareIxAccounts<'acc> =
+    SetProtocolFeeBeneficiaryShareIxAccs<AccountHandle<'acc>>;
+
+#[inline]
+pub fn set_protocol_fee_beneficiary_share_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+    data_no_discm: &[u8],
+) -> Result<(SetProtocolFeeBeneficiaryShareIxAccounts<'acc>, u32), ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = SetProtocolFeeBeneficiaryShareIxAccs(*accs);
+
+    let weight = SetProtocolFeeBeneficiaryShareIxData::parse_no_discm(
+        data_no_discm
+            .try_into()
+            .map_err(|_| INVALID_INSTRUCTION_DATA)?,
+    );
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewSetProtocolFeeBeneficiaryShareIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_protocol_fee_beneficiary_list(&PROTOCOL_FEE_BENEFICIARY_LIST_ID)
+        .with_system_program(&SYS_PROG_ID)
+        .with_admin(&pool.admin)
+        // Free: payer can be any signing pubkey with funds
+        .with_payer(abr.get(*accs.payer()).key())
+        // Free: admin is free to add any pubkey as a beneficiary
+        .with_beneficiary(abr.get(*accs.beneficiary()).key())
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(
+        abr,
+        &accs.0,
+        &SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_SIGNER.0,
+    )?;
+
+    Ok((accs, weight))
+}
+
+/// Creates the ProtocolFeeBeneficiary list PDA if it does not yet exist
+#[inline]
+pub fn process_set_protocol_fee_beneficiary_share(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accs: &SetProtocolFeeBeneficiaryShareIxAccounts,
+    weight: u32,
+    rent: &Rent,
+) -> Result<(), ProgramError> {
+    let beneficiary = *abr.get(*accs.beneficiary()).key();
+    upsert_protocol_fee_beneficiary_share(
+        abr,
+        cpi,
+        &NewTransferIxAccsBuilder::start()
+            .with_from(*accs.payer())
+            .with_to(*accs.protocol_fee_beneficiary_list())
+            .build(),
+        rent,
+        ProtocolFeeBeneficiaryShare::new(beneficiary, weight),
+    )
+}
@@ -0,0 +1,152 @@
+use inf1_ctl_jiminy::{
+    account_utils::{
+        pool_state_v2_checked, pool_state_v2_checked_mut, protocol_fee_beneficiary_list_checked,
+    },
+    accounts::protocol_fee_beneficiary_list::MAX_PROTOCOL_FEE_BENEFICIARIES,
+    err::Inf1CtlErr,
+    instructions::protocol_fee::withdraw_protocol_fees::split::{
+        NewWithdrawProtocolFeesSplitIxAccsBuilder, WithdrawProtocolFeesSplitIxAccs,
+        WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_SIGNER,
+    },
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_BENEFICIARY_LIST_ID},
+    pda_onchain::POOL_STATE_SIGNER,
+    program_err::Inf1CtlCustomProgErr,
+    svc::InfCalc,
+    typedefs::pool_sv::PoolSvLamports,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::{ProgramError, INVALID_ARGUMENT, NOT_ENOUGH_ACCOUNT_KEYS},
+    Cpi,
+};
+use jiminy_sysvar_clock::Clock;
+use sanctum_spl_token_jiminy::{
+    instructions::mint_to::mint_to_ix_account_handle_perms,
+    sanctum_spl_token_core::instructions::mint_to::{MintToIxData, NewMintToIxAccsBuilder},
+};
+
+use crate::{
+    token::{checked_mint_of, checked_token_acc_auth_of},
+    utils::accs_split_first_chunk,
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pks, verify_signers},
+};
+
+type WithdrawProtocolFeesSplitIxAccounts<'a, 'acc> = (
+    WithdrawProtocolFeesSplitIxAccs<AccountHandle<'acc>>,
+    &'a [AccountHandle<'acc>],
+);
+
+#[inline]
+pub fn withdraw_protocol_fees_split_checked<'a, 'acc>(
+    abr: &Abr,
+    accs: &'a [AccountHandle<'acc>],
+) -> Result<WithdrawProtocolFeesSplitIxAccounts<'a, 'acc>, ProgramError> {
+    let (ix_prefix, suf) = accs_split_first_chunk(accs)?;
+    let accs = WithdrawProtocolFeesSplitIxAccs(*ix_prefix);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+    let mint_acc = abr.get(*accs.inf_mint());
+
+    let expected_pks = NewWithdrawProtocolFeesSplitIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_protocol_fee_beneficiary_list(&PROTOCOL_FEE_BENEFICIARY_LIST_ID)
+        .with_inf_mint(&pool.lp_token_mint)
+        .with_token_program(mint_acc.owner())
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_SIGNER.0)?;
+
+    verify_not_rebalancing_and_not_disabled(pool)?;
+
+    let list =
+        protocol_fee_beneficiary_list_checked(abr.get(*accs.protocol_fee_beneficiary_list()))?;
+    if list.0.len() > MAX_PROTOCOL_FEE_BENEFICIARIES {
+        return Err(Inf1CtlCustomProgErr(Inf1CtlErr::TooManyProtocolFeeBeneficiaries).into());
+    }
+    let withdraw_tos = suf.get(..list.0.len()).ok_or(NOT_ENOUGH_ACCOUNT_KEYS)?;
+    // In the case of an INF token acc of the wrong mint, token prog mint_to CPI will fail
+    list.0
+        .iter()
+        .zip(withdraw_tos)
+        .try_for_each(|(entry, withdraw_to)| {
+            if *checked_token_acc_auth_of(abr.get(*withdraw_to))? == entry.beneficiary {
+                Ok(())
+            } else {
+                Err(ProgramError::from(INVALID_ARGUMENT))
+            }
+        })?;
+
+    Ok((accs, withdraw_tos))
+}
+
+#[inline]
+pub fn process_withdraw_protocol_fees_split(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    (accs, withdraw_tos): &WithdrawProtocolFeesSplitIxAccounts,
+    clock: &Clock,
+) -> Result<(), ProgramError> {
+    let pool = pool_state_v2_checked_mut(abr.get_mut(*accs.pool_state()))?;
+    pool.release_yield(clock.slot)
+        .map_err(Inf1CtlCustomProgErr)?;
+
+    let protocol_fee_lamports = pool.protocol_fee_lamports;
+
+    if protocol_fee_lamports == 0 {
+        return Ok(());
+    }
+
+    let pool_lamports = PoolSvLamports::from_pool_state_v2(pool);
+    let inf_token_supply = checked_mint_of(abr.get(*accs.inf_mint()))?.supply();
+
+    let inf_calc = InfCalc {
+        pool_lamports,
+        mint_supply: inf_token_supply,
+    };
+
+    let inf_to_mint = *inf_calc
+        .sol_to_inf(protocol_fee_lamports)
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::MathError))?
+        .start();
+
+    if inf_to_mint == 0 {
+        return Ok(());
+    }
+
+    // Compute all shares upfront to release the borrow of the list
+    // account before the mint_to CPIs.
+    // List len <= MAX_PROTOCOL_FEE_BENEFICIARIES checked in `withdraw_protocol_fees_split_checked`
+    let mut shares = [0u64; MAX_PROTOCOL_FEE_BENEFICIARIES];
+    protocol_fee_beneficiary_list_checked(abr.get(*accs.protocol_fee_beneficiary_list()))?
+        .split(inf_to_mint)
+        .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::ZeroValue))?
+        .zip(shares.iter_mut())
+        .for_each(|(share, s)| *s = share);
+
+    // withdraw_tos.len() == list len
+    shares
+        .iter()
+        .zip(withdraw_tos.iter())
+        .filter(|(share, _)| **share > 0)
+        .try_for_each(|(share, withdraw_to)| {
+            cpi.invoke_signed_handle(
+                abr,
+                *accs.token_program(),
+                MintToIxData::new(*share).as_buf(),
+                mint_to_ix_account_handle_perms(
+                    NewMintToIxAccsBuilder::start()
+                        .with_auth(*accs.pool_state())
+                        .with_mint(*accs.inf_mint())
+                        .with_to(*withdraw_to)
+                        .build(),
+                ),
+                &[POOL_STATE_SIGNER],
+            )
+        })?;
+
+    let pool = pool_state_v2_checked_mut(abr.get_mut(*accs.pool_state()))?;
+    pool.protocol_fee_lamports = 0;
+
+    Ok(())
+}
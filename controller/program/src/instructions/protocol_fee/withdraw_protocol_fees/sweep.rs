@@ -0,0 +1,122 @@
+use inf1_ctl_jiminy::{
+    account_utils::pool_state_v2_checked,
+    instructions::protocol_fee::withdraw_protocol_fees::sweep::{
+        NewWithdrawProtocolFeesSweepIxAccsBuilder, WithdrawProtocolFeesSweepIxAccs,
+        WITHDRAW_PROTOCOL_FEES_SWEEP_IX_IS_SIGNER,
+    },
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_ID},
+    pda_onchain::{find_protocol_fee_accumulator, PROTOCOL_FEE_SIGNER},
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::{ProgramError, INVALID_SEEDS},
+    Cpi,
+};
+use sanctum_spl_token_jiminy::{
+    instructions::{
+        close_account::close_account_ix_account_handle_perms,
+        transfer::transfer_checked_ix_account_handle_perms,
+    },
+    sanctum_spl_token_core::instructions::{
+        close_account::{CloseAccountIxData, NewCloseAccountIxAccsBuilder},
+        transfer::{NewTransferCheckedIxAccsBuilder, TransferCheckedIxData},
+    },
+};
+
+use crate::{
+    token::{checked_mint_of, get_token_account_amount},
+    utils::accs_split_first_chunk,
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_pks, verify_signers,
+        verify_tokenkeg_or_22_mint,
+    },
+};
+
+type WithdrawProtocolFeesSweepIxAccounts<'acc> =
+    WithdrawProtocolFeesSweepIxAccs<AccountHandle<'acc>>;
+
+#[inline]
+pub fn withdraw_protocol_fees_sweep_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+) -> Result<WithdrawProtocolFeesSweepIxAccounts<'acc>, ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = WithdrawProtocolFeesSweepIxAccs(*accs);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+    let mint_acc = abr.get(*accs.lst_mint());
+    let token_prog = mint_acc.owner();
+    let (expected_protocol_fee_accumulator, _) =
+        find_protocol_fee_accumulator(token_prog, mint_acc.key()).ok_or(INVALID_SEEDS)?;
+
+    let expected_pks = NewWithdrawProtocolFeesSweepIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_protocol_fee_accumulator_auth(&PROTOCOL_FEE_ID)
+        .with_beneficiary(&pool.protocol_fee_beneficiary)
+        .with_token_program(token_prog)
+        .with_protocol_fee_accumulator(&expected_protocol_fee_accumulator)
+        // Free: the beneficiary is entitled to all balances of all ATAs of the protocol fee PDA,
+        // including tokens that are not part of the pool
+        // owner = token-22 or tokenkeg checked below
+        .with_lst_mint(mint_acc.key())
+        // Free: the beneficiary is free to specify whatever token account to withdraw to
+        // In the case of an invalid token acc, token prog transfer CPI will fail
+        .with_withdraw_to(abr.get(*accs.withdraw_to()).key())
+        // Free: the beneficiary is free to specify whatever account to refund rent to
+        .with_refund_rent_to(abr.get(*accs.refund_rent_to()).key())
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &WITHDRAW_PROTOCOL_FEES_SWEEP_IX_IS_SIGNER.0)?;
+
+    verify_not_rebalancing_and_not_disabled(pool)?;
+
+    verify_tokenkeg_or_22_mint(mint_acc)?;
+
+    Ok(accs)
+}
+
+#[inline]
+pub fn process_withdraw_protocol_fees_sweep(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accs: &WithdrawProtocolFeesSweepIxAccounts,
+) -> Result<(), ProgramError> {
+    // also verifies the accumulator has not already been closed
+    let amt = get_token_account_amount(abr.get(*accs.protocol_fee_accumulator()))?;
+
+    if amt > 0 {
+        let decimals = checked_mint_of(abr.get(*accs.lst_mint()))?.decimals();
+
+        cpi.invoke_signed_handle(
+            abr,
+            *accs.token_program(),
+            TransferCheckedIxData::new(amt, decimals).as_buf(),
+            transfer_checked_ix_account_handle_perms(
+                NewTransferCheckedIxAccsBuilder::start()
+                    .with_mint(*accs.lst_mint())
+                    .with_auth(*accs.protocol_fee_accumulator_auth())
+                    .with_src(*accs.protocol_fee_accumulator())
+                    .with_dst(*accs.withdraw_to())
+                    .build(),
+            ),
+            &[PROTOCOL_FEE_SIGNER],
+        )?;
+    }
+
+    cpi.invoke_signed_handle(
+        abr,
+        *accs.token_program(),
+        CloseAccountIxData::as_buf(),
+        close_account_ix_account_handle_perms(
+            NewCloseAccountIxAccsBuilder::start()
+                .with_close(*accs.protocol_fee_accumulator())
+                .with_dst(*accs.refund_rent_to())
+                .with_auth(*accs.protocol_fee_accumulator_auth())
+                .build(),
+        ),
+        &[PROTOCOL_FEE_SIGNER],
+    )?;
+
+    Ok(())
+}
@@ -1,11 +1,13 @@
 use inf1_ctl_jiminy::{
-    account_utils::{pool_state_v2_checked, pool_state_v2_checked_mut},
+    account_utils::{
+        pool_state_v2_checked, pool_state_v2_checked_mut, protocol_fee_beneficiary_list_checked,
+    },
     err::Inf1CtlErr,
     instructions::protocol_fee::withdraw_protocol_fees::v2::{
         NewWithdrawProtocolFeesV2IxAccsBuilder, WithdrawProtocolFeesV2IxAccs,
         WITHDRAW_PROTOCOL_FEES_V2_IX_IS_SIGNER,
     },
-    keys::POOL_STATE_ID,
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_BENEFICIARY_LIST_ID},
     pda_onchain::POOL_STATE_SIGNER,
     program_err::Inf1CtlCustomProgErr,
     svc::InfCalc,
@@ -49,6 +51,7 @@ pub fn withdraw_protocol_fees_v2_checked<'acc>(
         // Free: the beneficiary is free to specify whatever INF token account to withdraw to
         // In the case of an invalid INF token acc, token prog mint_to CPI will fail
         .with_withdraw_to(abr.get(*accs.withdraw_to()).key())
+        .with_protocol_fee_beneficiary_list(&PROTOCOL_FEE_BENEFICIARY_LIST_ID)
         .build();
     verify_pks(abr, &accs.0, &expected_pks.0)?;
 
@@ -56,6 +59,16 @@ pub fn withdraw_protocol_fees_v2_checked<'acc>(
 
     verify_not_rebalancing_and_not_disabled(pool)?;
 
+    // Once protocol fees are split between beneficiaries,
+    // the single beneficiary may no longer withdraw all of them.
+    // An uncreated list PDA has no data, so is also empty
+    if !protocol_fee_beneficiary_list_checked(abr.get(*accs.protocol_fee_beneficiary_list()))?
+        .0
+        .is_empty()
+    {
+        return Err(Inf1CtlCustomProgErr(Inf1CtlErr::ProtocolFeeBeneficiaryListNotEmpty).into());
+    }
+
     Ok(accs)
 }
 
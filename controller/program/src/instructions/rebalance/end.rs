@@ -108,9 +108,13 @@ pub fn process_end_rebalance(
         inp_calc,
     } = end_rebalance_accs_checked(abr, accounts)?;
 
-    let (old_total_sol_value, inp_lst_idx) = {
+    let (old_total_sol_value, inp_lst_idx, max_loss_lamports) = {
         let rr = rebalance_record_checked(abr.get(*ix_prefix.rebalance_record()))?;
-        (rr.old_total_sol_value, rr.inp_lst_index as usize)
+        (
+            rr.old_total_sol_value,
+            rr.inp_lst_index as usize,
+            rr.rebalance_max_loss_lamports,
+        )
     };
 
     abr.close(*ix_prefix.rebalance_record(), *ix_prefix.pool_state())?;
@@ -143,7 +147,11 @@ pub fn process_end_rebalance(
     .exec(pool.total_sol_value)
     .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::MathError))?;
 
-    if new_total_sol_value < old_total_sol_value {
+    // losses up to rebalance_max_loss() are tolerated and
+    // absorbed from withheld_lamports by UpdateYield below
+    if new_total_sol_value.saturating_add(pool.rebalance_max_loss(max_loss_lamports))
+        < old_total_sol_value
+    {
         return Err(Inf1CtlCustomProgErr(Inf1CtlErr::PoolWouldLoseSolValue).into());
     }
 
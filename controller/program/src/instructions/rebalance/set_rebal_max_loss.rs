@@ -0,0 +1,90 @@
+use core::mem::size_of;
+
+use inf1_ctl_jiminy::{
+    account_utils::{pool_config_checked_mut, pool_state_v2_checked},
+    accounts::pool_config::PoolConfig,
+    instructions::rebalance::set_rebal_max_loss::{
+        NewSetRebalMaxLossIxAccsBuilder, SetRebalMaxLossIxAccs, SetRebalMaxLossIxData,
+        SET_REBAL_MAX_LOSS_IX_IS_SIGNER,
+    },
+    keys::{POOL_CONFIG_ID, POOL_STATE_ID, SYS_PROG_ID},
+    pda_onchain::POOL_CONFIG_SIGNER,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::ProgramError,
+};
+use jiminy_sysvar_rent::Rent;
+use sanctum_system_jiminy::sanctum_system_core::instructions::transfer::NewTransferIxAccsBuilder;
+
+use crate::{
+    utils::{accs_split_first_chunk, create_pda_idmpt, ix_data_as_arr},
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pks, verify_signers},
+    Cpi,
+};
+
+type SetRebalMaxLossIxAccounts<'acc> = SetRebalMaxLossIxAccs<AccountHandle<'acc>>;
+
+#[inline]
+pub fn set_rebal_max_loss_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+    ix_data_no_discm: &[u8],
+) -> Result<(SetRebalMaxLossIxAccounts<'acc>, u64), ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = SetRebalMaxLossIxAccs(*accs);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewSetRebalMaxLossIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_pool_config(&POOL_CONFIG_ID)
+        .with_system_program(&SYS_PROG_ID)
+        .with_admin(&pool.admin)
+        // Free: payer can be any signing pubkey with funds
+        .with_payer(abr.get(*accs.payer()).key())
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &SET_REBAL_MAX_LOSS_IX_IS_SIGNER.0)?;
+
+    // StartRebalance records the tolerance for EndRebalance, so changing it
+    // in the middle of a rebalance would not take effect until the next one
+    verify_not_rebalancing_and_not_disabled(pool)?;
+
+    let max_loss_lamports =
+        SetRebalMaxLossIxData::parse_no_discm(ix_data_as_arr(ix_data_no_discm)?);
+
+    Ok((accs, max_loss_lamports))
+}
+
+/// Creates the pool config PDA if it does not yet exist
+#[inline]
+pub fn process_set_rebal_max_loss(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accs: &SetRebalMaxLossIxAccounts,
+    max_loss_lamports: u64,
+    rent: &Rent,
+) -> Result<(), ProgramError> {
+    create_pda_idmpt(
+        abr,
+        cpi,
+        &NewTransferIxAccsBuilder::start()
+            .with_from(*accs.payer())
+            .with_to(*accs.pool_config())
+            .build(),
+        rent,
+        POOL_CONFIG_SIGNER,
+        size_of::<PoolConfig>(),
+    )?;
+
+    let PoolConfig {
+        rebalance_max_loss_lamports,
+        ..
+    } = pool_config_checked_mut(abr.get_mut(*accs.pool_config()))?;
+
+    *rebalance_max_loss_lamports = max_loss_lamports;
+
+    Ok(())
+}
@@ -3,8 +3,8 @@ use core::mem::size_of;
 use inf1_core::instructions::rebalance::start::StartRebalanceIxAccs;
 use inf1_ctl_jiminy::{
     account_utils::{
-        lst_state_list_checked, pool_state_v2_checked, pool_state_v2_checked_mut,
-        rebalance_record_checked_mut,
+        lst_state_list_checked, pool_config_rebalance_max_loss_lamports, pool_state_v2_checked,
+        pool_state_v2_checked_mut, rebalance_record_checked_mut,
     },
     accounts::rebalance_record::RebalanceRecord,
     cpi::StartRebalanceIxPreAccountHandles,
@@ -19,11 +19,14 @@ use inf1_ctl_jiminy::{
         },
         sync_sol_value::NewSyncSolValueIxPreAccsBuilder,
     },
-    keys::{INSTRUCTIONS_SYSVAR_ID, LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID},
+    keys::{
+        INSTRUCTIONS_SYSVAR_ID, LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID,
+        REBALANCE_RECORD_ID,
+    },
     pda_onchain::{create_raw_pool_reserves_addr, POOL_STATE_SIGNER, REBALANCE_RECORD_SIGNER},
     program_err::Inf1CtlCustomProgErr,
     sync_sol_val::SyncSolVal,
-    typedefs::u8bool::U8BoolMut,
+    typedefs::{disable_modes::DisableMode, u8bool::U8BoolMut},
     ID,
 };
 use jiminy_cpi::{
@@ -52,7 +55,7 @@ use crate::{
     utils::{accs_split_first_chunk, split_suf_accs},
     verify::{
         verify_not_input_disabled, verify_not_rebalancing_and_not_disabled, verify_pks,
-        verify_signers,
+        verify_pool_op_not_disabled, verify_signers,
     },
     Cpi,
 };
@@ -145,6 +148,7 @@ fn start_rebalance_accs_checked<'a, 'acc>(
         .with_instructions(&INSTRUCTIONS_SYSVAR_ID)
         .with_system_program(&SYSTEM_PROGRAM_ID)
         .with_out_lst_token_program(out_token_prog)
+        .with_pool_config(&POOL_CONFIG_ID)
         // Free account - caller can specify any destination for withdrawn tokens
         .with_withdraw_to(abr.get(*ix_prefix.withdraw_to()).key())
         .build();
@@ -153,6 +157,7 @@ fn start_rebalance_accs_checked<'a, 'acc>(
     verify_signers(abr, &ix_prefix.0, &START_REBALANCE_IX_PRE_IS_SIGNER.0)?;
 
     verify_not_rebalancing_and_not_disabled(pool)?;
+    verify_pool_op_not_disabled(pool, DisableMode::Rebalance)?;
 
     let [(out_calc_prog, out_calc), (inp_calc_prog, inp_calc)] =
         split_suf_accs(suf, &[*out_lst_value_calc_accs])?;
@@ -306,9 +311,13 @@ pub fn process_start_rebalance(
     abr.get_mut(*ix_prefix.rebalance_record())
         .realloc(rebalance_record_space, false)?;
 
+    let rebalance_max_loss_lamports =
+        pool_config_rebalance_max_loss_lamports(abr.get(*ix_prefix.pool_config()))?;
+
     let rr = rebalance_record_checked_mut(abr.get_mut(*ix_prefix.rebalance_record()))?;
     rr.inp_lst_index = args.inp_lst_index;
     rr.old_total_sol_value = old_total_sol_value;
+    rr.rebalance_max_loss_lamports = rebalance_max_loss_lamports;
 
     Ok(())
 }
@@ -0,0 +1,96 @@
+use core::mem::size_of;
+
+use inf1_ctl_jiminy::{
+    account_utils::{pool_state_v2_checked, referral_config_checked_mut},
+    accounts::referral_config::ReferralConfig,
+    err::Inf1CtlErr,
+    instructions::referral::set_referral_fee::{
+        NewSetReferralFeeIxAccsBuilder, SetReferralFeeIxAccs, SetReferralFeeIxData,
+        SET_REFERRAL_FEE_IX_IS_SIGNER,
+    },
+    keys::{POOL_STATE_ID, REFERRAL_CONFIG_ID, SYS_PROG_ID},
+    pda_onchain::REFERRAL_CONFIG_SIGNER,
+    program_err::Inf1CtlCustomProgErr,
+    typedefs::fee_nanos::FeeNanos,
+};
+use jiminy_cpi::{
+    account::{Abr, AccountHandle},
+    program_error::{ProgramError, INVALID_INSTRUCTION_DATA},
+};
+use jiminy_sysvar_rent::Rent;
+use sanctum_system_jiminy::sanctum_system_core::instructions::transfer::NewTransferIxAccsBuilder;
+
+use crate::{
+    utils::{accs_split_first_chunk, create_pda_idmpt},
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pks, verify_signers},
+    Cpi,
+};
+
+type SetReferralFeeIxAccounts<'acc> = SetReferralFeeIxAccs<AccountHandle<'acc>>;
+
+#[inline]
+pub fn set_referral_fee_checked<'acc>(
+    abr: &Abr,
+    accs: &[AccountHandle<'acc>],
+    ix_data_no_discm: &[u8],
+) -> Result<(SetReferralFeeIxAccounts<'acc>, FeeNanos), ProgramError> {
+    let (accs, _) = accs_split_first_chunk(accs)?;
+    let accs = SetReferralFeeIxAccs(*accs);
+
+    let pool = pool_state_v2_checked(abr.get(*accs.pool_state()))?;
+
+    let expected_pks = NewSetReferralFeeIxAccsBuilder::start()
+        .with_pool_state(&POOL_STATE_ID)
+        .with_referral_config(&REFERRAL_CONFIG_ID)
+        .with_system_program(&SYS_PROG_ID)
+        .with_admin(&pool.admin)
+        // Free: payer can be any signing pubkey with funds
+        .with_payer(abr.get(*accs.payer()).key())
+        .build();
+    verify_pks(abr, &accs.0, &expected_pks.0)?;
+
+    verify_signers(abr, &accs.0, &SET_REFERRAL_FEE_IX_IS_SIGNER.0)?;
+
+    verify_not_rebalancing_and_not_disabled(pool)?;
+
+    let referral_fee_nanos_raw = SetReferralFeeIxData::parse_no_discm(
+        ix_data_no_discm
+            .first_chunk()
+            .ok_or(INVALID_INSTRUCTION_DATA)?,
+    );
+
+    let referral_fee_nanos = FeeNanos::new(referral_fee_nanos_raw)
+        .map_err(|_| Inf1CtlCustomProgErr(Inf1CtlErr::FeeTooHigh))?;
+
+    Ok((accs, referral_fee_nanos))
+}
+
+/// Creates the referral config PDA if it does not yet exist
+#[inline]
+pub fn process_set_referral_fee(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accs: &SetReferralFeeIxAccounts,
+    referral_fee_nanos: FeeNanos,
+    rent: &Rent,
+) -> Result<(), ProgramError> {
+    create_pda_idmpt(
+        abr,
+        cpi,
+        &NewTransferIxAccsBuilder::start()
+            .with_from(*accs.payer())
+            .with_to(*accs.referral_config())
+            .build(),
+        rent,
+        REFERRAL_CONFIG_SIGNER,
+        size_of::<ReferralConfig>(),
+    )?;
+
+    let ReferralConfig {
+        referral_fee_nanos: config_referral_fee_nanos,
+    } = referral_config_checked_mut(abr.get_mut(*accs.referral_config()))?;
+
+    *config_referral_fee_nanos = referral_fee_nanos.get();
+
+    Ok(())
+}
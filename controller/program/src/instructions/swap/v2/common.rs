@@ -1,24 +1,33 @@
-use inf1_core::{instructions::swap::IxAccs, quote::Quote};
+use inf1_core::{
+    instructions::swap::IxAccs,
+    quote::{Quote, QuoteReferral},
+};
 use inf1_ctl_jiminy::{
     account_utils::{
         lst_state_list_checked, lst_state_list_get, pool_state_v2_checked,
-        pool_state_v2_checked_mut,
+        pool_state_v2_checked_mut, referral_config_checked,
     },
     cpi::{PricingRetVal, SolValCalcRetVal},
-    err::Inf1CtlErr,
+    err::{Inf1CtlErr, InvalidPoolStateDataErrV2},
     instructions::{
         swap::{
-            v2::{IxPreAccs, NewIxPreAccsBuilder},
+            v2::{
+                referral::{NewReferralSufAccsBuilder, ReferralSufAccs},
+                ret::{SwapV2RetV1, SwapV2RetV1Data},
+                IxPreAccs, NewIxPreAccsBuilder,
+            },
             IxArgs,
         },
         sync_sol_value::NewSyncSolValueIxPreAccsBuilder,
     },
-    keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    keys::{LST_STATE_LIST_ID, POOL_STATE_ID, REFERRAL_CONFIG_ID},
     pda_onchain::{create_raw_pool_reserves_addr, POOL_STATE_SIGNER},
     program_err::Inf1CtlCustomProgErr,
     svc::InfCalc,
     sync_sol_val::SyncSolVal,
     typedefs::{
+        disable_modes::DisableMode,
+        fee_nanos::FeeNanos,
         lst_state::LstState,
         pool_sv::{PoolSvLamports, PoolSvMutRefs},
         snap::{NewSnapBuilder, Snap, SnapU64},
@@ -28,8 +37,9 @@ use inf1_ctl_jiminy::{
 };
 use jiminy_cpi::{
     account::{Abr, AccountHandle},
-    program_error::ProgramError,
+    program_error::{ProgramError, NOT_ENOUGH_ACCOUNT_KEYS},
 };
+use jiminy_return_data::set_return_data;
 use jiminy_sysvar_clock::Clock;
 use sanctum_spl_token_jiminy::{
     instructions::{
@@ -49,7 +59,10 @@ use crate::{
     svc::{cpi_lst_reserves_sol_val, lst_ssv_uy, update_lst_state_sol_val, SyncSolValIxAccounts},
     token::{checked_mint_of, get_token_account_amount},
     utils::{accs_split_first_chunk, split_suf_accs},
-    verify::{verify_not_rebalancing_and_not_disabled, verify_pks, verify_pks_raw},
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_pks, verify_pks_raw,
+        verify_pool_op_not_disabled,
+    },
     Cpi,
 };
 
@@ -90,6 +103,78 @@ impl<T> AsRef<T> for SwapV2CtlUni<T> {
 
 pub type SwapV2CtlIxAccounts<'a, 'acc> = SwapV2CtlUni<SwapV2IxAccounts<'a, 'acc>>;
 
+/// Verified referral accounts suffix of a V2 swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SwapV2Referral<'acc> {
+    /// `FeeNanos::ZERO` if the referral config PDA has not been created
+    pub referral_fee_nanos: FeeNanos,
+    pub referrer_acc: AccountHandle<'acc>,
+}
+
+/// Splits the referral accounts suffix, if any, off the end of `accs`.
+///
+/// # Returns
+/// `(accs without the referral accounts suffix, referral)`
+#[inline]
+pub fn swap_v2_split_referral_checked<'a, 'acc>(
+    abr: &Abr,
+    accs: &'a [AccountHandle<'acc>],
+    has_referral: bool,
+) -> Result<(&'a [AccountHandle<'acc>], Option<SwapV2Referral<'acc>>), ProgramError> {
+    if !has_referral {
+        return Ok((accs, None));
+    }
+    let (accs, suf) = accs.split_last_chunk().ok_or(NOT_ENOUGH_ACCOUNT_KEYS)?;
+    let suf = ReferralSufAccs(*suf);
+
+    let expected_pks = NewReferralSufAccsBuilder::start()
+        .with_referral_config(&REFERRAL_CONFIG_ID)
+        // Free: transfer CPI will fail if this is not a token account of the input mint
+        .with_referrer_acc(abr.get(*suf.referrer_acc()).key())
+        .build();
+    verify_pks(abr, &suf.0, &expected_pks.0)?;
+
+    let referral_config = abr.get(*suf.referral_config());
+    let referral_fee_nanos = if referral_config.data_len() == 0 {
+        FeeNanos::ZERO
+    } else {
+        referral_config_checked(referral_config)?
+            .referral_fee_nanos_checked()
+            .map_err(|_| Inf1CtlCustomProgErr(Inf1CtlErr::InvalidReferralConfigData))?
+    };
+
+    Ok((
+        accs,
+        Some(SwapV2Referral {
+            referral_fee_nanos,
+            referrer_acc: *suf.referrer_acc(),
+        }),
+    ))
+}
+
+/// Referral portion of `quote`'s fee, zero if there is no referral
+#[inline]
+pub fn quote_referral(quote: &Quote, referral: Option<&SwapV2Referral>) -> QuoteReferral {
+    referral.map_or_else(QuoteReferral::default, |r| {
+        quote.referral(r.referral_fee_nanos)
+    })
+}
+
+/// Referrals are paid in the input token, which is INF for RemoveLiquidity.
+/// Paying INF to the referrer instead of burning it is not supported.
+#[inline]
+pub fn verify_swap_v2_referral(
+    accs: &SwapV2CtlIxAccounts,
+    referral: Option<&SwapV2Referral>,
+) -> Result<(), ProgramError> {
+    match (accs, referral) {
+        (SwapV2CtlIxAccounts::RemLiq(_), Some(_)) => {
+            Err(Inf1CtlCustomProgErr(Inf1CtlErr::RemoveLiquidityReferral).into())
+        }
+        _ => Ok(()),
+    }
+}
+
 #[inline]
 pub fn swap_v2_split_accs<'a, 'acc>(
     abr: &Abr,
@@ -168,6 +253,14 @@ pub fn verify_swap_v2(
     let pool = pool_state_v2_checked(abr.get(*ix_prefix.pool_state()))?;
 
     verify_not_rebalancing_and_not_disabled(pool)?;
+    verify_pool_op_not_disabled(
+        pool,
+        match accs {
+            SwapV2CtlIxAccounts::Swap(_) => DisableMode::Swap,
+            SwapV2CtlIxAccounts::AddLiq(_) => DisableMode::AddLiquidity,
+            SwapV2CtlIxAccounts::RemLiq(_) => DisableMode::RemoveLiquidity,
+        },
+    )?;
 
     let list = lst_state_list_checked(abr.get(*ix_prefix.lst_state_list()))?;
 
@@ -315,6 +408,18 @@ pub fn initial_sync(
     }
 }
 
+#[inline]
+pub fn pool_min_fee_nanos(abr: &Abr, accs: &SwapV2CtlIxAccounts) -> Result<FeeNanos, ProgramError> {
+    pool_state_v2_checked(abr.get(*accs.as_ref().ix_prefix.pool_state()))?
+        .min_fee_nanos_checked()
+        .map_err(|e| {
+            Inf1CtlCustomProgErr(Inf1CtlErr::InvalidPoolStateDataV2(
+                InvalidPoolStateDataErrV2::MinFeeNanos(e),
+            ))
+            .into()
+        })
+}
+
 /// Returns `u64::MAX` if out token is INF (no limit on how much can be minted)
 #[inline]
 pub fn out_reserves_balance(abr: &Abr, accs: &SwapV2CtlIxAccounts) -> Result<u64, ProgramError> {
@@ -331,13 +436,40 @@ pub fn move_tokens(
     abr: &mut Abr,
     cpi: &mut Cpi,
     accs: &SwapV2CtlIxAccounts,
+    referral: Option<&SwapV2Referral>,
     Quote { inp, out, .. }: &Quote,
+    QuoteReferral {
+        inp: referral_inp, ..
+    }: &QuoteReferral,
 ) -> Result<(), ProgramError> {
+    if let Some(SwapV2Referral { referrer_acc, .. }) = referral {
+        if *referral_inp > 0 {
+            let ix_prefix = &accs.as_ref().ix_prefix;
+            cpi.invoke_fwd_handle(
+                abr,
+                *ix_prefix.inp_token_program(),
+                TransferCheckedIxData::new(
+                    *referral_inp,
+                    checked_mint_of(abr.get(*ix_prefix.inp_mint()))?.decimals(),
+                )
+                .as_buf(),
+                NewTransferCheckedIxAccsBuilder::start()
+                    .with_auth(*ix_prefix.signer())
+                    .with_src(*ix_prefix.inp_acc())
+                    .with_dst(*referrer_acc)
+                    .with_mint(*ix_prefix.inp_mint())
+                    .build()
+                    .0,
+            )?;
+        }
+    }
+    // referral_inp <= inp always
+    let pool_inp = inp - referral_inp;
     match accs {
         SwapV2Ctl::RemLiq(accs) => cpi.invoke_fwd_handle(
             abr,
             *accs.ix_prefix.inp_token_program(),
-            BurnIxData::new(*inp).as_buf(),
+            BurnIxData::new(pool_inp).as_buf(),
             NewBurnIxAccsBuilder::start()
                 .with_auth(*accs.ix_prefix.signer())
                 .with_from(*accs.ix_prefix.inp_acc())
@@ -351,7 +483,7 @@ pub fn move_tokens(
             abr,
             *accs.ix_prefix.inp_token_program(),
             TransferCheckedIxData::new(
-                *inp,
+                pool_inp,
                 checked_mint_of(abr.get(*accs.ix_prefix.inp_mint()))?.decimals(),
             )
             .as_buf(),
@@ -578,6 +710,32 @@ fn verify_liq_no_loss(snap: &Snap<InfCalc>) -> Result<(), Inf1CtlCustomProgErr>
     }
 }
 
+/// Sets the swap's result as return data so that
+/// CPI callers can read the exact amounts swapped
+#[inline]
+pub fn set_swap_ret_data(
+    Quote {
+        inp,
+        out,
+        fee,
+        inp_sol_val,
+        inp_mint,
+        out_mint,
+    }: &Quote,
+) {
+    set_return_data(
+        SwapV2RetV1Data::new(&SwapV2RetV1 {
+            inp: *inp,
+            out: *out,
+            fee: *fee,
+            inp_sol_val: *inp_sol_val,
+            inp_mint: *inp_mint,
+            out_mint: *out_mint,
+        })
+        .as_buf(),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use sanctum_u64_ratio::Ratio;
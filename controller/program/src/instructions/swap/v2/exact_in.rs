@@ -24,7 +24,8 @@ use crate::{
     err::quote_err_to_inf1_ctl_err,
     instructions::swap::v2::{
         final_sync, final_sync_aux_post_movement, final_sync_aux_pre_movement, initial_sync,
-        move_tokens, out_reserves_balance, SwapCpiRetVals, SwapV2CtlIxAccounts, SwapV2IxAccounts,
+        move_tokens, out_reserves_balance, pool_min_fee_nanos, quote_referral, set_swap_ret_data,
+        SwapCpiRetVals, SwapV2CtlIxAccounts, SwapV2IxAccounts, SwapV2Referral,
     },
     token::checked_mint_of,
     Cpi,
@@ -35,6 +36,7 @@ pub fn process_swap_exact_in_v2(
     abr: &mut Abr,
     cpi: &mut Cpi,
     accs: &SwapV2CtlIxAccounts,
+    referral: Option<&SwapV2Referral>,
     args: &IxArgs,
     clock: &Clock,
 ) -> Result<(), ProgramError> {
@@ -51,6 +53,7 @@ pub fn process_swap_exact_in_v2(
     } = exec_calc_cpis_unchecked(abr, cpi, accs, args.amount)?;
 
     let out_reserves = out_reserves_balance(abr, accs)?;
+    let min_fee_nanos = pool_min_fee_nanos(abr, accs)?;
 
     let [inp_mint, out_mint] = [IxPreAccs::inp_mint, IxPreAccs::out_mint]
         .map(|getter| *abr.get(*getter(&accs.as_ref().ix_prefix)).key());
@@ -62,9 +65,11 @@ pub fn process_swap_exact_in_v2(
         pricing,
         inp_mint,
         out_mint,
+        min_fee_nanos,
     })
     .map_err(quote_err_to_inf1_ctl_err)
     .map_err(Inf1CtlCustomProgErr)?;
+    let quote_ref = quote_referral(&quote, referral);
 
     if quote.out < args.limit {
         return Err(Inf1CtlCustomProgErr(Inf1CtlErr::SlippageToleranceExceeded).into());
@@ -72,12 +77,20 @@ pub fn process_swap_exact_in_v2(
 
     let aux_pre = final_sync_aux_pre_movement(abr, accs)?;
 
-    move_tokens(abr, cpi, accs, &quote)?;
+    move_tokens(abr, cpi, accs, referral, &quote, &quote_ref)?;
 
-    let aux = final_sync_aux_post_movement(abr, &accs.as_ref().ix_prefix, quote.fee, aux_pre)?;
+    let aux = final_sync_aux_post_movement(
+        abr,
+        &accs.as_ref().ix_prefix,
+        // referral fee <= fee always
+        quote.fee - quote_ref.fee,
+        aux_pre,
+    )?;
 
     final_sync(abr, cpi, accs.as_ref(), args, &aux)?;
 
+    set_swap_ret_data(&quote);
+
     Ok(())
 }
 
@@ -1,24 +1,26 @@
 use inf1_core::instructions::sync_sol_value::SyncSolValueIxAccs;
 use inf1_ctl_jiminy::{
     account_utils::{
-        lst_state_list_checked, lst_state_list_get, pool_state_v2_checked,
-        pool_state_v2_checked_mut,
+        lst_state_list_checked, lst_state_list_checked_mut, lst_state_list_get,
+        lst_state_list_get_mut, pool_state_v2_checked, pool_state_v2_checked_mut,
     },
     err::Inf1CtlErr,
     instructions::sync_sol_value::{NewSyncSolValueIxPreAccsBuilder, SyncSolValueIxPreAccs},
     keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
     pda_onchain::create_raw_pool_reserves_addr,
     program_err::Inf1CtlCustomProgErr,
+    typedefs::{lst_state::LstState, u8bool::U8BoolMut},
 };
 use jiminy_cpi::{
     account::{Abr, AccountHandle},
     program_error::ProgramError,
 };
+use jiminy_log::sol_log;
 use jiminy_sysvar_clock::Clock;
 
 use crate::{
     acc_migrations::pool_state,
-    svc::{lst_ssv_uy, SyncSolValIxAccounts},
+    svc::{lst_ssv_uy_trips_circuit_breaker, SyncSolValIxAccounts},
     utils::{accs_split_first_chunk, split_suf_accs},
     verify::{verify_not_rebalancing_and_not_disabled, verify_pks},
     Cpi,
@@ -78,7 +80,16 @@ pub fn process_sync_sol_value(
         .release_yield(clock.slot)
         .map_err(Inf1CtlCustomProgErr)?;
 
-    lst_ssv_uy(abr, cpi, accs, lst_idx)?;
+    // Unlike other instructions that sync, trips are persisted
+    // instead of failing so that the LST's input stays disabled
+    if lst_ssv_uy_trips_circuit_breaker(abr, cpi, accs, lst_idx)? {
+        let LstState {
+            is_input_disabled, ..
+        } = lst_state_list_checked_mut(abr.get_mut(*accs.ix_prefix.lst_state_list()))
+            .and_then(|l| lst_state_list_get_mut(l, lst_idx))?;
+        U8BoolMut(is_input_disabled).set_true();
+        sol_log(&Inf1CtlErr::LstSolValueCircuitBreakerTripped.to_string());
+    }
 
     Ok(())
 }
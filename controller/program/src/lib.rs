@@ -8,33 +8,52 @@ use inf1_ctl_jiminy::instructions::{
         lst_input::{disable::DISABLE_LST_INPUT_IX_DISCM, enable::ENABLE_LST_INPUT_IX_DISCM},
         remove_lst::{RemoveLstIxData, REMOVE_LST_IX_DISCM},
         set_admin::SET_ADMIN_IX_DISCM,
+        set_min_fee::SET_MIN_FEE_IX_DISCM,
         set_pricing_prog::SET_PRICING_PROG_IX_DISCM,
         set_sol_value_calculator::{SetSolValueCalculatorIxData, SET_SOL_VALUE_CALC_IX_DISCM},
+        set_sol_value_circuit_breaker::SET_SOL_VALUE_CIRCUIT_BREAKER_IX_DISCM,
+        timelock::{
+            cancel::CANCEL_ADMIN_ACTION_IX_DISCM, execute::EXECUTE_ADMIN_ACTION_IX_DISCM,
+            queue::QUEUE_ADMIN_ACTION_IX_DISCM,
+            set_admin_action_delay::SET_ADMIN_ACTION_DELAY_IX_DISCM,
+        },
     },
+    crank_inf_price_history::CRANK_INF_PRICE_HISTORY_IX_DISCM,
     disable_pool::{
         add_disable_pool_auth::ADD_DISABLE_POOL_AUTH_IX_DISCM, disable::DISABLE_POOL_IX_DISCM,
         enable::ENABLE_POOL_IX_DISCM, remove_disable_pool_auth::REMOVE_DISABLE_POOL_AUTH_IX_DISCM,
+        set_disable_modes::SET_DISABLE_MODES_IX_DISCM,
     },
+    inf_price::INF_PRICE_IX_DISCM,
     liquidity::{
         add::ADD_LIQUIDITY_IX_DISCM, parse_liq_ix_args, remove::REMOVE_LIQUIDITY_IX_DISCM,
     },
     protocol_fee::{
+        remove_protocol_fee_beneficiary_share::REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_DISCM,
         set_protocol_fee::SET_PROTOCOL_FEE_IX_DISCM,
         set_protocol_fee_beneficiary::SET_PROTOCOL_FEE_BENEFICIARY_IX_DISCM,
+        set_protocol_fee_beneficiary_share::SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_DISCM,
         withdraw_protocol_fees::{
-            v1::WITHDRAW_PROTOCOL_FEES_IX_DISCM, v2::WITHDRAW_PROTOCOL_FEES_V2_IX_DISCM,
+            split::WITHDRAW_PROTOCOL_FEES_SPLIT_IX_DISCM,
+            sweep::WITHDRAW_PROTOCOL_FEES_SWEEP_IX_DISCM, v1::WITHDRAW_PROTOCOL_FEES_IX_DISCM,
+            v2::WITHDRAW_PROTOCOL_FEES_V2_IX_DISCM,
         },
     },
     rebalance::{
         end::END_REBALANCE_IX_DISCM,
         set_rebal_auth::SET_REBAL_AUTH_IX_DISCM,
+        set_rebal_max_loss::SET_REBAL_MAX_LOSS_IX_DISCM,
         start::{StartRebalanceIxData, START_REBALANCE_IX_DISCM},
     },
+    referral::set_referral_fee::SET_REFERRAL_FEE_IX_DISCM,
     rps::{set_rps::SET_RPS_IX_DISCM, set_rps_auth::SET_RPS_AUTH_IX_DISCM},
     swap::{
         parse_swap_ix_args,
         v1::{exact_in::SWAP_EXACT_IN_IX_DISCM, exact_out::SWAP_EXACT_OUT_IX_DISCM},
-        v2::{exact_in::SWAP_EXACT_IN_V2_IX_DISCM, exact_out::SWAP_EXACT_OUT_V2_IX_DISCM},
+        v2::{
+            exact_in::SWAP_EXACT_IN_V2_IX_DISCM, exact_out::SWAP_EXACT_OUT_V2_IX_DISCM,
+            referral::parse_swap_v2_ix_args,
+        },
     },
     sync_sol_value::{SyncSolValueIxData, SYNC_SOL_VALUE_IX_DISCM},
 };
@@ -59,10 +78,26 @@ use crate::{
             },
             remove_lst::process_remove_lst,
             set_admin::{process_set_admin, set_admin_accs_checked},
+            set_min_fee::{process_set_min_fee, set_min_fee_checked},
             set_pricing_prog::{process_set_pricing_prog, set_pricing_prog_accs_checked},
             set_sol_value_calculator::{
                 process_set_sol_value_calculator, set_sol_value_calculator_accs_checked,
             },
+            set_sol_value_circuit_breaker::{
+                process_set_sol_value_circuit_breaker, set_sol_value_circuit_breaker_checked,
+            },
+            timelock::{
+                cancel::{cancel_admin_action_accs_checked, process_cancel_admin_action},
+                execute::process_execute_admin_action,
+                queue::{process_queue_admin_action, queue_admin_action_checked},
+                set_admin_action_delay::{
+                    process_set_admin_action_delay, set_admin_action_delay_checked,
+                    verify_admin_action_delay_not_decreased,
+                },
+            },
+        },
+        crank_inf_price_history::{
+            crank_inf_price_history_accs_checked, process_crank_inf_price_history,
         },
         disable_pool::{
             add_disable_pool_auth::{
@@ -73,13 +108,29 @@ use crate::{
             remove_disable_pool_auth::{
                 process_remove_disable_pool_auth, remove_disable_pool_auth_checked,
             },
+            set_disable_modes::{process_set_disable_modes, set_disable_modes_checked},
         },
+        inf_price::{inf_price_accs_checked, process_inf_price},
         protocol_fee::{
+            remove_protocol_fee_beneficiary_share::{
+                process_remove_protocol_fee_beneficiary_share,
+                remove_protocol_fee_beneficiary_share_checked,
+            },
             set_protocol_fee::{process_set_protocol_fee, set_protocol_fee_checked},
             set_protocol_fee_beneficiary::{
                 process_set_protocol_fee_beneficiary, set_protocol_fee_beneficiary_accs_checked,
             },
+            set_protocol_fee_beneficiary_share::{
+                process_set_protocol_fee_beneficiary_share,
+                set_protocol_fee_beneficiary_share_checked,
+            },
             withdraw_protocol_fees::{
+                split::{
+                    process_withdraw_protocol_fees_split, withdraw_protocol_fees_split_checked,
+                },
+                sweep::{
+                    process_withdraw_protocol_fees_sweep, withdraw_protocol_fees_sweep_checked,
+                },
                 v1::{process_withdraw_protocol_fees, withdraw_protocol_fees_checked},
                 v2::{process_withdraw_protocol_fees_v2, withdraw_protocol_fees_v2_checked},
             },
@@ -87,8 +138,10 @@ use crate::{
         rebalance::{
             end::process_end_rebalance,
             set_rebal_auth::{process_set_rebal_auth, set_rebal_auth_accs_checked},
+            set_rebal_max_loss::{process_set_rebal_max_loss, set_rebal_max_loss_checked},
             start::process_start_rebalance,
         },
+        referral::set_referral_fee::{process_set_referral_fee, set_referral_fee_checked},
         rps::{
             set_rps::{process_set_rps, set_rps_checked},
             set_rps_auth::{process_set_rps_auth, set_rps_auth_accs_checked},
@@ -100,16 +153,18 @@ use crate::{
             },
             v2::{
                 process_swap_exact_in_v2, process_swap_exact_out_v2, swap_v2_split_accs,
-                verify_swap_v2,
+                swap_v2_split_referral_checked, verify_swap_v2, verify_swap_v2_referral,
             },
         },
         sync_sol_value::{process_sync_sol_value, sync_sol_value_accs_checked},
     },
     utils::ix_data_as_arr,
+    verify::verify_admin_action_not_timelocked,
 };
 
 mod acc_migrations;
 mod err;
+mod inf_price_history;
 mod instructions;
 mod svc;
 mod token;
@@ -172,7 +227,7 @@ fn process_ix(
             let accs = swap_split_v1_accs_into_v2(abr, accounts, &args)?;
             let clock = Clock::write_to(&mut clock)?;
             verify_swap_v2(abr, &accs, &args, clock)?;
-            process_swap_exact_in_v2(abr, cpi, &accs, &args, clock)
+            process_swap_exact_in_v2(abr, cpi, &accs, None, &args, clock)
         }
         (&SWAP_EXACT_OUT_IX_DISCM, data) => {
             sol_log("SwapExactOut");
@@ -180,7 +235,7 @@ fn process_ix(
             let accs = swap_split_v1_accs_into_v2(abr, accounts, &args)?;
             let clock = Clock::write_to(&mut clock)?;
             verify_swap_v2(abr, &accs, &args, clock)?;
-            process_swap_exact_out_v2(abr, cpi, &accs, &args, clock)
+            process_swap_exact_out_v2(abr, cpi, &accs, None, &args, clock)
         }
         (&ADD_LIQUIDITY_IX_DISCM, data) => {
             sol_log("AddLiquidity");
@@ -189,7 +244,7 @@ fn process_ix(
             let args = conv_add_liq_args(args);
             let clock = Clock::write_to(&mut clock)?;
             verify_swap_v2(abr, &accs, &args, clock)?;
-            process_swap_exact_in_v2(abr, cpi, &accs, &args, clock)
+            process_swap_exact_in_v2(abr, cpi, &accs, None, &args, clock)
         }
         (&REMOVE_LIQUIDITY_IX_DISCM, data) => {
             sol_log("RemoveLiquidity");
@@ -198,24 +253,32 @@ fn process_ix(
             let args = conv_rem_liq_args(args);
             let clock = Clock::write_to(&mut clock)?;
             verify_swap_v2(abr, &accs, &args, clock)?;
-            process_swap_exact_in_v2(abr, cpi, &accs, &args, clock)
+            process_swap_exact_in_v2(abr, cpi, &accs, None, &args, clock)
         }
         // v2 swap
         (&SWAP_EXACT_IN_V2_IX_DISCM, data) => {
             sol_log("SwapExactInV2");
-            let args = parse_swap_ix_args(ix_data_as_arr(data)?);
-            let accs = swap_v2_split_accs(abr, accounts, &args)?;
+            let (args, has_referral) =
+                parse_swap_v2_ix_args(data).ok_or(INVALID_INSTRUCTION_DATA)?;
+            let (swap_accs, referral) =
+                swap_v2_split_referral_checked(abr, accounts, has_referral)?;
+            let accs = swap_v2_split_accs(abr, swap_accs, &args)?;
             let clock = Clock::write_to(&mut clock)?;
             verify_swap_v2(abr, &accs, &args, clock)?;
-            process_swap_exact_in_v2(abr, cpi, &accs, &args, clock)
+            verify_swap_v2_referral(&accs, referral.as_ref())?;
+            process_swap_exact_in_v2(abr, cpi, &accs, referral.as_ref(), &args, clock)
         }
         (&SWAP_EXACT_OUT_V2_IX_DISCM, data) => {
             sol_log("SwapExactOutV2");
-            let args = parse_swap_ix_args(ix_data_as_arr(data)?);
-            let accs = swap_v2_split_accs(abr, accounts, &args)?;
+            let (args, has_referral) =
+                parse_swap_v2_ix_args(data).ok_or(INVALID_INSTRUCTION_DATA)?;
+            let (swap_accs, referral) =
+                swap_v2_split_referral_checked(abr, accounts, has_referral)?;
+            let accs = swap_v2_split_accs(abr, swap_accs, &args)?;
             let clock = Clock::write_to(&mut clock)?;
             verify_swap_v2(abr, &accs, &args, clock)?;
-            process_swap_exact_out_v2(abr, cpi, &accs, &args, clock)
+            verify_swap_v2_referral(&accs, referral.as_ref())?;
+            process_swap_exact_out_v2(abr, cpi, &accs, referral.as_ref(), &args, clock)
         }
         // admin ixs
         (&DISABLE_LST_INPUT_IX_DISCM, data) => {
@@ -228,6 +291,12 @@ fn process_ix(
             let (accs, idx) = set_lst_input_checked(abr, accounts, data)?;
             process_enable_lst_input(abr, &accs, idx)
         }
+        (&SET_SOL_VALUE_CIRCUIT_BREAKER_IX_DISCM, data) => {
+            sol_log("SetSolValueCircuitBreaker");
+            let (accs, circuit_breaker_nanos) =
+                set_sol_value_circuit_breaker_checked(abr, accounts, data)?;
+            process_set_sol_value_circuit_breaker(abr, &accs, circuit_breaker_nanos)
+        }
         (&ADD_LST_IX_DISCM, _data) => {
             sol_log("AddLst");
             let rent = Rent::write_to(&mut rent)?;
@@ -244,23 +313,60 @@ fn process_ix(
             let lst_idx =
                 SetSolValueCalculatorIxData::parse_no_discm(ix_data_as_arr(data)?) as usize;
             let accs = set_sol_value_calculator_accs_checked(abr, accounts, lst_idx)?;
+            verify_admin_action_not_timelocked(abr.get(*accs.ix_prefix.pool_config()))?;
             let clock = Clock::write_to(&mut clock)?;
-            process_set_sol_value_calculator(abr, cpi, &accs, lst_idx, clock)
+            let rent = Rent::write_to(&mut rent)?;
+            process_set_sol_value_calculator(abr, cpi, &accs, lst_idx, clock, rent)
         }
         (&SET_ADMIN_IX_DISCM, _) => {
             sol_log("SetAdmin");
             let accs = set_admin_accs_checked(abr, accounts)?;
+            verify_admin_action_not_timelocked(abr.get(*accs.pool_config()))?;
             process_set_admin(abr, &accs)
         }
         (&SET_PRICING_PROG_IX_DISCM, _) => {
             sol_log("SetPricingProg");
             let accs = set_pricing_prog_accs_checked(abr, accounts)?;
+            verify_admin_action_not_timelocked(abr.get(*accs.pool_config()))?;
             process_set_pricing_prog(abr, &accs)
         }
+        (&SET_MIN_FEE_IX_DISCM, data) => {
+            sol_log("SetMinFee");
+            let (accs, min_fee_nanos) = set_min_fee_checked(abr, accounts, data)?;
+            verify_admin_action_not_timelocked(abr.get(*accs.pool_config()))?;
+            process_set_min_fee(abr, &accs, min_fee_nanos)
+        }
+        // admin action timelock
+        (&QUEUE_ADMIN_ACTION_IX_DISCM, data) => {
+            sol_log("QueueAdminAction");
+            let (accs, args) = queue_admin_action_checked(abr, accounts, data)?;
+            let clock = Clock::write_to(&mut clock)?;
+            let rent = Rent::write_to(&mut rent)?;
+            process_queue_admin_action(abr, cpi, &accs, args, clock, rent)
+        }
+        (&EXECUTE_ADMIN_ACTION_IX_DISCM, _) => {
+            sol_log("ExecuteAdminAction");
+            let clock = Clock::write_to(&mut clock)?;
+            let rent = Rent::write_to(&mut rent)?;
+            process_execute_admin_action(abr, cpi, accounts, clock, rent)
+        }
+        (&CANCEL_ADMIN_ACTION_IX_DISCM, _) => {
+            sol_log("CancelAdminAction");
+            let accs = cancel_admin_action_accs_checked(abr, accounts)?;
+            process_cancel_admin_action(abr, &accs)
+        }
+        (&SET_ADMIN_ACTION_DELAY_IX_DISCM, data) => {
+            sol_log("SetAdminActionDelay");
+            let (accs, delay) = set_admin_action_delay_checked(abr, accounts, data)?;
+            verify_admin_action_delay_not_decreased(abr, &accs, delay)?;
+            let rent = Rent::write_to(&mut rent)?;
+            process_set_admin_action_delay(abr, cpi, &accs, delay, rent)
+        }
         // protocol fees
         (&SET_PROTOCOL_FEE_IX_DISCM, data) => {
             sol_log("SetProtocolFee");
             let (accs, protocol_fee_nanos) = set_protocol_fee_checked(abr, accounts, data)?;
+            verify_admin_action_not_timelocked(abr.get(*accs.pool_config()))?;
             process_set_protocol_fee(abr, &accs, protocol_fee_nanos)
         }
         (&SET_PROTOCOL_FEE_BENEFICIARY_IX_DISCM, _) => {
@@ -279,6 +385,29 @@ fn process_ix(
             let clock = Clock::write_to(&mut clock)?;
             process_withdraw_protocol_fees_v2(abr, cpi, &accs, clock)
         }
+        (&SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_DISCM, data) => {
+            sol_log("SetProtocolFeeBeneficiaryShare");
+            let (accs, weight) = set_protocol_fee_beneficiary_share_checked(abr, accounts, data)?;
+            let rent = Rent::write_to(&mut rent)?;
+            process_set_protocol_fee_beneficiary_share(abr, cpi, &accs, weight, rent)
+        }
+        (&REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_DISCM, data) => {
+            sol_log("RemoveProtocolFeeBeneficiaryShare");
+            let (accs, idx) = remove_protocol_fee_beneficiary_share_checked(abr, accounts, data)?;
+            let rent = Rent::write_to(&mut rent)?;
+            process_remove_protocol_fee_beneficiary_share(abr, &accs, idx, rent)
+        }
+        (&WITHDRAW_PROTOCOL_FEES_SPLIT_IX_DISCM, _) => {
+            sol_log("WithdrawProtocolFeesSplit");
+            let accs = withdraw_protocol_fees_split_checked(abr, accounts)?;
+            let clock = Clock::write_to(&mut clock)?;
+            process_withdraw_protocol_fees_split(abr, cpi, &accs, clock)
+        }
+        (&WITHDRAW_PROTOCOL_FEES_SWEEP_IX_DISCM, _) => {
+            sol_log("WithdrawProtocolFeesSweep");
+            let accs = withdraw_protocol_fees_sweep_checked(abr, accounts)?;
+            process_withdraw_protocol_fees_sweep(abr, cpi, &accs)
+        }
         // disable pool system
         (&ADD_DISABLE_POOL_AUTH_IX_DISCM, _) => {
             sol_log("AddDisablePoolAuth");
@@ -302,6 +431,11 @@ fn process_ix(
             let accs = enable_pool_accs_checked(abr, accounts)?;
             process_enable_pool(abr, &accs)
         }
+        (&SET_DISABLE_MODES_IX_DISCM, data) => {
+            sol_log("SetDisableModes");
+            let (accs, modes) = set_disable_modes_checked(abr, accounts, data)?;
+            process_set_disable_modes(abr, &accs, modes)
+        }
         // rebalance
         (&START_REBALANCE_IX_DISCM, data) => {
             sol_log("StartRebalance");
@@ -318,6 +452,12 @@ fn process_ix(
             let accs = set_rebal_auth_accs_checked(abr, accounts)?;
             process_set_rebal_auth(abr, &accs)
         }
+        (&SET_REBAL_MAX_LOSS_IX_DISCM, data) => {
+            sol_log("SetRebalMaxLoss");
+            let (accs, max_loss_lamports) = set_rebal_max_loss_checked(abr, accounts, data)?;
+            let rent = Rent::write_to(&mut rent)?;
+            process_set_rebal_max_loss(abr, cpi, &accs, max_loss_lamports, rent)
+        }
 
         // discm=22 old Initialize instruction, now unused
 
@@ -333,6 +473,28 @@ fn process_ix(
             let accs = set_rps_auth_accs_checked(abr, accounts)?;
             process_set_rps_auth(abr, &accs)
         }
+        // referral
+        (&SET_REFERRAL_FEE_IX_DISCM, data) => {
+            sol_log("SetReferralFee");
+            let (accs, referral_fee_nanos) = set_referral_fee_checked(abr, accounts, data)?;
+            let rent = Rent::write_to(&mut rent)?;
+            process_set_referral_fee(abr, cpi, &accs, referral_fee_nanos, rent)
+        }
+        // read-only
+        (&INF_PRICE_IX_DISCM, _) => {
+            sol_log("InfPrice");
+            let accs = inf_price_accs_checked(abr, accounts)?;
+            let clock = Clock::write_to(&mut clock)?;
+            process_inf_price(abr, &accs, clock)
+        }
+        // INF price history
+        (&CRANK_INF_PRICE_HISTORY_IX_DISCM, _) => {
+            sol_log("CrankInfPriceHistory");
+            let accs = crank_inf_price_history_accs_checked(abr, accounts)?;
+            let clock = Clock::write_to(&mut clock)?;
+            let rent = Rent::write_to(&mut rent)?;
+            process_crank_inf_price_history(abr, cpi, &accs, clock, rent)
+        }
         _ => Err(INVALID_INSTRUCTION_DATA.into()),
     }
 }
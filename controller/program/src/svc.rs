@@ -1,13 +1,17 @@
 use inf1_core::instructions::sync_sol_value::SyncSolValueIxAccs;
 use inf1_ctl_jiminy::{
     account_utils::{
-        lst_state_list_checked_mut, lst_state_list_get_mut, pool_state_v2_checked_mut,
+        lst_state_list_checked, lst_state_list_checked_mut, lst_state_list_get,
+        lst_state_list_get_mut, pool_state_v2_checked_mut,
     },
     cpi::SyncSolValueIxPreAccountHandles,
     err::Inf1CtlErr,
     program_err::Inf1CtlCustomProgErr,
     sync_sol_val::SyncSolVal,
-    typedefs::snap::{NewSnapBuilder, SnapU64},
+    typedefs::{
+        compact_u64::CompactU64,
+        snap::{NewSnapBuilder, SnapU64},
+    },
 };
 
 use inf1_svc_jiminy::cpi::cpi_lst_to_sol;
@@ -30,6 +34,12 @@ pub type SyncSolValIxAccounts<'a, 'acc> =
 /// - update sol_value on lst state list
 /// - update pool_state.total_sol_value
 /// - update yield for any observed PnL
+/// - fails if the change trips the pool's SOL value circuit breaker
+///
+/// Failing reverts the whole transaction, so the trip cannot disable
+/// the LST's input here. Swaps with the LST on either side, liquidity
+/// instructions and StartRebalance keep failing until a permissionless
+/// SyncSolValue persists the trip by setting `is_input_disabled`.
 ///
 /// TODO: use return value to create yield update event for self-cpi logging
 #[inline]
@@ -39,17 +49,44 @@ pub fn lst_ssv_uy(
     sync_sol_val_accs: &SyncSolValIxAccounts,
     lst_index: usize,
 ) -> Result<(), ProgramError> {
+    if lst_ssv_uy_trips_circuit_breaker(abr, cpi, sync_sol_val_accs, lst_index)? {
+        return Err(Inf1CtlCustomProgErr(Inf1CtlErr::LstSolValueCircuitBreakerTripped).into());
+    }
+    Ok(())
+}
+
+/// [`lst_ssv_uy`], but returns whether the change tripped
+/// the pool's SOL value circuit breaker instead of failing
+#[inline]
+pub fn lst_ssv_uy_trips_circuit_breaker(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    sync_sol_val_accs: &SyncSolValIxAccounts,
+    lst_index: usize,
+) -> Result<bool, ProgramError> {
+    let lst_state_list = *sync_sol_val_accs.ix_prefix.lst_state_list();
     let lst_new = cpi_lst_reserves_sol_val(abr, cpi, sync_sol_val_accs)?;
-    let lst_sol_val = update_lst_state_sol_val(
-        abr,
-        *sync_sol_val_accs.ix_prefix.lst_state_list(),
-        lst_index,
-        lst_new,
-    )?;
+    let old_balance = lst_state_list_checked(abr.get(lst_state_list))
+        .and_then(|list| lst_state_list_get(list, lst_index))?
+        .synced_reserves_balance
+        .get();
+    let lst_sol_val = update_lst_state_sol_val(abr, lst_state_list, lst_index, lst_new)?;
+    let sync = SyncSolVal { lst_sol_val };
+    let reserves_balance = NewSnapBuilder::start()
+        .with_old(old_balance)
+        .with_new(lst_new.balance)
+        .build();
     let ps = pool_state_v2_checked_mut(abr.get_mut(*sync_sol_val_accs.ix_prefix.pool_state()))?;
-    ps.apply_ssv_uy(&SyncSolVal { lst_sol_val })
+    ps.apply_ssv_uy(&sync)
         .ok_or(Inf1CtlCustomProgErr(Inf1CtlErr::MathError))?;
-    Ok(())
+    Ok(sync.trips_circuit_breaker(&reserves_balance, ps.sol_value_circuit_breaker_nanos))
+}
+
+/// An LST's pool reserves balance and the SOL value of that balance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReservesSolVal {
+    pub balance: u64,
+    pub sol_val: u64,
 }
 
 #[inline]
@@ -57,18 +94,18 @@ pub fn cpi_lst_reserves_sol_val(
     abr: &mut Abr,
     cpi: &mut Cpi,
     sync_sol_val_accs: &SyncSolValIxAccounts,
-) -> Result<u64, ProgramError> {
+) -> Result<ReservesSolVal, ProgramError> {
     let SyncSolValueIxAccs {
         ix_prefix,
         calc_prog,
         calc,
     } = sync_sol_val_accs;
-    let lst_balance = get_token_account_amount(abr.get(*ix_prefix.pool_reserves()))?;
-    Ok(*cpi_lst_to_sol(
+    let balance = get_token_account_amount(abr.get(*ix_prefix.pool_reserves()))?;
+    let sol_val = *cpi_lst_to_sol(
         cpi,
         abr,
         calc_prog,
-        lst_balance,
+        balance,
         SvcIxAccountHandles::new(
             NewSvcIxPreAccsBuilder::start()
                 .with_lst_mint(*ix_prefix.lst_mint())
@@ -76,10 +113,12 @@ pub fn cpi_lst_reserves_sol_val(
             calc,
         ),
     )?
-    .start())
+    .start();
+    Ok(ReservesSolVal { balance, sol_val })
 }
 
-/// Updates lst_state.sol_value on the lst_state_list acc
+/// Updates lst_state.sol_value and lst_state.synced_reserves_balance
+/// on the lst_state_list acc
 ///
 /// # Returns
 ///
@@ -88,12 +127,16 @@ pub fn update_lst_state_sol_val(
     abr: &mut Abr,
     lst_state_list: AccountHandle,
     lst_index: usize,
-    new_sol_val: u64,
+    ReservesSolVal {
+        balance,
+        sol_val: new_sol_val,
+    }: ReservesSolVal,
 ) -> Result<SnapU64, ProgramError> {
     let list = lst_state_list_checked_mut(abr.get_mut(lst_state_list))?;
     let lst_state = lst_state_list_get_mut(list, lst_index)?;
     let old_sol_val = lst_state.sol_value;
     lst_state.sol_value = new_sol_val;
+    lst_state.synced_reserves_balance = CompactU64::new(balance);
     Ok(NewSnapBuilder::start()
         .with_old(old_sol_val)
         .with_new(new_sol_val)
@@ -15,6 +15,22 @@ pub fn get_token_account_amount(acc: &Account) -> Result<u64, ProgramError> {
         .ok_or(INVALID_ACCOUNT_DATA)?)
 }
 
+/// Returns the authority of the token account.
+///
+/// `_checked` because it also verifies that the acc is properly initialized.
+///
+/// Compatible with token-22
+#[inline]
+pub fn checked_token_acc_auth_of(acc: &Account) -> Result<&[u8; 32], ProgramError> {
+    let raw = acc
+        .data()
+        .first_chunk() // ignore token-22 extension data
+        .map(RawTokenAccount::of_acc_data_arr)
+        .ok_or(INVALID_ACCOUNT_DATA)?;
+    TokenAccount::try_from_raw(raw).ok_or(INVALID_ACCOUNT_DATA)?;
+    Ok(&raw.auth)
+}
+
 /// `_checked` because it also verifies that the acc is properly initialized.
 ///
 /// Compatible with token-22
@@ -1,9 +1,22 @@
 use core::mem::size_of;
 
 use inf1_ctl_jiminy::{
+    account_utils::{
+        lst_calc_backing_list_checked, lst_calc_backing_list_checked_mut,
+        protocol_fee_beneficiary_list_checked, protocol_fee_beneficiary_list_checked_mut,
+    },
+    accounts::protocol_fee_beneficiary_list::MAX_PROTOCOL_FEE_BENEFICIARIES,
+    err::Inf1CtlErr,
     keys::SYS_PROG_ID,
-    pda_onchain::{DISABLE_POOL_AUTHORITY_LIST_SIGNER, LST_STATE_LIST_SIGNER},
-    typedefs::lst_state::LstState,
+    pda_onchain::{
+        DISABLE_POOL_AUTHORITY_LIST_SIGNER, LST_CALC_BACKING_LIST_SIGNER, LST_STATE_LIST_SIGNER,
+        PROTOCOL_FEE_BENEFICIARY_LIST_SIGNER,
+    },
+    program_err::Inf1CtlCustomProgErr,
+    typedefs::{
+        lst_calc_backing::LstCalcBacking, lst_state::LstState,
+        protocol_fee_beneficiary_share::ProtocolFeeBeneficiaryShare,
+    },
     ID,
 };
 use jiminy_cpi::{
@@ -102,6 +115,36 @@ fn extend_packed_list_pda<T>(
     Ok(())
 }
 
+/// Creates a fixed-size singleton PDA of `len` bytes owned by the controller program
+/// if it has not yet been created. No-op otherwise.
+///
+/// # Params
+/// - `accs`. `from` should be rent payer, `to` should be the PDA
+#[inline]
+pub fn create_pda_idmpt(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accs: &TransferIxAccs<AccountHandle>,
+    rent: &Rent,
+    signer: PdaSigner,
+    len: usize,
+) -> Result<(), ProgramError> {
+    if abr.get(*accs.to()).data_len() != 0 {
+        return Ok(());
+    }
+    assign_invoke_signed(
+        abr,
+        cpi,
+        NewAssignIxAccsBuilder::start()
+            .with_assign(*accs.to())
+            .build(),
+        &ID,
+        &[signer],
+    )?;
+    abr.get_mut(*accs.to()).grow_by(len, false)?;
+    pay_for_rent_exempt_shortfall(abr, cpi, accs, rent)
+}
+
 /// `accs`
 /// - `from` rent payer
 /// - `to` lst_state_list_pda
@@ -128,6 +171,95 @@ pub fn extend_disable_pool_auth_list(
     extend_packed_list_pda::<[u8; 32]>(abr, cpi, accs, rent, DISABLE_POOL_AUTHORITY_LIST_SIGNER)
 }
 
+/// Sets the entry of `new.mint` in the lst_calc_backing_list PDA,
+/// extending the list by 1 if no such entry exists yet
+///
+/// `accs`
+/// - `from` rent payer
+/// - `to` lst_calc_backing_list_pda
+#[inline]
+pub fn upsert_lst_calc_backing(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accs: &TransferIxAccs<AccountHandle>,
+    rent: &Rent,
+    new: LstCalcBacking,
+) -> Result<(), ProgramError> {
+    let exists = lst_calc_backing_list_checked(abr.get(*accs.to()))?
+        .find_by_mint(&new.mint)
+        .is_some();
+    if !exists {
+        extend_packed_list_pda::<LstCalcBacking>(
+            abr,
+            cpi,
+            accs,
+            rent,
+            LST_CALC_BACKING_LIST_SIGNER,
+        )?;
+    }
+
+    let mut list = lst_calc_backing_list_checked_mut(abr.get_mut(*accs.to()))?;
+    let entry = if exists {
+        list.find_by_mint(&new.mint)
+    } else {
+        list.0.last_mut()
+    }
+    .ok_or(Inf1CtlCustomProgErr(
+        Inf1CtlErr::InvalidLstCalcBackingListData,
+    ))?;
+    *entry = new;
+
+    Ok(())
+}
+
+/// Sets the entry of `new.beneficiary` in the protocol_fee_beneficiary_list PDA,
+/// extending the list by 1 if no such entry exists yet
+///
+/// `accs`
+/// - `from` rent payer
+/// - `to` protocol_fee_beneficiary_list_pda
+#[inline]
+pub fn upsert_protocol_fee_beneficiary_share(
+    abr: &mut Abr,
+    cpi: &mut Cpi,
+    accs: &TransferIxAccs<AccountHandle>,
+    rent: &Rent,
+    new: ProtocolFeeBeneficiaryShare,
+) -> Result<(), ProgramError> {
+    let exists = protocol_fee_beneficiary_list_checked(abr.get(*accs.to()))?
+        .find_by_beneficiary(&new.beneficiary)
+        .is_some();
+    if !exists {
+        if protocol_fee_beneficiary_list_checked(abr.get(*accs.to()))?
+            .0
+            .len()
+            >= MAX_PROTOCOL_FEE_BENEFICIARIES
+        {
+            return Err(Inf1CtlCustomProgErr(Inf1CtlErr::TooManyProtocolFeeBeneficiaries).into());
+        }
+        extend_packed_list_pda::<ProtocolFeeBeneficiaryShare>(
+            abr,
+            cpi,
+            accs,
+            rent,
+            PROTOCOL_FEE_BENEFICIARY_LIST_SIGNER,
+        )?;
+    }
+
+    let mut list = protocol_fee_beneficiary_list_checked_mut(abr.get_mut(*accs.to()))?;
+    let entry = if exists {
+        list.find_by_beneficiary(&new.beneficiary)
+    } else {
+        list.0.last_mut()
+    }
+    .ok_or(Inf1CtlCustomProgErr(
+        Inf1CtlErr::InvalidProtocolFeeBeneficiaryListData,
+    ))?;
+    *entry = new;
+
+    Ok(())
+}
+
 /// Inverse of [`extend_disable_pool_auth_list`]
 ///
 /// Removes the given index entry from the list, shrinking it down by 1
@@ -182,6 +314,19 @@ pub fn shrink_disable_pool_auth_list(
     shrink_packed_list_pda::<[u8; 32]>(abr, accs, rent, idx)
 }
 
+/// `accs`
+/// - `from` protocol_fee_beneficiary_list_pda
+/// - `to` refund_rent_to
+#[inline]
+pub fn shrink_protocol_fee_beneficiary_list(
+    abr: &mut Abr,
+    accs: &TransferIxAccs<AccountHandle>,
+    rent: &Rent,
+    idx: usize,
+) -> Result<(), ProgramError> {
+    shrink_packed_list_pda::<ProtocolFeeBeneficiaryShare>(abr, accs, rent, idx)
+}
+
 /// `accs`
 /// - `from` lst_state_list_pda
 /// - `to` refund_rent_to
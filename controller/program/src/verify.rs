@@ -1,9 +1,10 @@
 use inf1_ctl_jiminy::{
+    account_utils::pool_config_admin_action_delay_slots,
     accounts::pool_state::PoolStateV2,
     err::Inf1CtlErr,
     keys::{TOKENKEG_ID, TOKEN_2022_ID},
     program_err::Inf1CtlCustomProgErr,
-    typedefs::{lst_state::LstState, u8bool::U8Bool},
+    typedefs::{disable_modes::DisableMode, lst_state::LstState, u8bool::U8Bool},
 };
 use jiminy_cpi::{
     account::{Abr, Account, AccountHandle},
@@ -106,6 +107,27 @@ pub fn verify_not_rebalancing_and_not_disabled(pool: &PoolStateV2) -> Result<(),
     Ok(())
 }
 
+#[inline]
+pub fn verify_pool_op_not_disabled(
+    pool: &PoolStateV2,
+    mode: DisableMode,
+) -> Result<(), ProgramError> {
+    if pool.disable_modes().is_disabled(mode) {
+        return Err(Inf1CtlCustomProgErr(Inf1CtlErr::PoolOperationDisabled(mode)).into());
+    }
+    Ok(())
+}
+
+/// Timelocked admin instructions may only be called directly,
+/// instead of through `ExecuteAdminAction`, if the admin action delay is 0
+#[inline]
+pub fn verify_admin_action_not_timelocked(pool_config: &Account) -> Result<(), ProgramError> {
+    if pool_config_admin_action_delay_slots(pool_config)? != 0 {
+        return Err(Inf1CtlCustomProgErr(Inf1CtlErr::AdminActionTimelocked).into());
+    }
+    Ok(())
+}
+
 #[inline]
 pub fn verify_is_rebalancing(pool: &PoolStateV2) -> Result<(), ProgramError> {
     if !U8Bool(&pool.is_rebalancing).to_bool() {
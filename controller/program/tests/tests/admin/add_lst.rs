@@ -6,20 +6,21 @@ use inf1_ctl_jiminy::{
         ADD_LST_IX_IS_WRITER,
     },
     keys::{
-        ATOKEN_ID, LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID, SYS_PROG_ID, TOKENKEG_ID,
+        ATOKEN_ID, LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID,
+        SYS_PROG_ID, TOKENKEG_ID,
     },
     program_err::Inf1CtlCustomProgErr,
-    typedefs::lst_state::LstState,
+    typedefs::{compact_u64::CompactU64, lst_calc_backing::LstCalcBacking, lst_state::LstState},
     ID,
 };
 use inf1_svc_ag_core::SvcAgTy;
 use inf1_test_utils::{
     acc_bef_aft, any_lst_state_list, any_normal_pk, any_pool_state_v2, assert_diffs_lst_state_list,
     assert_jiminy_prog_err, find_pool_reserves_ata, find_protocol_fee_accumulator_ata,
-    fixtures_accounts_opt_cloned, keys_signer_writable_to_metas, lst_state_list_account, mock_mint,
-    mock_token_acc, mollusk_exec, pool_state_v2_account, pool_state_v2_u8_bools_normal_strat,
-    raw_mint, raw_token_acc, silence_mollusk_logs, AccountMap, LstStateListChanges,
-    LstStateListData, PoolStateV2FtaStrat,
+    fixtures_accounts_opt_cloned, get_lst_calc_backing_list, keys_signer_writable_to_metas,
+    lst_calc_backing_list_account, lst_state_list_account, mock_mint, mock_token_acc, mollusk_exec,
+    pool_state_v2_account, pool_state_v2_u8_bools_normal_strat, raw_mint, raw_token_acc,
+    silence_mollusk_logs, AccountMap, LstStateListChanges, LstStateListData, PoolStateV2FtaStrat,
 };
 
 use jiminy_cpi::program_error::INVALID_ARGUMENT;
@@ -38,6 +39,7 @@ fn add_lst_ix_keys_owned(
     mint: &[u8; 32],
     token_program: &[u8; 32],
     sol_value_calculator: &[u8; 32],
+    sol_value_calculator_backing: &[u8; 32],
 ) -> AddLstIxKeysOwned {
     let (pool_reserves, _) = find_pool_reserves_ata(token_program, mint);
     let (protocol_fee_accumulator, _) = find_protocol_fee_accumulator_ata(token_program, mint);
@@ -55,6 +57,8 @@ fn add_lst_ix_keys_owned(
         .with_associated_token_program(ATOKEN_ID)
         .with_system_program(SYS_PROG_ID)
         .with_lst_token_program(*token_program)
+        .with_lst_calc_backing_list(LST_CALC_BACKING_LIST_ID)
+        .with_sol_value_calculator_backing(*sol_value_calculator_backing)
         .build()
 }
 
@@ -81,6 +85,7 @@ fn assert_correct_add(
     mint: &[u8; 32],
     token_program: &[u8; 32],
     expected_sol_value_calculator: &[u8; 32],
+    expected_backing: &[u8; 32],
 ) {
     let (_, pool_reserves_bump) = find_pool_reserves_ata(token_program, mint);
     let (_, protocol_fee_accumulator_bump) = find_protocol_fee_accumulator_ata(token_program, mint);
@@ -102,7 +107,7 @@ fn assert_correct_add(
             is_input_disabled: 0,
             pool_reserves_bump,
             protocol_fee_accumulator_bump,
-            padding: [0u8; 5],
+            synced_reserves_balance: CompactU64::ZERO,
             sol_value: 0,
             mint: *mint,
             sol_value_calculator: *expected_sol_value_calculator,
@@ -110,6 +115,22 @@ fn assert_correct_add(
         .build();
 
     assert_diffs_lst_state_list(&diffs, &lst_state_list_bef, &lst_state_list_aft);
+
+    let [backing_list_bef, backing_list_aft] =
+        acc_bef_aft(&Pubkey::new_from_array(LST_CALC_BACKING_LIST_ID), bef, aft)
+            .map(|a| get_lst_calc_backing_list(&a.data));
+    let had_stale = backing_list_bef.iter().any(|e| e.mint == *mint);
+    assert_eq!(
+        backing_list_aft.len(),
+        backing_list_bef.len() + usize::from(!had_stale)
+    );
+    assert_eq!(
+        backing_list_aft.iter().find(|e| e.mint == *mint),
+        Some(&LstCalcBacking {
+            mint: *mint,
+            backing: *expected_backing,
+        })
+    );
 }
 
 // TODO: pool state fixture no longer applicable with
@@ -136,6 +157,8 @@ fn add_lst_proptest(
     mint: [u8; 32],
     token_program: [u8; 32],
     sol_value_calculator: [u8; 32],
+    backing: [u8; 32],
+    backing_list: &[LstCalcBacking],
     additional_accounts: impl IntoIterator<Item = (Pubkey, Account)>,
     error_type: Option<TestErrorType>,
 ) -> TestCaseResult {
@@ -143,7 +166,14 @@ fn add_lst_proptest(
 
     let LstStateListData { lst_state_list, .. } = lsl;
 
-    let keys = add_lst_ix_keys_owned(&admin, &payer, &mint, &token_program, &sol_value_calculator);
+    let keys = add_lst_ix_keys_owned(
+        &admin,
+        &payer,
+        &mint,
+        &token_program,
+        &sol_value_calculator,
+        &backing,
+    );
 
     let ix = add_lst_ix(&keys);
     let mut accounts = add_lst_fixtures_accounts_opt(&keys);
@@ -155,6 +185,10 @@ fn add_lst_proptest(
                 LST_STATE_LIST_ID.into(),
                 lst_state_list_account(lst_state_list),
             ),
+            (
+                LST_CALC_BACKING_LIST_ID.into(),
+                lst_calc_backing_list_account(backing_list),
+            ),
             (POOL_STATE_ID.into(), pool_state_v2_account(pool)),
             (
                 Pubkey::new_from_array(admin),
@@ -228,6 +262,7 @@ fn add_lst_proptest(
             &mint,
             &token_program,
             &sol_value_calculator,
+            &backing,
         );
     }
 
@@ -259,7 +294,14 @@ proptest! {
     #[test]
     fn add_lst_any(
         (pool, lsl, payer, mint) in add_lst_correct_strat(),
+        backing in any_normal_pk(),
+        stale_backing in proptest::option::of(any_normal_pk()),
     ) {
+        // LST previously removed from the pool leaves a stale entry behind
+        let backing_list: Vec<_> = stale_backing
+            .map(|backing| LstCalcBacking { mint, backing })
+            .into_iter()
+            .collect();
         add_lst_proptest(
             pool,
             lsl,
@@ -268,8 +310,11 @@ proptest! {
             mint,
             TOKENKEG_ID,
             *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+            backing,
+            &backing_list,
             [
                 (Pubkey::new_from_array(mint), mock_mint(raw_mint(None, None, u64::MAX, 9))),
+                (Pubkey::new_from_array(backing), Account::default()),
             ],
             None,
         ).unwrap();
@@ -313,6 +358,8 @@ proptest! {
             mint,
             TOKENKEG_ID,
             *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+            SYS_PROG_ID,
+            &[],
             [
                 (Pubkey::new_from_array(mint), mock_mint(raw_mint(None, None, u64::MAX, 9))),
             ],
@@ -358,6 +405,8 @@ proptest! {
             mint,
             TOKENKEG_ID,
             *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+            SYS_PROG_ID,
+            &[],
             [
                 (Pubkey::new_from_array(mint), mock_mint(raw_mint(None, None, u64::MAX, 9))),
             ],
@@ -403,6 +452,8 @@ proptest! {
             mint,
             TOKENKEG_ID,
             *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+            SYS_PROG_ID,
+            &[],
             [
                 (Pubkey::new_from_array(mint), mock_mint(raw_mint(None, None, u64::MAX, 9))),
             ],
@@ -439,6 +490,8 @@ proptest! {
             existing_mint,
             TOKENKEG_ID,
             *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+            SYS_PROG_ID,
+            &[],
             [
                 (Pubkey::new_from_array(existing_mint), mock_mint(raw_mint(None, None, u64::MAX, 9))),
             ],
@@ -485,6 +538,8 @@ proptest! {
             mint,
             TOKENKEG_ID,
             sol_value_calculator,
+            SYS_PROG_ID,
+            &[],
             [
                 (Pubkey::new_from_array(mint), mock_mint(raw_mint(None, None, u64::MAX, 9))),
                 (Pubkey::new_from_array(sol_value_calculator), Account {
@@ -9,7 +9,7 @@ use inf1_ctl_jiminy::{
         SetLstInputIxKeysOwned, SET_LST_INPUT_IX_ACCS_IDX_ADMIN,
     },
     program_err::Inf1CtlCustomProgErr,
-    typedefs::lst_state::LstState,
+    typedefs::{compact_u64::CompactU64, lst_state::LstState},
 };
 use inf1_test_utils::{
     any_pool_state_v2, keys_signer_writable_to_metas, pool_state_v2_u8_bools_normal_strat,
@@ -62,7 +62,7 @@ fn disable_lst_input_correct_basic() {
         is_input_disabled: 0,
         pool_reserves_bump: 255,
         protocol_fee_accumulator_bump: 255,
-        padding: [0; 5],
+        synced_reserves_balance: CompactU64::ZERO,
         sol_value: 0,
         sol_value_calculator: [3u8; 32],
     }];
@@ -2,5 +2,8 @@ mod add_lst;
 mod lst_input;
 mod remove_lst;
 mod set_admin;
+mod set_min_fee;
 mod set_pricing_prog;
 mod set_sol_value_calculator;
+mod set_sol_value_circuit_breaker;
+mod timelock;
@@ -260,6 +260,30 @@ proptest! {
     }
 }
 
+proptest! {
+    #[test]
+    fn remove_lst_accumulator_closed_any(
+        (pool, lsl, lst_idx, refund_rent_to) in remove_lst_correct_strat(),
+    ) {
+        // legacy protocol fee accumulator already closed by WithdrawProtocolFeesSweep
+        let mint = LstStatePackedList::of_acc_data(&lsl.lst_state_list).unwrap().0
+            [lst_idx as usize]
+            .into_lst_state()
+            .mint;
+        let (protocol_fee_accumulator_addr, _) =
+            find_protocol_fee_accumulator_ata(&TOKENKEG_ID, &mint);
+        remove_lst_proptest(
+            pool,
+            lsl,
+            pool.admin,
+            refund_rent_to,
+            lst_idx,
+            [(protocol_fee_accumulator_addr, Account::default())],
+            None,
+        ).unwrap();
+    }
+}
+
 fn remove_lst_unauthorized_strat(
 ) -> impl Strategy<Value = (PoolStateV2, LstStateListData, [u8; 32], u32, [u8; 32])> {
     (
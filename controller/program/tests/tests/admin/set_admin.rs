@@ -4,13 +4,13 @@ use inf1_ctl_jiminy::{
         NewSetAdminIxAccsBuilder, SetAdminIxData, SetAdminIxKeysOwned, SET_ADMIN_IX_ACCS_IDX_CURR,
         SET_ADMIN_IX_ACCS_IDX_NEW, SET_ADMIN_IX_IS_SIGNER, SET_ADMIN_IX_IS_WRITER,
     },
-    keys::POOL_STATE_ID,
+    keys::{POOL_CONFIG_ID, POOL_STATE_ID},
     ID,
 };
 use inf1_test_utils::{
     any_normal_pk, any_pool_state_v2, assert_diffs_pool_state_v2, assert_jiminy_prog_err,
-    keys_signer_writable_to_metas, mock_sys_acc, mollusk_exec, pool_state_v2_account,
-    silence_mollusk_logs, AccountMap, Diff, DiffsPoolStateV2,
+    keys_signer_writable_to_metas, mock_sys_acc, mollusk_exec, pool_config_account,
+    pool_state_v2_account, silence_mollusk_logs, AccountMap, Diff, DiffsPoolStateV2,
 };
 use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT, MISSING_REQUIRED_SIGNATURE};
 use proptest::prelude::*;
@@ -39,6 +39,7 @@ fn set_admin_test_accs(keys: SetAdminIxKeysOwned, pool: PoolStateV2) -> AccountM
         .with_curr(mock_sys_acc(LAMPORTS))
         .with_new(mock_sys_acc(LAMPORTS))
         .with_pool_state(pool_state_v2_account(pool))
+        .with_pool_config(pool_config_account(None))
         .build();
     keys.0.into_iter().map(Into::into).zip(accs.0).collect()
 }
@@ -96,6 +97,7 @@ fn set_admin_test_correct_basic() {
         .with_new(new_admin)
         .with_curr(curr_admin)
         .with_pool_state(POOL_STATE_ID)
+        .with_pool_config(POOL_CONFIG_ID)
         .build();
     let ret = set_admin_test(
         set_admin_ix(keys),
@@ -113,6 +115,7 @@ fn correct_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
                     .with_new(new_admin)
                     .with_curr(ps.admin)
                     .with_pool_state(POOL_STATE_ID)
+                    .with_pool_config(POOL_CONFIG_ID)
                     .build(),
                 ps,
             )
@@ -145,6 +148,7 @@ fn unauthorized_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
                     .with_new(new_admin)
                     .with_curr(wrong_curr_admin)
                     .with_pool_state(POOL_STATE_ID)
+                    .with_pool_config(POOL_CONFIG_ID)
                     .build(),
                 ps,
             )
@@ -6,13 +6,13 @@ use inf1_ctl_jiminy::{
         SET_PRICING_PROG_IX_ACCS_IDX_ADMIN, SET_PRICING_PROG_IX_ACCS_IDX_NEW,
         SET_PRICING_PROG_IX_IS_SIGNER, SET_PRICING_PROG_IX_IS_WRITER,
     },
-    keys::POOL_STATE_ID,
+    keys::{POOL_CONFIG_ID, POOL_STATE_ID},
     program_err::Inf1CtlCustomProgErr,
     ID,
 };
 use inf1_test_utils::{
     any_normal_pk, any_pool_state_v2, assert_diffs_pool_state_v2, assert_jiminy_prog_err,
-    keys_signer_writable_to_metas, mock_prog_acc, mock_sys_acc, mollusk_exec,
+    keys_signer_writable_to_metas, mock_prog_acc, mock_sys_acc, mollusk_exec, pool_config_account,
     pool_state_v2_account, pool_state_v2_u8_bools_normal_strat, silence_mollusk_logs, AccountMap,
     Diff, DiffsPoolStateV2, PoolStateV2FtaStrat, ProgramDataAddr,
 };
@@ -43,6 +43,7 @@ fn set_pricing_prog_test_accs(keys: SetPricingProgIxKeysOwned, pool: PoolStateV2
         .with_admin(mock_sys_acc(LAMPORTS))
         .with_new(mock_prog_acc(ProgramDataAddr::Raw(Default::default()))) // dont care about programdata address
         .with_pool_state(pool_state_v2_account(pool))
+        .with_pool_config(pool_config_account(None))
         .build();
     keys.0.into_iter().map(Into::into).zip(accs.0).collect()
 }
@@ -102,6 +103,7 @@ fn set_pricing_prog_correct_basic() {
         .with_new(new_pp)
         .with_admin(admin)
         .with_pool_state(POOL_STATE_ID)
+        .with_pool_config(POOL_CONFIG_ID)
         .build();
     let ret = set_pricing_prog_test(
         set_pricing_prog_ix(keys),
@@ -122,6 +124,7 @@ fn to_keys_and_accs(
                     .with_new(new_pp)
                     .with_admin(ps.admin)
                     .with_pool_state(POOL_STATE_ID)
+                    .with_pool_config(POOL_CONFIG_ID)
                     .build(),
                 ps,
             )
@@ -170,6 +173,7 @@ fn unauthorized_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
                     .with_new(new_pp)
                     .with_admin(wrong_curr_admin)
                     .with_pool_state(POOL_STATE_ID)
+                    .with_pool_config(POOL_CONFIG_ID)
                     .build(),
                 ps,
             )
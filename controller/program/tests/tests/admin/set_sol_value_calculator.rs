@@ -8,8 +8,11 @@ use inf1_ctl_jiminy::{
         NewSetSolValueCalculatorIxPreAccsBuilder, SetSolValueCalculatorIxData,
         SetSolValueCalculatorIxPreKeysOwned,
     },
-    keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    keys::{
+        LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID, SYS_PROG_ID,
+    },
     program_err::Inf1CtlCustomProgErr,
+    typedefs::lst_calc_backing::LstCalcBacking,
     ID,
 };
 
@@ -33,8 +36,9 @@ use inf1_test_utils::{
     acc_bef_aft, any_lst_state, any_lst_state_list, any_normal_pk, any_pool_state_v2,
     any_pool_sv_lamports_solvent_strat, any_spl_stake_pool, any_wsol_lst_state,
     assert_diffs_lst_state_list, assert_diffs_pool_state_v2, assert_jiminy_prog_err,
-    find_pool_reserves_ata, fixtures_accounts_opt_cloned, keys_signer_writable_to_metas,
-    lst_state_list_account, mock_mint, mock_spl_stake_pool, mock_token_acc, mollusk_exec,
+    find_pool_reserves_ata, fixtures_accounts_opt_cloned, get_lst_calc_backing_list,
+    keys_signer_writable_to_metas, lst_calc_backing_list_account, lst_state_list_account,
+    mock_mint, mock_spl_stake_pool, mock_token_acc, mollusk_exec, pool_config_account,
     pool_state_v2_account, pool_state_v2_u64s_just_lamports_strat,
     pool_state_v2_u8_bools_normal_strat, raw_mint, raw_token_acc, silence_mollusk_logs, AccountMap,
     AnyLstStateArgs, Diff, DiffLstStateArgs, DiffsPoolStateV2, GenStakePoolArgs, LstStateData,
@@ -73,6 +77,7 @@ fn set_sol_value_calculator_ix_pre_keys_owned(
     admin: [u8; 32],
     token_program: &[u8; 32],
     mint: [u8; 32],
+    sol_value_calculator_backing: [u8; 32],
 ) -> SetSolValueCalculatorIxPreKeysOwned {
     NewSetSolValueCalculatorIxPreAccsBuilder::start()
         .with_admin(admin)
@@ -80,9 +85,26 @@ fn set_sol_value_calculator_ix_pre_keys_owned(
         .with_pool_state(POOL_STATE_ID)
         .with_pool_reserves(find_pool_reserves_ata(token_program, &mint).0.to_bytes())
         .with_lst_state_list(LST_STATE_LIST_ID)
+        .with_lst_calc_backing_list(LST_CALC_BACKING_LIST_ID)
+        .with_sol_value_calculator_backing(sol_value_calculator_backing)
+        .with_system_program(SYS_PROG_ID)
+        .with_pool_config(POOL_CONFIG_ID)
         .build()
 }
 
+/// Stake pool account for SPL calculators, system program otherwise
+fn calc_backing(calc: &SvcCalcAccsAg) -> [u8; 32] {
+    match calc {
+        SvcCalcAccsAg::SanctumSpl(c) => c.stake_pool_addr,
+        SvcCalcAccsAg::SanctumSplMulti(c) => c.stake_pool_addr,
+        SvcCalcAccsAg::Spl(c) => c.stake_pool_addr,
+        SvcCalcAccsAg::Inf(_)
+        | SvcCalcAccsAg::Lido(_)
+        | SvcCalcAccsAg::Marinade(_)
+        | SvcCalcAccsAg::Wsol(_) => SYS_PROG_ID,
+    }
+}
+
 fn set_sol_value_calculator_ix(
     builder: &SetSolValueCalculatorKeysBuilder,
     lst_idx: u32,
@@ -114,6 +136,7 @@ pub fn assert_correct_set(
     aft: &AccountMap,
     mint: &[u8; 32],
     expected_new_calc: &[u8; 32],
+    expected_backing: &[u8; 32],
 ) {
     let [pools, lst_state_lists] = [POOL_STATE_ID, LST_STATE_LIST_ID]
         .map(|a| acc_bef_aft(&Pubkey::new_from_array(a), bef, aft));
@@ -174,6 +197,22 @@ pub fn assert_correct_set(
         &pool_bef,
         &pool_aft,
     );
+
+    let backing_list_aft = get_lst_calc_backing_list(
+        &aft.get(&Pubkey::new_from_array(LST_CALC_BACKING_LIST_ID))
+            .unwrap()
+            .data,
+    );
+    assert_eq!(
+        backing_list_aft
+            .iter()
+            .filter(|e| e.mint == *mint)
+            .collect::<Vec<_>>(),
+        [&LstCalcBacking {
+            mint: *mint,
+            backing: *expected_backing,
+        }]
+    );
 }
 
 // TODO: pool state fixture no longer applicable with
@@ -209,7 +248,9 @@ fn set_sol_value_calculator_proptest(
     let lst_mut = unsafe { lsl_mut.0.get_mut(lst_idx).unwrap().as_lst_state_mut() };
     lst_mut.sol_value_calculator = initial_calc_prog;
 
-    let ix_prefix = set_sol_value_calculator_ix_pre_keys_owned(admin, &TOKENKEG_PROGRAM, mint);
+    let backing = calc_backing(&calc);
+    let ix_prefix =
+        set_sol_value_calculator_ix_pre_keys_owned(admin, &TOKENKEG_PROGRAM, mint, backing);
     let builder = SetSolValueCalculatorKeysBuilder {
         ix_prefix,
         calc_prog,
@@ -222,7 +263,12 @@ fn set_sol_value_calculator_proptest(
     // Common inserts
     accounts.extend([
         (LST_STATE_LIST_ID.into(), lst_state_list_account(lsl_data)),
+        (
+            LST_CALC_BACKING_LIST_ID.into(),
+            lst_calc_backing_list_account(&[]),
+        ),
         (POOL_STATE_ID.into(), pool_state_v2_account(pool)),
+        (POOL_CONFIG_ID.into(), pool_config_account(None)),
         (
             Pubkey::new_from_array(admin),
             Account {
@@ -245,7 +291,7 @@ fn set_sol_value_calculator_proptest(
         Some(e) => assert_jiminy_prog_err(&result.unwrap_err(), e),
         None => {
             let resulting_accounts = result.unwrap().resulting_accounts;
-            assert_correct_set(&accounts, &resulting_accounts, &mint, &calc_prog);
+            assert_correct_set(&accounts, &resulting_accounts, &mint, &calc_prog, &backing);
         }
     }
 
@@ -0,0 +1,265 @@
+use inf1_ctl_jiminy::{
+    accounts::pool_state::{PoolStateV2, PoolStateV2Addrs, PoolStateV2FtaVals, PoolStateV2Packed},
+    err::Inf1CtlErr,
+    instructions::admin::set_sol_value_circuit_breaker::{
+        NewSetSolValueCircuitBreakerIxAccsBuilder, SetSolValueCircuitBreakerIxData,
+        SetSolValueCircuitBreakerIxKeysOwned, SET_SOL_VALUE_CIRCUIT_BREAKER_IX_ACCS_IDX_ADMIN,
+        SET_SOL_VALUE_CIRCUIT_BREAKER_IX_IS_SIGNER, SET_SOL_VALUE_CIRCUIT_BREAKER_IX_IS_WRITER,
+    },
+    keys::POOL_STATE_ID,
+    program_err::Inf1CtlCustomProgErr,
+    ID,
+};
+use inf1_test_utils::{
+    any_pool_state_v2, assert_diffs_pool_state_v2, assert_jiminy_prog_err,
+    keys_signer_writable_to_metas, mock_sys_acc, mollusk_exec, pool_state_v2_account,
+    pool_state_v2_u8_bools_normal_strat, silence_mollusk_logs, AccountMap, Diff, DiffsPoolStateV2,
+    PoolStateV2FtaStrat,
+};
+use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT, MISSING_REQUIRED_SIGNATURE};
+use proptest::prelude::*;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::common::SVM;
+
+fn set_sol_value_circuit_breaker_ix(
+    keys: SetSolValueCircuitBreakerIxKeysOwned,
+    circuit_breaker_nanos: u32,
+) -> Instruction {
+    let accounts = keys_signer_writable_to_metas(
+        keys.0.iter(),
+        SET_SOL_VALUE_CIRCUIT_BREAKER_IX_IS_SIGNER.0.iter(),
+        SET_SOL_VALUE_CIRCUIT_BREAKER_IX_IS_WRITER.0.iter(),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts,
+        data: SetSolValueCircuitBreakerIxData::new(circuit_breaker_nanos)
+            .as_buf()
+            .into(),
+    }
+}
+
+fn set_sol_value_circuit_breaker_keys(admin: [u8; 32]) -> SetSolValueCircuitBreakerIxKeysOwned {
+    NewSetSolValueCircuitBreakerIxAccsBuilder::start()
+        .with_admin(admin)
+        .with_pool_state(POOL_STATE_ID)
+        .build()
+}
+
+fn set_sol_value_circuit_breaker_ix_test_accs(
+    keys: SetSolValueCircuitBreakerIxKeysOwned,
+    pool: PoolStateV2,
+) -> AccountMap {
+    // dont care abt lamports, shouldnt affect anything
+    const LAMPORTS: u64 = 1_000_000_000;
+    let accs = NewSetSolValueCircuitBreakerIxAccsBuilder::start()
+        .with_admin(mock_sys_acc(LAMPORTS))
+        .with_pool_state(pool_state_v2_account(pool))
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+/// Returns `pool_state` at the end of ix
+fn set_sol_value_circuit_breaker_test(
+    ix: Instruction,
+    bef: &AccountMap,
+    circuit_breaker_nanos: u32,
+    expected_err: Option<impl Into<ProgramError>>,
+) -> PoolStateV2 {
+    let result = SVM.with(|svm| mollusk_exec(svm, &[ix], bef));
+
+    let pool_state_bef = PoolStateV2Packed::of_acc_data(&bef[&POOL_STATE_ID.into()].data)
+        .unwrap()
+        .into_pool_state_v2();
+
+    match expected_err {
+        None => {
+            let diffs = DiffsPoolStateV2 {
+                sol_value_circuit_breaker_nanos: Diff::Changed(
+                    pool_state_bef.sol_value_circuit_breaker_nanos,
+                    circuit_breaker_nanos,
+                ),
+                ..Default::default()
+            };
+            let resulting_accounts = result.unwrap().resulting_accounts;
+            let pool_state_aft =
+                PoolStateV2Packed::of_acc_data(&resulting_accounts[&POOL_STATE_ID.into()].data)
+                    .unwrap()
+                    .into_pool_state_v2();
+            assert_diffs_pool_state_v2(&diffs, &pool_state_bef, &pool_state_aft);
+            pool_state_aft
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+            pool_state_bef
+        }
+    }
+}
+
+#[test]
+fn set_sol_value_circuit_breaker_test_correct_basic() {
+    const CURR: u32 = 50_000_000;
+    const NEW: u32 = 100_000_000;
+
+    let admin = [69u8; 32];
+    let pool = PoolStateV2FtaVals {
+        addrs: PoolStateV2Addrs::default().with_admin(admin),
+        sol_value_circuit_breaker_nanos: CURR,
+        ..Default::default()
+    }
+    .into_pool_state_v2();
+    let keys = set_sol_value_circuit_breaker_keys(admin);
+    let ret = set_sol_value_circuit_breaker_test(
+        set_sol_value_circuit_breaker_ix(keys, NEW),
+        &set_sol_value_circuit_breaker_ix_test_accs(keys, pool),
+        NEW,
+        Option::<ProgramError>::None,
+    );
+    assert_eq!(ret.sol_value_circuit_breaker_nanos, NEW);
+}
+
+fn to_test_inp(
+    (k, circuit_breaker_nanos, ps): (SetSolValueCircuitBreakerIxKeysOwned, u32, PoolStateV2),
+) -> (Instruction, AccountMap, u32) {
+    (
+        set_sol_value_circuit_breaker_ix(k, circuit_breaker_nanos),
+        set_sol_value_circuit_breaker_ix_test_accs(k, ps),
+        circuit_breaker_nanos,
+    )
+}
+
+fn ps_strat_to_test_inp(
+    ps: impl Strategy<Value = PoolStateV2>,
+) -> impl Strategy<Value = (Instruction, AccountMap, u32)> {
+    (any::<u32>(), ps)
+        .prop_map(|(circuit_breaker_nanos, ps)| {
+            (
+                set_sol_value_circuit_breaker_keys(ps.admin),
+                circuit_breaker_nanos,
+                ps,
+            )
+        })
+        .prop_map(to_test_inp)
+}
+
+fn correct_strat() -> impl Strategy<Value = (Instruction, AccountMap, u32)> {
+    ps_strat_to_test_inp(any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat(),
+        sol_value_circuit_breaker_nanos: Some(any::<u32>().boxed()),
+        ..Default::default()
+    }))
+}
+
+proptest! {
+    #[test]
+    fn set_sol_value_circuit_breaker_correct_pt(
+        (ix, bef, circuit_breaker_nanos) in correct_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_sol_value_circuit_breaker_test(ix, &bef, circuit_breaker_nanos, Option::<ProgramError>::None);
+    }
+}
+
+fn unauthorized_strat() -> impl Strategy<Value = (Instruction, AccountMap, u32)> {
+    any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat(),
+        sol_value_circuit_breaker_nanos: Some(any::<u32>().boxed()),
+        ..Default::default()
+    })
+    .prop_flat_map(|ps| {
+        (
+            any::<[u8; 32]>().prop_filter("", move |pk| *pk != ps.admin),
+            any::<u32>(),
+            Just(ps),
+        )
+    })
+    .prop_map(|(wrong_admin, circuit_breaker_nanos, ps)| {
+        (
+            set_sol_value_circuit_breaker_keys(wrong_admin),
+            circuit_breaker_nanos,
+            ps,
+        )
+    })
+    .prop_map(to_test_inp)
+}
+
+proptest! {
+    #[test]
+    fn set_sol_value_circuit_breaker_unauthorized_pt(
+        (ix, bef, circuit_breaker_nanos) in unauthorized_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_sol_value_circuit_breaker_test(ix, &bef, circuit_breaker_nanos, Some(INVALID_ARGUMENT));
+    }
+}
+
+fn missing_sig_strat() -> impl Strategy<Value = (Instruction, AccountMap, u32)> {
+    correct_strat().prop_map(|(mut ix, accs, circuit_breaker_nanos)| {
+        ix.accounts[SET_SOL_VALUE_CIRCUIT_BREAKER_IX_ACCS_IDX_ADMIN].is_signer = false;
+        (ix, accs, circuit_breaker_nanos)
+    })
+}
+
+proptest! {
+    #[test]
+    fn set_sol_value_circuit_breaker_missing_sig_pt(
+        (ix, bef, circuit_breaker_nanos) in missing_sig_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_sol_value_circuit_breaker_test(
+            ix,
+            &bef,
+            circuit_breaker_nanos,
+            Some(MISSING_REQUIRED_SIGNATURE),
+        );
+    }
+}
+
+fn disabled_strat() -> impl Strategy<Value = (Instruction, AccountMap, u32)> {
+    ps_strat_to_test_inp(any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat().with_is_disabled(Some(Just(true).boxed())),
+        sol_value_circuit_breaker_nanos: Some(any::<u32>().boxed()),
+        ..Default::default()
+    }))
+}
+
+proptest! {
+    #[test]
+    fn set_sol_value_circuit_breaker_pool_disabled_pt(
+        (ix, bef, circuit_breaker_nanos) in disabled_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_sol_value_circuit_breaker_test(
+            ix,
+            &bef,
+            circuit_breaker_nanos,
+            Some(Inf1CtlCustomProgErr(Inf1CtlErr::PoolDisabled)),
+        );
+    }
+}
+
+fn rebalancing_strat() -> impl Strategy<Value = (Instruction, AccountMap, u32)> {
+    ps_strat_to_test_inp(any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat()
+            .with_is_rebalancing(Some(Just(true).boxed())),
+        sol_value_circuit_breaker_nanos: Some(any::<u32>().boxed()),
+        ..Default::default()
+    }))
+}
+
+proptest! {
+    #[test]
+    fn set_sol_value_circuit_breaker_pool_rebalancing_pt(
+        (ix, bef, circuit_breaker_nanos) in rebalancing_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_sol_value_circuit_breaker_test(
+            ix,
+            &bef,
+            circuit_breaker_nanos,
+            Some(Inf1CtlCustomProgErr(Inf1CtlErr::PoolRebalancing)),
+        );
+    }
+}
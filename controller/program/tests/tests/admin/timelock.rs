@@ -0,0 +1,565 @@
+use inf1_ctl_jiminy::{
+    accounts::{
+        pending_admin_action::PendingAdminAction,
+        pool_config::PoolConfig,
+        pool_state::{PoolStateV2, PoolStateV2Addrs, PoolStateV2FtaVals, PoolStateV2Packed},
+    },
+    err::Inf1CtlErr,
+    instructions::{
+        admin::{
+            set_admin::{
+                NewSetAdminIxAccsBuilder, SetAdminIxData, SetAdminIxKeysOwned,
+                SET_ADMIN_IX_IS_SIGNER, SET_ADMIN_IX_IS_WRITER,
+            },
+            set_min_fee::{
+                NewSetMinFeeIxAccsBuilder, SetMinFeeIxData, SET_MIN_FEE_IX_IS_SIGNER,
+                SET_MIN_FEE_IX_IS_WRITER,
+            },
+            timelock::{
+                cancel::{
+                    CancelAdminActionIxData, CancelAdminActionIxKeysOwned,
+                    NewCancelAdminActionIxAccsBuilder, CANCEL_ADMIN_ACTION_IX_IS_SIGNER,
+                    CANCEL_ADMIN_ACTION_IX_IS_WRITER,
+                },
+                execute::{
+                    ExecuteAdminActionIxData, NewExecuteAdminActionIxPreAccsBuilder,
+                    EXECUTE_ADMIN_ACTION_IX_PRE_IS_SIGNER, EXECUTE_ADMIN_ACTION_IX_PRE_IS_WRITER,
+                },
+                queue::{
+                    NewQueueAdminActionIxAccsBuilder, QueueAdminActionIxArgs,
+                    QueueAdminActionIxData, QueueAdminActionIxKeysOwned,
+                    QUEUE_ADMIN_ACTION_IX_IS_SIGNER, QUEUE_ADMIN_ACTION_IX_IS_WRITER,
+                },
+                set_admin_action_delay::{
+                    NewSetAdminActionDelayIxAccsBuilder, SetAdminActionDelayIxData,
+                    SetAdminActionDelayIxKeysOwned, SET_ADMIN_ACTION_DELAY_IX_IS_SIGNER,
+                    SET_ADMIN_ACTION_DELAY_IX_IS_WRITER,
+                },
+            },
+        },
+        disable_pool::set_disable_modes::SET_DISABLE_MODES_IX_DISCM,
+        protocol_fee::set_protocol_fee::{
+            NewSetProtocolFeeIxAccsBuilder, SetProtocolFeeIxData, SET_PROTOCOL_FEE_IX_IS_SIGNER,
+            SET_PROTOCOL_FEE_IX_IS_WRITER,
+        },
+    },
+    keys::{PENDING_ADMIN_ACTION_ID, POOL_CONFIG_ID, POOL_STATE_ID, SYS_PROG_ID},
+    program_err::Inf1CtlCustomProgErr,
+    ID,
+};
+use inf1_test_utils::{
+    assert_jiminy_prog_err, keys_signer_writable_to_metas, mock_sys_acc, mollusk_exec,
+    mollusk_with_clock_override, pending_admin_action_account, pool_config_account,
+    pool_state_v2_account, silence_mollusk_logs, AccountMap, ClockArgs, ClockU64s, ExecErr, ExecOk,
+};
+use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT, INVALID_INSTRUCTION_DATA};
+use mollusk_svm::program::keyed_account_for_system_program;
+use proptest::prelude::*;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::common::{SVM, SVM_MUT};
+
+// dont care abt lamports, shouldnt affect anything
+const LAMPORTS: u64 = 1_000_000_000;
+
+const ADMIN: [u8; 32] = [1; 32];
+
+const PAYER: [u8; 32] = [2; 32];
+
+const NEW_ADMIN: [u8; 32] = [3; 32];
+
+fn pool_with_admin(admin: [u8; 32]) -> PoolStateV2 {
+    PoolStateV2FtaVals {
+        addrs: PoolStateV2Addrs::default().with_admin(admin),
+        ..Default::default()
+    }
+    .into_pool_state_v2()
+}
+
+fn exec_at_slot(slot: u64, ix: Instruction, bef: &AccountMap) -> Result<ExecOk, ExecErr> {
+    SVM_MUT.with_borrow_mut(|svm| {
+        mollusk_with_clock_override(
+            svm,
+            &ClockArgs {
+                u64s: ClockU64s::default().with_slot(Some(slot)),
+                ..Default::default()
+            },
+            |svm| mollusk_exec(svm, &[ix], bef),
+        )
+    })
+}
+
+fn delay_config(admin_action_delay_slots: u64) -> Option<PoolConfig> {
+    Some(PoolConfig {
+        admin_action_delay_slots,
+        ..Default::default()
+    })
+}
+
+// SetAdminActionDelay
+
+fn set_admin_action_delay_keys() -> SetAdminActionDelayIxKeysOwned {
+    NewSetAdminActionDelayIxAccsBuilder::start()
+        .with_admin(ADMIN)
+        .with_payer(PAYER)
+        .with_pool_state(POOL_STATE_ID)
+        .with_pool_config(POOL_CONFIG_ID)
+        .with_system_program(SYS_PROG_ID)
+        .build()
+}
+
+fn set_admin_action_delay_ix(keys: SetAdminActionDelayIxKeysOwned, delay: u64) -> Instruction {
+    let accounts = keys_signer_writable_to_metas(
+        keys.0.iter(),
+        SET_ADMIN_ACTION_DELAY_IX_IS_SIGNER.0.iter(),
+        SET_ADMIN_ACTION_DELAY_IX_IS_WRITER.0.iter(),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts,
+        data: SetAdminActionDelayIxData::new(delay).as_buf().into(),
+    }
+}
+
+fn set_admin_action_delay_test_accs(
+    keys: SetAdminActionDelayIxKeysOwned,
+    config: Option<&PoolConfig>,
+) -> AccountMap {
+    let accs = NewSetAdminActionDelayIxAccsBuilder::start()
+        .with_admin(mock_sys_acc(LAMPORTS))
+        .with_payer(mock_sys_acc(LAMPORTS))
+        .with_pool_state(pool_state_v2_account(pool_with_admin(ADMIN)))
+        .with_pool_config(pool_config_account(config))
+        .with_system_program(keyed_account_for_system_program().1)
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+fn set_admin_action_delay_test(
+    curr: Option<PoolConfig>,
+    new_delay: u64,
+    expected_err: Option<impl Into<ProgramError>>,
+) {
+    let keys = set_admin_action_delay_keys();
+    let bef = set_admin_action_delay_test_accs(keys, curr.as_ref());
+    let result =
+        SVM.with(|svm| mollusk_exec(svm, &[set_admin_action_delay_ix(keys, new_delay)], &bef));
+
+    match expected_err {
+        None => {
+            let config_acc = &result.unwrap().resulting_accounts[&POOL_CONFIG_ID.into()];
+            assert_eq!(config_acc.owner, Pubkey::new_from_array(ID));
+            assert_eq!(
+                config_acc.data.as_slice(),
+                delay_config(new_delay).unwrap().as_acc_data_arr()
+            );
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+        }
+    }
+}
+
+#[test]
+fn set_admin_action_delay_create_basic() {
+    set_admin_action_delay_test(None, 216_000, Option::<ProgramError>::None);
+}
+
+proptest! {
+    #[test]
+    fn set_admin_action_delay_increase_pt(
+        (curr, new_delay) in any::<u64>().prop_flat_map(|c| (Just(c), c..=u64::MAX)),
+    ) {
+        silence_mollusk_logs();
+        set_admin_action_delay_test(delay_config(curr), new_delay, Option::<ProgramError>::None);
+    }
+}
+
+proptest! {
+    #[test]
+    fn set_admin_action_delay_decrease_timelocked_pt(
+        (curr, new_delay) in (1..=u64::MAX).prop_flat_map(|c| (Just(c), 0..c)),
+    ) {
+        silence_mollusk_logs();
+        set_admin_action_delay_test(
+            delay_config(curr),
+            new_delay,
+            Some(Inf1CtlCustomProgErr(Inf1CtlErr::AdminActionTimelocked)),
+        );
+    }
+}
+
+// Direct calls of timelocked instructions
+
+fn set_admin_keys(new: [u8; 32]) -> SetAdminIxKeysOwned {
+    NewSetAdminIxAccsBuilder::start()
+        .with_curr(ADMIN)
+        .with_new(new)
+        .with_pool_state(POOL_STATE_ID)
+        .with_pool_config(POOL_CONFIG_ID)
+        .build()
+}
+
+fn set_admin_ix(keys: SetAdminIxKeysOwned) -> Instruction {
+    let accounts = keys_signer_writable_to_metas(
+        keys.0.iter(),
+        SET_ADMIN_IX_IS_SIGNER.0.iter(),
+        SET_ADMIN_IX_IS_WRITER.0.iter(),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts,
+        data: SetAdminIxData::as_buf().into(),
+    }
+}
+
+fn set_admin_test_accs(keys: SetAdminIxKeysOwned, config: Option<&PoolConfig>) -> AccountMap {
+    let accs = NewSetAdminIxAccsBuilder::start()
+        .with_curr(mock_sys_acc(LAMPORTS))
+        .with_new(mock_sys_acc(LAMPORTS))
+        .with_pool_state(pool_state_v2_account(pool_with_admin(ADMIN)))
+        .with_pool_config(pool_config_account(config))
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+proptest! {
+    #[test]
+    fn direct_call_timelocked_pt(
+        delay in 1..=u64::MAX,
+        protocol_fee_nanos in 0..=1_000_000u32,
+        min_fee_nanos in 0..=1_000_000u32,
+    ) {
+        silence_mollusk_logs();
+        let config = delay_config(delay);
+
+        let keys = set_admin_keys(NEW_ADMIN);
+        let result = SVM.with(|svm| {
+            mollusk_exec(svm, &[set_admin_ix(keys)], &set_admin_test_accs(keys, config.as_ref()))
+        });
+        assert_jiminy_prog_err(
+            &result.unwrap_err(),
+            Inf1CtlCustomProgErr(Inf1CtlErr::AdminActionTimelocked),
+        );
+
+        let keys = NewSetProtocolFeeIxAccsBuilder::start()
+            .with_admin(ADMIN)
+            .with_pool_state(POOL_STATE_ID)
+            .with_pool_config(POOL_CONFIG_ID)
+            .build();
+        let ix = Instruction {
+            program_id: Pubkey::new_from_array(ID),
+            accounts: keys_signer_writable_to_metas(
+                keys.0.iter(),
+                SET_PROTOCOL_FEE_IX_IS_SIGNER.0.iter(),
+                SET_PROTOCOL_FEE_IX_IS_WRITER.0.iter(),
+            ),
+            data: SetProtocolFeeIxData::new(protocol_fee_nanos).as_buf().into(),
+        };
+        let accs = NewSetProtocolFeeIxAccsBuilder::start()
+            .with_admin(mock_sys_acc(LAMPORTS))
+            .with_pool_state(pool_state_v2_account(pool_with_admin(ADMIN)))
+            .with_pool_config(pool_config_account(config.as_ref()))
+            .build();
+        let bef: AccountMap = keys.0.into_iter().map(Into::into).zip(accs.0).collect();
+        let result = SVM.with(|svm| mollusk_exec(svm, &[ix], &bef));
+        assert_jiminy_prog_err(
+            &result.unwrap_err(),
+            Inf1CtlCustomProgErr(Inf1CtlErr::AdminActionTimelocked),
+        );
+
+        let keys = NewSetMinFeeIxAccsBuilder::start()
+            .with_admin(ADMIN)
+            .with_pool_state(POOL_STATE_ID)
+            .with_pool_config(POOL_CONFIG_ID)
+            .build();
+        let ix = Instruction {
+            program_id: Pubkey::new_from_array(ID),
+            accounts: keys_signer_writable_to_metas(
+                keys.0.iter(),
+                SET_MIN_FEE_IX_IS_SIGNER.0.iter(),
+                SET_MIN_FEE_IX_IS_WRITER.0.iter(),
+            ),
+            data: SetMinFeeIxData::new(min_fee_nanos).as_buf().into(),
+        };
+        let accs = NewSetMinFeeIxAccsBuilder::start()
+            .with_admin(mock_sys_acc(LAMPORTS))
+            .with_pool_state(pool_state_v2_account(pool_with_admin(ADMIN)))
+            .with_pool_config(pool_config_account(config.as_ref()))
+            .build();
+        let bef: AccountMap = keys.0.into_iter().map(Into::into).zip(accs.0).collect();
+        let result = SVM.with(|svm| mollusk_exec(svm, &[ix], &bef));
+        assert_jiminy_prog_err(
+            &result.unwrap_err(),
+            Inf1CtlCustomProgErr(Inf1CtlErr::AdminActionTimelocked),
+        );
+    }
+}
+
+// QueueAdminAction
+
+fn queue_keys() -> QueueAdminActionIxKeysOwned {
+    NewQueueAdminActionIxAccsBuilder::start()
+        .with_admin(ADMIN)
+        .with_payer(PAYER)
+        .with_pool_state(POOL_STATE_ID)
+        .with_pool_config(POOL_CONFIG_ID)
+        .with_pending_admin_action(PENDING_ADMIN_ACTION_ID)
+        .with_system_program(SYS_PROG_ID)
+        .build()
+}
+
+fn queue_ix(keys: QueueAdminActionIxKeysOwned, args: &QueueAdminActionIxArgs) -> Instruction {
+    let accounts = keys_signer_writable_to_metas(
+        keys.0.iter(),
+        QUEUE_ADMIN_ACTION_IX_IS_SIGNER.0.iter(),
+        QUEUE_ADMIN_ACTION_IX_IS_WRITER.0.iter(),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts,
+        data: QueueAdminActionIxData::new(args).as_buf().into(),
+    }
+}
+
+fn queue_test_accs(
+    keys: QueueAdminActionIxKeysOwned,
+    config: Option<&PoolConfig>,
+    pending: Option<&PendingAdminAction>,
+) -> AccountMap {
+    let accs = NewQueueAdminActionIxAccsBuilder::start()
+        .with_admin(mock_sys_acc(LAMPORTS))
+        .with_payer(mock_sys_acc(LAMPORTS))
+        .with_pool_state(pool_state_v2_account(pool_with_admin(ADMIN)))
+        .with_pool_config(pool_config_account(config))
+        .with_pending_admin_action(pending_admin_action_account(pending))
+        .with_system_program(keyed_account_for_system_program().1)
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+fn set_admin_action_args(new_admin: [u8; 32]) -> QueueAdminActionIxArgs {
+    QueueAdminActionIxArgs::new(SetAdminIxData::as_buf(), &[new_admin]).unwrap()
+}
+
+// ExecuteAdminAction
+
+/// `refund_rent_to` is `PAYER`, `wrapped` are the accounts of the queued instruction
+fn execute_ix(wrapped: Instruction) -> Instruction {
+    let prefix = NewExecuteAdminActionIxPreAccsBuilder::start()
+        .with_pending_admin_action(PENDING_ADMIN_ACTION_ID)
+        .with_refund_rent_to(PAYER)
+        .build();
+    let mut accounts = keys_signer_writable_to_metas(
+        prefix.0.iter(),
+        EXECUTE_ADMIN_ACTION_IX_PRE_IS_SIGNER.0.iter(),
+        EXECUTE_ADMIN_ACTION_IX_PRE_IS_WRITER.0.iter(),
+    );
+    accounts.extend(wrapped.accounts);
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts,
+        data: ExecuteAdminActionIxData::as_buf().into(),
+    }
+}
+
+fn execute_set_admin_test_accs(
+    keys: SetAdminIxKeysOwned,
+    config: Option<&PoolConfig>,
+    pending: &PendingAdminAction,
+) -> AccountMap {
+    let mut accs = set_admin_test_accs(keys, config);
+    accs.extend([
+        (
+            PENDING_ADMIN_ACTION_ID.into(),
+            pending_admin_action_account(Some(pending)),
+        ),
+        (PAYER.into(), mock_sys_acc(LAMPORTS)),
+    ]);
+    accs
+}
+
+proptest! {
+    #[test]
+    fn queue_then_execute_set_admin_pt(
+        delay in 0..=1_000_000u64,
+        queue_slot in 0..=u64::from(u32::MAX),
+        extra_slots in 0..=1_000u64,
+    ) {
+        silence_mollusk_logs();
+        let config = delay_config(delay);
+        let args = set_admin_action_args(NEW_ADMIN);
+
+        // queue
+        let keys = queue_keys();
+        let aft = exec_at_slot(
+            queue_slot,
+            queue_ix(keys, &args),
+            &queue_test_accs(keys, config.as_ref(), None),
+        )
+        .unwrap()
+        .resulting_accounts;
+        let pending_acc = &aft[&PENDING_ADMIN_ACTION_ID.into()];
+        let expected_pending = PendingAdminAction {
+            earliest_execution_slot: queue_slot + delay,
+            keys: args.keys,
+            ix_data: args.ix_data,
+        };
+        prop_assert_eq!(pending_acc.owner, Pubkey::new_from_array(ID));
+        prop_assert_eq!(pending_acc.data.as_slice(), expected_pending.as_acc_data_arr());
+
+        let set_admin_keys = set_admin_keys(NEW_ADMIN);
+        let bef = execute_set_admin_test_accs(set_admin_keys, config.as_ref(), &expected_pending);
+        let ix = execute_ix(set_admin_ix(set_admin_keys));
+
+        // too early
+        if delay > 0 {
+            let result = exec_at_slot(
+                expected_pending.earliest_execution_slot - 1,
+                ix.clone(),
+                &bef,
+            );
+            assert_jiminy_prog_err(
+                &result.unwrap_err(),
+                Inf1CtlCustomProgErr(Inf1CtlErr::AdminActionNotReady),
+            );
+        }
+
+        // wrong new admin
+        let wrong_keys = set_admin_keys([4; 32]);
+        let result = exec_at_slot(
+            expected_pending.earliest_execution_slot + extra_slots,
+            execute_ix(set_admin_ix(wrong_keys)),
+            &execute_set_admin_test_accs(wrong_keys, config.as_ref(), &expected_pending),
+        );
+        assert_jiminy_prog_err(&result.unwrap_err(), INVALID_ARGUMENT);
+
+        // ready
+        let aft = exec_at_slot(expected_pending.earliest_execution_slot + extra_slots, ix, &bef)
+            .unwrap()
+            .resulting_accounts;
+        let pool_aft =
+            PoolStateV2Packed::of_acc_data(&aft[&POOL_STATE_ID.into()].data)
+                .unwrap()
+                .into_pool_state_v2();
+        prop_assert_eq!(pool_aft.admin, NEW_ADMIN);
+        let pending_aft = &aft[&PENDING_ADMIN_ACTION_ID.into()];
+        prop_assert_eq!(pending_aft.lamports, 0);
+        prop_assert!(pending_aft.data.is_empty());
+    }
+}
+
+#[test]
+fn queue_already_queued_basic() {
+    silence_mollusk_logs();
+    let args = set_admin_action_args(NEW_ADMIN);
+    let pending = PendingAdminAction {
+        earliest_execution_slot: 100,
+        keys: args.keys,
+        ix_data: args.ix_data,
+    };
+    let keys = queue_keys();
+    let result = SVM.with(|svm| {
+        mollusk_exec(
+            svm,
+            &[queue_ix(keys, &args)],
+            &queue_test_accs(keys, delay_config(100).as_ref(), Some(&pending)),
+        )
+    });
+    assert_jiminy_prog_err(
+        &result.unwrap_err(),
+        Inf1CtlCustomProgErr(Inf1CtlErr::AdminActionAlreadyQueued),
+    );
+}
+
+proptest! {
+    #[test]
+    fn queue_invalid_action_pt(
+        trailing in 1..=u8::MAX,
+        extra_key in any::<[u8; 32]>().prop_filter("nonzero", |k| *k != [0u8; 32]),
+    ) {
+        silence_mollusk_logs();
+        let keys = queue_keys();
+        let mut invalids = [set_admin_action_args(NEW_ADMIN); 3];
+        // not a timelocked ix
+        invalids[0].ix_data[0] = SET_DISABLE_MODES_IX_DISCM;
+        // trailing data
+        invalids[1].ix_data[SetAdminIxData::DATA_LEN] = trailing;
+        // more keys than bound
+        invalids[2].keys[1] = extra_key;
+        for args in invalids {
+            let result = SVM.with(|svm| {
+                mollusk_exec(svm, &[queue_ix(keys, &args)], &queue_test_accs(keys, None, None))
+            });
+            assert_jiminy_prog_err(&result.unwrap_err(), INVALID_INSTRUCTION_DATA);
+        }
+    }
+}
+
+// CancelAdminAction
+
+fn cancel_keys(admin: [u8; 32]) -> CancelAdminActionIxKeysOwned {
+    NewCancelAdminActionIxAccsBuilder::start()
+        .with_admin(admin)
+        .with_pool_state(POOL_STATE_ID)
+        .with_pending_admin_action(PENDING_ADMIN_ACTION_ID)
+        .with_refund_rent_to(PAYER)
+        .build()
+}
+
+fn cancel_test(admin: [u8; 32], expected_err: Option<impl Into<ProgramError>>) {
+    let keys = cancel_keys(admin);
+    let ix = Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts: keys_signer_writable_to_metas(
+            keys.0.iter(),
+            CANCEL_ADMIN_ACTION_IX_IS_SIGNER.0.iter(),
+            CANCEL_ADMIN_ACTION_IX_IS_WRITER.0.iter(),
+        ),
+        data: CancelAdminActionIxData::as_buf().into(),
+    };
+    let args = set_admin_action_args(NEW_ADMIN);
+    let pending = PendingAdminAction {
+        earliest_execution_slot: u64::MAX,
+        keys: args.keys,
+        ix_data: args.ix_data,
+    };
+    let accs = NewCancelAdminActionIxAccsBuilder::start()
+        .with_admin(mock_sys_acc(LAMPORTS))
+        .with_pool_state(pool_state_v2_account(pool_with_admin(ADMIN)))
+        .with_pending_admin_action(pending_admin_action_account(Some(&pending)))
+        .with_refund_rent_to(mock_sys_acc(LAMPORTS))
+        .build();
+    let bef: AccountMap = keys.0.into_iter().map(Into::into).zip(accs.0).collect();
+    let result = SVM.with(|svm| mollusk_exec(svm, &[ix], &bef));
+
+    match expected_err {
+        None => {
+            let aft = result.unwrap().resulting_accounts;
+            let pending_bef = &bef[&PENDING_ADMIN_ACTION_ID.into()];
+            let pending_aft = &aft[&PENDING_ADMIN_ACTION_ID.into()];
+            assert_eq!(pending_aft.lamports, 0);
+            assert!(pending_aft.data.is_empty());
+            assert_eq!(aft[&PAYER.into()].lamports, LAMPORTS + pending_bef.lamports);
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+        }
+    }
+}
+
+#[test]
+fn cancel_admin_action_correct_basic() {
+    cancel_test(ADMIN, Option::<ProgramError>::None);
+}
+
+proptest! {
+    #[test]
+    fn cancel_admin_action_unauthorized_pt(
+        non_admin in any::<[u8; 32]>().prop_filter("", |pk| *pk != ADMIN && *pk != PAYER),
+    ) {
+        silence_mollusk_logs();
+        cancel_test(non_admin, Some(INVALID_ARGUMENT));
+    }
+}
@@ -0,0 +1,243 @@
+//! Generates the compute unit table in `inf1_std::cu`.
+//!
+//! Regenerate with `UPDATE_EXPECT=1 cargo test -p inf1-ctl-program cu_table_fixtures`
+//! after changes that could affect compute units consumed by the program.
+
+use expect_test::expect_file;
+use inf1_core::instructions::swap::v2::liquidity::LP_LST_INDEX;
+use inf1_ctl_jiminy::{instructions::swap::v2::IxPreAccs, svc::InfDummyCalcAccs};
+use inf1_pp_ag_core::{PricingAg, PricingAgTy};
+use inf1_std::cu::{CuIxKind, CuKey};
+use inf1_svc_ag_core::{
+    inf1_svc_wsol_core::instructions::sol_val_calc::WsolCalcAccs, instructions::SvcCalcAccsAg,
+    SvcAg, SvcAgTy,
+};
+use inf1_test_utils::{
+    flatslab_fixture_suf_accs, jupsol_fixture_svc_suf_accs, mollusk_exec,
+    msol_fixture_svc_suf_accs, AccountMap, JUPSOL_FIXTURE_LST_IDX, MSOL_FIXTURE_LST_IDX,
+    WSOL_FIXTURE_LST_IDX,
+};
+
+use crate::{
+    common::SVM,
+    tests::{
+        rebalance::chain::jupsol_o_wsol_i_fixture_basic_inp,
+        swap::{
+            common::fill_swap_prog_accs,
+            v2::{exact_in, exact_out, prefix_fixtures},
+            V2Accs, V2Args,
+        },
+    },
+};
+
+const SWAP_AMOUNT: u64 = 1_000;
+
+const REBALANCE_AMOUNT: u64 = 100_000;
+
+struct LstFixture {
+    /// test fixture name prefix
+    name: &'static str,
+    idx: usize,
+    ty: SvcAgTy,
+    accs: fn() -> (SvcCalcAccsAg, AccountMap),
+}
+
+const LST_FIXTURES: [LstFixture; 3] = [
+    LstFixture {
+        name: "wsol",
+        idx: WSOL_FIXTURE_LST_IDX,
+        ty: SvcAgTy::Wsol(()),
+        accs: || (SvcAg::Wsol(WsolCalcAccs), Default::default()),
+    },
+    LstFixture {
+        name: "msol",
+        idx: MSOL_FIXTURE_LST_IDX,
+        ty: SvcAgTy::Marinade(()),
+        accs: || {
+            let (accs, am) = msol_fixture_svc_suf_accs();
+            (SvcAg::Marinade(accs), am)
+        },
+    },
+    LstFixture {
+        name: "jupsol",
+        idx: JUPSOL_FIXTURE_LST_IDX,
+        ty: SvcAgTy::SanctumSplMulti(()),
+        accs: || {
+            let (accs, am) = jupsol_fixture_svc_suf_accs();
+            (SvcAg::SanctumSplMulti(accs), am)
+        },
+    },
+];
+
+/// The LP token, for add and remove liquidity
+const INF_FIXTURE: LstFixture = LstFixture {
+    name: "inf",
+    idx: LP_LST_INDEX as usize,
+    ty: SvcAgTy::Inf(()),
+    accs: || (SvcAg::Inf(InfDummyCalcAccs), Default::default()),
+};
+
+fn swap_cus(inp: &LstFixture, out: &LstFixture, kind: CuIxKind) -> u32 {
+    let prefix_am = prefix_fixtures(inp.name, out.name);
+    let (pp_accs, pp_am) = flatslab_fixture_suf_accs();
+    let (inp_calc, inp_am) = (inp.accs)();
+    let (out_calc, out_am) = (out.accs)();
+
+    let accs = V2Accs {
+        ix_prefix: IxPreAccs(prefix_am.0.each_ref().map(|(addr, _)| addr.to_bytes())),
+        inp_calc_prog: *inp.ty.svc_program_id(),
+        inp_calc,
+        out_calc_prog: *out.ty.svc_program_id(),
+        out_calc,
+        pricing_prog: *PricingAgTy::FlatSlab(()).program_id(),
+        pricing: PricingAg::FlatSlab(pp_accs),
+    };
+    let args = |limit| V2Args {
+        inp_lst_index: inp.idx.try_into().unwrap(),
+        out_lst_index: out.idx.try_into().unwrap(),
+        limit,
+        amount: SWAP_AMOUNT,
+        accs,
+    };
+    let ix = match kind {
+        CuIxKind::SwapExactIn => exact_in::to_ix(&args(0)),
+        CuIxKind::SwapExactOut => exact_out::to_ix(&args(u64::MAX)),
+        CuIxKind::Rebalance => unreachable!(),
+    };
+
+    let mut bef = prefix_am
+        .0
+        .into_iter()
+        .chain(pp_am)
+        .chain(inp_am)
+        .chain(out_am)
+        .collect();
+    fill_swap_prog_accs(&mut bef, &accs);
+
+    let cus = SVM
+        .with(|svm| mollusk_exec(svm, &[ix], &bef))
+        .unwrap()
+        .compute_units_consumed;
+    cus.try_into().unwrap()
+}
+
+/// StartRebalance + EndRebalance, excluding the transfer in between
+fn rebalance_cus() -> u32 {
+    let (ixs, bef, ..) = jupsol_o_wsol_i_fixture_basic_inp(REBALANCE_AMOUNT);
+    let [all, transfer] = [&ixs[..], &ixs[1..2]].map(|ixs| {
+        SVM.with(|svm| mollusk_exec(svm, ixs, &bef))
+            .unwrap()
+            .compute_units_consumed
+    });
+    (all - transfer).try_into().unwrap()
+}
+
+fn render_table(entries: &[(CuKey, u32)]) -> String {
+    let mut res = String::from(
+        "// @generated by `cu_table_fixtures` in controller/program/tests/tests/cu.rs.
+// Do not edit by hand; regenerate with
+// `UPDATE_EXPECT=1 cargo test -p inf1-ctl-program cu_table_fixtures`
+
+#[allow(unused_imports)]
+use inf1_pp_ag_std::PricingAg;
+#[allow(unused_imports)]
+use inf1_svc_ag_std::SvcAg;
+
+#[allow(unused_imports)]
+use super::{CuIxKind, CuKey};
+
+",
+    );
+    if entries.is_empty() {
+        res.push_str("pub const CU_TABLE: &[(CuKey, u32)] = &[];\n");
+        return res;
+    }
+    res.push_str("pub const CU_TABLE: &[(CuKey, u32)] = &[\n");
+    entries.iter().for_each(
+        |(
+            CuKey {
+                inp,
+                out,
+                pricing,
+                kind,
+            },
+            cus,
+        )| {
+            res.push_str(&format!(
+                "    (
+        CuKey {{
+            inp: SvcAg::{inp:?},
+            out: SvcAg::{out:?},
+            pricing: PricingAg::{pricing:?},
+            kind: CuIxKind::{kind:?},
+        }},
+        {cus},
+    ),
+"
+            ))
+        },
+    );
+    res.push_str("];\n");
+    res
+}
+
+#[test]
+fn cu_table_fixtures() {
+    let pricing = PricingAgTy::FlatSlab(());
+    let mut entries = Vec::new();
+
+    LST_FIXTURES.iter().for_each(|inp| {
+        LST_FIXTURES
+            .iter()
+            .filter(|out| out.name != inp.name)
+            .for_each(|out| {
+                [CuIxKind::SwapExactIn, CuIxKind::SwapExactOut]
+                    .into_iter()
+                    .for_each(|kind| {
+                        entries.push((
+                            CuKey {
+                                inp: inp.ty,
+                                out: out.ty,
+                                pricing,
+                                kind,
+                            },
+                            swap_cus(inp, out, kind),
+                        ))
+                    })
+            })
+    });
+
+    LST_FIXTURES.iter().for_each(|lst| {
+        // add liquidity, then remove liquidity
+        [(lst, &INF_FIXTURE), (&INF_FIXTURE, lst)]
+            .into_iter()
+            .for_each(|(inp, out)| {
+                [CuIxKind::SwapExactIn, CuIxKind::SwapExactOut]
+                    .into_iter()
+                    .for_each(|kind| {
+                        entries.push((
+                            CuKey {
+                                inp: inp.ty,
+                                out: out.ty,
+                                pricing,
+                                kind,
+                            },
+                            swap_cus(inp, out, kind),
+                        ))
+                    })
+            })
+    });
+
+    // pool fixture's pricing program is flatslab
+    entries.push((
+        CuKey {
+            inp: SvcAgTy::Wsol(()),
+            out: SvcAgTy::SanctumSplMulti(()),
+            pricing,
+            kind: CuIxKind::Rebalance,
+        },
+        rebalance_cus(),
+    ));
+
+    expect_file!["../../../../std/src/cu/table.rs"].assert_eq(&render_table(&entries));
+}
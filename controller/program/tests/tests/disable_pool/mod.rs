@@ -3,3 +3,4 @@ mod common;
 mod disable;
 mod enable;
 mod remove_disable_pool_auth;
+mod set_disable_modes;
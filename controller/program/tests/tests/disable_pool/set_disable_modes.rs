@@ -0,0 +1,299 @@
+use inf1_ctl_jiminy::{
+    accounts::pool_state::{PoolStateV2, PoolStateV2Addrs, PoolStateV2FtaVals, PoolStateV2Packed},
+    err::Inf1CtlErr,
+    instructions::disable_pool::set_disable_modes::{
+        NewSetDisableModesIxAccsBuilder, SetDisableModesIxData, SetDisableModesIxKeysOwned,
+        SET_DISABLE_MODES_IX_ACCS_IDX_SIGNER, SET_DISABLE_MODES_IX_DISCM,
+        SET_DISABLE_MODES_IX_IS_SIGNER, SET_DISABLE_MODES_IX_IS_WRITER,
+    },
+    keys::{DISABLE_POOL_AUTHORITY_LIST_ID, POOL_STATE_ID},
+    program_err::Inf1CtlCustomProgErr,
+    typedefs::disable_modes::{DisableMode, DisableModes},
+    ID,
+};
+use inf1_test_utils::{
+    any_disable_pool_auth_list, any_pool_state_v2, assert_diffs_pool_state_v2,
+    assert_jiminy_prog_err, disable_pool_auth_list_account, keys_signer_writable_to_metas,
+    list_sample_flat_map, mock_sys_acc, mollusk_exec, pool_state_v2_account, silence_mollusk_logs,
+    AccountMap, Diff, DiffsPoolStateV2, PoolStateV2FtaStrat,
+};
+use jiminy_cpi::program_error::{
+    ProgramError, INVALID_ARGUMENT, INVALID_INSTRUCTION_DATA, MISSING_REQUIRED_SIGNATURE,
+};
+use proptest::{prelude::*, strategy::Union};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::{common::SVM, tests::disable_pool::common::MAX_DISABLE_POOL_AUTH_LIST_LEN};
+
+/// Not using [`SetDisableModesIxData::new`] so that unknown bits can be tested
+fn set_disable_modes_ix(keys: SetDisableModesIxKeysOwned, disable_modes: u8) -> Instruction {
+    let accounts = keys_signer_writable_to_metas(
+        keys.0.iter(),
+        SET_DISABLE_MODES_IX_IS_SIGNER.0.iter(),
+        SET_DISABLE_MODES_IX_IS_WRITER.0.iter(),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts,
+        data: [SET_DISABLE_MODES_IX_DISCM, disable_modes].into(),
+    }
+}
+
+fn set_disable_modes_test_accs(
+    keys: SetDisableModesIxKeysOwned,
+    pool: PoolStateV2,
+    // disable pool authority list
+    dpal: Vec<[u8; 32]>,
+) -> AccountMap {
+    // dont care abt lamports, shouldnt affect anything
+    const LAMPORTS: u64 = 1_000_000_000;
+    let accs = NewSetDisableModesIxAccsBuilder::start()
+        .with_signer(mock_sys_acc(LAMPORTS))
+        .with_disable_pool_auth_list(disable_pool_auth_list_account(dpal))
+        .with_pool_state(pool_state_v2_account(pool))
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+fn set_disable_modes_test(
+    ix: Instruction,
+    bef: &AccountMap,
+    expected_err: Option<impl Into<ProgramError>>,
+) {
+    let new_modes = ix.data[1];
+    let result = SVM.with(|svm| mollusk_exec(svm, &[ix], bef));
+
+    let pool_state_bef =
+        PoolStateV2Packed::of_acc_data(&bef.get(&POOL_STATE_ID.into()).unwrap().data)
+            .unwrap()
+            .into_pool_state_v2();
+
+    match expected_err {
+        None => {
+            let resulting_accounts = result.unwrap().resulting_accounts;
+            let pool_state_aft = PoolStateV2Packed::of_acc_data(
+                &resulting_accounts.get(&POOL_STATE_ID.into()).unwrap().data,
+            )
+            .unwrap()
+            .into_pool_state_v2();
+            assert_diffs_pool_state_v2(
+                &DiffsPoolStateV2 {
+                    disable_modes: Diff::Changed(pool_state_bef.disable_modes, new_modes),
+                    ..Default::default()
+                },
+                &pool_state_bef,
+                &pool_state_aft,
+            );
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+        }
+    }
+}
+
+#[test]
+fn set_disable_modes_correct_basic() {
+    let admin = [69u8; 32];
+    let pool = PoolStateV2FtaVals {
+        addrs: PoolStateV2Addrs::default().with_admin(admin),
+        ..Default::default()
+    }
+    .into_pool_state_v2();
+    let keys = correct_keys(admin);
+    let modes = DisableModes::NONE
+        .with(DisableMode::Swap)
+        .with(DisableMode::AddLiquidity);
+    let mut ix = set_disable_modes_ix(keys, modes.get());
+    // check consistency with core ix data builder
+    assert_eq!(ix.data, SetDisableModesIxData::new(modes).as_buf());
+    set_disable_modes_test(
+        ix.clone(),
+        &set_disable_modes_test_accs(keys, pool, vec![]),
+        Option::<ProgramError>::None,
+    );
+
+    // only RemoveLiquidity still allowed
+    ix.data[1] = modes
+        .with(DisableMode::Rebalance)
+        .with(DisableMode::Swap)
+        .get();
+    set_disable_modes_test(
+        ix,
+        &set_disable_modes_test_accs(keys, pool, vec![]),
+        Option::<ProgramError>::None,
+    );
+}
+
+fn correct_keys(signer: [u8; 32]) -> SetDisableModesIxKeysOwned {
+    NewSetDisableModesIxAccsBuilder::start()
+        .with_signer(signer)
+        .with_pool_state(POOL_STATE_ID)
+        .with_disable_pool_auth_list(DISABLE_POOL_AUTHORITY_LIST_ID)
+        .build()
+}
+
+fn to_inp(
+    (k, ps, dpal, modes): (SetDisableModesIxKeysOwned, PoolStateV2, Vec<[u8; 32]>, u8),
+) -> (Instruction, AccountMap) {
+    (
+        set_disable_modes_ix(k, modes),
+        set_disable_modes_test_accs(k, ps, dpal),
+    )
+}
+
+fn any_disable_modes() -> impl Strategy<Value = u8> {
+    0..=DisableModes::ALL.get()
+}
+
+/// is_disabled and is_rebalancing are not restricted
+/// since modes can be set while the pool is disabled or rebalancing
+fn pool_with_any_disable_modes() -> impl Strategy<Value = PoolStateV2> {
+    any_pool_state_v2(PoolStateV2FtaStrat {
+        disable_modes: Some(any_disable_modes().boxed()),
+        ..Default::default()
+    })
+}
+
+/// `(pool, new_modes)` where `new_modes` only disables more modes than the pool already has
+fn only_adding_strat() -> impl Strategy<Value = (PoolStateV2, u8)> {
+    (pool_with_any_disable_modes(), any_disable_modes())
+        .prop_map(|(ps, add)| (ps, ps.disable_modes | add))
+}
+
+fn correct_admin_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
+    (
+        pool_with_any_disable_modes(),
+        any_disable_modes(),
+        any_disable_pool_auth_list(0..=MAX_DISABLE_POOL_AUTH_LIST_LEN),
+    )
+        .prop_map(|(ps, modes, dpal)| (correct_keys(ps.admin), ps, dpal, modes))
+        .prop_map(to_inp)
+}
+
+proptest! {
+    #[test]
+    fn set_disable_modes_admin_correct_pt(
+        (ix, bef) in correct_admin_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_disable_modes_test(ix, &bef, Option::<ProgramError>::None);
+    }
+}
+
+fn correct_disable_auth_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
+    any_disable_pool_auth_list(1..=MAX_DISABLE_POOL_AUTH_LIST_LEN)
+        .prop_flat_map(|l| (list_sample_flat_map(l), only_adding_strat()))
+        .prop_map(|((_, auth, dpal), (ps, modes))| (correct_keys(auth), ps, dpal, modes))
+        .prop_map(to_inp)
+}
+
+proptest! {
+    #[test]
+    fn set_disable_modes_disable_auth_correct_pt(
+        (ix, bef) in correct_disable_auth_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_disable_modes_test(ix, &bef, Option::<ProgramError>::None);
+    }
+}
+
+fn disable_auth_reenable_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
+    any_disable_pool_auth_list(1..=MAX_DISABLE_POOL_AUTH_LIST_LEN)
+        .prop_flat_map(|l| {
+            (
+                list_sample_flat_map(l),
+                pool_with_any_disable_modes(),
+                any_disable_modes(),
+            )
+        })
+        .prop_filter("must clear at least one bit", |(_, ps, modes)| {
+            ps.disable_modes & !modes != 0
+        })
+        .prop_map(|((_, auth, dpal), ps, modes)| (correct_keys(auth), ps, dpal, modes))
+        .prop_map(to_inp)
+}
+
+proptest! {
+    #[test]
+    fn set_disable_modes_disable_auth_reenable_pt(
+        (ix, bef) in disable_auth_reenable_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_disable_modes_test(ix, &bef, Some(INVALID_ARGUMENT));
+    }
+}
+
+fn unauthorized_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
+    (
+        any_disable_pool_auth_list(0..=MAX_DISABLE_POOL_AUTH_LIST_LEN),
+        only_adding_strat(),
+    )
+        .prop_flat_map(|(dpal, (ps, modes))| {
+            let dpal_clone = dpal.clone();
+            (
+                any::<[u8; 32]>()
+                    .prop_filter("", move |pk| *pk != ps.admin && !dpal_clone.contains(pk)),
+                Just(dpal),
+                Just(ps),
+                Just(modes),
+            )
+        })
+        .prop_map(|(unauth, dpal, ps, modes)| (correct_keys(unauth), ps, dpal, modes))
+        .prop_map(to_inp)
+}
+
+proptest! {
+    #[test]
+    fn set_disable_modes_unauthorized_pt(
+        (ix, bef) in unauthorized_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_disable_modes_test(
+            ix,
+            &bef,
+            Some(Inf1CtlCustomProgErr(Inf1CtlErr::UnauthorizedDisablePoolAuthoritySigner))
+        );
+    }
+}
+
+fn unknown_bits_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
+    (
+        pool_with_any_disable_modes(),
+        DisableModes::ALL.get() + 1..=u8::MAX,
+        any_disable_pool_auth_list(0..=MAX_DISABLE_POOL_AUTH_LIST_LEN),
+    )
+        .prop_map(|(ps, modes, dpal)| (correct_keys(ps.admin), ps, dpal, modes))
+        .prop_map(to_inp)
+}
+
+proptest! {
+    #[test]
+    fn set_disable_modes_unknown_bits_pt(
+        (ix, bef) in unknown_bits_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_disable_modes_test(ix, &bef, Some(INVALID_INSTRUCTION_DATA));
+    }
+}
+
+fn missing_sig_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
+    Union::new([
+        correct_admin_strat().boxed(),
+        correct_disable_auth_strat().boxed(),
+    ])
+    .prop_map(|(mut ix, accs)| {
+        ix.accounts[SET_DISABLE_MODES_IX_ACCS_IDX_SIGNER].is_signer = false;
+        (ix, accs)
+    })
+}
+
+proptest! {
+    #[test]
+    fn set_disable_modes_missing_sig_pt(
+        (ix, bef) in missing_sig_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_disable_modes_test(ix, &bef, Some(MISSING_REQUIRED_SIGNATURE));
+    }
+}
@@ -0,0 +1,134 @@
+use inf1_ctl_jiminy::{
+    accounts::pool_state::PoolStateV2,
+    instructions::inf_price::{
+        InfPriceIxData, InfPriceIxKeysOwned, InfPriceRet, InfPriceRetData,
+        NewInfPriceIxAccsBuilder, INF_PRICE_IX_IS_SIGNER, INF_PRICE_IX_IS_WRITER,
+        INF_PRICE_RET_LEN,
+    },
+    keys::POOL_STATE_ID,
+    svc::InfCalc,
+    yields::release::ReleaseYieldParams,
+    ID,
+};
+use inf1_test_utils::{
+    any_normal_pk, assert_jiminy_prog_err, keys_signer_writable_to_metas, mock_mint, mollusk_exec,
+    pool_state_v2_account, raw_mint, silence_mollusk_logs, solvent_pool_state_v2_strat, AccountMap,
+};
+use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT};
+use mollusk_svm::Mollusk;
+use proptest::prelude::*;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::common::SVM;
+
+fn inf_price_ix(keys: InfPriceIxKeysOwned) -> Instruction {
+    let accounts = keys_signer_writable_to_metas(
+        keys.0.iter(),
+        INF_PRICE_IX_IS_SIGNER.0.iter(),
+        INF_PRICE_IX_IS_WRITER.0.iter(),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts,
+        data: InfPriceIxData::as_buf().into(),
+    }
+}
+
+fn inf_price_ix_test_accs(
+    keys: InfPriceIxKeysOwned,
+    pool: PoolStateV2,
+    inf_supply: u64,
+) -> AccountMap {
+    let accs = NewInfPriceIxAccsBuilder::start()
+        .with_pool_state(pool_state_v2_account(pool))
+        .with_lp_token_mint(mock_mint(raw_mint(
+            Some(POOL_STATE_ID),
+            None,
+            inf_supply,
+            9,
+        )))
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+fn inf_price_test(
+    svm: &Mollusk,
+    ix: Instruction,
+    bef: &AccountMap,
+    pool: &PoolStateV2,
+    inf_supply: u64,
+    expected_err: Option<impl Into<ProgramError>>,
+) {
+    let result = mollusk_exec(svm, std::slice::from_ref(&ix), bef);
+
+    match expected_err {
+        None => {
+            let res = result.unwrap();
+
+            // read-only
+            bef.iter()
+                .for_each(|(pk, acc)| assert_eq!(res.resulting_accounts[pk], *acc));
+
+            let expected = InfCalc::new(pool, inf_supply)
+                .lookahead(ReleaseYieldParams::new(pool, svm.sysvars.clock.slot).unwrap())
+                .as_ref()
+                .and_then(InfPriceRet::from_inf_calc)
+                .unwrap();
+            let data: &[u8; INF_PRICE_RET_LEN] = res.return_data.as_slice().try_into().unwrap();
+            assert_eq!(InfPriceRetData::parse(data), expected);
+            assert_eq!(InfPriceRetData::new(&expected).as_buf(), data);
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+        }
+    }
+}
+
+fn correct_keys(pool: &PoolStateV2) -> InfPriceIxKeysOwned {
+    NewInfPriceIxAccsBuilder::start()
+        .with_pool_state(POOL_STATE_ID)
+        .with_lp_token_mint(pool.lp_token_mint)
+        .build()
+}
+
+proptest! {
+    #[test]
+    fn inf_price_correct_pt(
+        (pool, inf_supply) in (solvent_pool_state_v2_strat(), any::<u64>()),
+    ) {
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool);
+        SVM.with(|svm| {
+            inf_price_test(
+                svm,
+                inf_price_ix(keys),
+                &inf_price_ix_test_accs(keys, pool, inf_supply),
+                &pool,
+                inf_supply,
+                Option::<ProgramError>::None,
+            );
+        });
+    }
+}
+
+proptest! {
+    #[test]
+    fn inf_price_wrong_mint_pt(
+        ((pool, inf_supply), wrong_mint) in ((solvent_pool_state_v2_strat(), any::<u64>()), any_normal_pk()),
+    ) {
+        prop_assume!(wrong_mint != pool.lp_token_mint);
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool).with_lp_token_mint(wrong_mint);
+        SVM.with(|svm| {
+            inf_price_test(
+                svm,
+                inf_price_ix(keys),
+                &inf_price_ix_test_accs(keys, pool, inf_supply),
+                &pool,
+                inf_supply,
+                Some(INVALID_ARGUMENT),
+            );
+        });
+    }
+}
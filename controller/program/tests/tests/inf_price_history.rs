@@ -0,0 +1,202 @@
+use inf1_ctl_jiminy::{
+    accounts::{
+        inf_price_history::{InfPriceHistory, InfPriceSample, INF_PRICE_HISTORY_ACC_LEN},
+        pool_state::PoolStateV2,
+    },
+    instructions::crank_inf_price_history::{
+        CrankInfPriceHistoryIxData, CrankInfPriceHistoryIxKeysOwned,
+        NewCrankInfPriceHistoryIxAccsBuilder, CRANK_INF_PRICE_HISTORY_IX_ACCS_IDX_PAYER,
+        CRANK_INF_PRICE_HISTORY_IX_IS_SIGNER, CRANK_INF_PRICE_HISTORY_IX_IS_WRITER,
+    },
+    keys::{INF_PRICE_HISTORY_ID, POOL_STATE_ID, SYS_PROG_ID},
+    svc::InfCalc,
+    yields::release::ReleaseYieldParams,
+    ID,
+};
+use inf1_test_utils::{
+    any_normal_pk, assert_jiminy_prog_err, inf_price_history_account,
+    keys_signer_writable_to_metas, mock_mint, mock_sys_acc, mollusk_exec, pool_state_v2_account,
+    raw_mint, silence_mollusk_logs, solvent_pool_state_v2_strat, AccountMap,
+};
+use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT, MISSING_REQUIRED_SIGNATURE};
+use mollusk_svm::{program::keyed_account_for_system_program, Mollusk};
+use proptest::prelude::*;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::common::SVM;
+
+fn crank_inf_price_history_ix(keys: CrankInfPriceHistoryIxKeysOwned) -> Instruction {
+    let accounts = keys_signer_writable_to_metas(
+        keys.0.iter(),
+        CRANK_INF_PRICE_HISTORY_IX_IS_SIGNER.0.iter(),
+        CRANK_INF_PRICE_HISTORY_IX_IS_WRITER.0.iter(),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts,
+        data: CrankInfPriceHistoryIxData::as_buf().into(),
+    }
+}
+
+fn crank_inf_price_history_test_accs(
+    keys: CrankInfPriceHistoryIxKeysOwned,
+    pool: PoolStateV2,
+    inf_supply: u64,
+    history: Option<&InfPriceHistory>,
+) -> AccountMap {
+    // dont care abt lamports, shouldnt affect anything
+    const LAMPORTS: u64 = 1_000_000_000;
+    let accs = NewCrankInfPriceHistoryIxAccsBuilder::start()
+        .with_payer(mock_sys_acc(LAMPORTS))
+        .with_pool_state(pool_state_v2_account(pool))
+        .with_lp_token_mint(mock_mint(raw_mint(
+            Some(POOL_STATE_ID),
+            None,
+            inf_supply,
+            9,
+        )))
+        .with_inf_price_history(inf_price_history_account(history))
+        .with_system_program(keyed_account_for_system_program().1)
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+fn crank_inf_price_history_test(
+    svm: &Mollusk,
+    ix: Instruction,
+    bef: &AccountMap,
+    pool: &PoolStateV2,
+    inf_supply: u64,
+    history_bef: Option<&InfPriceHistory>,
+    expected_err: Option<impl Into<ProgramError>>,
+) {
+    let result = mollusk_exec(svm, std::slice::from_ref(&ix), bef);
+
+    match expected_err {
+        None => {
+            let res = result.unwrap();
+            let slot = svm.sysvars.clock.slot;
+
+            let history_acc = &res.resulting_accounts[&INF_PRICE_HISTORY_ID.into()];
+            assert_eq!(history_acc.owner, ID.into());
+            assert_eq!(history_acc.data.len(), INF_PRICE_HISTORY_ACC_LEN);
+            let history_aft = InfPriceHistory::of_acc_data(&history_acc.data).unwrap();
+
+            let mut expected = history_bef.copied().unwrap_or_default();
+            if expected.should_sample(slot) {
+                let calc = InfCalc::new(pool, inf_supply)
+                    .lookahead(ReleaseYieldParams::new(pool, slot).unwrap())
+                    .unwrap();
+                expected.push(&InfPriceSample::from_inf_calc(slot, &calc).unwrap());
+            }
+            assert_eq!(*history_aft, expected);
+
+            // pool state is never written to
+            assert_eq!(
+                res.resulting_accounts[&POOL_STATE_ID.into()],
+                bef[&POOL_STATE_ID.into()]
+            );
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+        }
+    }
+}
+
+fn correct_keys(pool: &PoolStateV2, payer: [u8; 32]) -> CrankInfPriceHistoryIxKeysOwned {
+    NewCrankInfPriceHistoryIxAccsBuilder::start()
+        .with_payer(payer)
+        .with_pool_state(POOL_STATE_ID)
+        .with_lp_token_mint(pool.lp_token_mint)
+        .with_inf_price_history(INF_PRICE_HISTORY_ID)
+        .with_system_program(SYS_PROG_ID)
+        .build()
+}
+
+fn history_strat() -> impl Strategy<Value = Option<InfPriceHistory>> {
+    prop_oneof![
+        Just(None),
+        Just(Some(InfPriceHistory::default())),
+        // latest sample at current slot, crank should be a no-op
+        (any::<u64>(), any::<u64>()).prop_map(|(sol_per_inf, total_sol_value)| {
+            let mut h = InfPriceHistory::default();
+            h.push(&InfPriceSample {
+                slot: SVM.with(|svm| svm.sysvars.clock.slot),
+                sol_per_inf,
+                total_sol_value,
+            });
+            Some(h)
+        }),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn crank_inf_price_history_correct_pt(
+        (pool, inf_supply) in (solvent_pool_state_v2_strat(), 1..=u64::MAX),
+        history in history_strat(),
+        payer in any_normal_pk(),
+    ) {
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool, payer);
+        SVM.with(|svm| {
+            crank_inf_price_history_test(
+                svm,
+                crank_inf_price_history_ix(keys),
+                &crank_inf_price_history_test_accs(keys, pool, inf_supply, history.as_ref()),
+                &pool,
+                inf_supply,
+                history.as_ref(),
+                Option::<ProgramError>::None,
+            );
+        });
+    }
+}
+
+proptest! {
+    #[test]
+    fn crank_inf_price_history_wrong_mint_pt(
+        ((pool, inf_supply), wrong_mint, payer) in
+            ((solvent_pool_state_v2_strat(), 1..=u64::MAX), any_normal_pk(), any_normal_pk()),
+    ) {
+        prop_assume!(wrong_mint != pool.lp_token_mint);
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool, payer).with_lp_token_mint(wrong_mint);
+        SVM.with(|svm| {
+            crank_inf_price_history_test(
+                svm,
+                crank_inf_price_history_ix(keys),
+                &crank_inf_price_history_test_accs(keys, pool, inf_supply, None),
+                &pool,
+                inf_supply,
+                None,
+                Some(INVALID_ARGUMENT),
+            );
+        });
+    }
+}
+
+proptest! {
+    #[test]
+    fn crank_inf_price_history_payer_not_signer_pt(
+        (pool, inf_supply) in (solvent_pool_state_v2_strat(), 1..=u64::MAX),
+        payer in any_normal_pk(),
+    ) {
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool, payer);
+        let mut ix = crank_inf_price_history_ix(keys);
+        ix.accounts[CRANK_INF_PRICE_HISTORY_IX_ACCS_IDX_PAYER].is_signer = false;
+        SVM.with(|svm| {
+            crank_inf_price_history_test(
+                svm,
+                ix,
+                &crank_inf_price_history_test_accs(keys, pool, inf_supply, None),
+                &pool,
+                inf_supply,
+                None,
+                Some(MISSING_REQUIRED_SIGNATURE),
+            );
+        });
+    }
+}
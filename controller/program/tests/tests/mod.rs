@@ -1,7 +1,12 @@
 mod admin;
+mod cu;
 mod disable_pool;
+mod inf_price;
+mod inf_price_history;
 mod protocol_fee;
 mod rebalance;
+mod referral;
 mod rps;
+mod svc_admin;
 mod swap;
 mod sync_sol_value;
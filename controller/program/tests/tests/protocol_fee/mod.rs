@@ -1,3 +1,4 @@
+pub mod protocol_fee_beneficiary_share;
 pub mod set_protocol_fee;
 pub mod set_protocol_fee_beneficiary;
 pub mod withdraw_protocol_fees;
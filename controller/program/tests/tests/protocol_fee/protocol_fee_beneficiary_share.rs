@@ -0,0 +1,446 @@
+use std::collections::HashSet;
+
+use inf1_ctl_jiminy::{
+    accounts::{
+        pool_state::{PoolStateV2, PoolStateV2Addrs, PoolStateV2FtaVals},
+        protocol_fee_beneficiary_list::MAX_PROTOCOL_FEE_BENEFICIARIES,
+    },
+    err::Inf1CtlErr,
+    instructions::protocol_fee::{
+        remove_protocol_fee_beneficiary_share::{
+            NewRemoveProtocolFeeBeneficiaryShareIxAccsBuilder,
+            RemoveProtocolFeeBeneficiaryShareIxData, RemoveProtocolFeeBeneficiaryShareIxKeysOwned,
+            REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_ACCS_IDX_ADMIN,
+            REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_SIGNER,
+            REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_WRITER,
+        },
+        set_protocol_fee_beneficiary_share::{
+            NewSetProtocolFeeBeneficiaryShareIxAccsBuilder, SetProtocolFeeBeneficiaryShareIxData,
+            SetProtocolFeeBeneficiaryShareIxKeysOwned,
+            SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_ACCS_IDX_ADMIN,
+            SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_SIGNER,
+            SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_WRITER,
+        },
+    },
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_BENEFICIARY_LIST_ID, SYS_PROG_ID},
+    program_err::Inf1CtlCustomProgErr,
+    typedefs::protocol_fee_beneficiary_share::ProtocolFeeBeneficiaryShare,
+    ID,
+};
+use inf1_test_utils::{
+    any_normal_pk, any_pool_state_v2, assert_jiminy_prog_err, get_protocol_fee_beneficiary_list,
+    idx_oob, keys_signer_writable_to_metas, list_sample_flat_map, mock_sys_acc, mollusk_exec,
+    pool_state_v2_account, protocol_fee_beneficiary_list_account, silence_mollusk_logs, AccountMap,
+};
+use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT, MISSING_REQUIRED_SIGNATURE};
+use mollusk_svm::program::keyed_account_for_system_program;
+use proptest::{collection::vec, prelude::*};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::common::SVM;
+
+// dont care abt lamports, shouldnt affect anything
+const LAMPORTS: u64 = 1_000_000_000;
+
+fn any_protocol_fee_beneficiary_list(
+    len: core::ops::RangeInclusive<usize>,
+) -> impl Strategy<Value = Vec<ProtocolFeeBeneficiaryShare>> {
+    vec((any_normal_pk(), any::<u32>()), len).prop_map(|v| {
+        let mut dedup = HashSet::new();
+        v.into_iter()
+            // insert returns true if did not previously contain value
+            .filter(|(b, _)| dedup.insert(*b))
+            .map(|(b, w)| ProtocolFeeBeneficiaryShare::new(b, w))
+            .collect()
+    })
+}
+
+fn list_aft(res: &AccountMap) -> Vec<ProtocolFeeBeneficiaryShare> {
+    get_protocol_fee_beneficiary_list(&res[&PROTOCOL_FEE_BENEFICIARY_LIST_ID.into()].data)
+}
+
+// Set
+
+fn set_share_ix(keys: &SetProtocolFeeBeneficiaryShareIxKeysOwned, weight: u32) -> Instruction {
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts: keys_signer_writable_to_metas(
+            keys.0.iter(),
+            SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_SIGNER.0.iter(),
+            SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_WRITER.0.iter(),
+        ),
+        data: SetProtocolFeeBeneficiaryShareIxData::new(weight)
+            .as_buf()
+            .into(),
+    }
+}
+
+fn set_share_test_accs(
+    keys: &SetProtocolFeeBeneficiaryShareIxKeysOwned,
+    pool: PoolStateV2,
+    list: &[ProtocolFeeBeneficiaryShare],
+) -> AccountMap {
+    let accs = NewSetProtocolFeeBeneficiaryShareIxAccsBuilder::start()
+        .with_payer(mock_sys_acc(LAMPORTS))
+        .with_admin(mock_sys_acc(LAMPORTS))
+        .with_pool_state(pool_state_v2_account(pool))
+        .with_beneficiary(mock_sys_acc(LAMPORTS))
+        .with_protocol_fee_beneficiary_list(protocol_fee_beneficiary_list_account(list))
+        .with_system_program(keyed_account_for_system_program().1)
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+fn set_share_correct_keys(
+    pool: &PoolStateV2,
+    payer: [u8; 32],
+    beneficiary: [u8; 32],
+) -> SetProtocolFeeBeneficiaryShareIxKeysOwned {
+    NewSetProtocolFeeBeneficiaryShareIxAccsBuilder::start()
+        .with_payer(payer)
+        .with_admin(pool.admin)
+        .with_pool_state(POOL_STATE_ID)
+        .with_beneficiary(beneficiary)
+        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
+        .with_system_program(SYS_PROG_ID)
+        .build()
+}
+
+fn set_share_test(
+    ix: Instruction,
+    bef: &AccountMap,
+    list_bef: &[ProtocolFeeBeneficiaryShare],
+    new: ProtocolFeeBeneficiaryShare,
+    expected_err: Option<impl Into<ProgramError>>,
+) {
+    let result = SVM.with(|svm| mollusk_exec(svm, &[ix], bef));
+
+    match expected_err {
+        None => {
+            let res = result.unwrap().resulting_accounts;
+            let mut expected = list_bef.to_vec();
+            match expected
+                .iter_mut()
+                .find(|e| e.beneficiary == new.beneficiary)
+            {
+                Some(e) => *e = new,
+                None => expected.push(new),
+            }
+            assert_eq!(list_aft(&res), expected);
+            assert_eq!(
+                res[&PROTOCOL_FEE_BENEFICIARY_LIST_ID.into()].owner,
+                ID.into()
+            );
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+        }
+    }
+}
+
+#[test]
+fn set_protocol_fee_beneficiary_share_create_basic() {
+    // +69 to avoid using system program [0; 32]
+    let [admin, payer, dao, ops] = core::array::from_fn(|i| [u8::try_from(i + 69).unwrap(); 32]);
+    let pool = PoolStateV2FtaVals {
+        addrs: PoolStateV2Addrs::default().with_admin(admin),
+        ..Default::default()
+    }
+    .into_pool_state_v2();
+
+    let keys = set_share_correct_keys(&pool, payer, dao);
+    set_share_test(
+        set_share_ix(&keys, 7),
+        &set_share_test_accs(&keys, pool, &[]),
+        &[],
+        ProtocolFeeBeneficiaryShare::new(dao, 7),
+        Option::<ProgramError>::None,
+    );
+
+    let list = [ProtocolFeeBeneficiaryShare::new(dao, 7)];
+    let keys = set_share_correct_keys(&pool, payer, ops);
+    set_share_test(
+        set_share_ix(&keys, 3),
+        &set_share_test_accs(&keys, pool, &list),
+        &list,
+        ProtocolFeeBeneficiaryShare::new(ops, 3),
+        Option::<ProgramError>::None,
+    );
+}
+
+fn set_share_correct_strat() -> impl Strategy<
+    Value = (
+        SetProtocolFeeBeneficiaryShareIxKeysOwned,
+        u32,
+        PoolStateV2,
+        Vec<ProtocolFeeBeneficiaryShare>,
+    ),
+> {
+    (
+        any_pool_state_v2(Default::default()),
+        any_normal_pk(),
+        any_protocol_fee_beneficiary_list(0..=MAX_PROTOCOL_FEE_BENEFICIARIES - 1),
+        any::<u32>(),
+    )
+        .prop_flat_map(|(pool, payer, list, weight)| {
+            // either update an existing entry or add a new one
+            let existing = list.iter().map(|e| e.beneficiary).collect::<Vec<_>>();
+            let beneficiary = if existing.is_empty() {
+                any_normal_pk().boxed()
+            } else {
+                prop_oneof![any_normal_pk(), prop::sample::select(existing)].boxed()
+            };
+            (
+                Just(pool),
+                Just(payer),
+                Just(list),
+                Just(weight),
+                beneficiary,
+            )
+        })
+        .prop_filter("", |(pool, payer, _, _, beneficiary)| {
+            *payer != pool.admin && *beneficiary != pool.admin && payer != beneficiary
+        })
+        .prop_map(|(pool, payer, list, weight, beneficiary)| {
+            (
+                set_share_correct_keys(&pool, payer, beneficiary),
+                weight,
+                pool,
+                list,
+            )
+        })
+}
+
+proptest! {
+    #[test]
+    fn set_protocol_fee_beneficiary_share_correct_pt(
+        (keys, weight, pool, list) in set_share_correct_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_share_test(
+            set_share_ix(&keys, weight),
+            &set_share_test_accs(&keys, pool, &list),
+            &list,
+            ProtocolFeeBeneficiaryShare::new(*keys.beneficiary(), weight),
+            Option::<ProgramError>::None,
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn set_protocol_fee_beneficiary_share_unauthorized_pt(
+        (keys, weight, pool, list) in set_share_correct_strat(),
+        wrong_admin in any_normal_pk(),
+    ) {
+        prop_assume!(
+            wrong_admin != pool.admin
+                && wrong_admin != *keys.payer()
+                && wrong_admin != *keys.beneficiary()
+        );
+        silence_mollusk_logs();
+        let keys = keys.with_admin(wrong_admin);
+        set_share_test(
+            set_share_ix(&keys, weight),
+            &set_share_test_accs(&keys, pool, &list),
+            &list,
+            ProtocolFeeBeneficiaryShare::new(*keys.beneficiary(), weight),
+            Some(INVALID_ARGUMENT),
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn set_protocol_fee_beneficiary_share_missing_sig_pt(
+        (keys, weight, pool, list) in set_share_correct_strat(),
+    ) {
+        silence_mollusk_logs();
+        let mut ix = set_share_ix(&keys, weight);
+        ix.accounts[SET_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_ACCS_IDX_ADMIN].is_signer = false;
+        set_share_test(
+            ix,
+            &set_share_test_accs(&keys, pool, &list),
+            &list,
+            ProtocolFeeBeneficiaryShare::new(*keys.beneficiary(), weight),
+            Some(MISSING_REQUIRED_SIGNATURE),
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn set_protocol_fee_beneficiary_share_list_full_pt(
+        pool in any_pool_state_v2(Default::default()),
+        payer in any_normal_pk(),
+        beneficiary in any_normal_pk(),
+        list in any_protocol_fee_beneficiary_list(
+            MAX_PROTOCOL_FEE_BENEFICIARIES..=MAX_PROTOCOL_FEE_BENEFICIARIES
+        ),
+        weight: u32,
+    ) {
+        prop_assume!(list.len() == MAX_PROTOCOL_FEE_BENEFICIARIES);
+        prop_assume!(list.iter().all(|e| e.beneficiary != beneficiary));
+        prop_assume!(payer != pool.admin && beneficiary != pool.admin && payer != beneficiary);
+        silence_mollusk_logs();
+        let keys = set_share_correct_keys(&pool, payer, beneficiary);
+        set_share_test(
+            set_share_ix(&keys, weight),
+            &set_share_test_accs(&keys, pool, &list),
+            &list,
+            ProtocolFeeBeneficiaryShare::new(*keys.beneficiary(), weight),
+            Some(Inf1CtlCustomProgErr(Inf1CtlErr::TooManyProtocolFeeBeneficiaries)),
+        );
+    }
+}
+
+// Remove
+
+fn remove_share_ix(keys: &RemoveProtocolFeeBeneficiaryShareIxKeysOwned, idx: u32) -> Instruction {
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts: keys_signer_writable_to_metas(
+            keys.0.iter(),
+            REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_SIGNER.0.iter(),
+            REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_IS_WRITER.0.iter(),
+        ),
+        data: RemoveProtocolFeeBeneficiaryShareIxData::new(idx)
+            .as_buf()
+            .into(),
+    }
+}
+
+fn remove_share_test_accs(
+    keys: &RemoveProtocolFeeBeneficiaryShareIxKeysOwned,
+    pool: PoolStateV2,
+    list: &[ProtocolFeeBeneficiaryShare],
+) -> AccountMap {
+    let accs = NewRemoveProtocolFeeBeneficiaryShareIxAccsBuilder::start()
+        .with_refund_rent_to(mock_sys_acc(LAMPORTS))
+        .with_admin(mock_sys_acc(LAMPORTS))
+        .with_pool_state(pool_state_v2_account(pool))
+        .with_remove(mock_sys_acc(LAMPORTS))
+        .with_protocol_fee_beneficiary_list(protocol_fee_beneficiary_list_account(list))
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+fn remove_share_correct_keys(
+    pool: &PoolStateV2,
+    refund: [u8; 32],
+    remove: [u8; 32],
+) -> RemoveProtocolFeeBeneficiaryShareIxKeysOwned {
+    NewRemoveProtocolFeeBeneficiaryShareIxAccsBuilder::start()
+        .with_refund_rent_to(refund)
+        .with_admin(pool.admin)
+        .with_pool_state(POOL_STATE_ID)
+        .with_remove(remove)
+        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
+        .build()
+}
+
+fn remove_share_test(
+    ix: Instruction,
+    bef: &AccountMap,
+    list_bef: &[ProtocolFeeBeneficiaryShare],
+    idx: usize,
+    expected_err: Option<impl Into<ProgramError>>,
+) {
+    let result = SVM.with(|svm| mollusk_exec(svm, &[ix], bef));
+
+    match expected_err {
+        None => {
+            let res = result.unwrap().resulting_accounts;
+            let mut expected = list_bef.to_vec();
+            expected.remove(idx);
+            let list_acc_aft = &res[&PROTOCOL_FEE_BENEFICIARY_LIST_ID.into()];
+            if expected.is_empty() {
+                assert_eq!(list_acc_aft.owner, SYS_PROG_ID.into());
+                assert!(list_acc_aft.data.is_empty());
+            } else {
+                assert_eq!(list_aft(&res), expected);
+            }
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+        }
+    }
+}
+
+fn remove_share_correct_strat() -> impl Strategy<
+    Value = (
+        RemoveProtocolFeeBeneficiaryShareIxKeysOwned,
+        usize,
+        PoolStateV2,
+        Vec<ProtocolFeeBeneficiaryShare>,
+    ),
+> {
+    (
+        any_normal_pk(),
+        any_pool_state_v2(Default::default()),
+        any_protocol_fee_beneficiary_list(1..=MAX_PROTOCOL_FEE_BENEFICIARIES)
+            .prop_flat_map(list_sample_flat_map),
+    )
+        .prop_map(|(refund, pool, (idx, remove, list))| {
+            (
+                remove_share_correct_keys(&pool, refund, remove.beneficiary),
+                idx,
+                pool,
+                list,
+            )
+        })
+}
+
+proptest! {
+    #[test]
+    fn remove_protocol_fee_beneficiary_share_correct_pt(
+        (keys, idx, pool, list) in remove_share_correct_strat(),
+    ) {
+        silence_mollusk_logs();
+        remove_share_test(
+            remove_share_ix(&keys, idx.try_into().unwrap()),
+            &remove_share_test_accs(&keys, pool, &list),
+            &list,
+            idx,
+            Option::<ProgramError>::None,
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn remove_protocol_fee_beneficiary_share_missing_sig_pt(
+        (keys, idx, pool, list) in remove_share_correct_strat(),
+    ) {
+        silence_mollusk_logs();
+        let mut ix = remove_share_ix(&keys, idx.try_into().unwrap());
+        ix.accounts[REMOVE_PROTOCOL_FEE_BENEFICIARY_SHARE_IX_ACCS_IDX_ADMIN].is_signer = false;
+        remove_share_test(
+            ix,
+            &remove_share_test_accs(&keys, pool, &list),
+            &list,
+            idx,
+            Some(MISSING_REQUIRED_SIGNATURE),
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn remove_protocol_fee_beneficiary_share_idx_oob_pt(
+        (refund, remove, pool) in (any_normal_pk(), any_normal_pk(), any_pool_state_v2(Default::default())),
+        (oob, list) in any_protocol_fee_beneficiary_list(0..=MAX_PROTOCOL_FEE_BENEFICIARIES)
+            .prop_flat_map(|l| (idx_oob(l.len()), Just(l))),
+    ) {
+        silence_mollusk_logs();
+        let keys = remove_share_correct_keys(&pool, refund, remove);
+        remove_share_test(
+            remove_share_ix(&keys, oob.try_into().unwrap()),
+            &remove_share_test_accs(&keys, pool, &list),
+            &list,
+            oob,
+            Some(Inf1CtlCustomProgErr(Inf1CtlErr::InvalidProtocolFeeBeneficiaryIndex)),
+        );
+    }
+}
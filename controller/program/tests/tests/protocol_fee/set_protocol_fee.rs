@@ -6,16 +6,16 @@ use inf1_ctl_jiminy::{
         SET_PROTOCOL_FEE_IX_ACCS_IDX_ADMIN, SET_PROTOCOL_FEE_IX_IS_SIGNER,
         SET_PROTOCOL_FEE_IX_IS_WRITER,
     },
-    keys::POOL_STATE_ID,
+    keys::{POOL_CONFIG_ID, POOL_STATE_ID},
     program_err::Inf1CtlCustomProgErr,
     typedefs::fee_nanos::{FeeNanos, MAX_FEE_NANOS},
     ID,
 };
 use inf1_test_utils::{
     any_ctl_fee_nanos_strat, any_pool_state_v2, assert_diffs_pool_state_v2, assert_jiminy_prog_err,
-    keys_signer_writable_to_metas, mock_sys_acc, mollusk_exec, pool_state_v2_account,
-    pool_state_v2_u8_bools_normal_strat, silence_mollusk_logs, AccountMap, Diff, DiffsPoolStateV2,
-    PoolStateV2FtaStrat,
+    keys_signer_writable_to_metas, mock_sys_acc, mollusk_exec, pool_config_account,
+    pool_state_v2_account, pool_state_v2_u8_bools_normal_strat, silence_mollusk_logs, AccountMap,
+    Diff, DiffsPoolStateV2, PoolStateV2FtaStrat,
 };
 use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT, MISSING_REQUIRED_SIGNATURE};
 use proptest::prelude::*;
@@ -45,6 +45,7 @@ fn set_protocol_fee_ix_test_accs(keys: SetProtocolFeeIxKeysOwned, pool: PoolStat
     let accs = NewSetProtocolFeeIxAccsBuilder::start()
         .with_admin(mock_sys_acc(LAMPORTS))
         .with_pool_state(pool_state_v2_account(pool))
+        .with_pool_config(pool_config_account(None))
         .build();
     keys.0.into_iter().map(Into::into).zip(accs.0).collect()
 }
@@ -101,6 +102,7 @@ fn set_protocol_fee_test_correct_basic() {
     let keys = NewSetProtocolFeeIxAccsBuilder::start()
         .with_admin(admin)
         .with_pool_state(POOL_STATE_ID)
+        .with_pool_config(POOL_CONFIG_ID)
         .build();
     let ret = set_protocol_fee_test(
         set_protocol_fee_ix(keys, new_fee_nanos),
@@ -126,6 +128,7 @@ fn args_ps_with_correct_keys(
         NewSetProtocolFeeIxAccsBuilder::start()
             .with_admin(ps.admin)
             .with_pool_state(POOL_STATE_ID)
+            .with_pool_config(POOL_CONFIG_ID)
             .build(),
         protocol_fee_nanos,
         ps,
@@ -208,6 +211,7 @@ fn unauthorized_strat() -> impl Strategy<Value = (Instruction, AccountMap, u32)>
             NewSetProtocolFeeIxAccsBuilder::start()
                 .with_admin(wrong_admin)
                 .with_pool_state(POOL_STATE_ID)
+                .with_pool_config(POOL_CONFIG_ID)
                 .build(),
             protocol_fee_nanos,
             ps,
@@ -0,0 +1,333 @@
+use inf1_ctl_jiminy::{
+    accounts::{
+        packed_list::PackedList,
+        pool_state::{
+            PoolStateV2, PoolStateV2Addrs, PoolStateV2FtaVals, PoolStateV2Packed, PoolStateV2U64s,
+        },
+        protocol_fee_beneficiary_list::MAX_PROTOCOL_FEE_BENEFICIARIES,
+    },
+    err::Inf1CtlErr,
+    instructions::protocol_fee::withdraw_protocol_fees::split::{
+        NewWithdrawProtocolFeesSplitIxAccsBuilder, WithdrawProtocolFeesSplitIxData,
+        WithdrawProtocolFeesSplitIxKeysOwned, WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_SIGNER,
+        WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_WRITER,
+    },
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_BENEFICIARY_LIST_ID},
+    program_err::Inf1CtlCustomProgErr,
+    svc::InfCalc,
+    typedefs::{
+        pool_sv::PoolSvLamports, protocol_fee_beneficiary_share::ProtocolFeeBeneficiaryShare,
+    },
+};
+use inf1_svc_ag_core::inf1_svc_lido_core::solido_legacy_core::TOKENKEG_PROGRAM;
+use inf1_test_utils::{
+    any_normal_pk, any_pool_state_v2, assert_jiminy_prog_err, get_mint_supply,
+    get_token_account_amount, keys_signer_writable_to_metas, mock_mint_with_prog,
+    mock_token_acc_with_prog, mollusk_exec, n_distinct_normal_pks, pool_state_v2_account,
+    pool_state_v2_u64s_just_lamports_strat, pool_state_v2_u8_bools_normal_strat,
+    pool_sv_lamports_solvent_strat, protocol_fee_beneficiary_list_account, raw_mint, raw_token_acc,
+    silence_mollusk_logs, AccountMap, PoolStateV2FtaStrat, ALL_FIXTURES, INF_MINT,
+};
+use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT, NOT_ENOUGH_ACCOUNT_KEYS};
+use mollusk_svm::Mollusk;
+use proptest::{collection::vec, prelude::*};
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+use crate::common::{header_lookahead_no_lsts, SVM};
+
+const INF_MINT_ID: [u8; 32] = INF_MINT.to_bytes();
+
+/// Safety margin to prevent u64 overflow in sol_to_inf calculation
+/// when protocol_fee_lamports * inf_mint_supply
+const SAFE_MUL_U64_MAX: u64 = u32::MAX as u64;
+
+const INF_MINT_SUPPLY: u64 = 10_000_000_000_000;
+
+fn keys() -> WithdrawProtocolFeesSplitIxKeysOwned {
+    NewWithdrawProtocolFeesSplitIxAccsBuilder::start()
+        .with_pool_state(POOL_STATE_ID)
+        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
+        .with_inf_mint(INF_MINT_ID)
+        .with_token_program(TOKENKEG_PROGRAM)
+        .build()
+}
+
+fn withdraw_protocol_fees_split_ix(withdraw_tos: &[[u8; 32]]) -> Instruction {
+    let mut accounts = keys_signer_writable_to_metas(
+        keys().0.iter(),
+        WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_SIGNER.0.iter(),
+        WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_WRITER.0.iter(),
+    );
+    accounts.extend(
+        withdraw_tos
+            .iter()
+            .map(|pk| AccountMeta::new(Pubkey::new_from_array(*pk), false)),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(inf1_ctl_jiminy::ID),
+        accounts,
+        data: WithdrawProtocolFeesSplitIxData::as_buf().into(),
+    }
+}
+
+/// `withdraw_tos` are `(pubkey, authority)`
+fn withdraw_protocol_fees_split_test_accs(
+    pool: PoolStateV2,
+    list: &[ProtocolFeeBeneficiaryShare],
+    withdraw_tos: &[([u8; 32], [u8; 32])],
+    inf_mint_supply: u64,
+) -> AccountMap {
+    let accs = NewWithdrawProtocolFeesSplitIxAccsBuilder::start()
+        .with_pool_state(pool_state_v2_account(pool))
+        .with_protocol_fee_beneficiary_list(protocol_fee_beneficiary_list_account(list))
+        .with_inf_mint(mock_mint_with_prog(
+            raw_mint(Some(POOL_STATE_ID), None, inf_mint_supply, 9),
+            TOKENKEG_PROGRAM,
+        ))
+        .with_token_program(ALL_FIXTURES.get(&TOKENKEG_PROGRAM.into()).unwrap().clone())
+        .build();
+    keys()
+        .0
+        .into_iter()
+        .map(Into::into)
+        .zip(accs.0)
+        .chain(withdraw_tos.iter().map(|(pk, auth)| {
+            (
+                Pubkey::new_from_array(*pk),
+                mock_token_acc_with_prog(raw_token_acc(INF_MINT_ID, *auth, 0), TOKENKEG_PROGRAM),
+            )
+        }))
+        .collect()
+}
+
+fn withdraw_protocol_fees_split_test(
+    svm: &Mollusk,
+    ix: Instruction,
+    bef: &AccountMap,
+    list: &[ProtocolFeeBeneficiaryShare],
+    expected_err: Option<impl Into<ProgramError>>,
+) {
+    let result = mollusk_exec(svm, std::slice::from_ref(&ix), bef);
+
+    match expected_err {
+        None => {
+            let aft = result.unwrap().resulting_accounts;
+            let [pool_bef, pool_aft] = [bef, &aft].map(|am| {
+                PoolStateV2Packed::of_acc_data(&am[&POOL_STATE_ID.into()].data)
+                    .unwrap()
+                    .into_pool_state_v2()
+            });
+            let pool_bef = header_lookahead_no_lsts(pool_bef, svm.sysvars.clock.slot);
+            let [supply_bef, supply_aft] =
+                [bef, &aft].map(|am| get_mint_supply(&am[&INF_MINT_ID.into()].data));
+
+            let expected_minted = *InfCalc {
+                pool_lamports: PoolSvLamports::from_pool_state_v2(&pool_bef),
+                mint_supply: supply_bef,
+            }
+            .sol_to_inf(pool_bef.protocol_fee_lamports)
+            .unwrap()
+            .start();
+            assert_eq!(supply_aft - supply_bef, expected_minted);
+
+            let withdraw_tos = &ix.accounts[keys().0.len()..];
+            if expected_minted == 0 {
+                withdraw_tos.iter().for_each(|a| {
+                    assert_eq!(get_token_account_amount(&aft[&a.pubkey].data), 0);
+                });
+                return;
+            }
+
+            let expected_shares = PackedList(list).split(expected_minted).unwrap();
+            withdraw_tos
+                .iter()
+                .zip(expected_shares)
+                .for_each(|(a, expected)| {
+                    assert_eq!(get_token_account_amount(&aft[&a.pubkey].data), expected);
+                });
+            assert_eq!(pool_aft.protocol_fee_lamports, 0);
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+        }
+    }
+}
+
+fn basic_pool(protocol_fee_lamports: u64) -> PoolStateV2 {
+    PoolStateV2FtaVals {
+        addrs: PoolStateV2Addrs::default().with_lp_token_mint(INF_MINT_ID),
+        u64s: PoolStateV2U64s::default()
+            .with_protocol_fee_lamports(protocol_fee_lamports)
+            .with_total_sol_value(100_000_000_000_000),
+        ..Default::default()
+    }
+    .into_pool_state_v2()
+}
+
+#[test]
+fn withdraw_protocol_fees_split_correct_basic() {
+    // 69 + to avoid colliding with system prog
+    let [dao, ops, dao_wt, ops_wt] = core::array::from_fn(|i| [69 + u8::try_from(i).unwrap(); 32]);
+    let list = [
+        ProtocolFeeBeneficiaryShare::new(dao, 2),
+        ProtocolFeeBeneficiaryShare::new(ops, 1),
+    ];
+    let pool = basic_pool(1_000_000_000);
+
+    SVM.with(|svm| {
+        withdraw_protocol_fees_split_test(
+            svm,
+            withdraw_protocol_fees_split_ix(&[dao_wt, ops_wt]),
+            &withdraw_protocol_fees_split_test_accs(
+                pool,
+                &list,
+                &[(dao_wt, dao), (ops_wt, ops)],
+                INF_MINT_SUPPLY,
+            ),
+            &list,
+            Option::<ProgramError>::None,
+        );
+    });
+}
+
+#[test]
+fn withdraw_protocol_fees_split_zero_weight_basic() {
+    let [dao, dao_wt] = core::array::from_fn(|i| [69 + u8::try_from(i).unwrap(); 32]);
+    let pool = basic_pool(1_000_000_000);
+
+    SVM.with(|svm| {
+        [vec![], vec![ProtocolFeeBeneficiaryShare::new(dao, 0)]]
+            .into_iter()
+            .for_each(|list| {
+                let withdraw_tos: Vec<_> = list.iter().map(|e| (dao_wt, e.beneficiary)).collect();
+                withdraw_protocol_fees_split_test(
+                    svm,
+                    withdraw_protocol_fees_split_ix(
+                        &withdraw_tos.iter().map(|(pk, _)| *pk).collect::<Vec<_>>(),
+                    ),
+                    &withdraw_protocol_fees_split_test_accs(
+                        pool,
+                        &list,
+                        &withdraw_tos,
+                        INF_MINT_SUPPLY,
+                    ),
+                    &list,
+                    Some(Inf1CtlCustomProgErr(Inf1CtlErr::ZeroValue)),
+                );
+            });
+    });
+}
+
+/// Returns `(pool, list, withdraw_tos, inf_mint_supply)`,
+/// with each withdraw_to's authority set to its entry's beneficiary
+fn correct_strat() -> impl Strategy<
+    Value = (
+        PoolStateV2,
+        Vec<ProtocolFeeBeneficiaryShare>,
+        Vec<([u8; 32], [u8; 32])>,
+        u64,
+    ),
+> {
+    (
+        (0..=SAFE_MUL_U64_MAX).prop_flat_map(pool_sv_lamports_solvent_strat),
+        1..=MAX_PROTOCOL_FEE_BENEFICIARIES,
+        0..=SAFE_MUL_U64_MAX,
+    )
+        .prop_flat_map(|(solvent_u64s, len, inf_mint_supply)| {
+            (
+                any_pool_state_v2(PoolStateV2FtaStrat {
+                    u8_bools: pool_state_v2_u8_bools_normal_strat(),
+                    addrs: PoolStateV2Addrs::default()
+                        .with_lp_token_mint(Some(Just(INF_MINT_ID).boxed())),
+                    u64s: pool_state_v2_u64s_just_lamports_strat(solvent_u64s)
+                        .with_last_release_slot(Some(Just(0).boxed())),
+                    ..Default::default()
+                }),
+                n_distinct_normal_pks::<{ 2 * MAX_PROTOCOL_FEE_BENEFICIARIES }>(),
+                vec(1..=u32::MAX, len),
+                Just(inf_mint_supply),
+            )
+        })
+        .prop_map(|(pool, pks, weights, inf_mint_supply)| {
+            let (bens, wts) = pks.split_at(MAX_PROTOCOL_FEE_BENEFICIARIES);
+            let list = weights
+                .iter()
+                .zip(bens)
+                .map(|(w, b)| ProtocolFeeBeneficiaryShare::new(*b, *w))
+                .collect();
+            let withdraw_tos = wts
+                .iter()
+                .copied()
+                .zip(bens.iter().copied())
+                .take(weights.len())
+                .collect();
+            (pool, list, withdraw_tos, inf_mint_supply)
+        })
+}
+
+proptest! {
+    #[test]
+    fn withdraw_protocol_fees_split_correct_pt(
+        (pool, list, withdraw_tos, inf_mint_supply) in correct_strat(),
+    ) {
+        silence_mollusk_logs();
+        SVM.with(|svm| {
+            withdraw_protocol_fees_split_test(
+                svm,
+                withdraw_protocol_fees_split_ix(
+                    &withdraw_tos.iter().map(|(pk, _)| *pk).collect::<Vec<_>>(),
+                ),
+                &withdraw_protocol_fees_split_test_accs(pool, &list, &withdraw_tos, inf_mint_supply),
+                &list,
+                Option::<ProgramError>::None,
+            );
+        });
+    }
+}
+
+proptest! {
+    #[test]
+    fn withdraw_protocol_fees_split_wrong_auth_pt(
+        (pool, list, mut withdraw_tos, inf_mint_supply) in correct_strat(),
+        wrong_auth in any_normal_pk(),
+        i in any::<prop::sample::Index>(),
+    ) {
+        let i = i.index(withdraw_tos.len());
+        prop_assume!(wrong_auth != withdraw_tos[i].1);
+        withdraw_tos[i].1 = wrong_auth;
+        silence_mollusk_logs();
+        SVM.with(|svm| {
+            withdraw_protocol_fees_split_test(
+                svm,
+                withdraw_protocol_fees_split_ix(
+                    &withdraw_tos.iter().map(|(pk, _)| *pk).collect::<Vec<_>>(),
+                ),
+                &withdraw_protocol_fees_split_test_accs(pool, &list, &withdraw_tos, inf_mint_supply),
+                &list,
+                Some(INVALID_ARGUMENT),
+            );
+        });
+    }
+}
+
+proptest! {
+    #[test]
+    fn withdraw_protocol_fees_split_missing_withdraw_to_pt(
+        (pool, list, mut withdraw_tos, inf_mint_supply) in correct_strat(),
+    ) {
+        withdraw_tos.pop();
+        silence_mollusk_logs();
+        SVM.with(|svm| {
+            withdraw_protocol_fees_split_test(
+                svm,
+                withdraw_protocol_fees_split_ix(
+                    &withdraw_tos.iter().map(|(pk, _)| *pk).collect::<Vec<_>>(),
+                ),
+                &withdraw_protocol_fees_split_test_accs(pool, &list, &withdraw_tos, inf_mint_supply),
+                &list,
+                Some(NOT_ENOUGH_ACCOUNT_KEYS),
+            );
+        });
+    }
+}
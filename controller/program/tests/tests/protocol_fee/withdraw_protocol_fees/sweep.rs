@@ -0,0 +1,282 @@
+use inf1_ctl_jiminy::{
+    accounts::pool_state::{PoolStateV2, PoolStateV2Addrs, PoolStateV2FtaVals},
+    instructions::protocol_fee::withdraw_protocol_fees::sweep::{
+        NewWithdrawProtocolFeesSweepIxAccsBuilder, WithdrawProtocolFeesSweepIxData,
+        WithdrawProtocolFeesSweepIxKeysOwned, WITHDRAW_PROTOCOL_FEES_SWEEP_IX_ACCS_IDX_BENEFICIARY,
+        WITHDRAW_PROTOCOL_FEES_SWEEP_IX_IS_SIGNER, WITHDRAW_PROTOCOL_FEES_SWEEP_IX_IS_WRITER,
+    },
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_ID},
+};
+use inf1_svc_ag_core::inf1_svc_lido_core::solido_legacy_core::TOKENKEG_PROGRAM;
+use inf1_test_utils::{
+    any_normal_pk, any_pool_state_v2, assert_jiminy_prog_err, bals_from_supply,
+    find_protocol_fee_accumulator_ata, get_token_account_amount, keys_signer_writable_to_metas,
+    mock_mint_with_prog, mock_sys_acc, mock_token_acc_with_prog, mollusk_exec,
+    n_distinct_normal_pks, pool_state_v2_account, pool_state_v2_u8_bools_normal_strat, raw_mint,
+    raw_token_acc, silence_mollusk_logs, AccountMap, PoolStateV2FtaStrat, ALL_FIXTURES,
+};
+use jiminy_cpi::program_error::{
+    ProgramError, INVALID_ACCOUNT_DATA, INVALID_ARGUMENT, MISSING_REQUIRED_SIGNATURE,
+};
+use proptest::prelude::*;
+use solana_account::Account;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::common::SVM;
+
+// dont care abt lamports of sys accounts, shouldnt affect anything
+const LAMPORTS: u64 = 1_000_000_000;
+
+fn withdraw_protocol_fees_sweep_ix(keys: &WithdrawProtocolFeesSweepIxKeysOwned) -> Instruction {
+    let accounts = keys_signer_writable_to_metas(
+        keys.0.iter(),
+        WITHDRAW_PROTOCOL_FEES_SWEEP_IX_IS_SIGNER.0.iter(),
+        WITHDRAW_PROTOCOL_FEES_SWEEP_IX_IS_WRITER.0.iter(),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(inf1_ctl_jiminy::ID),
+        accounts,
+        data: WithdrawProtocolFeesSweepIxData::as_buf().into(),
+    }
+}
+
+fn correct_keys(
+    pool: &PoolStateV2,
+    [mint, withdraw_to, refund_rent_to]: [[u8; 32]; 3],
+) -> WithdrawProtocolFeesSweepIxKeysOwned {
+    NewWithdrawProtocolFeesSweepIxAccsBuilder::start()
+        .with_beneficiary(pool.protocol_fee_beneficiary)
+        .with_withdraw_to(withdraw_to)
+        .with_protocol_fee_accumulator(
+            find_protocol_fee_accumulator_ata(&TOKENKEG_PROGRAM, &mint)
+                .0
+                .to_bytes(),
+        )
+        .with_protocol_fee_accumulator_auth(PROTOCOL_FEE_ID)
+        .with_token_program(TOKENKEG_PROGRAM)
+        .with_pool_state(POOL_STATE_ID)
+        .with_lst_mint(mint)
+        .with_refund_rent_to(refund_rent_to)
+        .build()
+}
+
+/// `accum` is the protocol fee accumulator account,
+/// which may not be a token account to simulate an already-closed accumulator
+fn withdraw_protocol_fees_sweep_test_accs(
+    keys: &WithdrawProtocolFeesSweepIxKeysOwned,
+    pool: PoolStateV2,
+    supply: u64,
+    accum: Account,
+    withdraw_to_bal: u64,
+) -> AccountMap {
+    let accs = NewWithdrawProtocolFeesSweepIxAccsBuilder::start()
+        .with_beneficiary(mock_sys_acc(LAMPORTS))
+        .with_withdraw_to(mock_token_acc_with_prog(
+            raw_token_acc(*keys.lst_mint(), [0xee; 32], withdraw_to_bal),
+            TOKENKEG_PROGRAM,
+        ))
+        .with_protocol_fee_accumulator(accum)
+        .with_protocol_fee_accumulator_auth(mock_sys_acc(0))
+        .with_token_program(ALL_FIXTURES.get(&TOKENKEG_PROGRAM.into()).unwrap().clone())
+        .with_pool_state(pool_state_v2_account(pool))
+        // dont care abt mint and freeze auth of the mint for this ix
+        .with_lst_mint(mock_mint_with_prog(
+            raw_mint(None, None, supply, 9),
+            TOKENKEG_PROGRAM,
+        ))
+        .with_refund_rent_to(mock_sys_acc(LAMPORTS))
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+fn accum_acc(mint: [u8; 32], amt: u64) -> Account {
+    mock_token_acc_with_prog(raw_token_acc(mint, PROTOCOL_FEE_ID, amt), TOKENKEG_PROGRAM)
+}
+
+fn withdraw_protocol_fees_sweep_test(
+    keys: &WithdrawProtocolFeesSweepIxKeysOwned,
+    ix: Instruction,
+    bef: &AccountMap,
+    expected_err: Option<impl Into<ProgramError>>,
+) {
+    let result = SVM.with(|svm| mollusk_exec(svm, &[ix], bef));
+
+    match expected_err {
+        None => {
+            let aft = result.unwrap().resulting_accounts;
+            let [accum, wt, refund] = [
+                keys.protocol_fee_accumulator(),
+                keys.withdraw_to(),
+                keys.refund_rent_to(),
+            ]
+            .map(|pk| Pubkey::new_from_array(*pk));
+
+            // entire balance moved to withdraw_to
+            let accum_bal = get_token_account_amount(&bef[&accum].data);
+            assert_eq!(
+                get_token_account_amount(&aft[&wt].data),
+                get_token_account_amount(&bef[&wt].data) + accum_bal
+            );
+
+            // accumulator closed and rent refunded
+            assert_eq!(aft[&accum].lamports, 0);
+            assert_eq!(
+                aft[&refund].lamports,
+                bef[&refund].lamports + bef[&accum].lamports
+            );
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+        }
+    }
+}
+
+fn basic_pool(ben: [u8; 32]) -> PoolStateV2 {
+    PoolStateV2FtaVals {
+        addrs: PoolStateV2Addrs::default().with_protocol_fee_beneficiary(ben),
+        ..Default::default()
+    }
+    .into_pool_state_v2()
+}
+
+#[test]
+fn withdraw_protocol_fees_sweep_correct_basic() {
+    const SUPPLY: u64 = 10_000_000_000_000;
+    const ACCUM_BAL: u64 = 1_000_000_000_000;
+
+    // 69 + to avoid colliding with system prog
+    let [ben, mint, wt, refund] = core::array::from_fn(|i| [69 + u8::try_from(i).unwrap(); 32]);
+    let pool = basic_pool(ben);
+    let keys = correct_keys(&pool, [mint, wt, refund]);
+    withdraw_protocol_fees_sweep_test(
+        &keys,
+        withdraw_protocol_fees_sweep_ix(&keys),
+        &withdraw_protocol_fees_sweep_test_accs(
+            &keys,
+            pool,
+            SUPPLY,
+            accum_acc(mint, ACCUM_BAL),
+            50,
+        ),
+        Option::<ProgramError>::None,
+    );
+}
+
+#[test]
+fn withdraw_protocol_fees_sweep_empty_basic() {
+    let [ben, mint, wt, refund] = core::array::from_fn(|i| [69 + u8::try_from(i).unwrap(); 32]);
+    let pool = basic_pool(ben);
+    let keys = correct_keys(&pool, [mint, wt, refund]);
+    withdraw_protocol_fees_sweep_test(
+        &keys,
+        withdraw_protocol_fees_sweep_ix(&keys),
+        &withdraw_protocol_fees_sweep_test_accs(&keys, pool, 0, accum_acc(mint, 0), 0),
+        Option::<ProgramError>::None,
+    );
+}
+
+#[test]
+fn withdraw_protocol_fees_sweep_already_closed_basic() {
+    let [ben, mint, wt, refund] = core::array::from_fn(|i| [69 + u8::try_from(i).unwrap(); 32]);
+    let pool = basic_pool(ben);
+    let keys = correct_keys(&pool, [mint, wt, refund]);
+    withdraw_protocol_fees_sweep_test(
+        &keys,
+        withdraw_protocol_fees_sweep_ix(&keys),
+        &withdraw_protocol_fees_sweep_test_accs(&keys, pool, 0, mock_sys_acc(0), 0),
+        Some(INVALID_ACCOUNT_DATA),
+    );
+}
+
+fn normal_pool_strat() -> impl Strategy<Value = PoolStateV2> {
+    any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat(),
+        ..Default::default()
+    })
+}
+
+/// `(supply, [accum_bal, withdraw_to_bal])`
+fn bals_strat() -> impl Strategy<Value = (u64, [u64; 2])> {
+    (0..=u64::MAX)
+        .prop_flat_map(|supply| (Just(supply), bals_from_supply(supply)))
+        .prop_map(|(supply, (bals, _rem))| (supply, bals))
+}
+
+proptest! {
+    #[test]
+    fn withdraw_protocol_fees_sweep_correct_pt(
+        pool in normal_pool_strat(),
+        pks in n_distinct_normal_pks(),
+        (supply, [accum_bal, wt_bal]) in bals_strat(),
+    ) {
+        prop_assume!(!pks.contains(&pool.protocol_fee_beneficiary));
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool, pks);
+        withdraw_protocol_fees_sweep_test(
+            &keys,
+            withdraw_protocol_fees_sweep_ix(&keys),
+            &withdraw_protocol_fees_sweep_test_accs(
+                &keys,
+                pool,
+                supply,
+                accum_acc(*keys.lst_mint(), accum_bal),
+                wt_bal,
+            ),
+            Option::<ProgramError>::None,
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn withdraw_protocol_fees_sweep_unauthorized_pt(
+        pool in normal_pool_strat(),
+        pks in n_distinct_normal_pks(),
+        unauth in any_normal_pk(),
+        (supply, [accum_bal, wt_bal]) in bals_strat(),
+    ) {
+        prop_assume!(unauth != pool.protocol_fee_beneficiary && !pks.contains(&unauth));
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool, pks).with_beneficiary(unauth);
+        withdraw_protocol_fees_sweep_test(
+            &keys,
+            withdraw_protocol_fees_sweep_ix(&keys),
+            &withdraw_protocol_fees_sweep_test_accs(
+                &keys,
+                pool,
+                supply,
+                accum_acc(*keys.lst_mint(), accum_bal),
+                wt_bal,
+            ),
+            Some(INVALID_ARGUMENT),
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn withdraw_protocol_fees_sweep_missing_sig_pt(
+        pool in normal_pool_strat(),
+        pks in n_distinct_normal_pks(),
+        (supply, [accum_bal, wt_bal]) in bals_strat(),
+    ) {
+        prop_assume!(!pks.contains(&pool.protocol_fee_beneficiary));
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool, pks);
+        let mut ix = withdraw_protocol_fees_sweep_ix(&keys);
+        ix.accounts[WITHDRAW_PROTOCOL_FEES_SWEEP_IX_ACCS_IDX_BENEFICIARY].is_signer = false;
+        withdraw_protocol_fees_sweep_test(
+            &keys,
+            ix,
+            &withdraw_protocol_fees_sweep_test_accs(
+                &keys,
+                pool,
+                supply,
+                accum_acc(*keys.lst_mint(), accum_bal),
+                wt_bal,
+            ),
+            Some(MISSING_REQUIRED_SIGNATURE),
+        );
+    }
+}
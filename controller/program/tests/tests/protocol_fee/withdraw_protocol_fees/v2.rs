@@ -11,10 +11,12 @@ use inf1_ctl_jiminy::{
         WITHDRAW_PROTOCOL_FEES_V2_IX_ACCS_IDX_WITHDRAW_TO, WITHDRAW_PROTOCOL_FEES_V2_IX_IS_SIGNER,
         WITHDRAW_PROTOCOL_FEES_V2_IX_IS_WRITER,
     },
-    keys::POOL_STATE_ID,
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_BENEFICIARY_LIST_ID},
     program_err::Inf1CtlCustomProgErr,
     svc::InfCalc,
-    typedefs::pool_sv::PoolSvLamports,
+    typedefs::{
+        pool_sv::PoolSvLamports, protocol_fee_beneficiary_share::ProtocolFeeBeneficiaryShare,
+    },
 };
 use inf1_svc_ag_core::inf1_svc_lido_core::solido_legacy_core::TOKENKEG_PROGRAM;
 use inf1_test_utils::{
@@ -22,9 +24,10 @@ use inf1_test_utils::{
     assert_jiminy_prog_err, assert_token_acc_diffs, keys_signer_writable_to_metas,
     mock_mint_with_prog, mock_sys_acc, mock_token_acc_with_prog, mollusk_exec,
     pool_state_v2_account, pool_state_v2_u64s_just_lamports_strat,
-    pool_state_v2_u8_bools_normal_strat, pool_sv_lamports_solvent_strat, raw_mint, raw_token_acc,
-    silence_mollusk_logs, token_acc_bal_diff_changed, AccountMap, Diff, DiffsPoolStateV2,
-    PoolStateV2FtaStrat, ALL_FIXTURES, INF_MINT,
+    pool_state_v2_u8_bools_normal_strat, pool_sv_lamports_solvent_strat,
+    protocol_fee_beneficiary_list_account, raw_mint, raw_token_acc, silence_mollusk_logs,
+    token_acc_bal_diff_changed, AccountMap, Diff, DiffsPoolStateV2, PoolStateV2FtaStrat,
+    ALL_FIXTURES, INF_MINT,
 };
 use mollusk_svm::Mollusk;
 use proptest::prelude::*;
@@ -85,6 +88,8 @@ fn withdraw_protocol_fees_v2_test_accs(
             TOKENKEG_PROGRAM,
         ))
         .with_token_program(ALL_FIXTURES.get(&TOKENKEG_PROGRAM.into()).unwrap().clone())
+        // not yet created
+        .with_protocol_fee_beneficiary_list(mock_sys_acc(0))
         .build();
 
     keys.0.into_iter().map(Into::into).zip(accs.0).collect()
@@ -219,6 +224,7 @@ fn withdraw_protocol_fees_v2_correct_basic() {
         .with_withdraw_to(wt)
         .with_inf_mint(INF_MINT_ID)
         .with_token_program(TOKENKEG_PROGRAM)
+        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
         .build();
 
     SVM.with(|svm| {
@@ -271,6 +277,7 @@ fn correct_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
                         .with_withdraw_to(wt_pk)
                         .with_inf_mint(INF_MINT_ID)
                         .with_token_program(TOKENKEG_PROGRAM)
+                        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
                         .build();
 
                     (keys, ps, inf_mint_supply, withdraw_to_balance)
@@ -318,6 +325,7 @@ fn zero_fees_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
                         .with_withdraw_to(wt_pk)
                         .with_inf_mint(INF_MINT_ID)
                         .with_token_program(TOKENKEG_PROGRAM)
+                        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
                         .build();
 
                     (keys, ps, inf_mint_supply, withdraw_to_balance)
@@ -375,6 +383,7 @@ fn unauthorized_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
                             .with_withdraw_to(wt_pk)
                             .with_inf_mint(INF_MINT_ID)
                             .with_token_program(TOKENKEG_PROGRAM)
+                            .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
                             .build();
 
                         (keys, ps, inf_mint_supply, withdraw_to_balance)
@@ -442,6 +451,7 @@ fn disabled_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
                         .with_withdraw_to(wt_pk)
                         .with_inf_mint(INF_MINT_ID)
                         .with_token_program(TOKENKEG_PROGRAM)
+                        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
                         .build();
 
                     (keys, ps, inf_mint_supply, withdraw_to_balance)
@@ -489,6 +499,7 @@ fn rebalancing_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
                         .with_withdraw_to(wt_pk)
                         .with_inf_mint(INF_MINT_ID)
                         .with_token_program(TOKENKEG_PROGRAM)
+                        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
                         .build();
 
                     (keys, ps, inf_mint_supply, withdraw_to_balance)
@@ -538,6 +549,7 @@ fn wrong_token_prog_strat() -> impl Strategy<Value = (Instruction, AccountMap)>
                             .with_withdraw_to(wt_pk)
                             .with_inf_mint(INF_MINT_ID)
                             .with_token_program(bad_token_prog)
+                            .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
                             .build();
 
                         (keys, ps, inf_mint_supply, withdraw_to_balance)
@@ -588,6 +600,7 @@ fn wrong_mint_strat() -> impl Strategy<Value = (Instruction, AccountMap)> {
                         .with_withdraw_to(wt_pk)
                         .with_inf_mint(INF_MINT_ID)
                         .with_token_program(TOKENKEG_PROGRAM)
+                        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
                         .build();
 
                     (keys, ps, inf_mint_supply, withdraw_to_balance)
@@ -607,3 +620,31 @@ proptest! {
       });
   }
 }
+
+fn beneficiary_list_strat() -> impl Strategy<Value = Vec<ProtocolFeeBeneficiaryShare>> {
+    proptest::collection::vec(
+        (any_normal_pk(), any::<u32>()).prop_map(|(b, w)| ProtocolFeeBeneficiaryShare::new(b, w)),
+        0..=4,
+    )
+}
+
+proptest! {
+  #[test]
+  fn withdraw_protocol_fees_v2_beneficiary_list_pt(
+      (ix, mut bef) in correct_strat(),
+      list in beneficiary_list_strat(),
+  ) {
+      silence_mollusk_logs();
+      // created list PDA, empty or not
+      bef.insert(
+          PROTOCOL_FEE_BENEFICIARY_LIST_ID.into(),
+          protocol_fee_beneficiary_list_account(&list),
+      );
+      let expected_err = (!list.is_empty()).then_some(Inf1CtlCustomProgErr(
+          Inf1CtlErr::ProtocolFeeBeneficiaryListNotEmpty,
+      ));
+      SVM.with(|svm| {
+          withdraw_protocol_fees_v2_test(svm, ix, &bef, expected_err);
+      });
+  }
+}
@@ -2,7 +2,10 @@ use std::{iter::once, ops::Neg};
 
 use expect_test::expect;
 use inf1_ctl_jiminy::{
-    accounts::pool_state::{PoolStateV2Packed, PoolStateV2U64s, VerPoolState},
+    accounts::{
+        pool_config::{PoolConfig, PoolConfigPacked},
+        pool_state::{PoolStateV2, PoolStateV2Packed, PoolStateV2U64s, VerPoolState},
+    },
     err::Inf1CtlErr,
     instructions::rebalance::{
         end::{EndRebalanceIxData, EndRebalanceIxPreKeysOwned},
@@ -11,12 +14,13 @@ use inf1_ctl_jiminy::{
             StartRebalanceIxPreKeysOwned, START_REBALANCE_IX_PRE_ACCS_IDX_INP_POOL_RESERVES,
             START_REBALANCE_IX_PRE_ACCS_IDX_LST_STATE_LIST,
             START_REBALANCE_IX_PRE_ACCS_IDX_OUT_POOL_RESERVES,
+            START_REBALANCE_IX_PRE_ACCS_IDX_POOL_CONFIG,
             START_REBALANCE_IX_PRE_ACCS_IDX_POOL_STATE,
             START_REBALANCE_IX_PRE_ACCS_IDX_REBALANCE_AUTH,
             START_REBALANCE_IX_PRE_ACCS_IDX_REBALANCE_RECORD,
         },
     },
-    keys::{INSTRUCTIONS_SYSVAR_ID, REBALANCE_RECORD_ID},
+    keys::{INSTRUCTIONS_SYSVAR_ID, POOL_CONFIG_ID, REBALANCE_RECORD_ID},
     program_err::Inf1CtlCustomProgErr,
     typedefs::u8bool::U8Bool,
 };
@@ -38,8 +42,8 @@ use inf1_test_utils::{
     acc_bef_aft, assert_diffs_lst_state_list, assert_diffs_pool_state_v2, assert_jiminy_prog_err,
     assert_token_acc_diffs, fill_mock_prog_accs, get_lst_state_list, get_token_account_amount,
     jupsol_fixture_svc_suf_accs, keys_signer_writable_to_metas, mock_instructions_sysvar,
-    mock_sys_acc, mock_token_acc, mollusk_exec, pool_state_v2_account, raw_token_acc,
-    token_acc_bal_diff_changed, AccountMap, Diff, DiffsPoolStateV2, KeyedUiAccount,
+    mock_sys_acc, mock_token_acc, mollusk_exec, pool_config_account, pool_state_v2_account,
+    raw_token_acc, token_acc_bal_diff_changed, AccountMap, Diff, DiffsPoolStateV2, KeyedUiAccount,
     LstStateListChanges, JUPSOL_FIXTURE_LST_IDX, WSOL_FIXTURE_LST_IDX,
 };
 use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT, MISSING_REQUIRED_SIGNATURE};
@@ -108,13 +112,14 @@ fn jupsol_o_wsol_i_prefix_fixtures() -> StartRebalanceIxPreAccs<(Pubkey, Account
             .with_rebalance_auth("wsol-mint")
             .with_rebalance_record("wsol-mint")
             .with_system_program("wsol-mint")
+            .with_pool_config("wsol-mint")
             .build()
             .0
             .map(|n| KeyedUiAccount::from_test_fixtures_json(n).into_keyed_account()),
     );
 
-    // Rebalance does not perform migration, but our fixtures are PoolStateV1,
-    // so just patch it into v2 here
+    // Rebalance does not perform migration, but our pool state fixture is the
+    // 240-byte v2 account from before `min_fee_nanos`, so just extend it here
     let ps = accs.pool_state();
     let ps_addr = ps.0;
     let ps_acc = pool_state_v2_account(
@@ -153,6 +158,7 @@ fn jupsol_o_wsol_i_fixture_accs() -> (StartAccs, AccountMap) {
 /// - instructions sysvar is empty and must be set after
 /// - rebalance auth set to mock_sys_acc of pool_state fixture
 /// - withdraw_to set to empty token acc owned by rebalance auth
+/// - pool config set to not yet created
 fn replace_fixture_fillers(
     accs: StartRebalanceIxPreAccs<(Pubkey, Account)>,
 ) -> StartRebalanceIxPreAccs<(Pubkey, Account)> {
@@ -178,6 +184,7 @@ fn replace_fixture_fillers(
         ))
         .with_rebalance_record((REBALANCE_RECORD_ID.into(), Default::default()))
         .with_system_program(keyed_account_for_system_program())
+        .with_pool_config((POOL_CONFIG_ID.into(), pool_config_account(None)))
 }
 
 fn to_start_ix(start: &StartArgs) -> Instruction {
@@ -336,18 +343,26 @@ fn assert_correct_rebalance(
         .into_iter()
         .for_each(|p| assert!(!U8Bool(&p.is_rebalancing).to_bool()));
 
+    let config_bef = fixture_pool_config(
+        bef,
+        &start_ix.accounts[START_REBALANCE_IX_PRE_ACCS_IDX_POOL_CONFIG].pubkey,
+    );
     let [tsv_bef, tsv_aft] = [ps_bef, ps_aft].map(|ps| ps.total_sol_value);
-    assert!(tsv_aft >= tsv_bef, "{tsv_aft} < {tsv_bef}",);
-    let tsv_inc = ps_aft.total_sol_value - ps_bef.total_sol_value;
+    let max_loss = ps_bef.rebalance_max_loss(config_bef.rebalance_max_loss_lamports);
+    assert!(
+        tsv_aft + max_loss >= tsv_bef,
+        "{tsv_aft} + {max_loss} < {tsv_bef}",
+    );
+    let tsv_inc = i128::from(tsv_aft) - i128::from(tsv_bef);
 
     let ps_diffs = DiffsPoolStateV2 {
         u64s: PoolStateV2U64s::default()
             .with_total_sol_value(Diff::Changed(tsv_bef, tsv_aft))
-            // if rebalance resulted in yield,
-            // it should inc withheld_lamports
+            // if rebalance resulted in yield, it should inc withheld_lamports.
+            // if it resulted in a tolerated loss, it should dec withheld_lamports
             .with_withheld_lamports(Diff::Changed(
                 ps_bef.withheld_lamports,
-                ps_bef.withheld_lamports + tsv_inc,
+                u64::try_from(i128::from(ps_bef.withheld_lamports) + tsv_inc).unwrap(),
             )),
         ..Default::default()
     };
@@ -366,7 +381,7 @@ fn assert_correct_rebalance(
 
     assert_eq!(
         inp_svc + out_svc,
-        i128::from(tsv_inc),
+        tsv_inc,
         "{} - {} != {}",
         inp_svc,
         out_svc.neg(),
@@ -374,17 +389,68 @@ fn assert_correct_rebalance(
     );
 }
 
-#[test]
-fn rebal_jupsol_o_wsol_i_fixture_basic() {
-    const AMOUNT: u64 = 100_000;
+fn fixture_pool_state(am: &AccountMap, start_accs: &StartAccs) -> PoolStateV2 {
+    PoolStateV2Packed::of_acc_data(&am[&(*start_accs.ix_prefix.pool_state()).into()].data)
+        .unwrap()
+        .into_pool_state_v2()
+}
+
+/// Default if the pool config has not been created
+fn fixture_pool_config(am: &AccountMap, addr: &Pubkey) -> PoolConfig {
+    match am[addr].data.as_slice() {
+        [] => PoolConfig::default(),
+        data => PoolConfigPacked::of_acc_data(data)
+            .unwrap()
+            .into_pool_config(),
+    }
+}
+
+/// Instructions and accounts before of a successful rebalance of `amount` jupsol out
+/// for wsol in, with a single transfer of the quoted wsol in between.
+///
+/// Returns (ixs, bef, out_calc, inp_calc, quote)
+pub(crate) fn jupsol_o_wsol_i_fixture_basic_inp(
+    amount: u64,
+) -> (
+    Vec<Instruction>,
+    AccountMap,
+    SvcCalcAg,
+    SvcCalcAg,
+    RebalanceQuote,
+) {
+    jupsol_o_wsol_i_fixture_inp(amount, |ps| ps, None)
+}
+
+/// Same as [`jupsol_o_wsol_i_fixture_basic_inp`], but with the fixture pool state
+/// modified by `map_ps` and the pool config set to `config` before quoting
+fn jupsol_o_wsol_i_fixture_inp(
+    amount: u64,
+    map_ps: impl FnOnce(PoolStateV2) -> PoolStateV2,
+    config: Option<&PoolConfig>,
+) -> (
+    Vec<Instruction>,
+    AccountMap,
+    SvcCalcAg,
+    SvcCalcAg,
+    RebalanceQuote,
+) {
     const CURR_EPOCH: u64 = 0;
 
-    let (start_accs, am) = jupsol_o_wsol_i_fixture_accs();
+    let (start_accs, mut am) = jupsol_o_wsol_i_fixture_accs();
+    let ps = map_ps(fixture_pool_state(&am, &start_accs));
+    am.insert(
+        (*start_accs.ix_prefix.pool_state()).into(),
+        pool_state_v2_account(ps),
+    );
+    am.insert(
+        (*start_accs.ix_prefix.pool_config()).into(),
+        pool_config_account(config),
+    );
 
     let start_args = StartArgs {
         out_lst_index: JUPSOL_FIXTURE_LST_IDX.try_into().unwrap(),
         inp_lst_index: WSOL_FIXTURE_LST_IDX.try_into().unwrap(),
-        amount: AMOUNT,
+        amount,
         min_starting_out_lst: 0,
         max_starting_inp_lst: u64::MAX,
         accs: start_accs,
@@ -398,14 +464,15 @@ fn rebal_jupsol_o_wsol_i_fixture_basic() {
     ]
     .map(|a| get_token_account_amount(&am[&(*a).into()].data));
 
-    let RebalanceQuote { inp, out, .. } = quote_rebalance_exact_out(RebalanceQuoteArgs {
-        amt: AMOUNT,
+    let quote = quote_rebalance_exact_out(RebalanceQuoteArgs {
+        amt: amount,
         inp_reserves,
         out_reserves,
         inp_mint: *start_accs.ix_prefix.inp_lst_mint(),
         out_mint: *start_accs.ix_prefix.out_lst_mint(),
         inp_calc,
         out_calc,
+        max_loss: ps.rebalance_max_loss(config.map_or(0, |c| c.rebalance_max_loss_lamports)),
     })
     .unwrap();
 
@@ -417,7 +484,7 @@ fn rebal_jupsol_o_wsol_i_fixture_basic() {
                 .with_dst(*start_accs.ix_prefix.inp_pool_reserves())
                 .with_src(DONOR_TOKEN_ACC_ADDR.to_bytes())
                 .build(),
-            inp,
+            quote.inp,
         )],
         &Some(EndAccs::from_start(start_accs)),
         [
@@ -427,13 +494,23 @@ fn rebal_jupsol_o_wsol_i_fixture_basic() {
                 mock_token_acc(raw_token_acc(
                     *start_accs.ix_prefix.inp_lst_mint(),
                     *start_accs.ix_prefix.rebalance_auth(),
-                    inp,
+                    quote.inp,
                 )),
             ))
             .collect(),
         ],
     );
 
+    (ixs, bef, out_calc, inp_calc, quote)
+}
+
+#[test]
+fn rebal_jupsol_o_wsol_i_fixture_basic() {
+    const AMOUNT: u64 = 100_000;
+
+    let (ixs, bef, out_calc, inp_calc, RebalanceQuote { inp, out, .. }) =
+        jupsol_o_wsol_i_fixture_basic_inp(AMOUNT);
+
     SVM.with(|svm| rebalance_test(svm, &bef, &ixs, &out_calc, &inp_calc, None::<ProgramError>));
 
     expect![[r#"
@@ -445,6 +522,69 @@ fn rebal_jupsol_o_wsol_i_fixture_basic() {
     .assert_debug_eq(&(out, inp));
 }
 
+const LOSS_TEST_WITHHELD_LAMPORTS: u64 = 1_000_000_000;
+const LOSS_TEST_MAX_LOSS_LAMPORTS: u64 = 1_000;
+
+const LOSS_TEST_CONFIG: PoolConfig = PoolConfig {
+    admin_action_delay_slots: 0,
+    rebalance_max_loss_lamports: LOSS_TEST_MAX_LOSS_LAMPORTS,
+};
+
+fn with_loss_test_withheld(ps: PoolStateV2) -> PoolStateV2 {
+    PoolStateV2 {
+        withheld_lamports: LOSS_TEST_WITHHELD_LAMPORTS,
+        ..ps
+    }
+}
+
+#[test]
+fn rebal_jupsol_o_wsol_i_fixture_loss_absorbed() {
+    const AMOUNT: u64 = 100_000;
+
+    let (ixs, bef, out_calc, inp_calc, RebalanceQuote { inp, out, .. }) =
+        jupsol_o_wsol_i_fixture_inp(AMOUNT, with_loss_test_withheld, Some(&LOSS_TEST_CONFIG));
+
+    SVM.with(|svm| rebalance_test(svm, &bef, &ixs, &out_calc, &inp_calc, None::<ProgramError>));
+
+    // LOSS_TEST_MAX_LOSS_LAMPORTS less wsol than basic case
+    expect![[r#"
+        (
+            100000,
+            110331,
+        )
+    "#]]
+    .assert_debug_eq(&(out, inp));
+}
+
+#[test]
+fn rebal_jupsol_o_wsol_i_fixture_loss_exceeds_tolerance() {
+    const AMOUNT: u64 = 100_000;
+
+    let (ixs, mut bef, out_calc, inp_calc, _) =
+        jupsol_o_wsol_i_fixture_inp(AMOUNT, with_loss_test_withheld, Some(&LOSS_TEST_CONFIG));
+
+    // quote assumed a loss tolerance that the pool no longer has
+    let config_addr = ixs[0].accounts[START_REBALANCE_IX_PRE_ACCS_IDX_POOL_CONFIG].pubkey;
+    bef.insert(
+        config_addr,
+        pool_config_account(Some(&PoolConfig {
+            rebalance_max_loss_lamports: 0,
+            ..LOSS_TEST_CONFIG
+        })),
+    );
+
+    SVM.with(|svm| {
+        rebalance_test(
+            svm,
+            &bef,
+            &ixs,
+            &out_calc,
+            &inp_calc,
+            Some(Inf1CtlCustomProgErr(Inf1CtlErr::PoolWouldLoseSolValue)),
+        )
+    });
+}
+
 #[test]
 fn rebal_jupsol_o_wsol_i_fixture_missing_end() {
     const AMOUNT: u64 = 100_000;
@@ -601,6 +741,10 @@ fn rebal_jupsol_o_wsol_i_fixture_insufficient_transfer() {
         out_mint: *start_accs.ix_prefix.out_lst_mint(),
         inp_calc,
         out_calc,
+        max_loss: fixture_pool_state(&am, &start_accs).rebalance_max_loss(
+            fixture_pool_config(&am, &(*start_accs.ix_prefix.pool_config()).into())
+                .rebalance_max_loss_lamports,
+        ),
     })
     .unwrap();
     // transfer 1 less than required
@@ -928,13 +1072,14 @@ fn wsol_o_wsol_i_prefix_fixtures() -> StartRebalanceIxPreAccs<(Pubkey, Account)>
             .with_rebalance_auth("wsol-mint")
             .with_rebalance_record("wsol-mint")
             .with_system_program("wsol-mint")
+            .with_pool_config("wsol-mint")
             .build()
             .0
             .map(|n| KeyedUiAccount::from_test_fixtures_json(n).into_keyed_account()),
     );
 
-    // Rebalance does not perform migration, but our fixtures are PoolStateV1,
-    // so just patch it into v2 here
+    // Rebalance does not perform migration, but our pool state fixture is the
+    // 240-byte v2 account from before `min_fee_nanos`, so just extend it here
     let ps = accs.pool_state();
     let ps_addr = ps.0;
     let ps_acc = pool_state_v2_account(
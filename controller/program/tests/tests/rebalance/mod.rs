@@ -1,3 +1,4 @@
 mod set_rebal_auth;
+mod set_rebal_max_loss;
 
-mod chain;
+pub(crate) mod chain;
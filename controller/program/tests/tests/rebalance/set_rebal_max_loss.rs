@@ -0,0 +1,328 @@
+use inf1_ctl_jiminy::{
+    accounts::{
+        pool_config::{PoolConfig, PoolConfigPacked},
+        pool_state::{PoolStateV2, PoolStateV2Addrs, PoolStateV2FtaVals},
+    },
+    err::Inf1CtlErr,
+    instructions::rebalance::set_rebal_max_loss::{
+        NewSetRebalMaxLossIxAccsBuilder, SetRebalMaxLossIxData, SetRebalMaxLossIxKeysOwned,
+        SET_REBAL_MAX_LOSS_IX_ACCS_IDX_ADMIN, SET_REBAL_MAX_LOSS_IX_IS_SIGNER,
+        SET_REBAL_MAX_LOSS_IX_IS_WRITER,
+    },
+    keys::{POOL_CONFIG_ID, POOL_STATE_ID, SYS_PROG_ID},
+    program_err::Inf1CtlCustomProgErr,
+    ID,
+};
+use inf1_test_utils::{
+    any_pool_state_v2, assert_jiminy_prog_err, keys_signer_writable_to_metas, mock_sys_acc,
+    mollusk_exec, pool_config_account, pool_state_v2_account, pool_state_v2_u8_bools_normal_strat,
+    silence_mollusk_logs, AccountMap, PoolStateV2FtaStrat,
+};
+use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT, MISSING_REQUIRED_SIGNATURE};
+use mollusk_svm::program::keyed_account_for_system_program;
+use proptest::prelude::*;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::common::SVM;
+
+const PAYER: [u8; 32] = [2; 32];
+
+fn set_rebal_max_loss_ix(
+    keys: SetRebalMaxLossIxKeysOwned,
+    rebalance_max_loss_lamports: u64,
+) -> Instruction {
+    let accounts = keys_signer_writable_to_metas(
+        keys.0.iter(),
+        SET_REBAL_MAX_LOSS_IX_IS_SIGNER.0.iter(),
+        SET_REBAL_MAX_LOSS_IX_IS_WRITER.0.iter(),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts,
+        data: SetRebalMaxLossIxData::new(rebalance_max_loss_lamports)
+            .as_buf()
+            .into(),
+    }
+}
+
+fn set_rebal_max_loss_keys(admin: [u8; 32]) -> SetRebalMaxLossIxKeysOwned {
+    NewSetRebalMaxLossIxAccsBuilder::start()
+        .with_admin(admin)
+        .with_payer(PAYER)
+        .with_pool_state(POOL_STATE_ID)
+        .with_pool_config(POOL_CONFIG_ID)
+        .with_system_program(SYS_PROG_ID)
+        .build()
+}
+
+fn set_rebal_max_loss_ix_test_accs(
+    keys: SetRebalMaxLossIxKeysOwned,
+    pool: PoolStateV2,
+    config: Option<&PoolConfig>,
+) -> AccountMap {
+    // dont care abt lamports, shouldnt affect anything
+    const LAMPORTS: u64 = 1_000_000_000;
+    let accs = NewSetRebalMaxLossIxAccsBuilder::start()
+        .with_admin(mock_sys_acc(LAMPORTS))
+        .with_payer(mock_sys_acc(LAMPORTS))
+        .with_pool_state(pool_state_v2_account(pool))
+        .with_pool_config(pool_config_account(config))
+        .with_system_program(keyed_account_for_system_program().1)
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+/// Default if the pool config has not been created
+fn pool_config_of(am: &AccountMap) -> PoolConfig {
+    match am[&POOL_CONFIG_ID.into()].data.as_slice() {
+        [] => PoolConfig::default(),
+        data => PoolConfigPacked::of_acc_data(data)
+            .unwrap()
+            .into_pool_config(),
+    }
+}
+
+/// Returns `pool_config` at the end of ix
+fn set_rebal_max_loss_test(
+    ix: Instruction,
+    bef: &AccountMap,
+    rebalance_max_loss_lamports: u64,
+    expected_err: Option<impl Into<ProgramError>>,
+) -> PoolConfig {
+    let result = SVM.with(|svm| mollusk_exec(svm, &[ix], bef));
+
+    let config_bef = pool_config_of(bef);
+
+    match expected_err {
+        None => {
+            let resulting_accounts = result.unwrap().resulting_accounts;
+            let config_acc = &resulting_accounts[&POOL_CONFIG_ID.into()];
+            assert_eq!(config_acc.owner, Pubkey::new_from_array(ID));
+
+            // only rebalance_max_loss_lamports should change
+            let config_aft = pool_config_of(&resulting_accounts);
+            assert_eq!(
+                config_aft,
+                PoolConfig {
+                    rebalance_max_loss_lamports,
+                    ..config_bef
+                }
+            );
+
+            // pool state should be untouched
+            assert_eq!(
+                resulting_accounts[&POOL_STATE_ID.into()].data,
+                bef[&POOL_STATE_ID.into()].data
+            );
+            config_aft
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+            config_bef
+        }
+    }
+}
+
+#[test]
+fn set_rebal_max_loss_test_create_basic() {
+    // more than u32::MAX
+    const NEW_MAX_LOSS: u64 = 10_000_000_000;
+
+    let admin = [69u8; 32];
+    let pool = PoolStateV2FtaVals {
+        addrs: PoolStateV2Addrs::default().with_admin(admin),
+        ..Default::default()
+    }
+    .into_pool_state_v2();
+    let keys = set_rebal_max_loss_keys(admin);
+    let ret = set_rebal_max_loss_test(
+        set_rebal_max_loss_ix(keys, NEW_MAX_LOSS),
+        &set_rebal_max_loss_ix_test_accs(keys, pool, None),
+        NEW_MAX_LOSS,
+        Option::<ProgramError>::None,
+    );
+    assert_eq!(ret.rebalance_max_loss_lamports, NEW_MAX_LOSS);
+}
+
+#[test]
+fn set_rebal_max_loss_test_update_basic() {
+    const CURR: PoolConfig = PoolConfig {
+        admin_action_delay_slots: 216_000,
+        rebalance_max_loss_lamports: 1_000,
+    };
+    const NEW_MAX_LOSS: u64 = 2_000;
+
+    let admin = [69u8; 32];
+    let pool = PoolStateV2FtaVals {
+        addrs: PoolStateV2Addrs::default().with_admin(admin),
+        ..Default::default()
+    }
+    .into_pool_state_v2();
+    let keys = set_rebal_max_loss_keys(admin);
+    let ret = set_rebal_max_loss_test(
+        set_rebal_max_loss_ix(keys, NEW_MAX_LOSS),
+        &set_rebal_max_loss_ix_test_accs(keys, pool, Some(&CURR)),
+        NEW_MAX_LOSS,
+        Option::<ProgramError>::None,
+    );
+    assert_eq!(
+        ret,
+        PoolConfig {
+            rebalance_max_loss_lamports: NEW_MAX_LOSS,
+            ..CURR
+        }
+    );
+}
+
+fn any_pool_config_opt() -> impl Strategy<Value = Option<PoolConfig>> {
+    proptest::option::of((any::<u64>(), any::<u64>()).prop_map(
+        |(admin_action_delay_slots, rebalance_max_loss_lamports)| PoolConfig {
+            admin_action_delay_slots,
+            rebalance_max_loss_lamports,
+        },
+    ))
+}
+
+fn to_test_inp(
+    (k, rebalance_max_loss_lamports, ps, config): (
+        SetRebalMaxLossIxKeysOwned,
+        u64,
+        PoolStateV2,
+        Option<PoolConfig>,
+    ),
+) -> (Instruction, AccountMap, u64) {
+    (
+        set_rebal_max_loss_ix(k, rebalance_max_loss_lamports),
+        set_rebal_max_loss_ix_test_accs(k, ps, config.as_ref()),
+        rebalance_max_loss_lamports,
+    )
+}
+
+fn ps_strat_to_test_inp(
+    ps: impl Strategy<Value = PoolStateV2>,
+) -> impl Strategy<Value = (Instruction, AccountMap, u64)> {
+    (any::<u64>(), ps, any_pool_config_opt())
+        .prop_map(|(rebalance_max_loss_lamports, ps, config)| {
+            (
+                set_rebal_max_loss_keys(ps.admin),
+                rebalance_max_loss_lamports,
+                ps,
+                config,
+            )
+        })
+        .prop_map(to_test_inp)
+}
+
+fn correct_strat() -> impl Strategy<Value = (Instruction, AccountMap, u64)> {
+    ps_strat_to_test_inp(any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat(),
+        ..Default::default()
+    }))
+}
+
+proptest! {
+    #[test]
+    fn set_rebal_max_loss_correct_pt(
+        (ix, bef, rebalance_max_loss_lamports) in correct_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_rebal_max_loss_test(ix, &bef, rebalance_max_loss_lamports, Option::<ProgramError>::None);
+    }
+}
+
+fn unauthorized_strat() -> impl Strategy<Value = (Instruction, AccountMap, u64)> {
+    any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat(),
+        ..Default::default()
+    })
+    .prop_flat_map(|ps| {
+        (
+            any::<[u8; 32]>().prop_filter("", move |pk| *pk != ps.admin),
+            any::<u64>(),
+            Just(ps),
+            any_pool_config_opt(),
+        )
+    })
+    .prop_map(|(wrong_admin, rebalance_max_loss_lamports, ps, config)| {
+        (
+            set_rebal_max_loss_keys(wrong_admin),
+            rebalance_max_loss_lamports,
+            ps,
+            config,
+        )
+    })
+    .prop_map(to_test_inp)
+}
+
+proptest! {
+    #[test]
+    fn set_rebal_max_loss_unauthorized_pt(
+        (ix, bef, rebalance_max_loss_lamports) in unauthorized_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_rebal_max_loss_test(ix, &bef, rebalance_max_loss_lamports, Some(INVALID_ARGUMENT));
+    }
+}
+
+fn missing_sig_strat() -> impl Strategy<Value = (Instruction, AccountMap, u64)> {
+    correct_strat().prop_map(|(mut ix, accs, rebalance_max_loss_lamports)| {
+        ix.accounts[SET_REBAL_MAX_LOSS_IX_ACCS_IDX_ADMIN].is_signer = false;
+        (ix, accs, rebalance_max_loss_lamports)
+    })
+}
+
+proptest! {
+    #[test]
+    fn set_rebal_max_loss_missing_sig_pt(
+        (ix, bef, rebalance_max_loss_lamports) in missing_sig_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_rebal_max_loss_test(ix, &bef, rebalance_max_loss_lamports, Some(MISSING_REQUIRED_SIGNATURE));
+    }
+}
+
+fn disabled_strat() -> impl Strategy<Value = (Instruction, AccountMap, u64)> {
+    ps_strat_to_test_inp(any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat().with_is_disabled(Some(Just(true).boxed())),
+        ..Default::default()
+    }))
+}
+
+proptest! {
+    #[test]
+    fn set_rebal_max_loss_pool_disabled_pt(
+        (ix, bef, rebalance_max_loss_lamports) in disabled_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_rebal_max_loss_test(
+            ix,
+            &bef,
+            rebalance_max_loss_lamports,
+            Some(Inf1CtlCustomProgErr(Inf1CtlErr::PoolDisabled)),
+        );
+    }
+}
+
+fn rebalancing_strat() -> impl Strategy<Value = (Instruction, AccountMap, u64)> {
+    ps_strat_to_test_inp(any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat()
+            .with_is_rebalancing(Some(Just(true).boxed())),
+        ..Default::default()
+    }))
+}
+
+proptest! {
+    #[test]
+    fn set_rebal_max_loss_pool_rebalancing_pt(
+        (ix, bef, rebalance_max_loss_lamports) in rebalancing_strat(),
+    ) {
+        silence_mollusk_logs();
+        set_rebal_max_loss_test(
+            ix,
+            &bef,
+            rebalance_max_loss_lamports,
+            Some(Inf1CtlCustomProgErr(Inf1CtlErr::PoolRebalancing)),
+        );
+    }
+}
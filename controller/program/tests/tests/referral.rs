@@ -0,0 +1,214 @@
+use inf1_ctl_jiminy::{
+    accounts::{
+        pool_state::{PoolStateV2, PoolStateV2Addrs, PoolStateV2FtaVals},
+        referral_config::ReferralConfig,
+    },
+    err::Inf1CtlErr,
+    instructions::referral::set_referral_fee::{
+        NewSetReferralFeeIxAccsBuilder, SetReferralFeeIxData, SetReferralFeeIxKeysOwned,
+        SET_REFERRAL_FEE_IX_ACCS_IDX_ADMIN, SET_REFERRAL_FEE_IX_IS_SIGNER,
+        SET_REFERRAL_FEE_IX_IS_WRITER,
+    },
+    keys::{POOL_STATE_ID, REFERRAL_CONFIG_ID, SYS_PROG_ID},
+    program_err::Inf1CtlCustomProgErr,
+    typedefs::fee_nanos::MAX_FEE_NANOS,
+    ID,
+};
+use inf1_test_utils::{
+    any_normal_pk, any_pool_state_v2, assert_jiminy_prog_err, keys_signer_writable_to_metas,
+    mock_sys_acc, mollusk_exec, pool_state_v2_account, pool_state_v2_u8_bools_normal_strat,
+    referral_config_account, silence_mollusk_logs, AccountMap, PoolStateV2FtaStrat,
+};
+use jiminy_cpi::program_error::{ProgramError, INVALID_ARGUMENT, MISSING_REQUIRED_SIGNATURE};
+use mollusk_svm::program::keyed_account_for_system_program;
+use proptest::prelude::*;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::common::SVM;
+
+fn set_referral_fee_ix(keys: SetReferralFeeIxKeysOwned, referral_fee_nanos: u32) -> Instruction {
+    let accounts = keys_signer_writable_to_metas(
+        keys.0.iter(),
+        SET_REFERRAL_FEE_IX_IS_SIGNER.0.iter(),
+        SET_REFERRAL_FEE_IX_IS_WRITER.0.iter(),
+    );
+    Instruction {
+        program_id: Pubkey::new_from_array(ID),
+        accounts,
+        data: SetReferralFeeIxData::new(referral_fee_nanos)
+            .as_buf()
+            .into(),
+    }
+}
+
+fn set_referral_fee_test_accs(
+    keys: SetReferralFeeIxKeysOwned,
+    pool: PoolStateV2,
+    config: Option<&ReferralConfig>,
+) -> AccountMap {
+    // dont care abt lamports, shouldnt affect anything
+    const LAMPORTS: u64 = 1_000_000_000;
+    let accs = NewSetReferralFeeIxAccsBuilder::start()
+        .with_admin(mock_sys_acc(LAMPORTS))
+        .with_payer(mock_sys_acc(LAMPORTS))
+        .with_pool_state(pool_state_v2_account(pool))
+        .with_referral_config(referral_config_account(config))
+        .with_system_program(keyed_account_for_system_program().1)
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+fn set_referral_fee_test(
+    ix: Instruction,
+    bef: &AccountMap,
+    expected_referral_fee_nanos: u32,
+    expected_err: Option<impl Into<ProgramError>>,
+) {
+    let result = SVM.with(|svm| mollusk_exec(svm, &[ix], bef));
+
+    match expected_err {
+        None => {
+            let res = result.unwrap();
+            let config_acc = &res.resulting_accounts[&REFERRAL_CONFIG_ID.into()];
+            assert_eq!(config_acc.owner, ID.into());
+            assert_eq!(
+                config_acc.data.as_slice(),
+                ReferralConfig {
+                    referral_fee_nanos: expected_referral_fee_nanos
+                }
+                .as_acc_data_arr()
+            );
+
+            // pool state is never written to
+            assert_eq!(
+                res.resulting_accounts[&POOL_STATE_ID.into()],
+                bef[&POOL_STATE_ID.into()]
+            );
+        }
+        Some(e) => {
+            assert_jiminy_prog_err(&result.unwrap_err(), e);
+        }
+    }
+}
+
+fn correct_keys(pool: &PoolStateV2, payer: [u8; 32]) -> SetReferralFeeIxKeysOwned {
+    NewSetReferralFeeIxAccsBuilder::start()
+        .with_admin(pool.admin)
+        .with_payer(payer)
+        .with_pool_state(POOL_STATE_ID)
+        .with_referral_config(REFERRAL_CONFIG_ID)
+        .with_system_program(SYS_PROG_ID)
+        .build()
+}
+
+fn normal_pool_strat() -> impl Strategy<Value = PoolStateV2> {
+    any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat(),
+        ..Default::default()
+    })
+}
+
+fn config_strat() -> impl Strategy<Value = Option<ReferralConfig>> {
+    prop_oneof![
+        Just(None),
+        (0..=MAX_FEE_NANOS)
+            .prop_map(|referral_fee_nanos| Some(ReferralConfig { referral_fee_nanos })),
+    ]
+}
+
+#[test]
+fn set_referral_fee_create_basic() {
+    let [admin, payer] = core::array::from_fn(|i| [u8::try_from(i + 1).unwrap(); 32]);
+    let pool = PoolStateV2FtaVals {
+        addrs: PoolStateV2Addrs::default().with_admin(admin),
+        ..Default::default()
+    }
+    .into_pool_state_v2();
+    let keys = correct_keys(&pool, payer);
+    set_referral_fee_test(
+        set_referral_fee_ix(keys, 100_000_000),
+        &set_referral_fee_test_accs(keys, pool, None),
+        100_000_000,
+        Option::<ProgramError>::None,
+    );
+}
+
+proptest! {
+    #[test]
+    fn set_referral_fee_correct_pt(
+        pool in normal_pool_strat(),
+        config in config_strat(),
+        payer in any_normal_pk(),
+        referral_fee_nanos in 0..=MAX_FEE_NANOS,
+    ) {
+        prop_assume!(payer != pool.admin);
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool, payer);
+        set_referral_fee_test(
+            set_referral_fee_ix(keys, referral_fee_nanos),
+            &set_referral_fee_test_accs(keys, pool, config.as_ref()),
+            referral_fee_nanos,
+            Option::<ProgramError>::None,
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn set_referral_fee_unauthorized_pt(
+        pool in normal_pool_strat(),
+        (payer, wrong_admin) in (any_normal_pk(), any_normal_pk()),
+        referral_fee_nanos in 0..=MAX_FEE_NANOS,
+    ) {
+        prop_assume!(wrong_admin != pool.admin && payer != wrong_admin);
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool, payer).with_admin(wrong_admin);
+        set_referral_fee_test(
+            set_referral_fee_ix(keys, referral_fee_nanos),
+            &set_referral_fee_test_accs(keys, pool, None),
+            referral_fee_nanos,
+            Some(INVALID_ARGUMENT),
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn set_referral_fee_admin_missing_sig_pt(
+        pool in normal_pool_strat(),
+        payer in any_normal_pk(),
+        referral_fee_nanos in 0..=MAX_FEE_NANOS,
+    ) {
+        prop_assume!(payer != pool.admin);
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool, payer);
+        let mut ix = set_referral_fee_ix(keys, referral_fee_nanos);
+        ix.accounts[SET_REFERRAL_FEE_IX_ACCS_IDX_ADMIN].is_signer = false;
+        set_referral_fee_test(
+            ix,
+            &set_referral_fee_test_accs(keys, pool, None),
+            referral_fee_nanos,
+            Some(MISSING_REQUIRED_SIGNATURE),
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn set_referral_fee_too_high_pt(
+        pool in normal_pool_strat(),
+        payer in any_normal_pk(),
+        referral_fee_nanos in (MAX_FEE_NANOS + 1)..=u32::MAX,
+    ) {
+        prop_assume!(payer != pool.admin);
+        silence_mollusk_logs();
+        let keys = correct_keys(&pool, payer);
+        set_referral_fee_test(
+            set_referral_fee_ix(keys, referral_fee_nanos),
+            &set_referral_fee_test_accs(keys, pool, None),
+            referral_fee_nanos,
+            Some(Inf1CtlCustomProgErr(Inf1CtlErr::FeeTooHigh)),
+        );
+    }
+}
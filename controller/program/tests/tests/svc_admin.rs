@@ -0,0 +1,182 @@
+//! Generic SOL value calculator programs' admin instructions,
+//! executed against the sanctum-spl-multi calculator program fixture
+
+use inf1_svc_ag_core::{
+    inf1_svc_generic::{
+        accounts::state::{State, StatePacked},
+        instructions::{
+            set_manager::{
+                NewSetManagerIxAccsBuilder, SetManagerIxData, SetManagerIxKeysOwned,
+                SET_MANAGER_IX_IS_SIGNER, SET_MANAGER_IX_IS_WRITER,
+            },
+            update_last_upgrade_slot::{
+                NewUpdateLastUpgradeSlotIxAccsBuilder, UpdateLastUpgradeSlotIxData,
+                UpdateLastUpgradeSlotIxKeysOwned, UPDATE_LAST_UPGRADE_SLOT_IX_IS_SIGNER,
+                UPDATE_LAST_UPGRADE_SLOT_IX_IS_WRITER,
+            },
+        },
+    },
+    inf1_svc_spl_core::keys::sanctum_spl_multi,
+};
+use inf1_test_utils::{
+    keys_signer_writable_to_metas, mock_prog_acc, mock_progdata_acc, mock_sys_acc, mollusk_exec,
+    AccountMap, KeyedUiAccount, ProgramDataAddr,
+};
+use solana_account::Account;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::common::SVM;
+
+// dont care abt lamports, shouldnt affect anything
+const LAMPORTS: u64 = 1_000_000_000;
+
+const NEW_MANAGER: [u8; 32] = [69; 32];
+
+fn calc_state_fixture() -> (Pubkey, Account) {
+    KeyedUiAccount::from_test_fixtures_json("sanctum-spl-multi-calc-state").into_keyed_account()
+}
+
+fn state_of(acc: &Account) -> State {
+    StatePacked::of_acc_data(&acc.data).unwrap().into_state()
+}
+
+/// `None` if the instruction failed
+fn exec_state_aft(ix: Instruction, bef: &AccountMap) -> Option<State> {
+    let result = SVM.with(|svm| mollusk_exec(svm, &[ix], bef));
+    result.ok().map(|r| {
+        state_of(
+            r.resulting_accounts
+                .get(&sanctum_spl_multi::STATE_ID.into())
+                .unwrap(),
+        )
+    })
+}
+
+fn set_manager_ix(keys: &SetManagerIxKeysOwned) -> Instruction {
+    Instruction {
+        program_id: Pubkey::new_from_array(sanctum_spl_multi::ID),
+        accounts: keys_signer_writable_to_metas(
+            keys.0.iter(),
+            SET_MANAGER_IX_IS_SIGNER.0.iter(),
+            SET_MANAGER_IX_IS_WRITER.0.iter(),
+        ),
+        data: SetManagerIxData::as_buf().into(),
+    }
+}
+
+fn set_manager_test_accs(keys: &SetManagerIxKeysOwned) -> AccountMap {
+    let (_, state) = calc_state_fixture();
+    let accs = NewSetManagerIxAccsBuilder::start()
+        .with_curr(mock_sys_acc(LAMPORTS))
+        .with_new(mock_sys_acc(LAMPORTS))
+        .with_state(state)
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+#[test]
+fn set_manager_keys_match_fixture() {
+    let (state_addr, _) = calc_state_fixture();
+    let keys = SetManagerIxKeysOwned::new_for_prog([1; 32], NEW_MANAGER, &sanctum_spl_multi::ID);
+    assert_eq!(*keys.state(), state_addr.to_bytes());
+    assert_eq!(*keys.state(), sanctum_spl_multi::STATE_ID);
+}
+
+#[test]
+fn set_manager_fixture() {
+    let (_, state) = calc_state_fixture();
+    let curr = state_of(&state).manager;
+    let keys = SetManagerIxKeysOwned::new_for_prog(curr, NEW_MANAGER, &sanctum_spl_multi::ID);
+
+    let aft = exec_state_aft(set_manager_ix(&keys), &set_manager_test_accs(&keys)).unwrap();
+
+    assert_eq!(
+        aft,
+        State {
+            manager: NEW_MANAGER,
+            ..state_of(&state)
+        }
+    );
+}
+
+#[test]
+fn set_manager_unauthorized_fixture() {
+    let keys =
+        SetManagerIxKeysOwned::new_for_prog(NEW_MANAGER, NEW_MANAGER, &sanctum_spl_multi::ID);
+
+    assert!(exec_state_aft(set_manager_ix(&keys), &set_manager_test_accs(&keys)).is_none());
+}
+
+fn update_last_upgrade_slot_ix(keys: &UpdateLastUpgradeSlotIxKeysOwned) -> Instruction {
+    Instruction {
+        program_id: Pubkey::new_from_array(sanctum_spl_multi::ID),
+        accounts: keys_signer_writable_to_metas(
+            keys.0.iter(),
+            UPDATE_LAST_UPGRADE_SLOT_IX_IS_SIGNER.0.iter(),
+            UPDATE_LAST_UPGRADE_SLOT_IX_IS_WRITER.0.iter(),
+        ),
+        data: UpdateLastUpgradeSlotIxData::as_buf().into(),
+    }
+}
+
+fn update_last_upgrade_slot_test_accs(
+    keys: &UpdateLastUpgradeSlotIxKeysOwned,
+    last_upgrade_slot: u64,
+) -> AccountMap {
+    let (_, state) = calc_state_fixture();
+    let accs = NewUpdateLastUpgradeSlotIxAccsBuilder::start()
+        .with_manager(mock_sys_acc(LAMPORTS))
+        .with_state(state)
+        .with_pool_prog(mock_prog_acc(ProgramDataAddr::Raw(
+            (*keys.pool_progdata()).into(),
+        )))
+        .with_pool_progdata(mock_progdata_acc(last_upgrade_slot))
+        .build();
+    keys.0.into_iter().map(Into::into).zip(accs.0).collect()
+}
+
+#[test]
+fn update_last_upgrade_slot_fixture() {
+    let (_, state) = calc_state_fixture();
+    let state = state_of(&state);
+    let last_upgrade_slot = state.last_upgrade_slot + 1;
+    let keys = UpdateLastUpgradeSlotIxKeysOwned::new_for_prog(
+        state.manager,
+        sanctum_spl_multi::POOL_PROG_ID,
+        sanctum_spl_multi::POOL_PROGDATA_ID,
+        &sanctum_spl_multi::ID,
+    );
+
+    let aft = exec_state_aft(
+        update_last_upgrade_slot_ix(&keys),
+        &update_last_upgrade_slot_test_accs(&keys, last_upgrade_slot),
+    )
+    .unwrap();
+
+    assert_eq!(
+        aft,
+        State {
+            last_upgrade_slot,
+            ..state
+        }
+    );
+}
+
+#[test]
+fn update_last_upgrade_slot_unauthorized_fixture() {
+    let (_, state) = calc_state_fixture();
+    let state = state_of(&state);
+    let keys = UpdateLastUpgradeSlotIxKeysOwned::new_for_prog(
+        NEW_MANAGER,
+        sanctum_spl_multi::POOL_PROG_ID,
+        sanctum_spl_multi::POOL_PROGDATA_ID,
+        &sanctum_spl_multi::ID,
+    );
+
+    assert!(exec_state_aft(
+        update_last_upgrade_slot_ix(&keys),
+        &update_last_upgrade_slot_test_accs(&keys, state.last_upgrade_slot + 1),
+    )
+    .is_none());
+}
@@ -2,7 +2,10 @@ use std::ops::Neg;
 
 use inf1_ctl_jiminy::{
     accounts::pool_state::{PoolStateV2, PoolStateV2Packed, PoolStateV2U64s},
-    instructions::swap::v2::IxPreAccs,
+    instructions::swap::v2::{
+        ret::{SwapV2Ret, SwapV2RetV1},
+        IxPreAccs,
+    },
     keys::POOL_STATE_ID,
     typedefs::{
         lst_state::LstState,
@@ -72,6 +75,29 @@ pub fn assert_correct_swap_exact_out_v2(
     quote
 }
 
+/// Assert that the controller set `quote` as return data
+pub fn assert_swap_ret_data(return_data: &[u8], quote: &Quote) {
+    let Quote {
+        inp,
+        out,
+        fee,
+        inp_sol_val,
+        inp_mint,
+        out_mint,
+    } = quote;
+    assert_eq!(
+        SwapV2Ret::parse(return_data),
+        Some(SwapV2Ret::V1(SwapV2RetV1 {
+            inp: *inp,
+            out: *out,
+            fee: *fee,
+            inp_sol_val: *inp_sol_val,
+            inp_mint: *inp_mint,
+            out_mint: *out_mint,
+        }))
+    );
+}
+
 fn assert_correct_swap_v2(
     bef: &AccountMap,
     aft: &AccountMap,
@@ -109,7 +135,6 @@ fn assert_swap_token_movements(
         out,
         inp_mint,
         out_mint,
-        ..
     } = quote;
 
     // user's token accs
@@ -169,7 +194,6 @@ fn assert_accs_swap(
         fee,
         inp_mint,
         out_mint,
-        ..
     }: &Quote,
 ) {
     // TODO: verify this error bound and verify that its due to rounding.
@@ -265,7 +289,6 @@ fn assert_accs_liq(
         fee,
         inp_mint,
         out_mint,
-        ..
     }: &Quote,
 ) {
     let diffs = DiffsPoolStateV2 {
@@ -77,6 +77,7 @@ pub fn derive_qa_hla(
             inp_calc,
             out_calc,
             pricing,
+            min_fee_nanos: ps_aft_header_la.min_fee_nanos_checked().unwrap(),
         },
         ps_aft_header_la,
         list_aft_header_la,
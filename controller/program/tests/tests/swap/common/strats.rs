@@ -1,10 +1,12 @@
 use inf1_ctl_jiminy::{
     accounts::{
-        lst_state_list::LstStatePackedList,
+        lst_state_list::{LstStatePackedList, LstStatePackedListMut},
         pool_state::{PoolStateV2, PoolStateV2Addrs},
     },
     svc::InfDummyCalcAccs,
-    typedefs::{pool_sv::PoolSvMutRefs, versioned::V1_2},
+    typedefs::{
+        compact_u64::CompactU64, lst_state::LstStatePacked, pool_sv::PoolSvMutRefs, versioned::V1_2,
+    },
 };
 use inf1_pp_ag_core::{PricingAg, PricingAgTy};
 use inf1_pp_core::pair::Pair;
@@ -80,6 +82,22 @@ pub fn swap_prog_accs_strat<const N: usize>(
         })
 }
 
+/// Sets the synced reserves balance of the LST at `idx`
+/// as if it was last synced with `balance` in its pool reserves
+fn with_synced_reserves_balance(
+    mut lsl: LstStateListData,
+    idx: usize,
+    balance: u64,
+) -> LstStateListData {
+    let packed = &mut LstStatePackedListMut::of_acc_data(&mut lsl.lst_state_list)
+        .unwrap()
+        .0[idx];
+    let mut lst_state = packed.into_lst_state();
+    lst_state.synced_reserves_balance = CompactU64::new(balance);
+    *packed = *LstStatePacked::of_acc_data_arr(lst_state.as_acc_data_arr());
+    lsl
+}
+
 /// Returns `(curr_slot, args, account_map)`
 pub fn wsol_add_liq_from_zero_inf_exact_in_strat(
 ) -> impl Strategy<Value = (u64, V2Args, AccountMap)> {
@@ -117,6 +135,9 @@ fn wsol_add_liq_from_zero_inf_exact_in_inner(
                         addrs: PoolStateV2Addrs::default().with_pricing_program(Some(
                             Just(*PricingAgTy::FlatSlab(()).program_id()).boxed(),
                         )),
+                        // wsol reserves are synced with the same balance below,
+                        // so the per-token SOL value is unchanged and never trips
+                        sol_value_circuit_breaker_nanos: Some((1..=u32::MAX).boxed()),
                         ..Default::default()
                     },
                 )
@@ -141,6 +162,7 @@ fn wsol_add_liq_from_zero_inf_exact_in_inner(
                 curr_slot,
                 (wsol_sol_val, inp_amt),
             )| {
+                let lsl = with_synced_reserves_balance(lsl, idx, wsol_sol_val);
                 let (ix_prefix, ix_prefix_am) = swap_pre_accs(
                     &signer,
                     &V1_2::V2(ps),
@@ -249,6 +271,9 @@ pub fn wsol_rem_liq_to_zero_inf_exact_in_strat() -> impl Strategy<Value = (u64,
                         addrs: PoolStateV2Addrs::default().with_pricing_program(Some(
                             Just(*PricingAgTy::FlatSlab(()).program_id()).boxed(),
                         )),
+                        // wsol reserves are synced with the same balance below,
+                        // so the per-token SOL value is unchanged and never trips
+                        sol_value_circuit_breaker_nanos: Some((1..=u32::MAX).boxed()),
                         ..Default::default()
                     },
                 )
@@ -272,6 +297,7 @@ pub fn wsol_rem_liq_to_zero_inf_exact_in_strat() -> impl Strategy<Value = (u64,
                 ((pp_accs, pp_am), (idx, lsl, ps)),
                 curr_slot,
             )| {
+                let lsl = with_synced_reserves_balance(lsl, idx, ps.total_sol_value);
                 let (ix_prefix, ix_prefix_am) = swap_pre_accs(
                     &signer,
                     &V1_2::V2(ps),
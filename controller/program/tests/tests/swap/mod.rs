@@ -15,9 +15,9 @@ use inf1_std::{
 };
 use inf1_svc_ag_core::{calc::SvcCalcAg, instructions::SvcCalcAccsAg};
 
-mod common;
+pub(crate) mod common;
 mod v1;
-mod v2;
+pub(crate) mod v2;
 
 /// impls both PriceExactInAccs and PriceExactOutAccs (but not deprectated LP interfaces)
 type PricingSwapAccsAg = PricingAg<FlatFeePriceAccs, FlatSlabPpAccs>;
@@ -32,9 +32,9 @@ type V1Accs =
 type V1Args =
     swap::IxArgs<[u8; 32], ctl_v1::IxPreKeysOwned, SvcCalcAccsAg, SvcCalcAccsAg, PricingSwapAccsAg>;
 
-type V2Accs =
+pub(crate) type V2Accs =
     swap::IxAccs<[u8; 32], ctl_v2::IxPreKeysOwned, SvcCalcAccsAg, SvcCalcAccsAg, PricingSwapAccsAg>;
-type V2Args =
+pub(crate) type V2Args =
     swap::IxArgs<[u8; 32], ctl_v2::IxPreKeysOwned, SvcCalcAccsAg, SvcCalcAccsAg, PricingSwapAccsAg>;
 
 // only flatslab has uniform interface across all 4 pp instructions
@@ -14,14 +14,17 @@ use mollusk_svm::Mollusk;
 use solana_instruction::Instruction;
 use solana_pubkey::Pubkey;
 
-use crate::tests::swap::{common::assert_correct_swap_exact_in_v2, V2Args};
+use crate::tests::swap::{
+    common::{assert_correct_swap_exact_in_v2, assert_swap_ret_data},
+    V2Args,
+};
 
 mod add_liq;
 mod errs;
 mod rem_liq;
 mod swap;
 
-fn to_ix(args: &V2Args) -> Instruction {
+pub(crate) fn to_ix(args: &V2Args) -> Instruction {
     let accounts = keys_signer_writable_to_metas(
         swap_exact_in_v2_ix_keys_owned(&args.accs).seq(),
         swap_exact_in_v2_ix_is_signer(&args.accs).seq(),
@@ -48,9 +51,11 @@ fn swap_exact_in_v2_test(
 
     match expected_err {
         None => {
-            let aft = result.unwrap().resulting_accounts;
+            let res = result.unwrap();
+            let aft = res.resulting_accounts;
             let clock = &svm.sysvars.clock;
             let quote = assert_correct_swap_exact_in_v2(bef, &aft, args, clock.epoch, clock.slot);
+            assert_swap_ret_data(&res.return_data, &quote);
             Some((quote, aft))
         }
         Some(e) => {
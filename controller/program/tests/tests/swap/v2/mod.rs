@@ -5,27 +5,41 @@ use inf1_test_utils::KeyedUiAccount;
 use solana_account::Account;
 use solana_pubkey::Pubkey;
 
-mod exact_in;
-mod exact_out;
+pub(crate) mod exact_in;
+pub(crate) mod exact_out;
+mod native_sol;
+mod referral;
 
 fn jupsol_to_wsol_prefix_fixtures() -> IxPreAccs<(Pubkey, Account)> {
+    prefix_fixtures("jupsol", "wsol")
+}
+
+/// `inp` and `out` are the LSTs' test fixture name prefixes e.g. "jupsol".
+/// "inf" is the LP token, whose pool reserves account is the INF mint.
+///
+/// Signer is the owner of the `inp` token account
+pub(crate) fn prefix_fixtures(inp: &str, out: &str) -> IxPreAccs<(Pubkey, Account)> {
+    let reserves = |name: &str| match name {
+        "inf" => "inf-mint".to_owned(),
+        _ => format!("{name}-reserves"),
+    };
     IxPreAccs(
         NewSwapExactOutV2IxPreAccsBuilder::start()
-            .with_signer("jupsol-token-acc-owner")
-            .with_pool_state("pool-state")
-            .with_lst_state_list("lst-state-list")
-            .with_inp_acc("jupsol-token-acc")
-            .with_inp_mint("jupsol-mint")
-            .with_inp_pool_reserves("jupsol-reserves")
-            .with_out_acc("wsol-token-acc")
-            .with_out_mint("wsol-mint")
-            .with_out_pool_reserves("wsol-reserves")
+            .with_signer(format!("{inp}-token-acc-owner"))
+            .with_pool_state("pool-state".to_owned())
+            .with_lst_state_list("lst-state-list".to_owned())
+            .with_inp_acc(format!("{inp}-token-acc"))
+            .with_inp_mint(format!("{inp}-mint"))
+            .with_inp_pool_reserves(reserves(inp))
+            .with_out_acc(format!("{out}-token-acc"))
+            .with_out_mint(format!("{out}-mint"))
+            .with_out_pool_reserves(reserves(out))
             // filler
-            .with_inp_token_program("wsol-mint")
-            .with_out_token_program("wsol-mint")
+            .with_inp_token_program(format!("{out}-mint"))
+            .with_out_token_program(format!("{out}-mint"))
             .build()
             .0
-            .map(|n| KeyedUiAccount::from_test_fixtures_json(n).into_keyed_account()),
+            .map(|n| KeyedUiAccount::from_test_fixtures_json(&n).into_keyed_account()),
     )
     .with_inp_token_program(mollusk_svm_programs_token::token::keyed_account())
     .with_out_token_program(mollusk_svm_programs_token::token::keyed_account())
@@ -0,0 +1,295 @@
+//! End-to-end tests of the native SOL instruction bundles
+//! built by `inf1_std::trade::instruction`
+
+use inf1_ctl_jiminy::{
+    accounts::pool_state::VerPoolState,
+    instructions::swap::v2::{
+        exact_in::SWAP_EXACT_IN_V2_IX_DISCM, exact_out::SWAP_EXACT_OUT_V2_IX_DISCM,
+    },
+    keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    ID,
+};
+use inf1_std::{
+    inf1_pp_core::pair::Pair,
+    instructions::swap::v2::{
+        exact_in::{
+            swap_exact_in_v2_ix_is_signer, swap_exact_in_v2_ix_is_writer,
+            swap_exact_in_v2_ix_keys_owned,
+        },
+        exact_out::{
+            swap_exact_out_v2_ix_is_signer, swap_exact_out_v2_ix_is_writer,
+            swap_exact_out_v2_ix_keys_owned,
+        },
+    },
+    trade::{
+        instruction::{TradeIxArgs, TradeIxBundleStd, WsolIx},
+        Trade, TradeLimitTy,
+    },
+    InfStd,
+};
+use inf1_test_utils::{
+    fixtures_accounts_opt_cloned, get_lst_state_list, get_token_account_amount,
+    keys_signer_writable_to_metas, mock_sys_acc, mock_token_acc, mollusk_exec, raw_token_acc,
+    AccountMap, ALL_FIXTURES, MSOL_FIXTURE_LST_IDX, WSOL_FIXTURE_LST_IDX,
+};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::common::SVM;
+
+const SIGNER: [u8; 32] = [0xab; 32];
+
+const SIGNER_LAMPORTS: u64 = 10_000_000_000;
+
+/// Signer's token account of the non-wSOL mint
+const TOKEN_ACC: [u8; 32] = [0xcd; 32];
+
+const TOKEN_ACC_BALANCE: u64 = 1_000_000_000;
+
+fn find_pda(seeds: &[&[u8]], prog_id: &[u8; 32]) -> Option<([u8; 32], u8)> {
+    Pubkey::try_find_program_address(seeds, &Pubkey::new_from_array(*prog_id))
+        .map(|(a, b)| (a.to_bytes(), b))
+}
+
+fn create_pda(seeds: &[&[u8]], prog_id: &[u8; 32]) -> Option<[u8; 32]> {
+    Pubkey::create_program_address(seeds, &Pubkey::new_from_array(*prog_id))
+        .ok()
+        .map(|a| a.to_bytes())
+}
+
+fn inf_fixture() -> InfStd {
+    InfStd::new(
+        VerPoolState::try_from_acc_data(&ALL_FIXTURES[&POOL_STATE_ID.into()].data).unwrap(),
+        ALL_FIXTURES[&LST_STATE_LIST_ID.into()]
+            .data
+            .clone()
+            .into_boxed_slice(),
+        None,
+        None,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap()
+}
+
+fn wsol_ix(ix: &WsolIx) -> Instruction {
+    Instruction {
+        program_id: Pubkey::new_from_array(*ix.program_id()),
+        accounts: keys_signer_writable_to_metas(
+            ix.keys_owned().iter(),
+            ix.is_signer().iter(),
+            ix.is_writer().iter(),
+        ),
+        data: ix.data(),
+    }
+}
+
+fn bundle_ixs(TradeIxBundleStd { pre, trade, post }: &TradeIxBundleStd) -> Vec<Instruction> {
+    let trade = match trade {
+        Trade::ExactIn(ix) => Instruction {
+            program_id: Pubkey::new_from_array(ID),
+            accounts: keys_signer_writable_to_metas(
+                swap_exact_in_v2_ix_keys_owned(&ix.swap.accs)
+                    .seq()
+                    .chain(ix.referral_suf_keys_owned()),
+                swap_exact_in_v2_ix_is_signer(&ix.swap.accs)
+                    .seq()
+                    .chain(ix.referral_suf_is_signer()),
+                swap_exact_in_v2_ix_is_writer(&ix.swap.accs)
+                    .seq()
+                    .chain(ix.referral_suf_is_writer()),
+            ),
+            data: ix.to_ix_data::<SWAP_EXACT_IN_V2_IX_DISCM>(),
+        },
+        Trade::ExactOut(ix) => Instruction {
+            program_id: Pubkey::new_from_array(ID),
+            accounts: keys_signer_writable_to_metas(
+                swap_exact_out_v2_ix_keys_owned(&ix.swap.accs)
+                    .seq()
+                    .chain(ix.referral_suf_keys_owned()),
+                swap_exact_out_v2_ix_is_signer(&ix.swap.accs)
+                    .seq()
+                    .chain(ix.referral_suf_is_signer()),
+                swap_exact_out_v2_ix_is_writer(&ix.swap.accs)
+                    .seq()
+                    .chain(ix.referral_suf_is_writer()),
+            ),
+            data: ix.to_ix_data::<SWAP_EXACT_OUT_V2_IX_DISCM>(),
+        },
+    };
+    pre.iter()
+        .map(wsol_ix)
+        .chain(core::iter::once(trade))
+        .chain(post.iter().map(wsol_ix))
+        .collect()
+}
+
+/// Returns `(bef, aft, signer's wSOL ATA, pool's wSOL reserves)`
+fn exec_bundle(
+    mints: &Pair<&[u8; 32]>,
+    amt: u64,
+    limit: u64,
+    limit_ty: TradeLimitTy,
+) -> (AccountMap, AccountMap, Pubkey, Pubkey) {
+    let mut inf = inf_fixture();
+    let bundle = inf
+        .trade_ix_bundle_mut(
+            &TradeIxArgs {
+                amt,
+                limit,
+                mints,
+                signer: &SIGNER,
+                // wSOL entry is replaced by the signer's wSOL ATA
+                token_accs: &Pair {
+                    inp: &TOKEN_ACC,
+                    out: &TOKEN_ACC,
+                },
+                referrer: None,
+            },
+            limit_ty,
+        )
+        .unwrap();
+    let WsolIx::CreateAta { ata, .. } = bundle.pre[0] else {
+        panic!("expected CreateAta first, got {:?}", bundle.pre[0]);
+    };
+    let wsol_reserves = match &bundle.trade {
+        Trade::ExactIn(ix) | Trade::ExactOut(ix) => {
+            let p = &ix.swap.accs.ix_prefix;
+            if is_wsol(mints.inp) {
+                *p.inp_pool_reserves()
+            } else {
+                *p.out_pool_reserves()
+            }
+        }
+    };
+    let ixs = bundle_ixs(&bundle);
+
+    let non_wsol_mint = if is_wsol(mints.inp) {
+        mints.out
+    } else {
+        mints.inp
+    };
+    let mut bef = fixtures_accounts_opt_cloned(
+        ixs.iter()
+            .flat_map(|ix| ix.accounts.iter().map(|a| a.pubkey)),
+    );
+    bef.extend([
+        (SIGNER.into(), mock_sys_acc(SIGNER_LAMPORTS)),
+        (ata.into(), mock_sys_acc(0)),
+        (
+            TOKEN_ACC.into(),
+            mock_token_acc(raw_token_acc(*non_wsol_mint, SIGNER, TOKEN_ACC_BALANCE)),
+        ),
+    ]);
+
+    let aft = SVM
+        .with(|svm| mollusk_exec(svm, &ixs, &bef))
+        .unwrap()
+        .resulting_accounts;
+
+    (bef, aft, ata.into(), wsol_reserves.into())
+}
+
+fn fixture_mint(lst_idx: usize) -> [u8; 32] {
+    get_lst_state_list(&ALL_FIXTURES[&LST_STATE_LIST_ID.into()].data)[lst_idx].mint
+}
+
+fn is_wsol(mint: &[u8; 32]) -> bool {
+    *mint == fixture_mint(WSOL_FIXTURE_LST_IDX)
+}
+
+fn token_bal(am: &AccountMap, pk: &Pubkey) -> u64 {
+    get_token_account_amount(&am[pk].data)
+}
+
+#[test]
+fn native_sol_inp_exact_out_fixture() {
+    const AMT: u64 = 1_000;
+    // no slippage limit, only the quoted input amount should be wrapped
+    const LIMIT: u64 = u64::MAX;
+
+    let [wsol, msol] = [WSOL_FIXTURE_LST_IDX, MSOL_FIXTURE_LST_IDX].map(fixture_mint);
+    let (bef, aft, ata, wsol_reserves) = exec_bundle(
+        &Pair {
+            inp: &wsol,
+            out: &msol,
+        },
+        AMT,
+        LIMIT,
+        Trade::ExactOut(()),
+    );
+
+    // wSOL ATA closed
+    assert_eq!(aft[&ata].lamports, 0);
+
+    // signer paid exactly what the pool received,
+    // with the ATA's rent refunded
+    let paid = bef[&SIGNER.into()].lamports - aft[&SIGNER.into()].lamports;
+    let received = token_bal(&aft, &wsol_reserves) - token_bal(&bef, &wsol_reserves);
+    assert!(paid > 0);
+    assert_eq!(paid, received);
+
+    assert_eq!(
+        token_bal(&aft, &TOKEN_ACC.into()) - token_bal(&bef, &TOKEN_ACC.into()),
+        AMT
+    );
+}
+
+#[test]
+fn native_sol_inp_exact_in_fixture() {
+    const AMT: u64 = 1_000_000;
+
+    let [wsol, msol] = [WSOL_FIXTURE_LST_IDX, MSOL_FIXTURE_LST_IDX].map(fixture_mint);
+    let (bef, aft, ata, wsol_reserves) = exec_bundle(
+        &Pair {
+            inp: &wsol,
+            out: &msol,
+        },
+        AMT,
+        0,
+        Trade::ExactIn(()),
+    );
+
+    assert_eq!(aft[&ata].lamports, 0);
+    assert_eq!(
+        bef[&SIGNER.into()].lamports - aft[&SIGNER.into()].lamports,
+        AMT
+    );
+    assert_eq!(
+        token_bal(&aft, &wsol_reserves) - token_bal(&bef, &wsol_reserves),
+        AMT
+    );
+    assert!(token_bal(&aft, &TOKEN_ACC.into()) > token_bal(&bef, &TOKEN_ACC.into()));
+}
+
+#[test]
+fn native_sol_out_exact_in_fixture() {
+    const AMT: u64 = 1_000_000;
+
+    let [wsol, msol] = [WSOL_FIXTURE_LST_IDX, MSOL_FIXTURE_LST_IDX].map(fixture_mint);
+    let (bef, aft, ata, wsol_reserves) = exec_bundle(
+        &Pair {
+            inp: &msol,
+            out: &wsol,
+        },
+        AMT,
+        0,
+        Trade::ExactIn(()),
+    );
+
+    assert_eq!(aft[&ata].lamports, 0);
+
+    // signer received exactly what left the pool, as native SOL
+    let received = aft[&SIGNER.into()].lamports - bef[&SIGNER.into()].lamports;
+    let paid = token_bal(&bef, &wsol_reserves) - token_bal(&aft, &wsol_reserves);
+    assert!(received > 0);
+    assert_eq!(received, paid);
+
+    assert_eq!(
+        token_bal(&bef, &TOKEN_ACC.into()) - token_bal(&aft, &TOKEN_ACC.into()),
+        AMT
+    );
+}
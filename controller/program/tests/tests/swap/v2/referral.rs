@@ -0,0 +1,232 @@
+use inf1_ctl_jiminy::{
+    accounts::referral_config::ReferralConfig,
+    err::Inf1CtlErr,
+    instructions::swap::v2::{
+        exact_in::SWAP_EXACT_IN_V2_IX_DISCM,
+        exact_out::SWAP_EXACT_OUT_V2_IX_DISCM,
+        referral::{
+            IxDataWithReferral, NewReferralSufAccsBuilder, REFERRAL_SUF_IS_SIGNER,
+            REFERRAL_SUF_IS_WRITER,
+        },
+        IxPreAccs,
+    },
+    keys::REFERRAL_CONFIG_ID,
+    program_err::Inf1CtlCustomProgErr,
+    svc::InfDummyCalcAccs,
+    typedefs::fee_nanos::FeeNanos,
+};
+use inf1_pp_ag_core::{PricingAg, PricingAgTy};
+use inf1_std::quote::{Quote, QuoteReferral};
+use inf1_svc_ag_core::{
+    inf1_svc_wsol_core::instructions::sol_val_calc::WsolCalcAccs, instructions::SvcCalcAccsAg,
+    SvcAg, SvcAgTy,
+};
+use inf1_test_utils::{
+    assert_jiminy_prog_err, flatslab_fixture_suf_accs, get_token_account_amount,
+    jupsol_fixture_svc_suf_accs, keys_signer_writable_to_metas, mock_token_acc, mollusk_exec,
+    raw_token_acc, referral_config_account, AccountMap, JUPSOL_FIXTURE_LST_IDX,
+    WSOL_FIXTURE_LST_IDX,
+};
+use mollusk_svm::Mollusk;
+use solana_instruction::Instruction;
+
+use crate::{
+    common::SVM,
+    tests::swap::{
+        common::{
+            assert_correct_swap_exact_in_v2, assert_correct_swap_exact_out_v2,
+            assert_swap_ret_data, fill_swap_prog_accs,
+        },
+        v2::{exact_in, exact_out, jupsol_to_wsol_prefix_fixtures, prefix_fixtures},
+        V2Accs, V2Args,
+    },
+};
+
+const REFERRAL_FEE_NANOS: u32 = 500_000_000;
+
+const REFERRER_ACC: [u8; 32] = [0xfe; 32];
+
+const REFERRER: [u8; 32] = [0xfd; 32];
+
+fn with_referral<const DISCM: u8>(mut ix: Instruction, args: &V2Args) -> Instruction {
+    ix.data = IxDataWithReferral::<DISCM>::new(&args.to_full())
+        .as_buf()
+        .into();
+    ix.accounts.extend(keys_signer_writable_to_metas(
+        NewReferralSufAccsBuilder::start()
+            .with_referral_config(REFERRAL_CONFIG_ID)
+            .with_referrer_acc(REFERRER_ACC)
+            .build()
+            .0
+            .iter(),
+        REFERRAL_SUF_IS_SIGNER.0.iter(),
+        REFERRAL_SUF_IS_WRITER.0.iter(),
+    ));
+    ix
+}
+
+fn insert_referral_accs(bef: &mut AccountMap, inp_mint: [u8; 32]) {
+    bef.insert(
+        REFERRAL_CONFIG_ID.into(),
+        referral_config_account(Some(&ReferralConfig {
+            referral_fee_nanos: REFERRAL_FEE_NANOS,
+        })),
+    );
+    bef.insert(
+        REFERRER_ACC.into(),
+        mock_token_acc(raw_token_acc(inp_mint, REFERRER, 0)),
+    );
+}
+
+fn jupsol_to_wsol_fixture(limit: u64, amount: u64) -> (V2Args, AccountMap) {
+    let prefix_am = jupsol_to_wsol_prefix_fixtures();
+    let prefix_keys = IxPreAccs(prefix_am.0.each_ref().map(|(addr, _)| addr.to_bytes()));
+    let (pp_accs, pp_am) = flatslab_fixture_suf_accs();
+    let (inp_accs, inp_am) = jupsol_fixture_svc_suf_accs();
+
+    let accs = V2Accs {
+        ix_prefix: prefix_keys,
+        inp_calc_prog: *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+        inp_calc: SvcAg::SanctumSplMulti(inp_accs),
+        out_calc_prog: *SvcAgTy::Wsol(()).svc_program_id(),
+        out_calc: SvcCalcAccsAg::Wsol(WsolCalcAccs),
+        pricing_prog: *PricingAgTy::FlatSlab(()).program_id(),
+        pricing: PricingAg::FlatSlab(pp_accs),
+    };
+    let args = V2Args {
+        inp_lst_index: JUPSOL_FIXTURE_LST_IDX.try_into().unwrap(),
+        out_lst_index: WSOL_FIXTURE_LST_IDX.try_into().unwrap(),
+        limit,
+        amount,
+        accs,
+    };
+
+    let mut bef = prefix_am.0.into_iter().chain(pp_am).chain(inp_am).collect();
+    fill_swap_prog_accs(&mut bef, &accs);
+    insert_referral_accs(&mut bef, *prefix_keys.inp_mint());
+
+    (args, bef)
+}
+
+type AssertCorrectSwapFn = fn(&AccountMap, &AccountMap, &V2Args, u64, u64) -> Quote;
+
+/// Executes `ix` and `referral_ix`, the same swap without and with a referrer,
+/// from the same starting accounts `bef`.
+///
+/// Asserts that the user pays and receives the same amounts in both cases
+/// and that the referral is carved out of the input tokens received by the pool.
+fn referral_test(
+    svm: &Mollusk,
+    args: &V2Args,
+    bef: &AccountMap,
+    [ix, referral_ix]: [Instruction; 2],
+    assert_correct_swap: AssertCorrectSwapFn,
+) -> QuoteReferral {
+    let clock = &svm.sysvars.clock;
+
+    let aft = mollusk_exec(svm, &[ix], bef).unwrap().resulting_accounts;
+    let quote = assert_correct_swap(bef, &aft, args, clock.epoch, clock.slot);
+
+    let res = mollusk_exec(svm, &[referral_ix], bef).unwrap();
+    assert_swap_ret_data(&res.return_data, &quote);
+    let referral_aft = res.resulting_accounts;
+
+    let referral = quote.referral(FeeNanos::new(REFERRAL_FEE_NANOS).unwrap());
+    let amt =
+        |accs: &AccountMap, key: &[u8; 32]| get_token_account_amount(&accs[&(*key).into()].data);
+    let ix_prefix = &args.accs.ix_prefix;
+
+    [
+        ix_prefix.inp_acc(),
+        ix_prefix.out_acc(),
+        ix_prefix.out_pool_reserves(),
+    ]
+    .into_iter()
+    .for_each(|key| assert_eq!(amt(&referral_aft, key), amt(&aft, key)));
+
+    assert_eq!(amt(bef, &REFERRER_ACC), 0);
+    assert_eq!(amt(&referral_aft, &REFERRER_ACC), referral.inp);
+    assert_eq!(
+        amt(&referral_aft, ix_prefix.inp_pool_reserves()),
+        amt(&aft, ix_prefix.inp_pool_reserves()) - referral.inp
+    );
+
+    referral
+}
+
+#[test]
+fn swap_exact_in_v2_jupsol_to_wsol_referral_fixture() {
+    let (args, bef) = jupsol_to_wsol_fixture(0, 9_031);
+    let ix = exact_in::to_ix(&args);
+    let referral_ix = with_referral::<SWAP_EXACT_IN_V2_IX_DISCM>(ix.clone(), &args);
+
+    let QuoteReferral { fee, inp } = SVM.with(|svm| {
+        referral_test(
+            svm,
+            &args,
+            &bef,
+            [ix, referral_ix],
+            assert_correct_swap_exact_in_v2,
+        )
+    });
+
+    assert!(fee > 0);
+    assert!(inp > 0);
+}
+
+#[test]
+fn swap_exact_out_v2_jupsol_to_wsol_referral_fixture() {
+    let (args, bef) = jupsol_to_wsol_fixture(u64::MAX, 10_000);
+    let ix = exact_out::to_ix(&args);
+    let referral_ix = with_referral::<SWAP_EXACT_OUT_V2_IX_DISCM>(ix.clone(), &args);
+
+    let QuoteReferral { fee, inp } = SVM.with(|svm| {
+        referral_test(
+            svm,
+            &args,
+            &bef,
+            [ix, referral_ix],
+            assert_correct_swap_exact_out_v2,
+        )
+    });
+
+    assert!(fee > 0);
+    assert!(inp > 0);
+}
+
+#[test]
+fn swap_exact_in_v2_rem_liq_referral_rejected_fixture() {
+    let prefix_am = prefix_fixtures("inf", "jupsol");
+    let prefix_keys = IxPreAccs(prefix_am.0.each_ref().map(|(addr, _)| addr.to_bytes()));
+    let (pp_accs, pp_am) = flatslab_fixture_suf_accs();
+    let (out_accs, out_am) = jupsol_fixture_svc_suf_accs();
+
+    let accs = V2Accs {
+        ix_prefix: prefix_keys,
+        inp_calc_prog: inf1_ctl_jiminy::ID,
+        inp_calc: SvcCalcAccsAg::Inf(InfDummyCalcAccs),
+        out_calc_prog: *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+        out_calc: SvcAg::SanctumSplMulti(out_accs),
+        pricing_prog: *PricingAgTy::FlatSlab(()).program_id(),
+        pricing: PricingAg::FlatSlab(pp_accs),
+    };
+    let args = V2Args {
+        inp_lst_index: u32::MAX,
+        out_lst_index: JUPSOL_FIXTURE_LST_IDX.try_into().unwrap(),
+        limit: 0,
+        amount: 10_000,
+        accs,
+    };
+
+    let mut bef = prefix_am.0.into_iter().chain(pp_am).chain(out_am).collect();
+    fill_swap_prog_accs(&mut bef, &accs);
+    insert_referral_accs(&mut bef, *prefix_keys.inp_mint());
+
+    let ix = with_referral::<SWAP_EXACT_IN_V2_IX_DISCM>(exact_in::to_ix(&args), &args);
+    let result = SVM.with(|svm| mollusk_exec(svm, &[ix], &bef));
+
+    assert_jiminy_prog_err(
+        &result.unwrap_err(),
+        Inf1CtlCustomProgErr(Inf1CtlErr::RemoveLiquidityReferral),
+    );
+}
@@ -5,16 +5,31 @@ use inf1_core::instructions::sync_sol_value::{
 };
 use inf1_ctl_jiminy::{
     accounts::{
-        lst_state_list::LstStatePackedList,
-        pool_state::{PoolStateV2Packed, PoolStateV2U64s, VerPoolState},
+        lst_state_list::{LstStatePackedList, LstStatePackedListMut},
+        pool_state::{
+            PoolStateV2, PoolStateV2Packed, PoolStateV2U64s, VerPoolState,
+            POOL_STATE_V2_INITIAL_LEN,
+        },
     },
-    instructions::sync_sol_value::{
-        NewSyncSolValueIxPreAccsBuilder, SyncSolValueIxData, SyncSolValueIxPreAccs,
-        SyncSolValueIxPreKeysOwned, SYNC_SOL_VALUE_IX_PRE_ACCS_IDX_LST_MINT,
+    err::Inf1CtlErr,
+    instructions::{
+        swap::v2::IxPreAccs,
+        sync_sol_value::{
+            NewSyncSolValueIxPreAccsBuilder, SyncSolValueIxData, SyncSolValueIxPreAccs,
+            SyncSolValueIxPreKeysOwned, SYNC_SOL_VALUE_IX_PRE_ACCS_IDX_LST_MINT,
+        },
     },
     keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    program_err::Inf1CtlCustomProgErr,
+    sync_sol_val::SyncSolVal,
+    typedefs::{
+        compact_u64::CompactU64,
+        lst_state::{LstState, LstStatePacked},
+        snap::NewSnapBuilder,
+    },
     ID,
 };
+use inf1_pp_ag_core::{PricingAg, PricingAgTy};
 use inf1_svc_ag_core::{
     inf1_svc_generic::accounts::state::State,
     inf1_svc_lido_core::solido_legacy_core::TOKENKEG_PROGRAM,
@@ -29,15 +44,16 @@ use inf1_test_utils::{
     acc_bef_aft, any_lst_state, any_lst_state_list, any_normal_pk, any_pool_state_ver,
     any_pool_sv_lamports_solvent_strat, any_spl_stake_pool, any_wsol_lst_state,
     assert_diffs_lst_state_list, assert_diffs_pool_state_mm, assert_jiminy_prog_err,
-    find_pool_reserves_ata, fixtures_accounts_opt_cloned, jupsol_fixture_svc_suf_accs,
-    keys_signer_writable_to_metas, lst_state_list_account, mock_mint, mock_prog_acc,
-    mock_token_acc, mollusk_exec, pool_state_v2_u64s_just_lamports_strat,
+    find_pool_reserves_ata, fixtures_accounts_opt_cloned, flatslab_fixture_suf_accs,
+    get_token_account_amount, jupsol_fixture_svc_suf_accs, keys_signer_writable_to_metas,
+    lst_state_list_account, mock_mint, mock_prog_acc, mock_token_acc, mollusk_exec,
+    pool_state_v2_account, pool_state_v2_u64s_just_lamports_strat,
     pool_state_v2_u8_bools_normal_strat, raw_mint, raw_token_acc, silence_mollusk_logs, svc_accs,
     ver_pool_state_into_account, AccountMap, AnyLstStateArgs, AnyPoolStateArgs, Diff,
-    DiffsPoolStateV2, GenStakePoolArgs, LstStateListChanges, LstStatePks, NewLstStatePksBuilder,
-    NewSplStakePoolU64sBuilder, PoolStateBools, PoolStateV2FtaStrat, ProgramDataAddr,
-    SplStakePoolU64s, SplSvcAccParams, SvcAccParamsAg, JUPSOL_FIXTURE_LST_IDX, JUPSOL_MINT,
-    WSOL_MINT,
+    DiffLstStateArgs, DiffsPoolStateV2, GenStakePoolArgs, LstStateListChanges, LstStatePks,
+    NewLstStatePksBuilder, NewSplStakePoolU64sBuilder, PoolStateBools, PoolStateV2FtaStrat,
+    ProgramDataAddr, SplStakePoolU64s, SplSvcAccParams, SvcAccParamsAg, JUPSOL_FIXTURE_LST_IDX,
+    JUPSOL_MINT, WSOL_FIXTURE_LST_IDX, WSOL_MINT,
 };
 use jiminy_cpi::program_error::ProgramError;
 use mollusk_svm::Mollusk;
@@ -46,7 +62,14 @@ use sanctum_spl_token_jiminy::sanctum_spl_token_core::state::account::RawTokenAc
 use solana_instruction::Instruction;
 use solana_pubkey::Pubkey;
 
-use crate::common::{max_sol_val_no_overflow, MAX_LAMPORTS_OVER_SUPPLY, MAX_LST_STATES, SVM};
+use crate::{
+    common::{max_sol_val_no_overflow, MAX_LAMPORTS_OVER_SUPPLY, MAX_LST_STATES, SVM},
+    tests::swap::{
+        common::fill_swap_prog_accs,
+        v2::{exact_in, prefix_fixtures},
+        V2Accs, V2Args,
+    },
+};
 
 type SyncSolValueKeysBuilder =
     SyncSolValueIxAccs<[u8; 32], SyncSolValueIxPreKeysOwned, SvcCalcAccsAg>;
@@ -87,8 +110,17 @@ fn assert_correct_sync(
     mint: &[u8; 32],
     migration_slot: u64,
 ) -> i128 {
-    let [[pool_bef, pool_aft], lst_state_lists] = [POOL_STATE_ID, LST_STATE_LIST_ID]
-        .map(|a| acc_bef_aft(&Pubkey::new_from_array(a), bef, aft));
+    let [[pool_bef, pool_aft], lst_state_lists, [reserves, _]] = [
+        POOL_STATE_ID,
+        LST_STATE_LIST_ID,
+        find_pool_reserves_ata(&TOKENKEG_PROGRAM, mint).0.to_bytes(),
+    ]
+    .map(|a| acc_bef_aft(&Pubkey::new_from_array(a), bef, aft));
+
+    let pool_bef = VerPoolState::try_from_acc_data(&pool_bef.data).unwrap();
+    let pool_aft = PoolStateV2Packed::of_acc_data(&pool_aft.data)
+        .unwrap()
+        .into_pool_state_v2();
 
     let [lst_state_list_bef, lst_state_list_aft]: [Vec<_>; 2] =
         lst_state_lists.each_ref().map(|a| {
@@ -99,15 +131,43 @@ fn assert_correct_sync(
                 .map(|x| x.into_lst_state())
                 .collect()
         });
-    let (diffs, expected_delta) = LstStateListChanges::new(&lst_state_list_bef)
-        .with_det_svc_by_mint(mint, &lst_state_list_aft);
-    let diffs = diffs.build();
+    let idx = lst_state_list_bef
+        .iter()
+        .position(|l| l.mint == *mint)
+        .unwrap();
+    let [lst_bef, lst_aft] = [&lst_state_list_bef, &lst_state_list_aft].map(|l| l[idx]);
+    let reserves_balance = get_token_account_amount(&reserves.data);
+    let tripped = SyncSolVal {
+        lst_sol_val: NewSnapBuilder::start()
+            .with_old(lst_bef.sol_value)
+            .with_new(lst_aft.sol_value)
+            .build(),
+    }
+    .trips_circuit_breaker(
+        &NewSnapBuilder::start()
+            .with_old(lst_bef.synced_reserves_balance.get())
+            .with_new(reserves_balance)
+            .build(),
+        pool_aft.sol_value_circuit_breaker_nanos,
+    );
+    let was_input_disabled = lst_bef.is_input_disabled != 0;
+    let diffs = LstStateListChanges::new(&lst_state_list_bef)
+        .with_diff(
+            idx,
+            DiffLstStateArgs {
+                sol_value: Diff::Changed(lst_bef.sol_value, lst_aft.sol_value),
+                // tripping disables input, but never re-enables it
+                is_input_disabled: Diff::Changed(was_input_disabled, was_input_disabled || tripped),
+                ..Default::default()
+            },
+        )
+        .build();
     assert_diffs_lst_state_list(&diffs, &lst_state_list_bef, &lst_state_list_aft);
-
-    let pool_bef = VerPoolState::try_from_acc_data(&pool_bef.data).unwrap();
-    let pool_aft = PoolStateV2Packed::of_acc_data(&pool_aft.data)
-        .unwrap()
-        .into_pool_state_v2();
+    assert_eq!(
+        lst_aft.synced_reserves_balance,
+        CompactU64::new(reserves_balance)
+    );
+    let expected_delta = i128::from(lst_aft.sol_value) - i128::from(lst_bef.sol_value);
 
     let expected_total_sol_value =
         u64::try_from(i128::from(pool_bef.total_sol_value()) + expected_delta).unwrap();
@@ -175,6 +235,267 @@ fn sync_sol_value_jupsol_fixture() {
     );
 }
 
+#[test]
+fn sync_sol_value_jupsol_fixture_extends_pool_state() {
+    let ix_prefix = sync_sol_value_ix_pre_keys_owned(&TOKENKEG_PROGRAM, JUPSOL_MINT.to_bytes());
+    let builder = SyncSolValueKeysBuilder {
+        ix_prefix,
+        calc_prog: *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+        calc: SvcAg::SanctumSplMulti(jupsol_fixture_svc_suf_accs().0),
+    };
+    let ix = sync_sol_value_ix(&builder, JUPSOL_FIXTURE_LST_IDX as u32);
+    let accounts = sync_sol_value_fixtures_accounts_opt(&builder);
+    let resulting_accounts = SVM
+        .with(|svm| mollusk_exec(svm, &[ix], &accounts))
+        .unwrap()
+        .resulting_accounts;
+
+    let [pool_bef, pool_aft] = acc_bef_aft(
+        &Pubkey::new_from_array(POOL_STATE_ID),
+        &accounts,
+        &resulting_accounts,
+    );
+    // fixture is the onchain v2 account from before fields were appended
+    assert_eq!(pool_bef.data.len(), POOL_STATE_V2_INITIAL_LEN);
+    assert_eq!(pool_aft.data.len(), core::mem::size_of::<PoolStateV2>());
+
+    let pool_aft = PoolStateV2Packed::of_acc_data(&pool_aft.data)
+        .unwrap()
+        .into_pool_state_v2();
+    assert_eq!(pool_aft.min_fee_nanos, 0);
+    assert_eq!(pool_aft.sol_value_circuit_breaker_nanos, 0);
+
+    // fields that existed before the extension are unchanged other than
+    // those modified by the sync itself, which `assert_correct_sync` covers
+    let VerPoolState::V2(pool_bef) = VerPoolState::try_from_acc_data(&pool_bef.data).unwrap()
+    else {
+        panic!("fixture should be v2");
+    };
+    assert_eq!(
+        PoolStateV2 {
+            total_sol_value: pool_aft.total_sol_value,
+            withheld_lamports: pool_aft.withheld_lamports,
+            protocol_fee_lamports: pool_aft.protocol_fee_lamports,
+            last_release_slot: pool_aft.last_release_slot,
+            ..pool_bef
+        },
+        pool_aft
+    );
+}
+
+/// Sets the pool's SOL value circuit breaker and jupsol's synced reserves balance
+/// to the fixture's reserves balance, as if jupsol was last synced with the same balance
+fn set_fixture_circuit_breaker(
+    accounts: &mut AccountMap,
+    builder: &SyncSolValueKeysBuilder,
+    circuit_breaker_nanos: u32,
+) {
+    let pool_acc = accounts
+        .get_mut(&Pubkey::new_from_array(POOL_STATE_ID))
+        .unwrap();
+    let VerPoolState::V2(mut pool) = VerPoolState::try_from_acc_data(&pool_acc.data).unwrap()
+    else {
+        panic!("fixture should be v2");
+    };
+    pool.sol_value_circuit_breaker_nanos = circuit_breaker_nanos;
+    *pool_acc = pool_state_v2_account(pool);
+
+    let balance = get_token_account_amount(
+        &accounts[&Pubkey::new_from_array(*builder.ix_prefix.pool_reserves())].data,
+    );
+    let list = &mut accounts
+        .get_mut(&Pubkey::new_from_array(LST_STATE_LIST_ID))
+        .unwrap()
+        .data;
+    let packed = &mut LstStatePackedListMut::of_acc_data(list).unwrap().0[JUPSOL_FIXTURE_LST_IDX];
+    let mut lst_state = packed.into_lst_state();
+    lst_state.synced_reserves_balance = CompactU64::new(balance);
+    *packed = *LstStatePacked::of_acc_data_arr(lst_state.as_acc_data_arr());
+}
+
+fn jupsol_fixture_lst_state(accounts: &AccountMap) -> LstState {
+    LstStatePackedList::of_acc_data(&accounts[&Pubkey::new_from_array(LST_STATE_LIST_ID)].data)
+        .unwrap()
+        .0[JUPSOL_FIXTURE_LST_IDX]
+        .into_lst_state()
+}
+
+#[test]
+fn sync_sol_value_jupsol_fixture_circuit_breaker() {
+    let ix_prefix = sync_sol_value_ix_pre_keys_owned(&TOKENKEG_PROGRAM, JUPSOL_MINT.to_bytes());
+    let builder = SyncSolValueKeysBuilder {
+        ix_prefix,
+        calc_prog: *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+        calc: SvcAg::SanctumSplMulti(jupsol_fixture_svc_suf_accs().0),
+    };
+    let ix = sync_sol_value_ix(&builder, JUPSOL_FIXTURE_LST_IDX as u32);
+
+    // fixture sync changes jupsol's per-token SOL value by ~0.56%,
+    // so 1 nano always trips and 100% never does
+    let [tripped, not_tripped] = [1, 1_000_000_000].map(|circuit_breaker_nanos| {
+        let mut accounts = sync_sol_value_fixtures_accounts_opt(&builder);
+        set_fixture_circuit_breaker(&mut accounts, &builder, circuit_breaker_nanos);
+
+        SVM.with(|svm| mollusk_exec(svm, &[ix.clone()], &accounts))
+            .unwrap()
+            .resulting_accounts
+    });
+
+    let [tripped_list, not_tripped_list] = [&tripped, &not_tripped].map(jupsol_fixture_lst_state);
+    assert_eq!(tripped_list.is_input_disabled, 1);
+    assert_eq!(not_tripped_list.is_input_disabled, 0);
+
+    // sync still goes through when tripped
+    assert_eq!(tripped_list.sol_value, not_tripped_list.sol_value);
+    assert_eq!(
+        tripped[&Pubkey::new_from_array(POOL_STATE_ID)],
+        not_tripped[&Pubkey::new_from_array(POOL_STATE_ID)]
+    );
+}
+
+#[test]
+fn sync_sol_value_jupsol_fixture_donation_does_not_trip_circuit_breaker() {
+    // above the ~0.56% change in per-token SOL value of the fixture sync
+    const CIRCUIT_BREAKER_NANOS: u32 = 10_000_000;
+
+    let ix_prefix = sync_sol_value_ix_pre_keys_owned(&TOKENKEG_PROGRAM, JUPSOL_MINT.to_bytes());
+    let builder = SyncSolValueKeysBuilder {
+        ix_prefix,
+        calc_prog: *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+        calc: SvcAg::SanctumSplMulti(jupsol_fixture_svc_suf_accs().0),
+    };
+    let ix = sync_sol_value_ix(&builder, JUPSOL_FIXTURE_LST_IDX as u32);
+    let mut bef = sync_sol_value_fixtures_accounts_opt(&builder);
+    set_fixture_circuit_breaker(&mut bef, &builder, CIRCUIT_BREAKER_NANOS);
+
+    // donate as many tokens as the reserves already hold,
+    // roughly doubling jupsol's SOL value
+    let reserves = Pubkey::new_from_array(*builder.ix_prefix.pool_reserves());
+    let donated_balance = get_token_account_amount(&bef[&reserves].data) * 2;
+    bef.insert(
+        reserves,
+        mock_token_acc(raw_token_acc(
+            JUPSOL_MINT.to_bytes(),
+            POOL_STATE_ID,
+            donated_balance,
+        )),
+    );
+
+    let aft = SVM
+        .with(|svm| mollusk_exec(svm, &[ix], &bef))
+        .unwrap()
+        .resulting_accounts;
+
+    let [lst_bef, lst_aft] = [&bef, &aft].map(jupsol_fixture_lst_state);
+    assert!(lst_aft.sol_value > lst_bef.sol_value / 2 * 3);
+    assert_eq!(lst_aft.is_input_disabled, 0);
+    assert_eq!(
+        lst_aft.synced_reserves_balance,
+        CompactU64::new(donated_balance)
+    );
+}
+
+/// Returns `(args, accounts)` of a SwapExactInV2 between jupsol and wsol on the fixtures
+fn jupsol_wsol_swap_exact_in_fixture(jupsol_is_inp: bool) -> (V2Args, AccountMap) {
+    let (jupsol_calc, jupsol_calc_am) = jupsol_fixture_svc_suf_accs();
+    let (pp_accs, pp_am) = flatslab_fixture_suf_accs();
+    let jupsol = (
+        "jupsol",
+        *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+        SvcAg::SanctumSplMulti(jupsol_calc),
+        JUPSOL_FIXTURE_LST_IDX,
+    );
+    let wsol = (
+        "wsol",
+        *SvcAgTy::Wsol(()).svc_program_id(),
+        SvcAg::Wsol(WsolCalcAccs),
+        WSOL_FIXTURE_LST_IDX,
+    );
+    let [(inp, inp_calc_prog, inp_calc, inp_idx), (out, out_calc_prog, out_calc, out_idx)] =
+        if jupsol_is_inp {
+            [jupsol, wsol]
+        } else {
+            [wsol, jupsol]
+        };
+
+    let prefix_am = prefix_fixtures(inp, out);
+    let accs = V2Accs {
+        ix_prefix: IxPreAccs(prefix_am.0.each_ref().map(|(addr, _)| addr.to_bytes())),
+        inp_calc_prog,
+        inp_calc,
+        out_calc_prog,
+        out_calc,
+        pricing_prog: *PricingAgTy::FlatSlab(()).program_id(),
+        pricing: PricingAg::FlatSlab(pp_accs),
+    };
+    let args = V2Args {
+        inp_lst_index: inp_idx.try_into().unwrap(),
+        out_lst_index: out_idx.try_into().unwrap(),
+        limit: 0,
+        amount: 10_000,
+        accs,
+    };
+
+    let mut am = prefix_am
+        .0
+        .into_iter()
+        .chain(pp_am)
+        .chain(jupsol_calc_am)
+        .collect();
+    fill_swap_prog_accs(&mut am, &accs);
+    (args, am)
+}
+
+#[test]
+fn sync_sol_value_jupsol_fixture_persists_swap_circuit_breaker_trip() {
+    let ix_prefix = sync_sol_value_ix_pre_keys_owned(&TOKENKEG_PROGRAM, JUPSOL_MINT.to_bytes());
+    let builder = SyncSolValueKeysBuilder {
+        ix_prefix,
+        calc_prog: *SvcAgTy::SanctumSplMulti(()).svc_program_id(),
+        calc: SvcAg::SanctumSplMulti(jupsol_fixture_svc_suf_accs().0),
+    };
+    let sync_ix = sync_sol_value_ix(&builder, JUPSOL_FIXTURE_LST_IDX as u32);
+    let [(jupsol_inp_args, jupsol_inp_am), (jupsol_out_args, jupsol_out_am)] =
+        [true, false].map(jupsol_wsol_swap_exact_in_fixture);
+    let [jupsol_inp_ix, jupsol_out_ix] = [&jupsol_inp_args, &jupsol_out_args].map(exact_in::to_ix);
+
+    let mut bef: AccountMap = sync_sol_value_fixtures_accounts_opt(&builder)
+        .into_iter()
+        .chain(jupsol_inp_am)
+        .chain(jupsol_out_am)
+        .collect();
+    // fixture sync changes jupsol's per-token SOL value by ~0.56%, so 1 nano always trips
+    set_fixture_circuit_breaker(&mut bef, &builder, 1);
+
+    // the trip reverts the swap along with the disabling of jupsol's input,
+    // so swaps into and out of jupsol both keep failing
+    [&jupsol_inp_ix, &jupsol_out_ix].into_iter().for_each(|ix| {
+        let res = SVM.with(|svm| mollusk_exec(svm, core::slice::from_ref(ix), &bef));
+        assert_jiminy_prog_err(
+            &res.unwrap_err(),
+            Inf1CtlCustomProgErr(Inf1CtlErr::LstSolValueCircuitBreakerTripped),
+        );
+    });
+
+    // until anyone runs SyncSolValue, which persists the trip
+    let synced = SVM
+        .with(|svm| mollusk_exec(svm, &[sync_ix], &bef))
+        .unwrap()
+        .resulting_accounts;
+    assert_eq!(jupsol_fixture_lst_state(&synced).is_input_disabled, 1);
+    bef.extend(synced);
+
+    SVM.with(|svm| {
+        assert_jiminy_prog_err(
+            &mollusk_exec(svm, &[jupsol_inp_ix], &bef).unwrap_err(),
+            Inf1CtlCustomProgErr(Inf1CtlErr::LstInputDisabled),
+        );
+        // jupsol's SOL value is now synced with its reserves balance,
+        // so it can be swapped out of the pool again
+        mollusk_exec(svm, &[jupsol_out_ix], &bef).unwrap();
+    });
+}
+
 fn sync_sol_value_test(
     svm: &Mollusk,
     ix: Instruction,
@@ -281,6 +602,7 @@ fn correct_pool_state_strat() -> impl Strategy<Value = VerPoolState> {
                 // In the meantime we have to keep it at 0 to avoid TimeWentBackwards
                 u64s: pool_state_v2_u64s_just_lamports_strat(psv)
                     .with_last_release_slot(Some(Just(0).boxed())),
+                sol_value_circuit_breaker_nanos: Some(any::<u32>().boxed()),
                 ..Default::default()
             },
         )
@@ -303,10 +625,14 @@ fn wsol_correct_strat() -> impl Strategy<Value = (SyncSolValueParams, TestParams
                 Just(pool),
                 Just(wsol_lsd),
                 0..=max_sol_val_no_overflow(pool.total_sol_value(), wsol_lsd.lst_state.sol_value),
+                // wsol's per-token SOL value is always 1, so syncing from a
+                // balance equal to the sol value never trips the circuit breaker
+                prop_oneof![Just(wsol_lsd.lst_state.sol_value), any::<u64>()],
                 any_lst_state_list(Default::default(), None, 0..=MAX_LST_STATES),
             )
         })
-        .prop_map(|(pool, wsol_lsd, new_bal, mut lsl)| {
+        .prop_map(|(pool, mut wsol_lsd, new_bal, synced_bal, mut lsl)| {
+            wsol_lsd.lst_state.synced_reserves_balance = CompactU64::new(synced_bal);
             let lst_idx = lsl.upsert(wsol_lsd).try_into().unwrap();
             (
                 SyncSolValueIxAccs {
@@ -385,11 +711,13 @@ fn sanctum_spl_multi_correct_strat() -> impl Strategy<Value = (SyncSolValueParam
                 Just(stake_pool),
                 0..=max_sol_val_no_overflow(pool.total_sol_value(), lsd.lst_state.sol_value)
                     / MAX_LAMPORTS_OVER_SUPPLY,
+                any::<u64>(),
                 any_lst_state_list(Default::default(), None, 0..=MAX_LST_STATES),
             )
         })
         .prop_map(
-            |(pool, lsd, stake_pool_addr, stake_pool, new_bal, mut lsl)| {
+            |(pool, mut lsd, stake_pool_addr, stake_pool, new_bal, synced_bal, mut lsl)| {
+                lsd.lst_state.synced_reserves_balance = CompactU64::new(synced_bal);
                 let lst_idx = lsl.upsert(lsd).try_into().unwrap();
                 (
                     SyncSolValueIxAccs {
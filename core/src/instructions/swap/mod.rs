@@ -99,3 +99,93 @@ impl<T, I, C: SolValCalcAccs, D: SolValCalcAccs, P> IxArgs<T, I, C, D, P> {
         }
     }
 }
+
+impl<T, I, C: AsRef<[T]>, D: AsRef<[T]>, P> IxArgs<T, I, C, D, P> {
+    /// Same as [`Self::to_full`], but for when the SOL value calculator suffixes
+    /// are the actual account lists e.g. `AccountHandle`s of a CPI.
+    ///
+    /// Returns `None` if either suffix is too long
+    #[inline]
+    pub fn to_full_from_accs(&self) -> Option<inf1_ctl_core_swap::IxArgs> {
+        let Self {
+            inp_lst_index,
+            out_lst_index,
+            limit,
+            amount,
+            accs: IxAccs {
+                inp_calc, out_calc, ..
+            },
+        } = self;
+        // +1 for program account
+        let [inp_lst_value_calc_accs, out_lst_value_calc_accs] =
+            [inp_calc.as_ref(), out_calc.as_ref()]
+                .map(|suf| u8::try_from(suf.len()).ok().and_then(|l| l.checked_add(1)));
+        Some(inf1_ctl_core_swap::IxArgs {
+            inp_lst_value_calc_accs: inp_lst_value_calc_accs?,
+            out_lst_value_calc_accs: out_lst_value_calc_accs?,
+            inp_lst_index: *inp_lst_index,
+            out_lst_index: *out_lst_index,
+            limit: *limit,
+            amount: *amount,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use inf1_ctl_core::svc::InfDummyCalcAccs;
+
+    use super::*;
+
+    fn args<C, D>(
+        inp_calc: C,
+        out_calc: D,
+    ) -> IxArgs<[u8; 32], [[u8; 32]; 0], C, D, [[u8; 32]; 0]> {
+        IxArgs {
+            inp_lst_index: 1,
+            out_lst_index: 2,
+            limit: 3,
+            amount: 4,
+            accs: IxAccs {
+                ix_prefix: [],
+                inp_calc_prog: [5; 32],
+                inp_calc,
+                out_calc_prog: [6; 32],
+                out_calc,
+                pricing_prog: [7; 32],
+                pricing: [],
+            },
+        }
+    }
+
+    #[test]
+    fn to_full_from_accs_matches_to_full() {
+        let full = args(InfDummyCalcAccs, InfDummyCalcAccs).to_full();
+        assert_eq!(
+            args([[0u8; 32]; 0], [[0u8; 32]; 0]).to_full_from_accs(),
+            Some(full)
+        );
+    }
+
+    #[test]
+    fn to_full_from_accs_suffix_lens() {
+        assert_eq!(
+            args([[0u8; 32]; 3], [[0u8; 32]; 254]).to_full_from_accs(),
+            Some(inf1_ctl_core_swap::IxArgs {
+                inp_lst_value_calc_accs: 4,
+                out_lst_value_calc_accs: 255,
+                inp_lst_index: 1,
+                out_lst_index: 2,
+                limit: 3,
+                amount: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn to_full_from_accs_suffix_too_long() {
+        let long = vec![[0u8; 32]; 255];
+        assert_eq!(args(long.clone(), []).to_full_from_accs(), None);
+        assert_eq!(args([], long).to_full_from_accs(), None);
+    }
+}
@@ -0,0 +1,319 @@
+//! Adding and removing liquidity via `SwapExactInV2`/`SwapExactOutV2`
+//! by swapping to and from the LP token.
+
+use inf1_ctl_core::instructions::swap::v2::IxPreAccs;
+
+use crate::instructions::swap::{IxAccs, IxArgs};
+
+/// The LST index used for the LP token in V2 swaps
+pub const LP_LST_INDEX: u32 = u32::MAX;
+
+/// Accounts of a V2 swap that adds liquidity i.e. `out_mint = LP mint`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AddLiquidityIxAccs<T, C, P> {
+    /// `out_mint` and `out_pool_reserves` must be the LP mint
+    pub ix_prefix: IxPreAccs<T>,
+
+    pub inp_calc_prog: T,
+    pub inp_calc: C,
+
+    /// Stands in for the LP token's SOL value calculator program.
+    ///
+    /// Unchecked, so can be any account e.g. the controller program.
+    pub lp_calc_prog: T,
+
+    pub pricing_prog: T,
+    pub pricing: P,
+}
+
+impl<T, C, P> AddLiquidityIxAccs<T, C, P> {
+    #[inline]
+    pub fn into_swap_accs(self) -> IxAccs<T, IxPreAccs<T>, C, [T; 0], P> {
+        let Self {
+            ix_prefix,
+            inp_calc_prog,
+            inp_calc,
+            lp_calc_prog,
+            pricing_prog,
+            pricing,
+        } = self;
+        IxAccs {
+            ix_prefix,
+            inp_calc_prog,
+            inp_calc,
+            out_calc_prog: lp_calc_prog,
+            out_calc: [],
+            pricing_prog,
+            pricing,
+        }
+    }
+}
+
+/// Accounts of a V2 swap that removes liquidity i.e. `inp_mint = LP mint`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RemoveLiquidityIxAccs<T, D, P> {
+    /// `inp_mint` and `inp_pool_reserves` must be the LP mint
+    pub ix_prefix: IxPreAccs<T>,
+
+    /// Stands in for the LP token's SOL value calculator program.
+    ///
+    /// Unchecked, so can be any account e.g. the controller program.
+    pub lp_calc_prog: T,
+
+    pub out_calc_prog: T,
+    pub out_calc: D,
+
+    pub pricing_prog: T,
+    pub pricing: P,
+}
+
+impl<T, D, P> RemoveLiquidityIxAccs<T, D, P> {
+    #[inline]
+    pub fn into_swap_accs(self) -> IxAccs<T, IxPreAccs<T>, [T; 0], D, P> {
+        let Self {
+            ix_prefix,
+            lp_calc_prog,
+            out_calc_prog,
+            out_calc,
+            pricing_prog,
+            pricing,
+        } = self;
+        IxAccs {
+            ix_prefix,
+            inp_calc_prog: lp_calc_prog,
+            inp_calc: [],
+            out_calc_prog,
+            out_calc,
+            pricing_prog,
+            pricing,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AddLiquidityIxArgs<T, C, P> {
+    pub inp_lst_index: u32,
+
+    /// - min_amount_out for ExactIn
+    /// - max_amount_in for ExactOut
+    pub limit: u64,
+
+    pub amount: u64,
+
+    pub accs: AddLiquidityIxAccs<T, C, P>,
+}
+
+impl<T, C, P> AddLiquidityIxArgs<T, C, P> {
+    #[inline]
+    pub fn into_swap_args(self) -> IxArgs<T, IxPreAccs<T>, C, [T; 0], P> {
+        let Self {
+            inp_lst_index,
+            limit,
+            amount,
+            accs,
+        } = self;
+        IxArgs {
+            inp_lst_index,
+            out_lst_index: LP_LST_INDEX,
+            limit,
+            amount,
+            accs: accs.into_swap_accs(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RemoveLiquidityIxArgs<T, D, P> {
+    pub out_lst_index: u32,
+
+    /// - min_amount_out for ExactIn
+    /// - max_amount_in for ExactOut
+    pub limit: u64,
+
+    pub amount: u64,
+
+    pub accs: RemoveLiquidityIxAccs<T, D, P>,
+}
+
+impl<T, D, P> RemoveLiquidityIxArgs<T, D, P> {
+    #[inline]
+    pub fn into_swap_args(self) -> IxArgs<T, IxPreAccs<T>, [T; 0], D, P> {
+        let Self {
+            out_lst_index,
+            limit,
+            amount,
+            accs,
+        } = self;
+        IxArgs {
+            inp_lst_index: LP_LST_INDEX,
+            out_lst_index,
+            limit,
+            amount,
+            accs: accs.into_swap_accs(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use inf1_ctl_core::{
+        instructions::swap::v2::{IxPreKeysOwned, IX_PRE_ACCS_LEN},
+        svc::InfDummyCalcAccs,
+    };
+    use inf1_pp_core::traits::main::PriceExactInAccs;
+    use inf1_pp_flatfee_core::instructions::pricing::price::{
+        FlatFeePriceAccs, IxSufKeysOwned as FlatFeeSufKeysOwned,
+    };
+    use inf1_svc_core::traits::SolValCalcAccs;
+    use inf1_svc_spl_core::instructions::sol_val_calc::SplCalcAccs;
+
+    use crate::instructions::swap::v2::{
+        exact_in::{
+            swap_exact_in_v2_ix_is_signer, swap_exact_in_v2_ix_is_writer,
+            swap_exact_in_v2_ix_keys_owned,
+        },
+        exact_out::swap_exact_out_v2_ix_keys_owned,
+    };
+
+    use super::*;
+
+    const LST_CALC_PROG: [u8; 32] = [30; 32];
+    const PRICING_PROG: [u8; 32] = [31; 32];
+    const SPL: SplCalcAccs = SplCalcAccs {
+        stake_pool_addr: [32; 32],
+    };
+    const PRICING: FlatFeePriceAccs =
+        FlatFeePriceAccs(FlatFeeSufKeysOwned::memset([33; 32]).const_with_output_fee([34; 32]));
+
+    fn prefix() -> IxPreKeysOwned {
+        IxPreAccs::new(core::array::from_fn(|i| [i as u8 + 1; 32]))
+    }
+
+    fn add_liq() -> AddLiquidityIxArgs<
+        [u8; 32],
+        <SplCalcAccs as SolValCalcAccs>::KeysOwned,
+        <FlatFeePriceAccs as PriceExactInAccs>::KeysOwned,
+    > {
+        AddLiquidityIxArgs {
+            inp_lst_index: 5,
+            limit: 6,
+            amount: 7,
+            accs: AddLiquidityIxAccs {
+                ix_prefix: prefix(),
+                inp_calc_prog: LST_CALC_PROG,
+                inp_calc: SPL.suf_keys_owned(),
+                lp_calc_prog: inf1_ctl_core::ID,
+                pricing_prog: PRICING_PROG,
+                pricing: PRICING.suf_keys_owned(),
+            },
+        }
+    }
+
+    fn remove_liq() -> RemoveLiquidityIxArgs<
+        [u8; 32],
+        <SplCalcAccs as SolValCalcAccs>::KeysOwned,
+        <FlatFeePriceAccs as PriceExactInAccs>::KeysOwned,
+    > {
+        RemoveLiquidityIxArgs {
+            out_lst_index: 5,
+            limit: 6,
+            amount: 7,
+            accs: RemoveLiquidityIxAccs {
+                ix_prefix: prefix(),
+                lp_calc_prog: inf1_ctl_core::ID,
+                out_calc_prog: LST_CALC_PROG,
+                out_calc: SPL.suf_keys_owned(),
+                pricing_prog: PRICING_PROG,
+                pricing: PRICING.suf_keys_owned(),
+            },
+        }
+    }
+
+    #[test]
+    fn add_liquidity_round_trip() {
+        let add = add_liq().into_swap_args();
+        let swap = IxArgs {
+            inp_lst_index: 5,
+            out_lst_index: LP_LST_INDEX,
+            limit: 6,
+            amount: 7,
+            accs: IxAccs {
+                ix_prefix: prefix(),
+                inp_calc_prog: LST_CALC_PROG,
+                inp_calc: SPL,
+                out_calc_prog: inf1_ctl_core::ID,
+                out_calc: InfDummyCalcAccs,
+                pricing_prog: PRICING_PROG,
+                pricing: PRICING,
+            },
+        };
+
+        assert_eq!(add.to_full_from_accs(), Some(swap.to_full()));
+        assert!(add
+            .accs
+            .seq()
+            .eq(swap_exact_in_v2_ix_keys_owned(&swap.accs).seq()));
+        assert!(add
+            .accs
+            .seq()
+            .eq(swap_exact_out_v2_ix_keys_owned(&swap.accs).seq()));
+        [
+            swap_exact_in_v2_ix_is_signer(&swap.accs),
+            swap_exact_in_v2_ix_is_writer(&swap.accs),
+        ]
+        .iter()
+        .for_each(|flags| assert_eq!(flags.seq().count(), add.accs.seq().count()));
+    }
+
+    #[test]
+    fn remove_liquidity_round_trip() {
+        let remove = remove_liq().into_swap_args();
+        let swap = IxArgs {
+            inp_lst_index: LP_LST_INDEX,
+            out_lst_index: 5,
+            limit: 6,
+            amount: 7,
+            accs: IxAccs {
+                ix_prefix: prefix(),
+                inp_calc_prog: inf1_ctl_core::ID,
+                inp_calc: InfDummyCalcAccs,
+                out_calc_prog: LST_CALC_PROG,
+                out_calc: SPL,
+                pricing_prog: PRICING_PROG,
+                pricing: PRICING,
+            },
+        };
+
+        assert_eq!(remove.to_full_from_accs(), Some(swap.to_full()));
+        assert!(remove
+            .accs
+            .seq()
+            .eq(swap_exact_in_v2_ix_keys_owned(&swap.accs).seq()));
+        assert!(remove
+            .accs
+            .seq()
+            .eq(swap_exact_out_v2_ix_keys_owned(&swap.accs).seq()));
+        [
+            swap_exact_in_v2_ix_is_signer(&swap.accs),
+            swap_exact_in_v2_ix_is_writer(&swap.accs),
+        ]
+        .iter()
+        .for_each(|flags| assert_eq!(flags.seq().count(), remove.accs.seq().count()));
+    }
+
+    #[test]
+    fn lp_calc_has_no_suffix() {
+        let add = add_liq().into_swap_args().to_full_from_accs().unwrap();
+        assert_eq!(add.out_lst_index, LP_LST_INDEX);
+        assert_eq!(add.out_lst_value_calc_accs, 1);
+
+        let remove = remove_liq().into_swap_args().to_full_from_accs().unwrap();
+        assert_eq!(remove.inp_lst_index, LP_LST_INDEX);
+        assert_eq!(remove.inp_lst_value_calc_accs, 1);
+
+        // prefix, LST calc prog + suffix, LP calc prog, pricing prog + suffix
+        let len = IX_PRE_ACCS_LEN + 1 + 4 + 1 + 1 + 2;
+        assert_eq!(add_liq().into_swap_args().accs.seq().count(), len);
+        assert_eq!(remove_liq().into_swap_args().accs.seq().count(), len);
+    }
+}
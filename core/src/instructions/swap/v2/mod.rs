@@ -1,2 +1,3 @@
 pub mod exact_in;
 pub mod exact_out;
+pub mod liquidity;
@@ -1,3 +1,5 @@
+use inf1_ctl_core::typedefs::fee_nanos::{FeeNanos, NANOS_DENOM};
+
 pub mod rebalance;
 pub mod swap;
 
@@ -14,7 +16,10 @@ pub struct Quote {
     pub out: u64,
 
     /// The amount of fee accrued to the pool,
-    /// in terms of sol value (lamports)
+    /// in terms of sol value (lamports).
+    ///
+    /// If there is a referrer, this includes [`QuoteReferral::fee`]
+    /// and the pool only accrues the difference.
     pub fee: u64,
 
     /// SOL value of `inp` input tokens.
@@ -29,3 +34,100 @@ pub struct Quote {
     /// This is INF for AddLiquidity
     pub out_mint: [u8; 32],
 }
+
+/// Portion of a [`Quote`]'s fee paid to a referrer
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QuoteReferral {
+    /// Portion of [`Quote::fee`] paid to the referrer,
+    /// in terms of sol value (lamports)
+    pub fee: u64,
+
+    /// Amount of input tokens, included in [`Quote::inp`],
+    /// that are transferred to the referrer instead of the pool
+    pub inp: u64,
+}
+
+impl Quote {
+    /// Portion of `self.fee` paid to a referrer that is owed `referral_fee_nanos` of it.
+    ///
+    /// Both the referral fee and its equivalent amount of input tokens
+    /// are rounded down in favour of the pool.
+    ///
+    /// `self.inp` and `self.out` are unaffected, so slippage limits are too.
+    #[inline]
+    pub const fn referral(&self, referral_fee_nanos: FeeNanos) -> QuoteReferral {
+        let fee = mul_div_floor(
+            self.fee,
+            referral_fee_nanos.get() as u64,
+            NANOS_DENOM as u64,
+        );
+        // inp_sol_val is never 0 for a valid quote
+        let inp = if self.inp_sol_val == 0 {
+            0
+        } else {
+            mul_div_floor(self.inp, fee, self.inp_sol_val)
+        };
+        QuoteReferral { fee, inp }
+    }
+}
+
+/// floor(a * b / c) where b <= c, so result never exceeds a
+#[inline]
+const fn mul_div_floor(a: u64, b: u64, c: u64) -> u64 {
+    ((a as u128 * b as u128) / c as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn quote(inp: u64, fee: u64, inp_sol_val: u64) -> Quote {
+        Quote {
+            inp,
+            out: 1,
+            fee,
+            inp_sol_val,
+            inp_mint: [1; 32],
+            out_mint: [2; 32],
+        }
+    }
+
+    #[test]
+    fn referral_rounds_down() {
+        // 10% of 999 lamports of fee = 99.9
+        // 100 input tokens worth 300 lamports => 99 lamports = 33 input tokens
+        let nanos = FeeNanos::new(100_000_000).unwrap();
+        assert_eq!(
+            quote(100, 999, 300).referral(nanos),
+            QuoteReferral { fee: 99, inp: 33 }
+        );
+    }
+
+    #[test]
+    fn referral_zero_inp_sol_val() {
+        assert_eq!(
+            quote(100, 0, 0).referral(FeeNanos::MAX),
+            QuoteReferral { fee: 0, inp: 0 }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn referral_bounded(
+            inp: u64,
+            (inp_sol_val, fee) in any::<u64>().prop_flat_map(|v| (Just(v), 0..=v)),
+            nanos in 0..=NANOS_DENOM,
+        ) {
+            let q = quote(inp, fee, inp_sol_val);
+
+            let QuoteReferral { fee: r_fee, inp: r_inp } = q.referral(FeeNanos::new(nanos).unwrap());
+            prop_assert!(r_fee <= fee);
+            prop_assert!(r_inp <= inp);
+
+            prop_assert_eq!(q.referral(FeeNanos::ZERO), QuoteReferral::default());
+            prop_assert_eq!(q.referral(FeeNanos::MAX).fee, fee);
+        }
+    }
+}
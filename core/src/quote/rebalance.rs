@@ -1,5 +1,6 @@
 use core::{error::Error, fmt::Display};
 
+use inf1_ctl_core::typedefs::disable_modes::{DisableMode, DisableModes};
 use inf1_svc_core::traits::SolValCalc;
 
 use crate::err::NotEnoughLiquidityErr;
@@ -29,6 +30,12 @@ pub struct RebalanceQuoteArgs<I, O> {
     pub inp_calc: I,
 
     pub out_calc: O,
+
+    /// SOL value the pool may lose in the rebalance,
+    /// reducing the amount of input tokens required.
+    ///
+    /// Should be `pool_state.rebalance_max_loss(pool_config.rebalance_max_loss_lamports)`
+    pub max_loss: u64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -40,6 +47,7 @@ pub struct RebalanceQuote {
     ///
     /// SOL value of `inp` input tokens
     /// should be the same, barring rounding
+    /// and any loss absorbed by the pool
     pub out_sol_val: u64,
 
     /// Amount of input tokens that needs to enter the pool by EndRebalance
@@ -55,6 +63,7 @@ pub enum RebalanceQuoteErr<I, O> {
     OutCalc(O),
     NotEnoughLiquidity(NotEnoughLiquidityErr),
     Overflow,
+    Disabled,
 }
 
 impl<I: Display, O: Display> Display for RebalanceQuoteErr<I, O> {
@@ -65,6 +74,7 @@ impl<I: Display, O: Display> Display for RebalanceQuoteErr<I, O> {
             Self::Overflow => f.write_str("arithmetic overflow"),
             Self::NotEnoughLiquidity(e) => e.fmt(f),
             Self::InpCalc(e) => e.fmt(f),
+            Self::Disabled => f.write_str("pool rebalance disabled"),
         }
     }
 }
@@ -77,6 +87,19 @@ impl<I: core::fmt::Debug + Display, O: core::fmt::Debug + Display> Error
 
 pub type RebalanceQuoteResult<I, O> = Result<RebalanceQuote, RebalanceQuoteErr<I, O>>;
 
+/// Mirrors the onchain check that fails StartRebalance
+/// if [`DisableMode::Rebalance`] is in the pool's `disable_modes`
+#[inline]
+pub fn verify_rebalance_not_disabled<I, O>(
+    disable_modes: DisableModes,
+) -> Result<(), RebalanceQuoteErr<I, O>> {
+    if disable_modes.is_disabled(DisableMode::Rebalance) {
+        Err(RebalanceQuoteErr::Disabled)
+    } else {
+        Ok(())
+    }
+}
+
 pub fn quote_rebalance_exact_out<I: SolValCalc, O: SolValCalc>(
     RebalanceQuoteArgs {
         amt,
@@ -86,6 +109,7 @@ pub fn quote_rebalance_exact_out<I: SolValCalc, O: SolValCalc>(
         out_calc,
         inp_reserves,
         out_reserves,
+        max_loss,
     }: RebalanceQuoteArgs<I, O>,
 ) -> RebalanceQuoteResult<I::Error, O::Error> {
     if amt > out_reserves {
@@ -116,19 +140,22 @@ pub fn quote_rebalance_exact_out<I: SolValCalc, O: SolValCalc>(
     // want to find:
     // smallest `post - pre` s.t.
     // s(post) - s(pre) = x
-    // where x = sol_value,
+    // where x = sol_value - max_loss,
     // s(y) = lst_to_sol(y).start()
     //
     // s(post) = x + s(pre)
 
     let req_inp_post_sol_value = inp_pre_sol_value
-        .checked_add(out_sol_val)
+        .checked_add(out_sol_val.saturating_sub(max_loss))
         .ok_or(RebalanceQuoteErr::Overflow)?;
 
-    let post_inp = *inp_calc
+    // start search from at least `inp_reserves`
+    // because `post - pre` cannot be negative
+    let post_inp = (*inp_calc
         .sol_to_lst(req_inp_post_sol_value)
         .map_err(RebalanceQuoteErr::InpCalc)?
-        .start();
+        .start())
+    .max(inp_reserves);
     // TODO: this loop kinda suss for perf, improve
     let inp = (post_inp..)
         .map(|possible_post| {
@@ -1,16 +1,20 @@
 use core::{error::Error, fmt::Display};
 
+use inf1_ctl_core::typedefs::disable_modes::DisableMode;
+
 use crate::err::NotEnoughLiquidityErr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum QuoteErr<I, O, P> {
     InpCalc(I),
     InpDisabled,
+    Disabled(DisableMode),
     OutCalc(O),
     PoolLoss,
     NotEnoughLiquidity(NotEnoughLiquidityErr),
     Pricing(P),
     ZeroValue,
+    FeeBelowMin,
 }
 
 impl<I: Display, O: Display, P: Display> Display for QuoteErr<I, O, P> {
@@ -19,11 +23,13 @@ impl<I: Display, O: Display, P: Display> Display for QuoteErr<I, O, P> {
         match self {
             Self::InpCalc(e) => e.fmt(f),
             Self::InpDisabled => f.write_str("LST input disabled"),
+            Self::Disabled(m) => f.write_fmt(format_args!("pool {} disabled", m.as_str())),
             Self::NotEnoughLiquidity(e) => e.fmt(f),
             Self::OutCalc(e) => e.fmt(f),
             Self::PoolLoss => f.write_str("pool would lose SOL value"),
             Self::Pricing(e) => e.fmt(f),
             Self::ZeroValue => f.write_str("zero value"),
+            Self::FeeBelowMin => f.write_str("fee below pool minimum"),
         }
     }
 }
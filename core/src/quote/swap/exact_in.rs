@@ -3,7 +3,7 @@ use inf1_svc_core::traits::SolValCalc;
 
 use crate::{err::NotEnoughLiquidityErr, quote::Quote};
 
-use super::{err::QuoteErr, QuoteArgs, QuoteResult};
+use super::{err::QuoteErr, verify_min_fee, QuoteArgs, QuoteResult};
 
 pub fn quote_exact_in<I: SolValCalc, O: SolValCalc, P: PriceExactIn>(
     QuoteArgs {
@@ -14,6 +14,7 @@ pub fn quote_exact_in<I: SolValCalc, O: SolValCalc, P: PriceExactIn>(
         pricing,
         inp_mint,
         out_mint,
+        min_fee_nanos,
     }: &QuoteArgs<I, O, P>,
 ) -> QuoteResult<I::Error, O::Error, P::Error> {
     let inp_sol_val = *inp_calc
@@ -42,6 +43,7 @@ pub fn quote_exact_in<I: SolValCalc, O: SolValCalc, P: PriceExactIn>(
     let fee_sol_val = inp_sol_val
         .checked_sub(out_sol_val)
         .ok_or(QuoteErr::PoolLoss)?;
+    verify_min_fee(inp_sol_val, fee_sol_val, *min_fee_nanos)?;
 
     if *out_reserves < out {
         return Err(QuoteErr::NotEnoughLiquidity(NotEnoughLiquidityErr {
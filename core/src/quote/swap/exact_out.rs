@@ -6,7 +6,7 @@ use crate::{
     quote::{swap::err::QuoteErr, Quote},
 };
 
-use super::{QuoteArgs, QuoteResult};
+use super::{verify_min_fee, QuoteArgs, QuoteResult};
 
 pub fn quote_exact_out<I: SolValCalc, O: SolValCalc, P: PriceExactOut>(
     QuoteArgs {
@@ -17,6 +17,7 @@ pub fn quote_exact_out<I: SolValCalc, O: SolValCalc, P: PriceExactOut>(
         pricing,
         inp_mint,
         out_mint,
+        min_fee_nanos,
     }: &QuoteArgs<I, O, P>,
 ) -> QuoteResult<I::Error, O::Error, P::Error> {
     let out_sol_val = *out_calc.lst_to_sol(*amt).map_err(QuoteErr::OutCalc)?.end();
@@ -41,6 +42,7 @@ pub fn quote_exact_out<I: SolValCalc, O: SolValCalc, P: PriceExactOut>(
     let fee_sol_val = inp_sol_val
         .checked_sub(out_sol_val)
         .ok_or(QuoteErr::PoolLoss)?;
+    verify_min_fee(inp_sol_val, fee_sol_val, *min_fee_nanos)?;
 
     if out_reserves < amt {
         return Err(QuoteErr::NotEnoughLiquidity(NotEnoughLiquidityErr {
@@ -1,4 +1,8 @@
 use err::QuoteErr;
+use inf1_ctl_core::typedefs::{
+    disable_modes::{DisableMode, DisableModes},
+    fee_nanos::{FeeNanos, NANOS_DENOM},
+};
 
 use super::Quote;
 
@@ -24,6 +28,61 @@ pub struct QuoteArgs<I, O, P> {
     pub out_calc: O,
 
     pub pricing: P,
+
+    /// The pool's `min_fee_nanos`. The quote fails if the pricing program
+    /// charges a fee less than this proportion of the input SOL value.
+    pub min_fee_nanos: FeeNanos,
 }
 
 pub type QuoteResult<I, O, P> = Result<Quote, QuoteErr<I, O, P>>;
+
+/// The [`DisableMode`] that a swap of `inp_mint` -> `out_mint` falls under
+#[inline]
+pub fn swap_disable_mode(
+    lp_token_mint: &[u8; 32],
+    inp_mint: &[u8; 32],
+    out_mint: &[u8; 32],
+) -> DisableMode {
+    if out_mint == lp_token_mint {
+        DisableMode::AddLiquidity
+    } else if inp_mint == lp_token_mint {
+        DisableMode::RemoveLiquidity
+    } else {
+        DisableMode::Swap
+    }
+}
+
+/// Mirrors the onchain check that fails the swap
+/// if its [`swap_disable_mode`] is in the pool's `disable_modes`
+#[inline]
+pub fn verify_swap_not_disabled<I, O, P>(
+    disable_modes: DisableModes,
+    lp_token_mint: &[u8; 32],
+    inp_mint: &[u8; 32],
+    out_mint: &[u8; 32],
+) -> Result<(), QuoteErr<I, O, P>> {
+    let mode = swap_disable_mode(lp_token_mint, inp_mint, out_mint);
+    if disable_modes.is_disabled(mode) {
+        Err(QuoteErr::Disabled(mode))
+    } else {
+        Ok(())
+    }
+}
+
+/// Mirrors the onchain check that fails the swap if `fee_sol_val` is
+/// less than `min_fee_nanos` of `inp_sol_val`
+#[inline]
+pub const fn verify_min_fee<I, O, P>(
+    inp_sol_val: u64,
+    fee_sol_val: u64,
+    min_fee_nanos: FeeNanos,
+) -> Result<(), QuoteErr<I, O, P>> {
+    // u64 * u32 never overflows u128
+    if (fee_sol_val as u128) * (NANOS_DENOM as u128)
+        < (inp_sol_val as u128) * (min_fee_nanos.get() as u128)
+    {
+        Err(QuoteErr::FeeBelowMin)
+    } else {
+        Ok(())
+    }
+}
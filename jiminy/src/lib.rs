@@ -18,6 +18,9 @@ impl<
         match e {
             QuoteErr::InpCalc(e) => e.into(),
             QuoteErr::InpDisabled => Inf1CtlCustomProgErr(Inf1CtlErr::LstInputDisabled).into(),
+            QuoteErr::Disabled(m) => {
+                Inf1CtlCustomProgErr(Inf1CtlErr::PoolOperationDisabled(m)).into()
+            }
             QuoteErr::NotEnoughLiquidity(_) => {
                 Inf1CtlCustomProgErr(Inf1CtlErr::NotEnoughLiquidity).into()
             }
@@ -89,4 +89,11 @@ impl<
     ) -> Result<(), UpdateErr<Self::InnerErr>> {
         map_update_method!(&mut self.0, update_all(all_mints, update_map))
     }
+
+    fn last_update_slot(&self) -> Option<u64> {
+        match &self.0 {
+            PricingAg::FlatFee(p) => p.last_update_slot(),
+            PricingAg::FlatSlab(p) => p.last_update_slot(),
+        }
+    }
 }
@@ -1,7 +1,8 @@
 use core::{borrow::Borrow, hash::Hash};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use inf1_pp_flatfee_core::{accounts::fee::FeeAccount, pda::fee_account_seeds};
+use inf1_pp_std::update::{oldest_slot, LastUpdateSlot};
 
 // Re-exports
 pub use inf1_pp_flatfee_core::*;
@@ -25,6 +26,14 @@ pub struct FlatFeePricing<F, C> {
     /// Entry does not exist if acc not yet fetched
     lsts: HashMap<[u8; 32], FeeAccount>,
 
+    /// Slot `lp_withdrawal_fee_bps` was last updated at
+    program_state_slot: LastUpdateSlot,
+
+    /// key=mint
+    ///
+    /// Slot the corresponding entry in `lsts` was last updated at
+    lst_slots: BTreeMap<[u8; 32], LastUpdateSlot>,
+
     find_pda: F,
 
     create_pda: C,
@@ -46,6 +55,8 @@ impl<
         Self {
             lp_withdrawal_fee_bps,
             lsts,
+            program_state_slot: LastUpdateSlot::NONE,
+            lst_slots: BTreeMap::new(),
             find_pda: find_pda_fn,
             create_pda: create_pda_fn,
         }
@@ -54,6 +65,19 @@ impl<
 
 /// Mutators
 impl<F, C> FlatFeePricing<F, C> {
+    /// Restore slots previously obtained via [`Self::program_state_slot`]
+    /// and [`Self::lst_slots`], e.g. when restoring from a snapshot.
+    #[inline]
+    pub fn with_update_slots(
+        mut self,
+        program_state_slot: LastUpdateSlot,
+        lst_slots: BTreeMap<[u8; 32], LastUpdateSlot>,
+    ) -> Self {
+        self.program_state_slot = program_state_slot;
+        self.lst_slots = lst_slots;
+        self
+    }
+
     #[inline]
     pub fn upsert_fee_account(&mut self, mint: [u8; 32], fee_account: FeeAccount) {
         self.lsts.insert(mint, fee_account);
@@ -75,6 +99,32 @@ impl<F, C> FlatFeePricing<F, C> {
     pub const fn lp_withdrawal_fee_bps(&self) -> Option<u16> {
         self.lp_withdrawal_fee_bps
     }
+
+    /// key=mint
+    #[inline]
+    pub const fn fee_accounts(&self) -> &HashMap<[u8; 32], FeeAccount> {
+        &self.lsts
+    }
+
+    #[inline]
+    pub const fn program_state_slot(&self) -> LastUpdateSlot {
+        self.program_state_slot
+    }
+
+    /// key=mint
+    #[inline]
+    pub const fn lst_slots(&self) -> &BTreeMap<[u8; 32], LastUpdateSlot> {
+        &self.lst_slots
+    }
+
+    /// Oldest slot out of the program state's and all fee accounts'
+    /// last update slots
+    #[inline]
+    pub fn oldest_update_slot(&self) -> Option<u64> {
+        oldest_slot(
+            core::iter::once(self.program_state_slot.0).chain(self.lst_slots.values().map(|s| s.0)),
+        )
+    }
 }
 
 /// PDA
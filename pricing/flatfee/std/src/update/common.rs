@@ -9,7 +9,7 @@ use inf1_pp_flatfee_core::accounts::{
 };
 use inf1_pp_std::{
     pair::Pair,
-    update::{Account, UpdateErr, UpdateMap, UpdatePricingProg},
+    update::{Account, LastUpdateSlot, UpdateErr, UpdateMap, UpdatePricingProg},
 };
 
 use crate::FlatFeePricing;
@@ -47,6 +47,14 @@ impl<
     ) -> Result<(), UpdateErr<FlatFeePricingUpdateErr>> {
         let fee_acc = self.fee_account_pda(mint);
         let new_fee_acc = update_map.get_account_checked(&fee_acc)?;
+        let slot = new_fee_acc.slot();
+        if self
+            .lst_slots
+            .get(mint)
+            .is_some_and(|last| last.is_regression(slot))
+        {
+            return Ok(());
+        }
         let new_fee_acc = FeeAccountPacked::of_acc_data(new_fee_acc.data())
             .ok_or(UpdateErr::Inner(FlatFeePricingUpdateErr::AccDeser {
                 pk: fee_acc,
@@ -54,6 +62,7 @@ impl<
             .into_fee_account();
 
         self.upsert_fee_account(*mint, new_fee_acc);
+        self.lst_slots.insert(*mint, LastUpdateSlot(slot));
 
         Ok(())
     }
@@ -65,6 +74,10 @@ impl<
     ) -> Result<(), UpdateErr<FlatFeePricingUpdateErr>> {
         let new_program_state =
             update_map.get_account_checked(&inf1_pp_flatfee_core::keys::STATE_ID)?;
+        let slot = new_program_state.slot();
+        if self.program_state_slot.is_regression(slot) {
+            return Ok(());
+        }
         let ProgramState {
             lp_withdrawal_fee_bps,
             ..
@@ -75,6 +88,7 @@ impl<
             .into_program_state();
 
         self.update_lp_withdrawal_fee_bps(lp_withdrawal_fee_bps);
+        self.program_state_slot = LastUpdateSlot(slot);
 
         Ok(())
     }
@@ -138,6 +152,11 @@ impl<
             .into_iter()
             .try_for_each(|mint| self.update_lst(&mint, &update_map))
     }
+
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        self.oldest_update_slot()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
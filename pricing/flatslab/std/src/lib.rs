@@ -1,4 +1,5 @@
 use inf1_pp_flatslab_core::{accounts::Slab, typedefs::SlabEntryPackedList};
+use inf1_pp_std::update::LastUpdateSlot;
 
 pub mod traits;
 pub mod update;
@@ -9,12 +10,30 @@ pub use inf1_pp_flatslab_core::*;
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct FlatSlabPricing {
     slab_acc_data: Box<[u8]>,
+    last_update_slot: LastUpdateSlot,
 }
 
 impl FlatSlabPricing {
     #[inline]
     pub const fn new(slab_acc_data: Box<[u8]>) -> Self {
-        Self { slab_acc_data }
+        Self {
+            slab_acc_data,
+            last_update_slot: LastUpdateSlot::NONE,
+        }
+    }
+
+    /// Restore slot previously obtained via
+    /// [`inf1_pp_std::update::UpdatePricingProg::last_update_slot`],
+    /// e.g. when restoring from a snapshot.
+    #[inline]
+    pub fn with_last_update_slot(mut self, last_update_slot: LastUpdateSlot) -> Self {
+        self.last_update_slot = last_update_slot;
+        self
+    }
+
+    #[inline]
+    pub fn slab_acc_data(&self) -> &[u8] {
+        &self.slab_acc_data
     }
 
     #[inline]
@@ -9,8 +9,8 @@ use inf1_pp_std::{
     pair::Pair,
     update::{
         Account, AccountsToUpdateAll, AccountsToUpdateMintLp, AccountsToUpdatePriceExactIn,
-        AccountsToUpdatePriceExactOut, AccountsToUpdateRedeemLp, UpdateErr, UpdateMap,
-        UpdatePricingProg,
+        AccountsToUpdatePriceExactOut, AccountsToUpdateRedeemLp, LastUpdateSlot, UpdateErr,
+        UpdateMap, UpdatePricingProg,
     },
 };
 
@@ -45,6 +45,9 @@ impl FlatSlabPricing {
         update_map: impl UpdateMap,
     ) -> Result<(), UpdateErr<FlatSlabPricingUpdateErr>> {
         let slab = update_map.get_account_checked(&SLAB_ID)?;
+        if self.last_update_slot.is_regression(slab.slot()) {
+            return Ok(());
+        }
         if Slab::of_acc_data(slab.data()).is_none() {
             return Err(UpdateErr::Inner(FlatSlabPricingUpdateErr::AccDeser {
                 pk: SLAB_ID,
@@ -52,6 +55,7 @@ impl FlatSlabPricing {
         }
 
         self.slab_acc_data = slab.data().into();
+        self.last_update_slot = LastUpdateSlot(slab.slot());
 
         Ok(())
     }
@@ -153,4 +157,9 @@ impl UpdatePricingProg for FlatSlabPricing {
     ) -> Result<(), UpdateErr<Self::InnerErr>> {
         self.update_slab(update_map)
     }
+
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        self.last_update_slot.0
+    }
 }
@@ -92,4 +92,15 @@ pub trait UpdatePricingProg {
         all_mints: impl IntoIterator<Item = [u8; 32]>,
         update_map: impl UpdateMap,
     ) -> Result<(), UpdateErr<Self::InnerErr>>;
+
+    /// Oldest context slot of the accounts that the current state was derived from.
+    ///
+    /// Implementations must not overwrite state with accounts whose
+    /// [`Account::slot`] is older than the slot that state was last updated at.
+    ///
+    /// Defaults to `None` (unknown) for implementations that do not track slots.
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        None
+    }
 }
@@ -1,13 +1,13 @@
 use core::{convert::Infallible, ops::RangeInclusive};
 
 use inf1_ctl_core::svc::{InfCalc, InfCalcErr};
-use inf1_svc_core::traits::SolValCalc;
+use inf1_svc_core::traits::{SolValCalc, Staleness};
 use inf1_svc_lido_core::calc::{LidoCalc, LidoCalcErr};
 use inf1_svc_marinade_core::calc::{MarinadeCalc, MarinadeCalcErr};
-use inf1_svc_spl_core::calc::{SplCalc, SplCalcErr};
+use inf1_svc_spl_core::calc::{SplCalc, SplCalcErr, SplEstCalc};
 use inf1_svc_wsol_core::calc::WsolCalc;
 
-use crate::{each_fallible_variant_method, SvcAg};
+use crate::{each_fallible_variant_method, each_variant_method, SvcAg};
 
 pub type SvcCalcAg = SvcAg<InfCalc, LidoCalc, MarinadeCalc, SplCalc, SplCalc, SplCalc, WsolCalc>;
 
@@ -40,6 +40,11 @@ impl SvcCalcAgRef<'_> {
     ) -> Result<RangeInclusive<u64>, SvcCalcAgErr> {
         each_fallible_variant_method!(self, svc_sol_to_lst(lamports_amount))
     }
+
+    #[inline]
+    pub const fn svc_is_stale(&self) -> bool {
+        each_variant_method!(self, svc_is_stale())
+    }
 }
 
 impl SolValCalc for SvcCalcAgRef<'_> {
@@ -69,3 +74,139 @@ impl SolValCalc for SvcCalcAg {
         self.as_ref_const().svc_sol_to_lst(lamports_amount)
     }
 }
+
+impl Staleness for SvcCalcAgRef<'_> {
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.svc_is_stale()
+    }
+}
+
+impl Staleness for SvcCalcAg {
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.as_ref_const().svc_is_stale()
+    }
+}
+
+/// Same as [`SvcCalcAg`], but SPL calculators are opt-in estimators
+/// for the window after an epoch boundary but before the stake pool is updated.
+///
+/// See [`SplEstCalc`]
+pub type SvcEstCalcAg =
+    SvcAg<InfCalc, LidoCalc, MarinadeCalc, SplEstCalc, SplEstCalc, SplEstCalc, WsolCalc>;
+
+pub type SvcEstCalcAgRef<'a> = SvcAg<
+    &'a InfCalc,
+    &'a LidoCalc,
+    &'a MarinadeCalc,
+    &'a SplEstCalc,
+    &'a SplEstCalc,
+    &'a SplEstCalc,
+    &'a WsolCalc,
+>;
+
+impl SvcCalcAg {
+    /// SPL calculators are converted with [`SplEstCalc::no_projection`],
+    /// so the returned aggregate returns the same values and errors as `self`
+    #[inline]
+    pub const fn into_est(self) -> SvcEstCalcAg {
+        match self {
+            Self::Inf(c) => SvcAg::Inf(c),
+            Self::Lido(c) => SvcAg::Lido(c),
+            Self::Marinade(c) => SvcAg::Marinade(c),
+            Self::SanctumSpl(c) => SvcAg::SanctumSpl(SplEstCalc::no_projection(c)),
+            Self::SanctumSplMulti(c) => SvcAg::SanctumSplMulti(SplEstCalc::no_projection(c)),
+            Self::Spl(c) => SvcAg::Spl(SplEstCalc::no_projection(c)),
+            Self::Wsol(c) => SvcAg::Wsol(c),
+        }
+    }
+}
+
+/// Non-SPL variants delegate to [`SvcCalcAgRef`]
+impl SvcEstCalcAgRef<'_> {
+    #[inline]
+    pub const fn svc_lst_to_sol(
+        &self,
+        lst_amount: u64,
+    ) -> Result<RangeInclusive<u64>, SvcCalcAgErr> {
+        match *self {
+            Self::Inf(c) => SvcCalcAgRef::Inf(c).svc_lst_to_sol(lst_amount),
+            Self::Lido(c) => SvcCalcAgRef::Lido(c).svc_lst_to_sol(lst_amount),
+            Self::Marinade(c) => SvcCalcAgRef::Marinade(c).svc_lst_to_sol(lst_amount),
+            Self::Wsol(c) => SvcCalcAgRef::Wsol(c).svc_lst_to_sol(lst_amount),
+            Self::SanctumSpl(_) | Self::SanctumSplMulti(_) | Self::Spl(_) => {
+                each_fallible_variant_method!(self, svc_lst_to_sol(lst_amount))
+            }
+        }
+    }
+
+    #[inline]
+    pub const fn svc_sol_to_lst(
+        &self,
+        lamports_amount: u64,
+    ) -> Result<RangeInclusive<u64>, SvcCalcAgErr> {
+        match *self {
+            Self::Inf(c) => SvcCalcAgRef::Inf(c).svc_sol_to_lst(lamports_amount),
+            Self::Lido(c) => SvcCalcAgRef::Lido(c).svc_sol_to_lst(lamports_amount),
+            Self::Marinade(c) => SvcCalcAgRef::Marinade(c).svc_sol_to_lst(lamports_amount),
+            Self::Wsol(c) => SvcCalcAgRef::Wsol(c).svc_sol_to_lst(lamports_amount),
+            Self::SanctumSpl(_) | Self::SanctumSplMulti(_) | Self::Spl(_) => {
+                each_fallible_variant_method!(self, svc_sol_to_lst(lamports_amount))
+            }
+        }
+    }
+
+    #[inline]
+    pub const fn svc_is_stale(&self) -> bool {
+        match *self {
+            Self::Inf(c) => SvcCalcAgRef::Inf(c).svc_is_stale(),
+            Self::Lido(c) => SvcCalcAgRef::Lido(c).svc_is_stale(),
+            Self::Marinade(c) => SvcCalcAgRef::Marinade(c).svc_is_stale(),
+            Self::Wsol(c) => SvcCalcAgRef::Wsol(c).svc_is_stale(),
+            Self::SanctumSpl(c) | Self::SanctumSplMulti(c) | Self::Spl(c) => c.svc_is_stale(),
+        }
+    }
+}
+
+impl SolValCalc for SvcEstCalcAgRef<'_> {
+    type Error = SvcCalcAgErr;
+
+    #[inline]
+    fn lst_to_sol(&self, lst_amount: u64) -> Result<RangeInclusive<u64>, Self::Error> {
+        self.svc_lst_to_sol(lst_amount)
+    }
+
+    #[inline]
+    fn sol_to_lst(&self, lamports_amount: u64) -> Result<RangeInclusive<u64>, Self::Error> {
+        self.svc_sol_to_lst(lamports_amount)
+    }
+}
+
+impl SolValCalc for SvcEstCalcAg {
+    type Error = SvcCalcAgErr;
+
+    #[inline]
+    fn lst_to_sol(&self, lst_amount: u64) -> Result<RangeInclusive<u64>, Self::Error> {
+        self.as_ref_const().svc_lst_to_sol(lst_amount)
+    }
+
+    #[inline]
+    fn sol_to_lst(&self, lamports_amount: u64) -> Result<RangeInclusive<u64>, Self::Error> {
+        self.as_ref_const().svc_sol_to_lst(lamports_amount)
+    }
+}
+
+impl Staleness for SvcEstCalcAgRef<'_> {
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.svc_is_stale()
+    }
+}
+
+impl Staleness for SvcEstCalcAg {
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.as_ref_const().svc_is_stale()
+    }
+}
@@ -1,9 +1,13 @@
-use inf1_svc_ag_core::{calc::SvcCalcAgRef, instructions::SvcCalcAccsAgRef};
+use inf1_svc_ag_core::{
+    calc::{SvcCalcAgRef, SvcEstCalcAgRef},
+    inf1_svc_generic::upgrade::LastUpgradeSlots,
+    instructions::SvcCalcAccsAgRef,
+};
 
 use inf1_svc_inf_std::InfSvcStd;
 use inf1_svc_lido_std::LidoSvcStd;
 use inf1_svc_marinade_std::MarinadeSvcStd;
-use inf1_svc_spl_std::{SanctumSplMultiSvcStd, SanctumSplSvcStd, SplSvcStd};
+use inf1_svc_spl_std::{calc::SplFeeMode, SanctumSplMultiSvcStd, SanctumSplSvcStd, SplSvcStd};
 use inf1_svc_wsol_std::WsolSvcStd;
 
 // Re-exports
@@ -11,6 +15,7 @@ pub use inf1_svc_ag_core::*;
 pub use inf1_svc_inf_std;
 pub use inf1_svc_lido_std;
 pub use inf1_svc_marinade_std;
+pub use inf1_svc_spl_std;
 pub use inf1_svc_wsol_std;
 
 pub mod update;
@@ -85,8 +90,100 @@ impl SvcAgStd {
         }
     }
 
+    /// Same as [`Self::as_sol_val_calc`], but returns the SPL estimators for SPL variants.
+    ///
+    /// Returns `None` for SPL variants that have not opted-in to estimates via
+    /// [`Self::set_spl_est_enabled`] or have yet to fetch the accounts required for them.
+    #[inline]
+    pub const fn as_sol_val_calc_est(&self) -> Option<SvcEstCalcAgRef<'_>> {
+        match &self.0 {
+            SvcAg::Inf(c) => Some(SvcAg::Inf(c.as_calc())),
+            SvcAg::Lido(c) => match c.as_calc() {
+                Some(r) => Some(SvcAg::Lido(r)),
+                None => None,
+            },
+            SvcAg::Marinade(c) => match c.as_calc() {
+                Some(r) => Some(SvcAg::Marinade(r)),
+                None => None,
+            },
+            SvcAg::SanctumSpl(c) => match c.as_est_calc() {
+                Some(r) => Some(SvcAg::SanctumSpl(r)),
+                None => None,
+            },
+            SvcAg::SanctumSplMulti(c) => match c.as_est_calc() {
+                Some(r) => Some(SvcAg::SanctumSplMulti(r)),
+                None => None,
+            },
+            SvcAg::Spl(c) => match c.as_est_calc() {
+                Some(r) => Some(SvcAg::Spl(r)),
+                None => None,
+            },
+            SvcAg::Wsol(c) => Some(SvcAg::Wsol(c.as_calc())),
+        }
+    }
+
     #[inline]
     pub const fn as_sol_val_calc_accs(&self) -> SvcCalcAccsAgRef<'_> {
         map_variant_method!(&self.0, as_accs())
     }
+
+    /// Returns `None` for variants that do not wrap an upgradeable stake pool program,
+    /// have not opted-in to upgrade checks via [`Self::set_upgrade_check_enabled`],
+    /// or have yet to fetch the accounts required for them.
+    #[inline]
+    pub const fn as_last_upgrade_slots(&self) -> Option<&LastUpgradeSlots> {
+        match &self.0 {
+            SvcAg::Lido(c) => c.as_last_upgrade_slots(),
+            SvcAg::Marinade(c) => c.as_last_upgrade_slots(),
+            SvcAg::SanctumSpl(c) => c.as_last_upgrade_slots(),
+            SvcAg::SanctumSplMulti(c) => c.as_last_upgrade_slots(),
+            SvcAg::Spl(c) => c.as_last_upgrade_slots(),
+            SvcAg::Inf(_) | SvcAg::Wsol(_) => None,
+        }
+    }
+}
+
+/// Mutators
+impl SvcAgStd {
+    /// Opt in or out of estimates for SPL variants. No-op for other variants.
+    ///
+    /// See [`inf1_svc_spl_core::calc::SplEstCalc`]
+    #[inline]
+    pub fn set_spl_est_enabled(&mut self, enabled: bool) {
+        match &mut self.0 {
+            SvcAg::SanctumSpl(c) => c.set_est_enabled(enabled),
+            SvcAg::SanctumSplMulti(c) => c.set_est_enabled(enabled),
+            SvcAg::Spl(c) => c.set_est_enabled(enabled),
+            SvcAg::Inf(_) | SvcAg::Lido(_) | SvcAg::Marinade(_) | SvcAg::Wsol(_) => (),
+        }
+    }
+
+    /// Opt in or out of checking for upgrades of the wrapped stake pool program
+    /// for generic calculator variants (Lido, Marinade, SPL). No-op for other variants.
+    ///
+    /// See [`inf1_svc_ag_core::inf1_svc_generic::upgrade::UpgradeCheck`]
+    #[inline]
+    pub fn set_upgrade_check_enabled(&mut self, enabled: bool) {
+        match &mut self.0 {
+            SvcAg::Lido(c) => c.set_upgrade_check_enabled(enabled),
+            SvcAg::Marinade(c) => c.set_upgrade_check_enabled(enabled),
+            SvcAg::SanctumSpl(c) => c.set_upgrade_check_enabled(enabled),
+            SvcAg::SanctumSplMulti(c) => c.set_upgrade_check_enabled(enabled),
+            SvcAg::Spl(c) => c.set_upgrade_check_enabled(enabled),
+            SvcAg::Inf(_) | SvcAg::Wsol(_) => (),
+        }
+    }
+
+    /// Select the stake pool fee schedule modelled by SPL variants. No-op for other variants.
+    ///
+    /// See [`SplFeeMode`]
+    #[inline]
+    pub fn set_spl_fee_mode(&mut self, fee_mode: SplFeeMode) {
+        match &mut self.0 {
+            SvcAg::SanctumSpl(c) => c.set_fee_mode(fee_mode),
+            SvcAg::SanctumSplMulti(c) => c.set_fee_mode(fee_mode),
+            SvcAg::Spl(c) => c.set_fee_mode(fee_mode),
+            SvcAg::Inf(_) | SvcAg::Lido(_) | SvcAg::Marinade(_) | SvcAg::Wsol(_) => (),
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use inf1_svc_ag_core::{map_variant_method, SvcAg};
+use inf1_svc_ag_core::{each_variant_method, map_variant_method, SvcAg};
 
 use crate::SvcAgStd;
 
@@ -60,4 +60,9 @@ impl UpdateSvc for SvcAgStd {
                 .map_err(|e| e.map_inner(SvcAg::Wsol)),
         }
     }
+
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        each_variant_method!(&self.0, last_update_slot())
+    }
 }
@@ -25,6 +25,26 @@ where
     }
 }
 
+/// Whether the data a calculator was created from may no longer
+/// reflect the LST's exchange rate as of the current epoch/slot.
+///
+/// A stale calculator may still successfully return values from
+/// [`SolValCalc`] methods; it is up to the caller to decide whether to use them.
+pub trait Staleness {
+    fn is_stale(&self) -> bool;
+}
+
+/// Blanket for refs
+impl<R, T: Staleness> Staleness for R
+where
+    R: Deref<Target = T>,
+{
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.deref().is_stale()
+    }
+}
+
 /// Suffix account meta slices returned by the 3 methods
 /// - must all have the same length
 /// - must all have length <= u8::MAX
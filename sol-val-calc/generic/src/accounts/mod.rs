@@ -1 +1,2 @@
+pub mod progdata;
 pub mod state;
@@ -0,0 +1,23 @@
+//! The wrapped stake pool program's BPF upgradeable loader ProgramData account
+
+/// `UpgradeableLoaderState::ProgramData` discriminant, is bincode enum
+pub const PROGDATA_DISCM: u32 = 3;
+
+/// Offset of the `slot` field, which is the slot the program was last deployed/upgraded at
+pub const PROGDATA_SLOT_OFFSET: usize = 4;
+
+/// Only the header is read; the account's program bytecode that follows is ignored.
+///
+/// # Returns
+/// `None` if `progdata_acc_data` is not a ProgramData account
+#[inline]
+pub const fn progdata_last_upgrade_slot(progdata_acc_data: &[u8]) -> Option<u64> {
+    let [d0, d1, d2, d3, s0, s1, s2, s3, s4, s5, s6, s7] = match progdata_acc_data.first_chunk() {
+        Some(c) => *c,
+        None => return None,
+    };
+    if u32::from_le_bytes([d0, d1, d2, d3]) != PROGDATA_DISCM {
+        return None;
+    }
+    Some(u64::from_le_bytes([s0, s1, s2, s3, s4, s5, s6, s7]))
+}
@@ -0,0 +1,87 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::pda::const_find_state;
+
+use super::DiscmOnlyIxData;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SetManagerIxAccs<T> {
+    /// The calculator program's current manager
+    pub curr: T,
+
+    /// New manager to set to
+    pub new: T,
+
+    /// The calculator program's state singleton PDA
+    pub state: T,
+}
+
+impl<T: Copy> SetManagerIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; SET_MANAGER_IX_ACCS_LEN])
+    }
+}
+
+impl<T> AsRef<[T]> for SetManagerIxAccs<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+pub type SetManagerIxKeys<'a> = SetManagerIxAccs<&'a [u8; 32]>;
+
+pub type SetManagerIxKeysOwned = SetManagerIxAccs<[u8; 32]>;
+
+pub type SetManagerIxAccFlags = SetManagerIxAccs<bool>;
+
+pub const SET_MANAGER_IX_IS_WRITER: SetManagerIxAccFlags =
+    SetManagerIxAccFlags::memset(false).const_with_state(true);
+
+pub const SET_MANAGER_IX_IS_SIGNER: SetManagerIxAccFlags =
+    SetManagerIxAccFlags::memset(false).const_with_curr(true);
+
+impl SetManagerIxKeys<'_> {
+    #[inline]
+    pub fn into_owned(&self) -> SetManagerIxKeysOwned {
+        SetManagerIxAccs(self.0.map(|p| *p))
+    }
+}
+
+/// Constructors
+impl SetManagerIxKeysOwned {
+    /// Keys to change the manager of the calculator program `prog_id` from `curr` to `new`
+    #[inline]
+    pub const fn new_for_prog(curr: [u8; 32], new: [u8; 32], prog_id: &[u8; 32]) -> Self {
+        Self::memset([0; 32])
+            .const_with_curr(curr)
+            .const_with_new(new)
+            .const_with_state_of_prog(prog_id)
+    }
+}
+
+impl SetManagerIxKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> SetManagerIxKeys<'_> {
+        SetManagerIxAccs(self.0.each_ref())
+    }
+
+    /// Sets `state` to the state PDA of the calculator program `prog_id`
+    #[inline]
+    pub const fn const_with_state_of_prog(self, prog_id: &[u8; 32]) -> Self {
+        self.const_with_state(const_find_state(prog_id).0)
+    }
+}
+
+// Data
+
+pub const SET_MANAGER_IX_DISCM: u8 = 254;
+
+pub type SetManagerIxData = DiscmOnlyIxData<SET_MANAGER_IX_DISCM>;
+
+pub const SET_MANAGER_IX_DATA_LEN: usize = SetManagerIxData::DATA_LEN;
@@ -0,0 +1,99 @@
+use generic_array_struct::generic_array_struct;
+
+use crate::pda::const_find_state;
+
+use super::DiscmOnlyIxData;
+
+// Accounts
+
+#[generic_array_struct(builder pub)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct UpdateLastUpgradeSlotIxAccs<T> {
+    /// The calculator program's manager
+    pub manager: T,
+
+    /// The calculator program's state singleton PDA
+    pub state: T,
+
+    /// The stake pool program
+    pub pool_prog: T,
+
+    /// The stake pool program's program data account.
+    ///
+    /// `last_upgrade_slot` is read from here
+    pub pool_progdata: T,
+}
+
+impl<T: Copy> UpdateLastUpgradeSlotIxAccs<T> {
+    #[inline]
+    pub const fn memset(val: T) -> Self {
+        Self([val; UPDATE_LAST_UPGRADE_SLOT_IX_ACCS_LEN])
+    }
+}
+
+impl<T> AsRef<[T]> for UpdateLastUpgradeSlotIxAccs<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+pub type UpdateLastUpgradeSlotIxKeys<'a> = UpdateLastUpgradeSlotIxAccs<&'a [u8; 32]>;
+
+pub type UpdateLastUpgradeSlotIxKeysOwned = UpdateLastUpgradeSlotIxAccs<[u8; 32]>;
+
+pub type UpdateLastUpgradeSlotIxAccFlags = UpdateLastUpgradeSlotIxAccs<bool>;
+
+pub const UPDATE_LAST_UPGRADE_SLOT_IX_IS_WRITER: UpdateLastUpgradeSlotIxAccFlags =
+    UpdateLastUpgradeSlotIxAccFlags::memset(false).const_with_state(true);
+
+pub const UPDATE_LAST_UPGRADE_SLOT_IX_IS_SIGNER: UpdateLastUpgradeSlotIxAccFlags =
+    UpdateLastUpgradeSlotIxAccFlags::memset(false).const_with_manager(true);
+
+impl UpdateLastUpgradeSlotIxKeys<'_> {
+    #[inline]
+    pub fn into_owned(&self) -> UpdateLastUpgradeSlotIxKeysOwned {
+        UpdateLastUpgradeSlotIxAccs(self.0.map(|p| *p))
+    }
+}
+
+/// Constructors
+impl UpdateLastUpgradeSlotIxKeysOwned {
+    /// Keys to sync the `last_upgrade_slot` of the calculator program `prog_id`
+    /// to that of the stake pool program `pool_prog`, whose ProgramData account is `pool_progdata`
+    #[inline]
+    pub const fn new_for_prog(
+        manager: [u8; 32],
+        pool_prog: [u8; 32],
+        pool_progdata: [u8; 32],
+        prog_id: &[u8; 32],
+    ) -> Self {
+        Self::memset([0; 32])
+            .const_with_manager(manager)
+            .const_with_pool_prog(pool_prog)
+            .const_with_pool_progdata(pool_progdata)
+            .const_with_state_of_prog(prog_id)
+    }
+}
+
+impl UpdateLastUpgradeSlotIxKeysOwned {
+    #[inline]
+    pub fn as_borrowed(&self) -> UpdateLastUpgradeSlotIxKeys<'_> {
+        UpdateLastUpgradeSlotIxAccs(self.0.each_ref())
+    }
+
+    /// Sets `state` to the state PDA of the calculator program `prog_id`
+    #[inline]
+    pub const fn const_with_state_of_prog(self, prog_id: &[u8; 32]) -> Self {
+        self.const_with_state(const_find_state(prog_id).0)
+    }
+}
+
+// Data
+
+pub const UPDATE_LAST_UPGRADE_SLOT_IX_DISCM: u8 = 253;
+
+pub type UpdateLastUpgradeSlotIxData = DiscmOnlyIxData<UPDATE_LAST_UPGRADE_SLOT_IX_DISCM>;
+
+pub const UPDATE_LAST_UPGRADE_SLOT_IX_DATA_LEN: usize = UpdateLastUpgradeSlotIxData::DATA_LEN;
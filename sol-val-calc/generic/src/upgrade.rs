@@ -0,0 +1,101 @@
+//! Off-chain replication of the check generic calculator programs make
+//! to guard against the wrapped stake pool program being upgraded
+
+use core::{error::Error, fmt::Display, iter::Flatten, option};
+
+use crate::{
+    accounts::{progdata::progdata_last_upgrade_slot, state::StatePacked},
+    instructions::IxSufKeysOwned,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LastUpgradeSlots {
+    /// [`crate::accounts::state::State::last_upgrade_slot`]
+    pub state: u64,
+
+    /// Slot the wrapped stake pool program was actually last upgraded at,
+    /// as recorded in its ProgramData account
+    pub pool_progdata: u64,
+}
+
+impl LastUpgradeSlots {
+    /// # Returns
+    /// [`LastUpgradeSlotsErr`] for whichever account data failed to deserialize
+    #[inline]
+    pub const fn of_acc_data(
+        state_acc_data: &[u8],
+        pool_progdata_acc_data: &[u8],
+    ) -> Result<Self, LastUpgradeSlotsErr> {
+        let state = match StatePacked::of_acc_data(state_acc_data) {
+            Some(s) => s.into_state().last_upgrade_slot,
+            None => return Err(LastUpgradeSlotsErr::StateDeser),
+        };
+        let pool_progdata = match progdata_last_upgrade_slot(pool_progdata_acc_data) {
+            Some(s) => s,
+            None => return Err(LastUpgradeSlotsErr::PoolProgdataDeser),
+        };
+        Ok(Self {
+            state,
+            pool_progdata,
+        })
+    }
+
+    /// The calculator program refuses to run if this is `true`
+    /// until its manager runs `UpdateLastUpgradeSlot`
+    #[inline]
+    pub const fn is_pool_prog_upgraded(&self) -> bool {
+        self.state != self.pool_progdata
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LastUpgradeSlotsErr {
+    StateDeser,
+    PoolProgdataDeser,
+}
+
+impl LastUpgradeSlotsErr {
+    /// Pubkey of the account that failed to deserialize
+    #[inline]
+    pub const fn pk<'a>(&self, suf_keys: &'a IxSufKeysOwned) -> &'a [u8; 32] {
+        match self {
+            Self::StateDeser => suf_keys.state(),
+            Self::PoolProgdataDeser => suf_keys.pool_progdata(),
+        }
+    }
+}
+
+impl Display for LastUpgradeSlotsErr {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::StateDeser => "StateDeser",
+            Self::PoolProgdataDeser => "PoolProgdataDeser",
+        })
+    }
+}
+
+impl Error for LastUpgradeSlotsErr {}
+
+/// Opt-in state kept by off-chain calculators that check for
+/// upgrades of the wrapped stake pool program
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UpgradeCheck {
+    /// Might be `None` before the required accounts have been fetched
+    pub slots: Option<LastUpgradeSlots>,
+}
+
+/// Calculator program state and stake pool ProgramData,
+/// only if opted-in to upgrade checks
+pub type UpgradeCheckPkIter = Flatten<option::IntoIter<[[u8; 32]; 2]>>;
+
+#[inline]
+pub fn upgrade_check_pks(
+    check: &Option<UpgradeCheck>,
+    suf_keys: &IxSufKeysOwned,
+) -> UpgradeCheckPkIter {
+    check
+        .map(|_| [*suf_keys.state(), *suf_keys.pool_progdata()])
+        .into_iter()
+        .flatten()
+}
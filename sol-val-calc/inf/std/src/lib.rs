@@ -8,7 +8,9 @@ use inf1_ctl_core::{
     keys::POOL_STATE_ID,
     svc::{InfCalc, InfDummyCalcAccs},
 };
-use inf1_svc_std::update::{Account, AccountsToUpdateSvc, UpdateErr, UpdateMap, UpdateSvc};
+use inf1_svc_std::update::{
+    Account, AccountsToUpdateSvc, LastUpdateSlot, UpdateErr, UpdateMap, UpdateSvc,
+};
 
 // Re-exports
 pub use inf1_ctl_core::*;
@@ -20,6 +22,9 @@ pub struct InfSvcStd {
     // FIXME? this mint addr will probably be duplicated in
     // most contexts with the one stored in an accompanying PoolState
     pub mint_addr: [u8; 32],
+
+    /// See [`UpdateSvc::last_update_slot`]
+    pub last_update_slot: LastUpdateSlot,
 }
 
 pub type PkIter = core::array::IntoIter<[u8; 32], 2>;
@@ -56,12 +61,18 @@ impl UpdateSvc for InfSvcStd {
     fn update_svc(&mut self, update_map: impl UpdateMap) -> Result<(), UpdateErr<Self::InnerErr>> {
         self.us_update_svc(update_map)
     }
+
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        self.last_update_slot.0
+    }
 }
 
 impl InfSvcStd {
     pub const DEFAULT: Self = Self {
         calc: InfCalc::DEFAULT,
         mint_addr: [0u8; 32],
+        last_update_slot: LastUpdateSlot::NONE,
     };
 
     #[inline]
@@ -75,6 +86,10 @@ impl InfSvcStd {
         update_map: impl UpdateMap,
     ) -> Result<(), UpdateErr<InfUpdateErr>> {
         let [pool_addr, mint_addr] = self.atus_accs_to_update_svc();
+        let slot = update_map.oldest_slot_of([pool_addr, mint_addr]);
+        if self.last_update_slot.is_regression(slot) {
+            return Ok(());
+        }
         let [p, m] = [pool_addr, mint_addr].map(|a| update_map.get_account_checked(&a));
         let pool_state_acc = p?;
         let lp_mint_acc = m?;
@@ -87,6 +102,7 @@ impl InfSvcStd {
             .ok_or(UpdateErr::Inner(InfUpdateErr::AccDeser { pk: mint_addr }))?;
 
         self.calc = InfCalc::new(&pool_state_v2, inf_mint_supply);
+        self.last_update_slot = LastUpdateSlot(slot);
 
         Ok(())
     }
@@ -1,6 +1,6 @@
 use core::{error::Error, fmt::Display, ops::RangeInclusive};
 
-use inf1_svc_core::traits::SolValCalc;
+use inf1_svc_core::traits::{SolValCalc, Staleness};
 use sanctum_token_ratio_compat::floor_ratio_u64_u64_reverse;
 use solido_legacy_core::{ExchangeRate, Lido};
 
@@ -28,6 +28,12 @@ impl LidoCalc {
         self.exchange_rate.computed_in_epoch >= self.current_epoch
     }
 
+    /// Stale if the exchange rate has not yet been computed for the current epoch
+    #[inline]
+    pub const fn svc_is_stale(&self) -> bool {
+        !self.is_updated()
+    }
+
     #[inline]
     pub const fn svc_lst_to_sol(
         &self,
@@ -78,6 +84,13 @@ impl SolValCalc for LidoCalc {
     }
 }
 
+impl Staleness for LidoCalc {
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.svc_is_stale()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LidoCalcErr {
     Ratio,
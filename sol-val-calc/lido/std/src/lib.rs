@@ -1,4 +1,9 @@
-use inf1_svc_lido_core::{calc::LidoCalc, instructions::sol_val_calc::LidoCalcAccs};
+use inf1_svc_lido_core::{
+    calc::LidoCalc,
+    inf1_svc_generic::upgrade::{LastUpgradeSlots, UpgradeCheck},
+    instructions::sol_val_calc::LidoCalcAccs,
+};
+use inf1_svc_std::update::LastUpdateSlot;
 
 // Re-exports
 pub use inf1_svc_lido_core::*;
@@ -10,6 +15,14 @@ pub struct LidoSvcStd {
     /// Might be `None` at initialization before accounts required
     /// to create the calc have been fetched
     pub calc: Option<LidoCalc>,
+
+    /// `Some` if opted-in to checking for upgrades of the Lido program.
+    ///
+    /// See [`UpgradeCheck`]
+    pub upgrade_check: Option<UpgradeCheck>,
+
+    /// See [`inf1_svc_std::update::UpdateSvc::last_update_slot`]
+    pub last_update_slot: LastUpdateSlot,
 }
 
 impl Default for LidoSvcStd {
@@ -21,7 +34,11 @@ impl Default for LidoSvcStd {
 
 /// Constructors
 impl LidoSvcStd {
-    pub const DEFAULT: Self = Self { calc: None };
+    pub const DEFAULT: Self = Self {
+        calc: None,
+        upgrade_check: None,
+        last_update_slot: LastUpdateSlot::NONE,
+    };
 }
 
 /// Accessors
@@ -35,4 +52,30 @@ impl LidoSvcStd {
     pub const fn as_accs(&self) -> &LidoCalcAccs {
         &LidoCalcAccs
     }
+
+    /// Returns `None` if not opted-in to upgrade checks or
+    /// accounts required for them have not been fetched
+    #[inline]
+    pub const fn as_last_upgrade_slots(&self) -> Option<&LastUpgradeSlots> {
+        match &self.upgrade_check {
+            Some(UpgradeCheck { slots: Some(s) }) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// Mutators
+impl LidoSvcStd {
+    /// Opt in or out of checking for upgrades of the Lido program.
+    ///
+    /// Opting in adds the calculator program's state and the
+    /// Lido program's ProgramData to the accounts to update.
+    #[inline]
+    pub fn set_upgrade_check_enabled(&mut self, enabled: bool) {
+        match (enabled, self.upgrade_check.is_some()) {
+            (true, false) => self.upgrade_check = Some(UpgradeCheck::default()),
+            (false, true) => self.upgrade_check = None,
+            _ => (),
+        }
+    }
 }
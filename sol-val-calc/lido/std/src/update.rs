@@ -5,6 +5,10 @@ use std::{
 
 use inf1_svc_lido_core::{
     calc::LidoCalc,
+    inf1_svc_generic::upgrade::{
+        upgrade_check_pks, LastUpgradeSlots, UpgradeCheck, UpgradeCheckPkIter,
+    },
+    instructions::sol_val_calc::IX_SUF_KEYS_OWNED,
     solido_legacy_core::{Lido, LIDO_STATE_ADDR, SYSVAR_CLOCK},
 };
 
@@ -13,14 +17,18 @@ use crate::LidoSvcStd;
 // Re-exports
 pub use inf1_svc_std::update::*;
 
-pub type PkIter = core::array::IntoIter<[u8; 32], 2>;
+/// Calculator program state and Lido program ProgramData are only included
+/// if opted-in to upgrade checks
+pub type PkIter = core::iter::Chain<core::array::IntoIter<[u8; 32], 2>, UpgradeCheckPkIter>;
 
 impl AccountsToUpdateSvc for LidoSvcStd {
     type PkIter = PkIter;
 
     #[inline]
     fn accounts_to_update_svc(&self) -> Self::PkIter {
-        [LIDO_STATE_ADDR, SYSVAR_CLOCK].into_iter()
+        [LIDO_STATE_ADDR, SYSVAR_CLOCK]
+            .into_iter()
+            .chain(upgrade_check_pks(&self.upgrade_check, &IX_SUF_KEYS_OWNED))
     }
 }
 
@@ -45,16 +53,29 @@ impl UpdateSvc for LidoSvcStd {
 
     #[inline]
     fn update_svc(&mut self, update_map: impl UpdateMap) -> Result<(), UpdateErr<Self::InnerErr>> {
+        let slot = update_map.oldest_slot_of(self.accounts_to_update_svc());
+        if self.last_update_slot.is_regression(slot) {
+            return Ok(());
+        }
+
         let lido = fetched_lido(&update_map)?;
         let clock_acc = update_map.get_account_checked(&SYSVAR_CLOCK)?;
         let current_epoch = epoch_from_clock_data(clock_acc.data()).ok_or(UpdateErr::Inner(
             LidoUpdateErr::AccDeser { pk: SYSVAR_CLOCK },
         ))?;
 
+        update_upgrade_check(&mut self.upgrade_check, &update_map)?;
+
         self.calc = Some(LidoCalc::new(&lido, current_epoch));
+        self.last_update_slot = LastUpdateSlot(slot);
 
         Ok(())
     }
+
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        self.last_update_slot.0
+    }
 }
 
 impl LidoSvcStd {
@@ -70,9 +91,15 @@ impl LidoSvcStd {
         &mut self,
         update_map: impl UpdateMap,
     ) -> Result<(), UpdateErr<LidoUpdateErr>> {
-        let lido = fetched_lido(update_map)?;
+        let slot = update_map.oldest_slot_of(self.accounts_to_update_svc());
+        if self.last_update_slot.is_regression(slot) {
+            return Ok(());
+        }
+        let lido = fetched_lido(&update_map)?;
+        update_upgrade_check(&mut self.upgrade_check, &update_map)?;
         let current_epoch = self.calc.map(|c| c.current_epoch).unwrap_or_default();
         self.calc = Some(LidoCalc::new(&lido, current_epoch));
+        self.last_update_slot = LastUpdateSlot(slot);
         Ok(())
     }
 }
@@ -86,6 +113,24 @@ fn fetched_lido(update_map: impl UpdateMap) -> Result<Lido, UpdateErr<LidoUpdate
     })
 }
 
+/// No-op if not opted-in to upgrade checks
+fn update_upgrade_check(
+    upgrade_check: &mut Option<UpgradeCheck>,
+    update_map: impl UpdateMap,
+) -> Result<(), UpdateErr<LidoUpdateErr>> {
+    let upgrade_check = match upgrade_check {
+        None => return Ok(()),
+        Some(u) => u,
+    };
+    let keys = &IX_SUF_KEYS_OWNED;
+    let state_acc = update_map.get_account_checked(keys.state())?;
+    let progdata_acc = update_map.get_account_checked(keys.pool_progdata())?;
+    let slots = LastUpgradeSlots::of_acc_data(state_acc.data(), progdata_acc.data())
+        .map_err(|e| UpdateErr::Inner(LidoUpdateErr::AccDeser { pk: *e.pk(keys) }))?;
+    upgrade_check.slots = Some(slots);
+    Ok(())
+}
+
 fn epoch_from_clock_data(clock_acc_data: &[u8]) -> Option<u64> {
     u64_le_at(clock_acc_data, 16)
 }
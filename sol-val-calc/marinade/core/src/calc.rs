@@ -1,6 +1,6 @@
 use core::{error::Error, fmt::Display, ops::RangeInclusive};
 
-use inf1_svc_core::traits::SolValCalc;
+use inf1_svc_core::traits::{SolValCalc, Staleness};
 use sanctum_marinade_liquid_staking_core::{FeeCents, StakeSystem, State, ValidatorSystem};
 use sanctum_token_ratio_compat::{
     fee_floor_ratio_u32_u32_reverse_from_rem, floor_ratio_u64_u64_reverse,
@@ -113,6 +113,15 @@ impl MarinadeCalc {
 
 /// SolValCalc
 impl MarinadeCalc {
+    /// Marinade's exchange rate is derived from balances that are updated
+    /// incrementally by permissionless cranks and the marinade program
+    /// does not gate stake withdrawals on them having run this epoch,
+    /// so the calculator is never considered stale.
+    #[inline]
+    pub const fn svc_is_stale(&self) -> bool {
+        false
+    }
+
     #[inline]
     pub const fn svc_lst_to_sol(
         &self,
@@ -178,6 +187,13 @@ impl SolValCalc for MarinadeCalc {
     }
 }
 
+impl Staleness for MarinadeCalc {
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.svc_is_stale()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MarinadeCalcErr {
     Ratio,
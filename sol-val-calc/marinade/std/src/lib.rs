@@ -1,4 +1,9 @@
-use inf1_svc_marinade_core::{calc::MarinadeCalc, instructions::sol_val_calc::MarinadeCalcAccs};
+use inf1_svc_marinade_core::{
+    calc::MarinadeCalc,
+    inf1_svc_generic::upgrade::{LastUpgradeSlots, UpgradeCheck},
+    instructions::sol_val_calc::MarinadeCalcAccs,
+};
+use inf1_svc_std::update::LastUpdateSlot;
 
 // Re-exports
 pub use inf1_svc_marinade_core::*;
@@ -10,6 +15,13 @@ pub struct MarinadeSvcStd {
     /// Might be `None` at initialization before accounts required
     /// to create the calc have been fetched
     calc: Option<MarinadeCalc>,
+
+    /// `Some` if opted-in to checking for upgrades of the Marinade program.
+    ///
+    /// See [`UpgradeCheck`]
+    upgrade_check: Option<UpgradeCheck>,
+
+    last_update_slot: LastUpdateSlot,
 }
 
 impl Default for MarinadeSvcStd {
@@ -21,7 +33,26 @@ impl Default for MarinadeSvcStd {
 
 /// Constructors
 impl MarinadeSvcStd {
-    pub const DEFAULT: Self = Self { calc: None };
+    pub const DEFAULT: Self = Self {
+        calc: None,
+        upgrade_check: None,
+        last_update_slot: LastUpdateSlot::NONE,
+    };
+
+    /// Reassemble from parts previously obtained via accessors,
+    /// e.g. when restoring from a snapshot.
+    #[inline]
+    pub const fn from_parts(
+        calc: Option<MarinadeCalc>,
+        upgrade_check: Option<UpgradeCheck>,
+        last_update_slot: LastUpdateSlot,
+    ) -> Self {
+        Self {
+            calc,
+            upgrade_check,
+            last_update_slot,
+        }
+    }
 }
 
 /// Accessors
@@ -35,4 +66,36 @@ impl MarinadeSvcStd {
     pub const fn as_accs(&self) -> &MarinadeCalcAccs {
         &MarinadeCalcAccs
     }
+
+    /// Returns `None` if not opted-in to upgrade checks
+    #[inline]
+    pub const fn as_upgrade_check(&self) -> Option<&UpgradeCheck> {
+        self.upgrade_check.as_ref()
+    }
+
+    /// Returns `None` if not opted-in to upgrade checks or
+    /// accounts required for them have not been fetched
+    #[inline]
+    pub const fn as_last_upgrade_slots(&self) -> Option<&LastUpgradeSlots> {
+        match &self.upgrade_check {
+            Some(UpgradeCheck { slots: Some(s) }) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// Mutators
+impl MarinadeSvcStd {
+    /// Opt in or out of checking for upgrades of the Marinade program.
+    ///
+    /// Opting in adds the calculator program's state and the
+    /// Marinade program's ProgramData to the accounts to update.
+    #[inline]
+    pub fn set_upgrade_check_enabled(&mut self, enabled: bool) {
+        match (enabled, self.upgrade_check.is_some()) {
+            (true, false) => self.upgrade_check = Some(UpgradeCheck::default()),
+            (false, true) => self.upgrade_check = None,
+            _ => (),
+        }
+    }
 }
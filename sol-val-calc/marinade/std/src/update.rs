@@ -6,6 +6,10 @@ use std::{
 
 use inf1_svc_marinade_core::{
     calc::MarinadeCalc,
+    inf1_svc_generic::upgrade::{
+        upgrade_check_pks, LastUpgradeSlots, UpgradeCheck, UpgradeCheckPkIter,
+    },
+    instructions::sol_val_calc::IX_SUF_KEYS_OWNED,
     sanctum_marinade_liquid_staking_core::{State, STATE_PUBKEY},
 };
 
@@ -14,14 +18,16 @@ use crate::MarinadeSvcStd;
 // Re-exports
 pub use inf1_svc_std::update::*;
 
-pub type PkIter = core::iter::Once<[u8; 32]>;
+/// Calculator program state and Marinade program ProgramData are only included
+/// if opted-in to upgrade checks
+pub type PkIter = core::iter::Chain<core::iter::Once<[u8; 32]>, UpgradeCheckPkIter>;
 
 impl AccountsToUpdateSvc for MarinadeSvcStd {
     type PkIter = PkIter;
 
     #[inline]
     fn accounts_to_update_svc(&self) -> Self::PkIter {
-        once(STATE_PUBKEY)
+        once(STATE_PUBKEY).chain(upgrade_check_pks(&self.upgrade_check, &IX_SUF_KEYS_OWNED))
     }
 }
 
@@ -46,12 +52,43 @@ impl UpdateSvc for MarinadeSvcStd {
 
     #[inline]
     fn update_svc(&mut self, update_map: impl UpdateMap) -> Result<(), UpdateErr<Self::InnerErr>> {
+        let slot = update_map.oldest_slot_of(self.accounts_to_update_svc());
+        if self.last_update_slot.is_regression(slot) {
+            return Ok(());
+        }
+
         let marinade_acc = update_map.get_account_checked(&STATE_PUBKEY)?;
         let marinade = State::borsh_de(marinade_acc.data())
             .map_err(|_e| UpdateErr::Inner(MarinadeUpdateErr::AccDeser { pk: STATE_PUBKEY }))?;
 
+        update_upgrade_check(&mut self.upgrade_check, &update_map)?;
+
         self.calc = Some(MarinadeCalc::new(&marinade));
+        self.last_update_slot = LastUpdateSlot(slot);
 
         Ok(())
     }
+
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        self.last_update_slot.0
+    }
+}
+
+/// No-op if not opted-in to upgrade checks
+fn update_upgrade_check(
+    upgrade_check: &mut Option<UpgradeCheck>,
+    update_map: impl UpdateMap,
+) -> Result<(), UpdateErr<MarinadeUpdateErr>> {
+    let upgrade_check = match upgrade_check {
+        None => return Ok(()),
+        Some(u) => u,
+    };
+    let keys = &IX_SUF_KEYS_OWNED;
+    let state_acc = update_map.get_account_checked(keys.state())?;
+    let progdata_acc = update_map.get_account_checked(keys.pool_progdata())?;
+    let slots = LastUpgradeSlots::of_acc_data(state_acc.data(), progdata_acc.data())
+        .map_err(|e| UpdateErr::Inner(MarinadeUpdateErr::AccDeser { pk: *e.pk(keys) }))?;
+    upgrade_check.slots = Some(slots);
+    Ok(())
 }
@@ -1,6 +1,6 @@
 use core::{error::Error, fmt::Display, ops::RangeInclusive};
 
-use inf1_svc_core::traits::SolValCalc;
+use inf1_svc_core::traits::{SolValCalc, Staleness};
 use sanctum_fee_ratio::ratio::{Ceil, Ratio};
 use sanctum_spl_stake_pool_core::{Fee, StakePool};
 use sanctum_token_ratio_compat::{
@@ -8,17 +8,64 @@ use sanctum_token_ratio_compat::{
 };
 use sanctum_u64_ratio::Floor;
 
+/// Which stake pool instruction's fee schedule a [`SplCalc`] models.
+///
+/// For withdrawal variants, `lst_to_sol` is the forward direction
+/// (pool tokens burnt -> lamports received)
+/// and `sol_to_lst` is its reverse.
+///
+/// For deposit variants, `sol_to_lst` is the forward direction
+/// (lamports deposited -> pool tokens received)
+/// and `lst_to_sol` is its reverse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SplFeeMode {
+    /// `WithdrawStake` from an active or transient stake account,
+    /// charged `stake_withdrawal_fee`.
+    ///
+    /// This is what the on-chain SPL SOL value calculator program uses
+    /// and should be used for pool valuation.
+    #[default]
+    WithdrawStake,
+
+    /// `WithdrawStake` when the pool only has validator stake accounts left
+    /// at minimum delegation, so withdrawal happens via `StakeWithdrawSource::ValidatorRemoval`.
+    ///
+    /// Charged `stake_withdrawal_fee`, but the withdrawn lamports are
+    /// recalculated from the stake account's balance after removal,
+    /// so the user may receive up to `ceil(total_lamports / pool_token_supply) + 1`
+    /// lamports less than for [`Self::WithdrawStake`].
+    WithdrawStakeValidatorRemoval,
+
+    /// `WithdrawSol` from the reserve, charged `sol_withdrawal_fee`
+    WithdrawSol,
+
+    /// `DepositStake`, charged `stake_deposit_fee`.
+    ///
+    /// Assumes all deposited lamports are active stake i.e.
+    /// the stake account's rent-exempt reserve, which is charged
+    /// `sol_deposit_fee` instead, is not modelled.
+    DepositStake,
+
+    /// `DepositSol` into the reserve, charged `sol_deposit_fee`
+    DepositSol,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SplCalc {
     pub last_update_epoch: u64,
     pub total_lamports: u64,
     pub pool_token_supply: u64,
     pub stake_withdrawal_fee: Fee,
+    pub sol_withdrawal_fee: Fee,
+    pub stake_deposit_fee: Fee,
+    pub sol_deposit_fee: Fee,
     pub current_epoch: u64,
+    pub fee_mode: SplFeeMode,
 }
 
 /// Constructors
 impl SplCalc {
+    /// Uses [`SplFeeMode::WithdrawStake`]
     #[inline]
     pub const fn new(
         StakePool {
@@ -26,6 +73,9 @@ impl SplCalc {
             total_lamports,
             pool_token_supply,
             stake_withdrawal_fee,
+            sol_withdrawal_fee,
+            stake_deposit_fee,
+            sol_deposit_fee,
             ..
         }: &StakePool,
         current_epoch: u64,
@@ -35,40 +85,91 @@ impl SplCalc {
             total_lamports: *total_lamports,
             pool_token_supply: *pool_token_supply,
             stake_withdrawal_fee: *stake_withdrawal_fee,
+            sol_withdrawal_fee: *sol_withdrawal_fee,
+            stake_deposit_fee: *stake_deposit_fee,
+            sol_deposit_fee: *sol_deposit_fee,
             current_epoch,
+            fee_mode: SplFeeMode::WithdrawStake,
         }
     }
+
+    #[inline]
+    pub const fn with_fee_mode(mut self, fee_mode: SplFeeMode) -> Self {
+        self.fee_mode = fee_mode;
+        self
+    }
 }
 
 type Fcr = sanctum_fee_ratio::Fee<Ceil<Ratio<u64, u64>>>;
 
+/// Returns `None` if fee > 1.0
+#[inline]
+const fn fee_ceil(
+    Fee {
+        denominator: d,
+        numerator: n,
+    }: Fee,
+) -> Option<Fcr> {
+    // The SPL stake pool program permits denominator to = 0
+    // (treated as 0 fee in that case)
+    // But sanctum_fee_ratio does not, so we need to
+    // preprocess all 0 denom fees
+    let ratio = if d == 0 {
+        Ratio { n: 0, d: 1 }
+    } else {
+        Ratio { n, d }
+    };
+    Fcr::new(ratio)
+}
+
 /// SolValCalc
 ///
 /// Assumes:
-/// - stake pool manager is always valid, so stake withdraw fee will always be charged
-/// - stake pool always has active and transient stake, so withdraw_source != StakeWithdrawSource::ValidatorRemoval
+/// - stake pool manager is always valid, so fees will always be charged
+/// - no referrer, so the full fee is deducted from the user
 impl SplCalc {
     #[inline]
     pub const fn is_updated(&self) -> bool {
         self.last_update_epoch >= self.current_epoch
     }
 
+    /// Stale if `UpdateStakePoolBalance` has not yet run for the current epoch
+    #[inline]
+    pub const fn svc_is_stale(&self) -> bool {
+        !self.is_updated()
+    }
+
     #[inline]
     pub const fn stake_withdrawal_fee_ceil(&self) -> Option<Fcr> {
-        let Fee {
-            denominator: d,
-            numerator: n,
-        } = self.stake_withdrawal_fee;
-        // The SPL stake pool program permits denominator to = 0
-        // (treated as 0 fee in that case)
-        // But sanctum_fee_ratio does not, so we need to
-        // preprocess all 0 denom fees
-        let ratio = if d == 0 {
-            Ratio { n: 0, d: 1 }
-        } else {
-            Ratio { n, d }
-        };
-        Fcr::new(ratio)
+        fee_ceil(self.stake_withdrawal_fee)
+    }
+
+    #[inline]
+    pub const fn sol_withdrawal_fee_ceil(&self) -> Option<Fcr> {
+        fee_ceil(self.sol_withdrawal_fee)
+    }
+
+    #[inline]
+    pub const fn stake_deposit_fee_ceil(&self) -> Option<Fcr> {
+        fee_ceil(self.stake_deposit_fee)
+    }
+
+    #[inline]
+    pub const fn sol_deposit_fee_ceil(&self) -> Option<Fcr> {
+        fee_ceil(self.sol_deposit_fee)
+    }
+
+    /// Fee charged in pool tokens by the instruction modelled by `self.fee_mode`
+    #[inline]
+    pub const fn fee_mode_fee_ceil(&self) -> Option<Fcr> {
+        match self.fee_mode {
+            SplFeeMode::WithdrawStake | SplFeeMode::WithdrawStakeValidatorRemoval => {
+                self.stake_withdrawal_fee_ceil()
+            }
+            SplFeeMode::WithdrawSol => self.sol_withdrawal_fee_ceil(),
+            SplFeeMode::DepositStake => self.stake_deposit_fee_ceil(),
+            SplFeeMode::DepositSol => self.sol_deposit_fee_ceil(),
+        }
     }
 
     #[inline]
@@ -84,12 +185,54 @@ impl SplCalc {
         })
     }
 
+    /// Pool tokens minted per lamport deposited, before fees.
+    ///
+    /// The stake pool program mints 1:1 if the pool is empty.
     #[inline]
-    pub const fn svc_lst_to_sol(&self, lst_amount: u64) -> Result<RangeInclusive<u64>, SplCalcErr> {
-        if !self.is_updated() {
-            return Err(SplCalcErr::NotUpdated);
+    pub const fn lamports_to_lst_ratio(&self) -> Floor<Ratio<u64, u64>> {
+        let Self {
+            total_lamports,
+            pool_token_supply,
+            ..
+        } = self;
+        if *total_lamports == 0 || *pool_token_supply == 0 {
+            Floor(Ratio { n: 1, d: 1 })
+        } else {
+            Floor(Ratio {
+                n: *pool_token_supply,
+                d: *total_lamports,
+            })
         }
-        let fee = match self.stake_withdrawal_fee_ceil() {
+    }
+
+    /// Max lamports the user may receive less than expected for
+    /// [`SplFeeMode::WithdrawStakeValidatorRemoval`]:
+    /// `ceil(total_lamports / pool_token_supply) + 1`
+    #[inline]
+    pub const fn validator_removal_lamports_err(&self) -> u64 {
+        let Self {
+            total_lamports,
+            pool_token_supply,
+            ..
+        } = self;
+        if *pool_token_supply == 0 {
+            return 1;
+        }
+        // unchecked-arith: sum of 2 u64s does not overflow u128,
+        // pool_token_supply != 0
+        let lpt =
+            (*total_lamports as u128 + *pool_token_supply as u128 - 1) / *pool_token_supply as u128;
+        if lpt >= u64::MAX as u128 {
+            u64::MAX
+        } else {
+            lpt as u64 + 1
+        }
+    }
+
+    /// Forward direction of withdrawal fee modes, excluding validator removal error
+    #[inline]
+    const fn withdraw_lamports(&self, lst_amount: u64) -> Result<u64, SplCalcErr> {
+        let fee = match self.fee_mode_fee_ceil() {
             Some(f) => f,
             None => return Err(SplCalcErr::Ratio),
         };
@@ -98,15 +241,15 @@ impl SplCalc {
             None => return Err(SplCalcErr::Ratio),
         };
         let pool_tokens_burnt = aaf.rem();
-        let withdraw_lamports = match self.lst_to_lamports_ratio().apply(pool_tokens_burnt) {
-            Some(w) => w,
-            None => return Err(SplCalcErr::Ratio),
-        };
-        Ok(withdraw_lamports..=withdraw_lamports)
+        match self.lst_to_lamports_ratio().apply(pool_tokens_burnt) {
+            Some(w) => Ok(w),
+            None => Err(SplCalcErr::Ratio),
+        }
     }
 
+    /// Reverse direction of withdrawal fee modes, excluding validator removal error
     #[inline]
-    pub const fn svc_sol_to_lst(
+    const fn withdraw_lamports_reverse(
         &self,
         lamports_amount: u64,
     ) -> Result<RangeInclusive<u64>, SplCalcErr> {
@@ -114,18 +257,111 @@ impl SplCalc {
             Some(r) => r,
             None => return Err(SplCalcErr::Ratio),
         };
-        let fee = match self.stake_withdrawal_fee_ceil() {
+        let fee = match self.fee_mode_fee_ceil() {
             Some(f) => f,
             None => return Err(SplCalcErr::Ratio),
         };
-        let (min, max) = match (
+        match (
             fee_ceil_ratio_u64_u64_reverse_from_rem(fee, *r.start()),
             fee_ceil_ratio_u64_u64_reverse_from_rem(fee, *r.end()),
         ) {
-            (Some(min), Some(max)) => (*min.start(), *max.end()),
-            _ => return Err(SplCalcErr::Ratio),
+            (Some(min), Some(max)) => Ok(*min.start()..=*max.end()),
+            _ => Err(SplCalcErr::Ratio),
+        }
+    }
+
+    /// Forward direction of deposit fee modes
+    #[inline]
+    const fn deposit_pool_tokens(&self, lamports_amount: u64) -> Result<u64, SplCalcErr> {
+        let new_pool_tokens = match self.lamports_to_lst_ratio().apply(lamports_amount) {
+            Some(t) => t,
+            None => return Err(SplCalcErr::Ratio),
+        };
+        let fee = match self.fee_mode_fee_ceil() {
+            Some(f) => f,
+            None => return Err(SplCalcErr::Ratio),
         };
-        Ok(min..=max)
+        match fee.apply(new_pool_tokens) {
+            Some(aaf) => Ok(aaf.rem()),
+            None => Err(SplCalcErr::Ratio),
+        }
+    }
+
+    /// Reverse direction of deposit fee modes
+    #[inline]
+    const fn deposit_pool_tokens_reverse(
+        &self,
+        lst_amount: u64,
+    ) -> Result<RangeInclusive<u64>, SplCalcErr> {
+        let fee = match self.fee_mode_fee_ceil() {
+            Some(f) => f,
+            None => return Err(SplCalcErr::Ratio),
+        };
+        let r = match fee_ceil_ratio_u64_u64_reverse_from_rem(fee, lst_amount) {
+            Some(r) => r,
+            None => return Err(SplCalcErr::Ratio),
+        };
+        let ratio = self.lamports_to_lst_ratio();
+        match (
+            floor_ratio_u64_u64_reverse(ratio, *r.start()),
+            floor_ratio_u64_u64_reverse(ratio, *r.end()),
+        ) {
+            (Some(min), Some(max)) => Ok(*min.start()..=*max.end()),
+            _ => Err(SplCalcErr::Ratio),
+        }
+    }
+
+    #[inline]
+    pub const fn svc_lst_to_sol(&self, lst_amount: u64) -> Result<RangeInclusive<u64>, SplCalcErr> {
+        if !self.is_updated() {
+            return Err(SplCalcErr::NotUpdated);
+        }
+        match self.fee_mode {
+            SplFeeMode::WithdrawStake | SplFeeMode::WithdrawSol => {
+                match self.withdraw_lamports(lst_amount) {
+                    Ok(w) => Ok(w..=w),
+                    Err(e) => Err(e),
+                }
+            }
+            SplFeeMode::WithdrawStakeValidatorRemoval => match self.withdraw_lamports(lst_amount) {
+                Ok(w) => Ok(w.saturating_sub(self.validator_removal_lamports_err())..=w),
+                Err(e) => Err(e),
+            },
+            SplFeeMode::DepositStake | SplFeeMode::DepositSol => {
+                self.deposit_pool_tokens_reverse(lst_amount)
+            }
+        }
+    }
+
+    #[inline]
+    pub const fn svc_sol_to_lst(
+        &self,
+        lamports_amount: u64,
+    ) -> Result<RangeInclusive<u64>, SplCalcErr> {
+        match self.fee_mode {
+            SplFeeMode::WithdrawStake | SplFeeMode::WithdrawSol => {
+                self.withdraw_lamports_reverse(lamports_amount)
+            }
+            SplFeeMode::WithdrawStakeValidatorRemoval => {
+                // user receives lamports_amount if withdraw_lamports is in
+                // [lamports_amount, lamports_amount + validator_removal_lamports_err]
+                let max_withdraw_lamports =
+                    lamports_amount.saturating_add(self.validator_removal_lamports_err());
+                match (
+                    self.withdraw_lamports_reverse(lamports_amount),
+                    self.withdraw_lamports_reverse(max_withdraw_lamports),
+                ) {
+                    (Ok(min), Ok(max)) => Ok(*min.start()..=*max.end()),
+                    (Err(e), _) | (_, Err(e)) => Err(e),
+                }
+            }
+            SplFeeMode::DepositStake | SplFeeMode::DepositSol => {
+                match self.deposit_pool_tokens(lamports_amount) {
+                    Ok(t) => Ok(t..=t),
+                    Err(e) => Err(e),
+                }
+            }
+        }
     }
 }
 
@@ -143,6 +379,232 @@ impl SolValCalc for SplCalc {
     }
 }
 
+impl Staleness for SplCalc {
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.svc_is_stale()
+    }
+}
+
+/// Opt-in estimator for the window after an epoch boundary but before
+/// `UpdateStakePoolBalance` has run for the stake pool, during which
+/// [`SplCalc`] either errors or uses the previous epoch's exchange rate.
+///
+/// `total_lamports` is projected forward by assuming the pool's active stake earns
+/// the same rewards as the pool's per-pool-token growth over the last epoch.
+/// Reserve and transient stake do not earn inflation rewards, so only
+/// active stake (summed from the validator list) is taken into account.
+///
+/// Values returned by [`SolValCalc`] methods are estimates: returned ranges
+/// span from the value calculated with the unprojected `total_lamports` to
+/// the value calculated with the projected `total_lamports`. If the stake pool
+/// has already been updated for the current epoch or there is no last epoch data
+/// to project from, this behaves exactly like [`SplCalc`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplEstCalc {
+    pub calc: SplCalc,
+    pub last_epoch_total_lamports: u64,
+    pub last_epoch_pool_token_supply: u64,
+
+    /// Sum of `active_stake_lamports` of all entries in the stake pool's validator list
+    pub active_stake_lamports: u64,
+}
+
+/// Constructors
+impl SplEstCalc {
+    #[inline]
+    pub const fn new(pool: &StakePool, current_epoch: u64, active_stake_lamports: u64) -> Self {
+        Self {
+            calc: SplCalc::new(pool, current_epoch),
+            last_epoch_total_lamports: pool.last_epoch_total_lamports,
+            last_epoch_pool_token_supply: pool.last_epoch_pool_token_supply,
+            active_stake_lamports,
+        }
+    }
+
+    /// Estimator without last epoch data to project from,
+    /// i.e. returns the same values and errors as `calc`.
+    ///
+    /// Used to quote with a [`SplEstCalc`] for stake pools
+    /// that have not opted-in to estimates.
+    #[inline]
+    pub const fn no_projection(calc: SplCalc) -> Self {
+        Self {
+            calc,
+            last_epoch_total_lamports: 0,
+            last_epoch_pool_token_supply: 0,
+            active_stake_lamports: 0,
+        }
+    }
+}
+
+/// Estimation
+impl SplEstCalc {
+    /// `false` if there is no last epoch data to project `total_lamports` forward from,
+    /// in which case this behaves exactly like [`SplCalc`]
+    #[inline]
+    pub const fn can_project(&self) -> bool {
+        self.last_epoch_total_lamports != 0 && self.last_epoch_pool_token_supply != 0
+    }
+
+    /// Estimated inflation rewards earned by the pool's active stake
+    /// over a single epoch.
+    ///
+    /// Only a single epoch's rewards are estimated even if
+    /// more than 1 epoch has passed since the last update, so the estimate is
+    /// conservative in that case.
+    ///
+    /// Returns `Some(0)` if the pool did not grow over the last epoch.
+    ///
+    /// # Returns
+    /// `None` on overflow
+    #[inline]
+    pub const fn est_epoch_rewards(&self) -> Option<u64> {
+        let SplCalc {
+            total_lamports,
+            pool_token_supply,
+            ..
+        } = self.calc;
+        // growth in lamports per pool token over last epoch =
+        // (total_lamports / pool_token_supply) / (last_epoch_total_lamports / last_epoch_pool_token_supply)
+        //
+        // unchecked-arith: u64 * u64 does not overflow u128
+        let curr = total_lamports as u128 * self.last_epoch_pool_token_supply as u128;
+        let prev = self.last_epoch_total_lamports as u128 * pool_token_supply as u128;
+        if prev == 0 || curr <= prev {
+            return Some(0);
+        }
+        // scale down both terms so that curr fits in 64 bits
+        // and the multiplication below does not overflow
+        let shift = (u128::BITS - curr.leading_zeros()).saturating_sub(u64::BITS);
+        let curr = curr >> shift;
+        let prev = prev >> shift;
+        if prev == 0 {
+            return None;
+        }
+        // unchecked-arith: curr >= prev after shift since curr > prev before,
+        // and curr < 2^64, so product < 2^128
+        let rewards = self.active_stake_lamports as u128 * (curr - prev) / prev;
+        if rewards > u64::MAX as u128 {
+            None
+        } else {
+            Some(rewards as u64)
+        }
+    }
+
+    /// # Returns
+    /// `None` on overflow
+    #[inline]
+    pub const fn projected_total_lamports(&self) -> Option<u64> {
+        if self.calc.is_updated() {
+            return Some(self.calc.total_lamports);
+        }
+        match self.est_epoch_rewards() {
+            Some(r) => self.calc.total_lamports.checked_add(r),
+            None => None,
+        }
+    }
+
+    /// [`SplCalc`] using the unprojected `total_lamports`,
+    /// treated as if it were updated for the current epoch
+    #[inline]
+    pub const fn unprojected_calc(&self) -> SplCalc {
+        let mut calc = self.calc;
+        if !calc.is_updated() {
+            calc.last_update_epoch = calc.current_epoch;
+        }
+        calc
+    }
+
+    /// [`SplCalc`] using the projected `total_lamports`,
+    /// treated as if it were updated for the current epoch
+    ///
+    /// # Returns
+    /// `None` on overflow
+    #[inline]
+    pub const fn projected_calc(&self) -> Option<SplCalc> {
+        let total_lamports = match self.projected_total_lamports() {
+            Some(t) => t,
+            None => return None,
+        };
+        let mut calc = self.unprojected_calc();
+        calc.total_lamports = total_lamports;
+        Some(calc)
+    }
+}
+
+/// SolValCalc
+impl SplEstCalc {
+    /// Estimates are still derived from stale data,
+    /// so this is the same as [`SplCalc::svc_is_stale`]
+    #[inline]
+    pub const fn svc_is_stale(&self) -> bool {
+        self.calc.svc_is_stale()
+    }
+
+    #[inline]
+    pub const fn svc_lst_to_sol(&self, lst_amount: u64) -> Result<RangeInclusive<u64>, SplCalcErr> {
+        if !self.can_project() {
+            return self.calc.svc_lst_to_sol(lst_amount);
+        }
+        let projected = match self.projected_calc() {
+            Some(c) => c,
+            None => return Err(SplCalcErr::Ratio),
+        };
+        // projected total_lamports >= unprojected, so unprojected gives the lower bound
+        match (
+            self.unprojected_calc().svc_lst_to_sol(lst_amount),
+            projected.svc_lst_to_sol(lst_amount),
+        ) {
+            (Ok(min), Ok(max)) => Ok(*min.start()..=*max.end()),
+            (Err(e), _) | (_, Err(e)) => Err(e),
+        }
+    }
+
+    #[inline]
+    pub const fn svc_sol_to_lst(
+        &self,
+        lamports_amount: u64,
+    ) -> Result<RangeInclusive<u64>, SplCalcErr> {
+        if !self.can_project() {
+            return self.calc.svc_sol_to_lst(lamports_amount);
+        }
+        let projected = match self.projected_calc() {
+            Some(c) => c,
+            None => return Err(SplCalcErr::Ratio),
+        };
+        // projected total_lamports >= unprojected, so projected gives the lower bound
+        match (
+            projected.svc_sol_to_lst(lamports_amount),
+            self.unprojected_calc().svc_sol_to_lst(lamports_amount),
+        ) {
+            (Ok(min), Ok(max)) => Ok(*min.start()..=*max.end()),
+            (Err(e), _) | (_, Err(e)) => Err(e),
+        }
+    }
+}
+
+impl SolValCalc for SplEstCalc {
+    type Error = SplCalcErr;
+
+    #[inline]
+    fn lst_to_sol(&self, lst_amount: u64) -> Result<RangeInclusive<u64>, Self::Error> {
+        self.svc_lst_to_sol(lst_amount)
+    }
+
+    #[inline]
+    fn sol_to_lst(&self, lamports_amount: u64) -> Result<RangeInclusive<u64>, Self::Error> {
+        self.svc_sol_to_lst(lamports_amount)
+    }
+}
+
+impl Staleness for SplEstCalc {
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.svc_is_stale()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SplCalcErr {
     Ratio,
@@ -160,3 +622,242 @@ impl Display for SplCalcErr {
 }
 
 impl Error for SplCalcErr {}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn any_fee() -> impl Strategy<Value = Fee> {
+        (0..=u64::MAX)
+            .prop_flat_map(|d| (0..=d, Just(d)))
+            .prop_map(|(numerator, denominator)| Fee {
+                denominator,
+                numerator,
+            })
+    }
+
+    fn any_fee_mode() -> impl Strategy<Value = SplFeeMode> {
+        prop_oneof![
+            Just(SplFeeMode::WithdrawStake),
+            Just(SplFeeMode::WithdrawStakeValidatorRemoval),
+            Just(SplFeeMode::WithdrawSol),
+            Just(SplFeeMode::DepositStake),
+            Just(SplFeeMode::DepositSol),
+        ]
+    }
+
+    /// - updated for current epoch
+    fn any_calc() -> impl Strategy<Value = SplCalc> {
+        (
+            any::<u64>(),
+            1..=u64::MAX,
+            1..=u64::MAX,
+            [any_fee(), any_fee(), any_fee(), any_fee()],
+            any_fee_mode(),
+        )
+            .prop_map(
+                |(
+                    current_epoch,
+                    total_lamports,
+                    pool_token_supply,
+                    [stake_withdrawal_fee, sol_withdrawal_fee, stake_deposit_fee, sol_deposit_fee],
+                    fee_mode,
+                )| SplCalc {
+                    last_update_epoch: current_epoch,
+                    total_lamports,
+                    pool_token_supply,
+                    stake_withdrawal_fee,
+                    sol_withdrawal_fee,
+                    stake_deposit_fee,
+                    sol_deposit_fee,
+                    current_epoch,
+                    fee_mode,
+                },
+            )
+    }
+
+    fn assert_valid_range(r: &RangeInclusive<u64>) {
+        assert!(r.start() <= r.end(), "{r:?}");
+    }
+
+    fn assert_err_bound(val: u64, r: &RangeInclusive<u64>) {
+        assert!(r.contains(&val), "{val} {r:?}");
+    }
+
+    /// Outer bounds of `f` applied to both ends of `r`,
+    /// `None` if either errors
+    fn rt(
+        r: &RangeInclusive<u64>,
+        f: impl Fn(u64) -> Result<RangeInclusive<u64>, SplCalcErr>,
+    ) -> Option<RangeInclusive<u64>> {
+        match (f(*r.start()), f(*r.end())) {
+            (Ok(min), Ok(max)) => Some(*min.start()..=*max.end()),
+            _ => None,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn rt_errbound(
+            val: u64,
+            calc in any_calc(),
+        ) {
+            if let Ok(lst) = calc.svc_sol_to_lst(val) {
+                assert_valid_range(&lst);
+                if let Some(r) = rt(&lst, |x| calc.svc_lst_to_sol(x)) {
+                    assert_err_bound(val, &r);
+                }
+            }
+            if let Ok(sol) = calc.svc_lst_to_sol(val) {
+                assert_valid_range(&sol);
+                if let Some(r) = rt(&sol, |x| calc.svc_sol_to_lst(x)) {
+                    assert_err_bound(val, &r);
+                }
+            }
+        }
+    }
+
+    const ZERO_FEE: Fee = Fee {
+        denominator: 0,
+        numerator: 0,
+    };
+
+    /// Pool grew 10% over the last epoch and has not been updated for the current one
+    fn stale_est_calc(active_stake_lamports: u64) -> SplEstCalc {
+        SplEstCalc {
+            calc: SplCalc {
+                last_update_epoch: 99,
+                total_lamports: 1_100_000_000,
+                pool_token_supply: 1_000_000_000,
+                stake_withdrawal_fee: ZERO_FEE,
+                sol_withdrawal_fee: ZERO_FEE,
+                stake_deposit_fee: ZERO_FEE,
+                sol_deposit_fee: ZERO_FEE,
+                current_epoch: 100,
+                fee_mode: SplFeeMode::WithdrawStake,
+            },
+            last_epoch_total_lamports: 1_000_000_000,
+            last_epoch_pool_token_supply: 1_000_000_000,
+            active_stake_lamports,
+        }
+    }
+
+    #[test]
+    fn est_epoch_rewards_known_growth() {
+        let est = stale_est_calc(500_000_000);
+        assert_eq!(est.est_epoch_rewards(), Some(50_000_000));
+        assert_eq!(est.projected_total_lamports(), Some(1_150_000_000));
+    }
+
+    #[test]
+    fn est_epoch_rewards_no_growth() {
+        let mut est = stale_est_calc(500_000_000);
+        est.last_epoch_total_lamports = 1_100_000_000;
+        assert_eq!(est.est_epoch_rewards(), Some(0));
+        est.last_epoch_total_lamports = 1_200_000_000;
+        assert_eq!(est.est_epoch_rewards(), Some(0));
+        est.last_epoch_pool_token_supply = 0;
+        assert_eq!(est.est_epoch_rewards(), Some(0));
+    }
+
+    #[test]
+    fn est_epoch_rewards_overflow() {
+        let mut est = stale_est_calc(u64::MAX);
+        est.calc.total_lamports = 3_000_000_000;
+        assert_eq!(est.est_epoch_rewards(), None);
+        assert_eq!(est.projected_total_lamports(), None);
+        assert_eq!(est.svc_lst_to_sol(1), Err(SplCalcErr::Ratio));
+    }
+
+    #[test]
+    fn est_updated_pool_not_projected() {
+        let mut est = stale_est_calc(500_000_000);
+        est.calc.last_update_epoch = est.calc.current_epoch;
+        assert_eq!(est.projected_total_lamports(), Some(1_100_000_000));
+        assert_eq!(
+            est.svc_lst_to_sol(1_000_000_000),
+            est.calc.svc_lst_to_sol(1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn est_range_spans_unprojected_to_projected() {
+        let est = stale_est_calc(500_000_000);
+        let sol = est.svc_lst_to_sol(1_000_000_000).unwrap();
+        assert_eq!(sol, 1_100_000_000..=1_150_000_000);
+        let lst = est.svc_sol_to_lst(1_150_000_000).unwrap();
+        assert!(*lst.start() <= 1_000_000_000, "{lst:?}");
+        assert!(*lst.end() >= 1_045_454_545, "{lst:?}");
+    }
+
+    proptest! {
+        #[test]
+        fn no_projection_matches_calc(
+            val: u64,
+            calc in any_calc(),
+            stale: bool,
+        ) {
+            let mut calc = calc;
+            if stale {
+                calc.last_update_epoch = calc.current_epoch.wrapping_sub(1);
+            }
+            let est = SplEstCalc::no_projection(calc);
+            prop_assert_eq!(est.svc_lst_to_sol(val), calc.svc_lst_to_sol(val));
+            prop_assert_eq!(est.svc_sol_to_lst(val), calc.svc_sol_to_lst(val));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn forward_matches_compat_reverse(
+            val: u64,
+            calc in any_calc(),
+        ) {
+            let fee = calc.fee_mode_fee_ceil().unwrap();
+            match calc.fee_mode {
+                SplFeeMode::WithdrawStake | SplFeeMode::WithdrawSol => {
+                    if let Ok(sol) = calc.svc_lst_to_sol(val) {
+                        prop_assert_eq!(sol.start(), sol.end());
+                        let r = floor_ratio_u64_u64_reverse(
+                            calc.lst_to_lamports_ratio(),
+                            *sol.start(),
+                        )
+                        .and_then(|burnt| {
+                            rt(&burnt, |x| {
+                                fee_ceil_ratio_u64_u64_reverse_from_rem(fee, x)
+                                    .ok_or(SplCalcErr::Ratio)
+                            })
+                        });
+                        if let Some(r) = r {
+                            assert_err_bound(val, &r);
+                        }
+                    }
+                }
+                SplFeeMode::WithdrawStakeValidatorRemoval => {
+                    if let Ok(sol) = calc.svc_lst_to_sol(val) {
+                        prop_assert!(
+                            sol.end() - sol.start() <= calc.validator_removal_lamports_err()
+                        );
+                    }
+                }
+                SplFeeMode::DepositStake | SplFeeMode::DepositSol => {
+                    if let Ok(lst) = calc.svc_sol_to_lst(val) {
+                        prop_assert_eq!(lst.start(), lst.end());
+                        let r = fee_ceil_ratio_u64_u64_reverse_from_rem(fee, *lst.start())
+                            .and_then(|minted| {
+                                rt(&minted, |x| {
+                                    floor_ratio_u64_u64_reverse(calc.lamports_to_lst_ratio(), x)
+                                        .ok_or(SplCalcErr::Ratio)
+                                })
+                            });
+                        if let Some(r) = r {
+                            assert_err_bound(val, &r);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
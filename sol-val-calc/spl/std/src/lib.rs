@@ -1,7 +1,9 @@
 use inf1_svc_spl_core::{
-    calc::SplCalc,
+    calc::{SplCalc, SplEstCalc, SplFeeMode},
+    inf1_svc_generic::upgrade::{LastUpgradeSlots, UpgradeCheck},
     instructions::sol_val_calc::{SanctumSplCalcAccs, SanctumSplMultiCalcAccs, SplCalcAccs},
 };
+use inf1_svc_std::update::LastUpdateSlot;
 
 // Re-exports
 pub use inf1_svc_spl_core::*;
@@ -18,6 +20,32 @@ pub struct GenSplSvcStd<A> {
     /// to create the calc have been fetched
     calc: Option<SplCalc>,
     accs: A,
+
+    /// `Some` if opted-in to estimating values for the window after an epoch
+    /// boundary but before the stake pool has been updated.
+    ///
+    /// See [`SplEstCalc`]
+    est: Option<SplEstState>,
+
+    /// Fee schedule used by `calc`, retained across updates.
+    /// Defaults to [`SplFeeMode::WithdrawStake`] for pool valuation.
+    fee_mode: SplFeeMode,
+
+    /// `Some` if opted-in to checking for upgrades of the stake pool program.
+    ///
+    /// See [`UpgradeCheck`]
+    upgrade_check: Option<UpgradeCheck>,
+
+    last_update_slot: LastUpdateSlot,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SplEstState {
+    /// Might be `None` before the stake pool account has been fetched
+    pub validator_list_addr: Option<[u8; 32]>,
+
+    /// Might be `None` before the validator list account has been fetched
+    pub calc: Option<SplEstCalc>,
 }
 
 /// Constructors
@@ -26,6 +54,10 @@ impl SanctumSplSvcStd {
         Self {
             calc: None,
             accs: SanctumSplCalcAccs { stake_pool_addr },
+            est: None,
+            fee_mode: SplFeeMode::WithdrawStake,
+            upgrade_check: None,
+            last_update_slot: LastUpdateSlot::NONE,
         }
     }
 }
@@ -36,6 +68,10 @@ impl SanctumSplMultiSvcStd {
         Self {
             calc: None,
             accs: SanctumSplMultiCalcAccs { stake_pool_addr },
+            est: None,
+            fee_mode: SplFeeMode::WithdrawStake,
+            upgrade_check: None,
+            last_update_slot: LastUpdateSlot::NONE,
         }
     }
 }
@@ -46,6 +82,34 @@ impl SplSvcStd {
         Self {
             calc: None,
             accs: SplCalcAccs { stake_pool_addr },
+            est: None,
+            fee_mode: SplFeeMode::WithdrawStake,
+            upgrade_check: None,
+            last_update_slot: LastUpdateSlot::NONE,
+        }
+    }
+}
+
+/// Constructors
+impl<A> GenSplSvcStd<A> {
+    /// Reassemble from parts previously obtained via accessors,
+    /// e.g. when restoring from a snapshot.
+    #[inline]
+    pub const fn from_parts(
+        accs: A,
+        calc: Option<SplCalc>,
+        est: Option<SplEstState>,
+        fee_mode: SplFeeMode,
+        upgrade_check: Option<UpgradeCheck>,
+        last_update_slot: LastUpdateSlot,
+    ) -> Self {
+        Self {
+            calc,
+            accs,
+            est,
+            fee_mode,
+            upgrade_check,
+            last_update_slot,
         }
     }
 }
@@ -61,4 +125,93 @@ impl<A> GenSplSvcStd<A> {
     pub const fn as_accs(&self) -> &A {
         &self.accs
     }
+
+    /// Returns `None` if not opted-in to estimates or
+    /// accounts required to create the estimator have not been fetched
+    #[inline]
+    pub const fn as_est_calc(&self) -> Option<&SplEstCalc> {
+        match &self.est {
+            Some(SplEstState { calc: Some(c), .. }) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Returns `None` if not opted-in to estimates
+    #[inline]
+    pub const fn as_est(&self) -> Option<&SplEstState> {
+        self.est.as_ref()
+    }
+
+    #[inline]
+    pub const fn is_est_enabled(&self) -> bool {
+        self.est.is_some()
+    }
+
+    #[inline]
+    pub const fn fee_mode(&self) -> SplFeeMode {
+        self.fee_mode
+    }
+
+    /// Returns `None` if not opted-in to upgrade checks
+    #[inline]
+    pub const fn as_upgrade_check(&self) -> Option<&UpgradeCheck> {
+        self.upgrade_check.as_ref()
+    }
+
+    /// Returns `None` if not opted-in to upgrade checks or
+    /// accounts required for them have not been fetched
+    #[inline]
+    pub const fn as_last_upgrade_slots(&self) -> Option<&LastUpgradeSlots> {
+        match &self.upgrade_check {
+            Some(UpgradeCheck { slots: Some(s) }) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// Mutators
+impl<A> GenSplSvcStd<A> {
+    /// Opt in or out of estimates.
+    ///
+    /// Opting in adds the stake pool's validator list
+    /// to the accounts to update once the stake pool account has been fetched.
+    #[inline]
+    pub fn set_est_enabled(&mut self, enabled: bool) {
+        match (enabled, self.est.is_some()) {
+            (true, false) => self.est = Some(SplEstState::default()),
+            (false, true) => self.est = None,
+            _ => (),
+        }
+    }
+
+    /// Opt in or out of checking for upgrades of the stake pool program.
+    ///
+    /// Opting in adds the calculator program's state and the
+    /// stake pool program's ProgramData to the accounts to update.
+    #[inline]
+    pub fn set_upgrade_check_enabled(&mut self, enabled: bool) {
+        match (enabled, self.upgrade_check.is_some()) {
+            (true, false) => self.upgrade_check = Some(UpgradeCheck::default()),
+            (false, true) => self.upgrade_check = None,
+            _ => (),
+        }
+    }
+
+    /// Select the fee schedule to model, e.g. [`SplFeeMode::WithdrawSol`]
+    /// for user-facing unstake quotes.
+    ///
+    /// Takes effect immediately on existing calc data.
+    #[inline]
+    pub fn set_fee_mode(&mut self, fee_mode: SplFeeMode) {
+        self.fee_mode = fee_mode;
+        if let Some(calc) = self.calc.as_mut() {
+            calc.fee_mode = fee_mode;
+        }
+        if let Some(SplEstState {
+            calc: Some(est), ..
+        }) = self.est.as_mut()
+        {
+            est.calc.fee_mode = fee_mode;
+        }
+    }
 }
@@ -4,23 +4,44 @@ use std::{
 };
 
 use inf1_svc_spl_core::{
-    calc::SplCalc,
+    calc::{SplCalc, SplEstCalc, SplFeeMode},
+    inf1_svc_generic::{
+        instructions::IxSufKeysOwned,
+        upgrade::{upgrade_check_pks, LastUpgradeSlots, UpgradeCheck, UpgradeCheckPkIter},
+    },
     sanctum_spl_stake_pool_core::{StakePool, SYSVAR_CLOCK},
 };
 
-use crate::{SanctumSplMultiSvcStd, SanctumSplSvcStd, SplSvcStd};
+use crate::{SanctumSplMultiSvcStd, SanctumSplSvcStd, SplEstState, SplSvcStd};
 
 // Re-exports
 pub use inf1_svc_std::update::*;
 
-pub type PkIter = core::array::IntoIter<[u8; 32], 2>;
+/// - Validator list is only included if opted-in to estimates
+///   and the stake pool account has been fetched
+/// - Calculator program state and stake pool program ProgramData
+///   are only included if opted-in to upgrade checks
+pub type PkIter = core::iter::Chain<
+    core::iter::Chain<core::array::IntoIter<[u8; 32], 2>, core::option::IntoIter<[u8; 32]>>,
+    UpgradeCheckPkIter,
+>;
+
+fn est_validator_list_addr(est: &Option<SplEstState>) -> Option<[u8; 32]> {
+    est.and_then(|e| e.validator_list_addr)
+}
 
 impl AccountsToUpdateSvc for SanctumSplSvcStd {
     type PkIter = PkIter;
 
     #[inline]
     fn accounts_to_update_svc(&self) -> Self::PkIter {
-        [self.accs.stake_pool_addr, SYSVAR_CLOCK].into_iter()
+        [self.accs.stake_pool_addr, SYSVAR_CLOCK]
+            .into_iter()
+            .chain(est_validator_list_addr(&self.est))
+            .chain(upgrade_check_pks(
+                &self.upgrade_check,
+                &self.accs.svc_suf_keys_owned(),
+            ))
     }
 }
 
@@ -29,7 +50,13 @@ impl AccountsToUpdateSvc for SanctumSplMultiSvcStd {
 
     #[inline]
     fn accounts_to_update_svc(&self) -> Self::PkIter {
-        [self.accs.stake_pool_addr, SYSVAR_CLOCK].into_iter()
+        [self.accs.stake_pool_addr, SYSVAR_CLOCK]
+            .into_iter()
+            .chain(est_validator_list_addr(&self.est))
+            .chain(upgrade_check_pks(
+                &self.upgrade_check,
+                &self.accs.svc_suf_keys_owned(),
+            ))
     }
 }
 
@@ -38,7 +65,13 @@ impl AccountsToUpdateSvc for SplSvcStd {
 
     #[inline]
     fn accounts_to_update_svc(&self) -> Self::PkIter {
-        [self.accs.stake_pool_addr, SYSVAR_CLOCK].into_iter()
+        [self.accs.stake_pool_addr, SYSVAR_CLOCK]
+            .into_iter()
+            .chain(est_validator_list_addr(&self.est))
+            .chain(upgrade_check_pks(
+                &self.upgrade_check,
+                &self.accs.svc_suf_keys_owned(),
+            ))
     }
 }
 
@@ -60,6 +93,8 @@ impl Error for SplUpdateErr {}
 
 fn updated_spl_calc(
     stake_pool_addr: [u8; 32],
+    fee_mode: SplFeeMode,
+    est: &mut Option<SplEstState>,
     update_map: impl UpdateMap,
 ) -> Result<SplCalc, UpdateErr<SplUpdateErr>> {
     let pool = fetched_stake_pool(&stake_pool_addr, &update_map)?;
@@ -67,7 +102,58 @@ fn updated_spl_calc(
     let current_epoch = epoch_from_clock_data(clock_acc.data()).ok_or(UpdateErr::Inner(
         SplUpdateErr::AccDeser { pk: SYSVAR_CLOCK },
     ))?;
-    Ok(SplCalc::new(&pool, current_epoch))
+    update_est(est, &pool, current_epoch, fee_mode, &update_map)?;
+    Ok(SplCalc::new(&pool, current_epoch).with_fee_mode(fee_mode))
+}
+
+/// No-op if not opted-in to estimates.
+///
+/// The validator list is not required to be in `update_map` since its address
+/// is only known after the stake pool account has been fetched. The estimator
+/// is reset to `None` if it is missing.
+fn update_est(
+    est: &mut Option<SplEstState>,
+    pool: &StakePool,
+    current_epoch: u64,
+    fee_mode: SplFeeMode,
+    update_map: impl UpdateMap,
+) -> Result<(), UpdateErr<SplUpdateErr>> {
+    let est = match est {
+        None => return Ok(()),
+        Some(e) => e,
+    };
+    est.validator_list_addr = Some(pool.validator_list);
+    est.calc = match update_map.get_account(&pool.validator_list) {
+        None => None,
+        Some(acc) => {
+            let active_stake_lamports = active_stake_lamports_from_validator_list_data(acc.data())
+                .ok_or(UpdateErr::Inner(SplUpdateErr::AccDeser {
+                    pk: pool.validator_list,
+                }))?;
+            let mut calc = SplEstCalc::new(pool, current_epoch, active_stake_lamports);
+            calc.calc = calc.calc.with_fee_mode(fee_mode);
+            Some(calc)
+        }
+    };
+    Ok(())
+}
+
+/// No-op if not opted-in to upgrade checks
+fn update_upgrade_check(
+    upgrade_check: &mut Option<UpgradeCheck>,
+    keys: &IxSufKeysOwned,
+    update_map: impl UpdateMap,
+) -> Result<(), UpdateErr<SplUpdateErr>> {
+    let upgrade_check = match upgrade_check {
+        None => return Ok(()),
+        Some(u) => u,
+    };
+    let state_acc = update_map.get_account_checked(keys.state())?;
+    let progdata_acc = update_map.get_account_checked(keys.pool_progdata())?;
+    let slots = LastUpgradeSlots::of_acc_data(state_acc.data(), progdata_acc.data())
+        .map_err(|e| UpdateErr::Inner(SplUpdateErr::AccDeser { pk: *e.pk(keys) }))?;
+    upgrade_check.slots = Some(slots);
+    Ok(())
 }
 
 fn fetched_stake_pool(
@@ -87,9 +173,29 @@ impl UpdateSvc for SanctumSplSvcStd {
 
     #[inline]
     fn update_svc(&mut self, update_map: impl UpdateMap) -> Result<(), UpdateErr<Self::InnerErr>> {
-        self.calc = Some(updated_spl_calc(self.accs.stake_pool_addr, update_map)?);
+        let slot = update_map.oldest_slot_of(self.accounts_to_update_svc());
+        if self.last_update_slot.is_regression(slot) {
+            return Ok(());
+        }
+        self.calc = Some(updated_spl_calc(
+            self.accs.stake_pool_addr,
+            self.fee_mode,
+            &mut self.est,
+            &update_map,
+        )?);
+        update_upgrade_check(
+            &mut self.upgrade_check,
+            &self.accs.svc_suf_keys_owned(),
+            &update_map,
+        )?;
+        self.last_update_slot = LastUpdateSlot(slot);
         Ok(())
     }
+
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        self.last_update_slot.0
+    }
 }
 
 impl UpdateSvc for SanctumSplMultiSvcStd {
@@ -97,9 +203,29 @@ impl UpdateSvc for SanctumSplMultiSvcStd {
 
     #[inline]
     fn update_svc(&mut self, update_map: impl UpdateMap) -> Result<(), UpdateErr<Self::InnerErr>> {
-        self.calc = Some(updated_spl_calc(self.accs.stake_pool_addr, update_map)?);
+        let slot = update_map.oldest_slot_of(self.accounts_to_update_svc());
+        if self.last_update_slot.is_regression(slot) {
+            return Ok(());
+        }
+        self.calc = Some(updated_spl_calc(
+            self.accs.stake_pool_addr,
+            self.fee_mode,
+            &mut self.est,
+            &update_map,
+        )?);
+        update_upgrade_check(
+            &mut self.upgrade_check,
+            &self.accs.svc_suf_keys_owned(),
+            &update_map,
+        )?;
+        self.last_update_slot = LastUpdateSlot(slot);
         Ok(())
     }
+
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        self.last_update_slot.0
+    }
 }
 
 impl UpdateSvc for SplSvcStd {
@@ -107,9 +233,29 @@ impl UpdateSvc for SplSvcStd {
 
     #[inline]
     fn update_svc(&mut self, update_map: impl UpdateMap) -> Result<(), UpdateErr<Self::InnerErr>> {
-        self.calc = Some(updated_spl_calc(self.accs.stake_pool_addr, update_map)?);
+        let slot = update_map.oldest_slot_of(self.accounts_to_update_svc());
+        if self.last_update_slot.is_regression(slot) {
+            return Ok(());
+        }
+        self.calc = Some(updated_spl_calc(
+            self.accs.stake_pool_addr,
+            self.fee_mode,
+            &mut self.est,
+            &update_map,
+        )?);
+        update_upgrade_check(
+            &mut self.upgrade_check,
+            &self.accs.svc_suf_keys_owned(),
+            &update_map,
+        )?;
+        self.last_update_slot = LastUpdateSlot(slot);
         Ok(())
     }
+
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        self.last_update_slot.0
+    }
 }
 
 impl SanctumSplSvcStd {
@@ -125,11 +271,26 @@ impl SanctumSplSvcStd {
         &mut self,
         update_map: impl UpdateMap,
     ) -> Result<(), UpdateErr<SplUpdateErr>> {
+        let slot = update_map.oldest_slot_of(self.accounts_to_update_svc());
+        if self.last_update_slot.is_regression(slot) {
+            return Ok(());
+        }
         let current_epoch = self.calc.map(|c| c.current_epoch).unwrap_or_default();
-        self.calc = Some(SplCalc::new(
-            &fetched_stake_pool(&self.accs.stake_pool_addr, update_map)?,
+        let pool = fetched_stake_pool(&self.accs.stake_pool_addr, &update_map)?;
+        update_est(
+            &mut self.est,
+            &pool,
             current_epoch,
-        ));
+            self.fee_mode,
+            &update_map,
+        )?;
+        update_upgrade_check(
+            &mut self.upgrade_check,
+            &self.accs.svc_suf_keys_owned(),
+            &update_map,
+        )?;
+        self.calc = Some(SplCalc::new(&pool, current_epoch).with_fee_mode(self.fee_mode));
+        self.last_update_slot = LastUpdateSlot(slot);
         Ok(())
     }
 }
@@ -147,11 +308,26 @@ impl SanctumSplMultiSvcStd {
         &mut self,
         update_map: impl UpdateMap,
     ) -> Result<(), UpdateErr<SplUpdateErr>> {
+        let slot = update_map.oldest_slot_of(self.accounts_to_update_svc());
+        if self.last_update_slot.is_regression(slot) {
+            return Ok(());
+        }
         let current_epoch = self.calc.map(|c| c.current_epoch).unwrap_or_default();
-        self.calc = Some(SplCalc::new(
-            &fetched_stake_pool(&self.accs.stake_pool_addr, update_map)?,
+        let pool = fetched_stake_pool(&self.accs.stake_pool_addr, &update_map)?;
+        update_est(
+            &mut self.est,
+            &pool,
             current_epoch,
-        ));
+            self.fee_mode,
+            &update_map,
+        )?;
+        update_upgrade_check(
+            &mut self.upgrade_check,
+            &self.accs.svc_suf_keys_owned(),
+            &update_map,
+        )?;
+        self.calc = Some(SplCalc::new(&pool, current_epoch).with_fee_mode(self.fee_mode));
+        self.last_update_slot = LastUpdateSlot(slot);
         Ok(())
     }
 }
@@ -169,15 +345,50 @@ impl SplSvcStd {
         &mut self,
         update_map: impl UpdateMap,
     ) -> Result<(), UpdateErr<SplUpdateErr>> {
+        let slot = update_map.oldest_slot_of(self.accounts_to_update_svc());
+        if self.last_update_slot.is_regression(slot) {
+            return Ok(());
+        }
         let current_epoch = self.calc.map(|c| c.current_epoch).unwrap_or_default();
-        self.calc = Some(SplCalc::new(
-            &fetched_stake_pool(&self.accs.stake_pool_addr, update_map)?,
+        let pool = fetched_stake_pool(&self.accs.stake_pool_addr, &update_map)?;
+        update_est(
+            &mut self.est,
+            &pool,
             current_epoch,
-        ));
+            self.fee_mode,
+            &update_map,
+        )?;
+        update_upgrade_check(
+            &mut self.upgrade_check,
+            &self.accs.svc_suf_keys_owned(),
+            &update_map,
+        )?;
+        self.calc = Some(SplCalc::new(&pool, current_epoch).with_fee_mode(self.fee_mode));
+        self.last_update_slot = LastUpdateSlot(slot);
         Ok(())
     }
 }
 
+/// ValidatorList layout:
+/// - header: account_type (u8), max_validators (u32)
+/// - borsh vec len (u32)
+/// - [`VALIDATOR_STAKE_INFO_LEN`]-byte ValidatorStakeInfo entries,
+///   each starting with `active_stake_lamports` (u64)
+fn active_stake_lamports_from_validator_list_data(data: &[u8]) -> Option<u64> {
+    const VEC_LEN_OFFSET: usize = 5;
+    const VALIDATOR_STAKE_INFO_LEN: usize = 73;
+
+    let len = u32_le_at(data, VEC_LEN_OFFSET)? as usize;
+    let entries = data
+        .get(VEC_LEN_OFFSET + 4..)?
+        .chunks_exact(VALIDATOR_STAKE_INFO_LEN)
+        .take(len);
+    if entries.len() != len {
+        return None;
+    }
+    entries.try_fold(0u64, |sum, entry| sum.checked_add(u64_le_at(entry, 0)?))
+}
+
 fn epoch_from_clock_data(clock_acc_data: &[u8]) -> Option<u64> {
     u64_le_at(clock_acc_data, 16)
 }
@@ -186,6 +397,10 @@ fn u64_le_at(data: &[u8], at: usize) -> Option<u64> {
     chunk_at(data, at).map(|c| u64::from_le_bytes(*c))
 }
 
+fn u32_le_at(data: &[u8], at: usize) -> Option<u32> {
+    chunk_at(data, at).map(|c| u32::from_le_bytes(*c))
+}
+
 fn chunk_at<const N: usize>(data: &[u8], at: usize) -> Option<&[u8; N]> {
     data.get(at..).and_then(|s| s.first_chunk())
 }
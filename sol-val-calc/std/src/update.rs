@@ -1,7 +1,7 @@
 use std::error::Error;
 
 // Re-exports
-pub use inf1_update_traits::{Account, UpdateErr, UpdateMap};
+pub use inf1_update_traits::{oldest_slot, Account, LastUpdateSlot, UpdateErr, UpdateMap};
 
 pub trait AccountsToUpdateSvc {
     type PkIter: Iterator<Item = [u8; 32]>;
@@ -14,7 +14,18 @@ pub trait AccountsToUpdateSvc {
 pub trait UpdateSvc {
     type InnerErr: Error;
 
+    /// Implementations must not overwrite state with accounts whose
+    /// [`Account::slot`] is older than [`Self::last_update_slot`],
+    /// returning `Ok(())` without making any changes instead.
     fn update_svc(&mut self, update_map: impl UpdateMap) -> Result<(), UpdateErr<Self::InnerErr>>;
+
+    /// Oldest context slot of the accounts that the current state was derived from.
+    ///
+    /// Defaults to `None` (unknown) for implementations that do not track slots.
+    #[inline]
+    fn last_update_slot(&self) -> Option<u64> {
+        None
+    }
 }
 
 // TODO: might need a new trait if a different set of accounts
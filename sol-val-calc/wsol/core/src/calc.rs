@@ -1,11 +1,17 @@
 use core::{convert::Infallible, ops::RangeInclusive};
 
-use inf1_svc_core::traits::SolValCalc;
+use inf1_svc_core::traits::{SolValCalc, Staleness};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct WsolCalc;
 
 impl WsolCalc {
+    /// wSOL is always 1:1 with SOL
+    #[inline]
+    pub const fn svc_is_stale(&self) -> bool {
+        false
+    }
+
     #[inline]
     pub const fn svc_lst_to_sol(&self, lst_amount: u64) -> Result<RangeInclusive<u64>, Infallible> {
         Ok(lst_amount..=lst_amount)
@@ -33,3 +39,10 @@ impl SolValCalc for WsolCalc {
         self.svc_sol_to_lst(lamports_amount)
     }
 }
+
+impl Staleness for WsolCalc {
+    #[inline]
+    fn is_stale(&self) -> bool {
+        self.svc_is_stale()
+    }
+}
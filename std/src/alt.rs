@@ -0,0 +1,193 @@
+//! Address lookup table planning.
+//!
+//! Swaps between LSTs using more complex SOL value calculators and pricing programs
+//! come close to the transaction account limit, so integrators should load the
+//! pool's static accounts from address lookup tables.
+
+use std::{collections::HashSet, iter::empty};
+
+use inf1_core::{
+    inf1_ctl_core::{
+        self,
+        keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    },
+    inf1_svc_core::traits::SolValCalcAccs,
+    instructions::swap::v2::{
+        exact_in::{swap_exact_in_v2_ix_is_signer, swap_exact_in_v2_ix_keys_owned},
+        exact_out::{swap_exact_out_v2_ix_is_signer, swap_exact_out_v2_ix_keys_owned},
+    },
+};
+use inf1_pp_ag_std::update::all::AccountsToUpdateAll;
+use inf1_svc_ag_std::inf1_svc_marinade_core::sanctum_marinade_liquid_staking_core::TOKEN_PROGRAM;
+
+use crate::{
+    err::InfErr,
+    trade::{instruction::TradeIxArgsStd, Trade},
+    Inf,
+};
+
+/// Max number of addresses a single address lookup table can hold
+pub const ALT_MAX_ADDRESSES: usize = 256;
+
+/// The pool's static accounts partitioned into address lookup tables,
+/// returned by [`Inf::alt_plan_mut`]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct AltPlan {
+    /// Each table holds at most [`ALT_MAX_ADDRESSES`] addresses.
+    ///
+    /// Addresses are deduplicated across all tables.
+    /// The first table starts with accounts shared by all swaps,
+    /// followed by each LST's accounts, which are never split across tables.
+    pub tables: Vec<Vec<[u8; 32]>>,
+
+    /// `(mint, err)` of LSTs whose accounts could not be determined.
+    ///
+    /// These LSTs' accounts are excluded from `tables`.
+    pub lst_errs: Vec<([u8; 32], InfErr)>,
+}
+
+/// Accounts of an instruction that can be loaded from a single lookup table
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct AltCover {
+    /// Index of the table in [`AltPlan::tables`]
+    pub table: usize,
+
+    /// Indices of the covered accounts in the instruction's account metas
+    pub ix_accs: Vec<usize>,
+}
+
+impl AltPlan {
+    /// Greedily picks the tables that cover the most remaining accounts of an instruction
+    /// given its account keys and signer flags, in account meta order.
+    ///
+    /// Signers are never covered since they cannot be loaded from lookup tables.
+    /// Accounts not in any table are not covered.
+    #[inline]
+    pub fn cover(
+        &self,
+        keys: impl IntoIterator<Item = [u8; 32]>,
+        is_signer: impl IntoIterator<Item = bool>,
+    ) -> Vec<AltCover> {
+        let mut rem: Vec<(usize, [u8; 32])> = keys
+            .into_iter()
+            .zip(is_signer)
+            .enumerate()
+            .filter_map(|(i, (pk, is_signer))| (!is_signer).then_some((i, pk)))
+            .collect();
+        let mut res = Vec::new();
+        loop {
+            let best = self
+                .tables
+                .iter()
+                .enumerate()
+                .map(|(t, table)| {
+                    let n = rem.iter().filter(|(_, pk)| table.contains(pk)).count();
+                    (t, n)
+                })
+                .filter(|(_, n)| *n > 0)
+                // ties resolved in favour of the earlier table
+                .max_by(|(ta, na), (tb, nb)| na.cmp(nb).then(tb.cmp(ta)));
+            let Some((table, _)) = best else {
+                break;
+            };
+            let (covered, uncovered): (Vec<_>, Vec<_>) = rem
+                .into_iter()
+                .partition(|(_, pk)| self.tables[table].contains(pk));
+            rem = uncovered;
+            res.push(AltCover {
+                table,
+                ix_accs: covered.into_iter().map(|(i, _)| i).collect(),
+            });
+        }
+        res
+    }
+
+    /// [`Self::cover`] for an instruction returned by [`Inf::trade_ix`]
+    /// or [`Inf::trade_ix_mut`]
+    #[inline]
+    pub fn cover_trade_ix(&self, ix: &TradeIxArgsStd) -> Vec<AltCover> {
+        match ix {
+            Trade::ExactIn(ix) => self.cover(
+                swap_exact_in_v2_ix_keys_owned(&ix.swap.accs)
+                    .seq()
+                    .chain(ix.referral_suf_keys_owned())
+                    .copied(),
+                swap_exact_in_v2_ix_is_signer(&ix.swap.accs)
+                    .seq()
+                    .chain(ix.referral_suf_is_signer())
+                    .copied(),
+            ),
+            Trade::ExactOut(ix) => self.cover(
+                swap_exact_out_v2_ix_keys_owned(&ix.swap.accs)
+                    .seq()
+                    .chain(ix.referral_suf_keys_owned())
+                    .copied(),
+                swap_exact_out_v2_ix_is_signer(&ix.swap.accs)
+                    .seq()
+                    .chain(ix.referral_suf_is_signer())
+                    .copied(),
+            ),
+        }
+    }
+}
+
+impl<
+        F: Fn(&[&[u8]], &[u8; 32]) -> Option<([u8; 32], u8)> + Clone,
+        C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]> + Clone,
+    > Inf<F, C>
+{
+    /// Partitions the pool's static swap accounts into address lookup tables:
+    /// - controller program and PDAs, token program, INF mint
+    /// - SOL value calculator programs and the pricing program
+    /// - the pricing program's accounts
+    /// - each LST's mint, pool reserves and SOL value calculator accounts
+    ///
+    /// Lazily initializes SOL value calculators of LSTs if able to.
+    /// LSTs that fail to initialize are reported in [`AltPlan::lst_errs`]
+    /// instead of failing the entire procedure.
+    #[inline]
+    pub fn alt_plan_mut(&mut self) -> Result<AltPlan, InfErr> {
+        let lsts = self.lst_states_owned()?;
+        let mut res = AltPlan::default();
+        let mut seen = HashSet::new();
+        let mut push_group =
+            |tables: &mut Vec<Vec<[u8; 32]>>, group: &mut dyn Iterator<Item = [u8; 32]>| {
+                let group: Vec<_> = group.filter(|pk| seen.insert(*pk)).collect();
+                match tables.last_mut() {
+                    Some(t) if t.len() + group.len() <= ALT_MAX_ADDRESSES => t.extend(group),
+                    _ => tables.extend(group.chunks(ALT_MAX_ADDRESSES).map(<[_]>::to_vec)),
+                }
+            };
+
+        let mut common = vec![
+            inf1_ctl_core::ID,
+            POOL_STATE_ID,
+            LST_STATE_LIST_ID,
+            TOKEN_PROGRAM,
+            *self.pool.lp_token_mint(),
+            *self.pool.pricing_program(),
+        ];
+        common.extend(lsts.iter().map(|s| s.sol_value_calculator));
+        common.extend(self.pricing.accounts_to_update_all(empty()));
+        push_group(&mut res.tables, &mut common.into_iter());
+
+        lsts.iter().for_each(|lst_state| {
+            let accs = self
+                .lst_vars_mut(&lst_state.mint)
+                .map(|(_, _, calc, reserves)| {
+                    let calc_keys = calc.suf_keys_owned();
+                    [lst_state.mint, reserves]
+                        .into_iter()
+                        .chain(AsRef::<[[u8; 32]]>::as_ref(&calc_keys).iter().copied())
+                        .chain(self.pricing.accounts_to_update_all([lst_state.mint]))
+                        .collect::<Vec<_>>()
+                });
+            match accs {
+                Ok(accs) => push_group(&mut res.tables, &mut accs.into_iter()),
+                Err(e) => res.lst_errs.push((lst_state.mint, e)),
+            }
+        });
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,117 @@
+//! Compute unit estimates for the controller's trade and rebalance instructions.
+//!
+//! Estimates are looked up from [`CU_TABLE`], which is generated from
+//! measurements in the controller program's mollusk test suite.
+
+use inf1_core::inf1_pp_core::pair::Pair;
+use inf1_pp_ag_std::PricingAgTy;
+use inf1_svc_ag_std::SvcAgTy;
+
+use crate::{err::InfErr, trade::TradeLimitTy, utils::try_find_lst_state, Inf};
+
+mod table;
+
+pub use table::*;
+
+/// Default compute unit limit of a single instruction.
+///
+/// Used as the estimate if [`CU_TABLE`] has no measurements for an instruction kind at all.
+pub const DEFAULT_IX_CU_LIMIT: u32 = 200_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuIxKind {
+    SwapExactIn,
+    SwapExactOut,
+
+    /// StartRebalance + EndRebalance, excluding instructions in between
+    Rebalance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuKey {
+    /// SOL value calculator of the input LST.
+    ///
+    /// [`SvcAgTy::Inf`] for remove liquidity
+    pub inp: SvcAgTy,
+
+    /// SOL value calculator of the output LST.
+    ///
+    /// [`SvcAgTy::Inf`] for add liquidity
+    pub out: SvcAgTy,
+
+    /// Unused by the pool for [`CuIxKind::Rebalance`],
+    /// but recorded to keep the table uniform
+    pub pricing: PricingAgTy,
+
+    pub kind: CuIxKind,
+}
+
+/// Compute units consumed by an instruction as measured for `key`.
+///
+/// Falls back to the max measured for the same [`CuIxKind`] if `key` was not measured,
+/// and [`DEFAULT_IX_CU_LIMIT`] if nothing for the same [`CuIxKind`] was measured.
+///
+/// The estimate does not include any headroom.
+#[inline]
+pub fn estimate_cus(key: &CuKey) -> u32 {
+    CU_TABLE
+        .iter()
+        .find_map(|(k, cus)| (k == key).then_some(*cus))
+        .or_else(|| {
+            CU_TABLE
+                .iter()
+                .filter_map(|(k, cus)| (k.kind == key.kind).then_some(*cus))
+                .max()
+        })
+        .unwrap_or(DEFAULT_IX_CU_LIMIT)
+}
+
+impl<F, C> Inf<F, C> {
+    /// Estimated compute units consumed by the trade instruction
+    /// returned by [`Self::trade_ix`] for `mints`.
+    ///
+    /// See [`estimate_cus`]
+    #[inline]
+    pub fn estimate_cus(
+        &self,
+        mints: &Pair<&[u8; 32]>,
+        limit_ty: TradeLimitTy,
+    ) -> Result<u32, InfErr> {
+        let kind = match limit_ty {
+            TradeLimitTy::ExactIn(_) => CuIxKind::SwapExactIn,
+            TradeLimitTy::ExactOut(_) => CuIxKind::SwapExactOut,
+        };
+        self.estimate_cus_for(mints, kind)
+    }
+
+    /// Estimated compute units consumed by the StartRebalance and EndRebalance instructions
+    /// returned by [`Self::rebalance_ixs_mut`] for `mints`.
+    ///
+    /// See [`estimate_cus`]
+    #[inline]
+    pub fn estimate_rebalance_cus(&self, mints: &Pair<&[u8; 32]>) -> Result<u32, InfErr> {
+        self.estimate_cus_for(mints, CuIxKind::Rebalance)
+    }
+
+    fn estimate_cus_for(&self, mints: &Pair<&[u8; 32]>, kind: CuIxKind) -> Result<u32, InfErr> {
+        let Pair { inp, out } = mints.try_map(|mint| self.svc_ty(mint))?;
+        Ok(estimate_cus(&CuKey {
+            inp,
+            out,
+            pricing: self.pricing.0.ty(),
+            kind,
+        }))
+    }
+
+    fn svc_ty(&self, mint: &[u8; 32]) -> Result<SvcAgTy, InfErr> {
+        if mint == self.pool.lp_token_mint() {
+            return Ok(SvcAgTy::Inf(()));
+        }
+        let (_i, lst_state) = try_find_lst_state(self.try_lst_state_list()?, mint)?;
+        SvcAgTy::try_from_svc_program_id(&lst_state.sol_value_calculator).ok_or(
+            InfErr::UnknownSvc {
+                svc_prog_id: lst_state.sol_value_calculator,
+            },
+        )
+    }
+}
@@ -0,0 +1,13 @@
+// @generated by `cu_table_fixtures` in controller/program/tests/tests/cu.rs.
+// Do not edit by hand; regenerate with
+// `UPDATE_EXPECT=1 cargo test -p inf1-ctl-program cu_table_fixtures`
+
+#[allow(unused_imports)]
+use inf1_pp_ag_std::PricingAg;
+#[allow(unused_imports)]
+use inf1_svc_ag_std::SvcAg;
+
+#[allow(unused_imports)]
+use super::{CuIxKind, CuKey};
+
+pub const CU_TABLE: &[(CuKey, u32)] = &[];
@@ -20,8 +20,11 @@ pub enum InfErr {
     MissingSplData { mint: [u8; 32] },
     MissingSvcData { mint: [u8; 32] },
     NoValidPda,
+    PoolProgUpgraded { mint: [u8; 32] },
     PricingProg(PricingProgAgErr),
     RebalanceQuote(RebalanceQuoteErr<SvcCalcAgErr, SvcCalcAgErr>),
+    SnapshotDeser,
+    StaleSvc { mint: [u8; 32] },
     SwapQuote(QuoteErr<SvcCalcAgErr, SvcCalcAgErr, PricingAgErr>),
     UnknownPp { pp_prog_id: [u8; 32] },
     UnknownSvc { svc_prog_id: [u8; 32] },
@@ -40,8 +43,11 @@ impl Display for InfErr {
             InfErr::MissingSplData { .. } => "MissingSplData",
             InfErr::MissingSvcData { .. } => "MissingSvcData",
             InfErr::NoValidPda => "NoValidPdaErr",
+            InfErr::PoolProgUpgraded { .. } => "PoolProgUpgraded",
             InfErr::PricingProg(..) => "PricingProg",
             InfErr::RebalanceQuote(..) => "RebalanceQuote",
+            InfErr::SnapshotDeser => "SnapshotDeser",
+            InfErr::StaleSvc { .. } => "StaleSvc",
             InfErr::SwapQuote(..) => "SwapQuote",
             InfErr::UnknownPp { .. } => "UnknownPpErr",
             InfErr::UnknownSvc { .. } => "UnknownSvcErr",
@@ -1,11 +1,17 @@
 use std::collections::{hash_map::Entry, HashMap};
 
 use inf1_core::inf1_ctl_core::{
-    accounts::{lst_state_list::LstStatePackedList, pool_state::VerPoolState},
+    accounts::{
+        lst_calc_backing_list::LstCalcBackingList, lst_state_list::LstStatePackedList,
+        pool_config::PoolConfig, pool_state::VerPoolState,
+    },
     err::Inf1CtlErr,
-    keys::LST_STATE_LIST_ID,
+    keys::{LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID},
     svc::InfCalc,
-    typedefs::lst_state::{LstState, LstStatePacked},
+    typedefs::{
+        lst_calc_backing::LstCalcBacking,
+        lst_state::{LstState, LstStatePacked},
+    },
     yields::release::ReleaseYieldParams,
 };
 use inf1_pp_ag_std::PricingProgAg;
@@ -13,6 +19,8 @@ use inf1_svc_ag_std::{calc::SvcCalcAg, instructions::SvcCalcAccsAg, SvcAg, SvcAg
 
 use crate::{
     err::InfErr,
+    trade::quote::StaleSvcPolicy,
+    update::LastUpdateSlot,
     utils::{try_default_pricing_prog_from_program_id, try_find_lst_state},
 };
 
@@ -21,9 +29,13 @@ pub use inf1_core::*;
 pub use inf1_pp_ag_std;
 pub use inf1_svc_ag_std;
 
+pub mod alt;
+pub mod cu;
 pub mod err;
 pub mod pda;
+pub mod protocol_fee;
 pub mod rebalance;
+pub mod snapshot;
 pub mod trade;
 pub mod update;
 
@@ -37,6 +49,16 @@ pub struct Inf<F, C> {
 
     pub lst_state_list_data: Box<[u8]>,
 
+    /// Data of the controller's `LstCalcBackingList` PDA.
+    ///
+    /// Empty if the account has not been fetched or does not exist yet.
+    pub lst_calc_backing_list_data: Box<[u8]>,
+
+    /// The controller's `PoolConfig` PDA.
+    ///
+    /// Default if the account has not been fetched or does not exist yet.
+    pub pool_config: PoolConfig,
+
     pub lp_token_supply: Option<u64>,
 
     pub pricing: PricingProgAg<F, C>,
@@ -51,10 +73,32 @@ pub struct Inf<F, C> {
 
     /// Map of `spl_lst_mint: spl_stake_pool_addr`
     ///
-    /// We store this in the struct so that we are able to
-    /// initialize any added SPL LSTs newly added to the pool
+    /// Fallback used to initialize SPL LSTs newly added to the pool
+    /// that do not have an entry in `lst_calc_backing_list_data`,
+    /// i.e. those added before the controller started recording them onchain.
     pub spl_lsts: HashMap<[u8; 32], [u8; 32]>,
 
+    /// What to do when quoting a trade that involves a stale SOL value calculator.
+    ///
+    /// Defaults to [`StaleSvcPolicy::Allow`]
+    pub stale_svc_policy: StaleSvcPolicy,
+
+    /// Whether to fetch the calculator program state and wrapped stake pool program's
+    /// ProgramData for LSTs using generic SOL value calculator programs, and refuse to
+    /// quote trades involving LSTs whose stake pool program has been upgraded since
+    /// the calculator program's `last_upgrade_slot`.
+    ///
+    /// Applied to each LST's [`SvcAgStd`] on [`Self::try_get_or_init_lst_svc`].
+    ///
+    /// Defaults to `false`
+    pub check_svc_upgrades: bool,
+
+    /// Slots that the pool's own accounts were last updated at.
+    ///
+    /// Those of LSTs' reserves, SOL value calculators and the pricing program
+    /// are tracked by each respectively.
+    pub slots: InfUpdateSlots,
+
     pub find_pda: F,
 
     pub create_pda: C,
@@ -72,6 +116,20 @@ pub struct Reserves {
     // TODO: add more Reserves related fields as required
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InfUpdateSlots {
+    pub pool: LastUpdateSlot,
+    pub lst_state_list: LastUpdateSlot,
+    pub lst_calc_backing_list: LastUpdateSlot,
+    pub pool_config: LastUpdateSlot,
+    pub lp_token_supply: LastUpdateSlot,
+
+    /// Slots that each entry of [`Inf::lst_reserves`] was last updated at.
+    ///
+    /// key=mint
+    pub lst_reserves: HashMap<[u8; 32], LastUpdateSlot>,
+}
+
 /// Constructors
 impl<
         F: Fn(&[&[u8]], &[u8; 32]) -> Option<([u8; 32], u8)> + Clone,
@@ -109,11 +167,16 @@ impl<
         Ok(Self {
             pool,
             lst_state_list_data,
+            lst_calc_backing_list_data: Box::default(),
+            pool_config: PoolConfig::default(),
             lp_token_supply,
             pricing,
             lst_reserves,
             lst_calcs,
             spl_lsts,
+            stale_svc_policy: StaleSvcPolicy::default(),
+            check_svc_upgrades: false,
+            slots: InfUpdateSlots::default(),
             find_pda,
             create_pda,
         })
@@ -131,6 +194,17 @@ impl<F, C> Inf<F, C> {
             .0)
     }
 
+    #[inline]
+    pub fn try_lst_calc_backing_list(&self) -> Result<&[LstCalcBacking], InfErr> {
+        Ok(
+            LstCalcBackingList::of_acc_data(&self.lst_calc_backing_list_data)
+                .ok_or(InfErr::AccDeser {
+                    pk: LST_CALC_BACKING_LIST_ID,
+                })?
+                .0,
+        )
+    }
+
     #[inline]
     pub fn try_get_lst_svc(&self, mint: &[u8; 32]) -> Result<&SvcAgStd, InfErr> {
         self.lst_calcs
@@ -144,7 +218,8 @@ impl<F, C> Inf<F, C> {
     /// determined to have changed
     ///
     /// Errors if:
-    /// - LST is a SPL LST and SPL data is not in `self.spl_lsts`
+    /// - LST is a SPL LST and its stake pool is neither recorded onchain
+    ///   in `self.lst_calc_backing_list_data` nor in `self.spl_lsts`
     /// - SOL value calculator is unknown
     #[inline]
     pub fn try_get_or_init_lst_svc<'a>(
@@ -153,18 +228,48 @@ impl<F, C> Inf<F, C> {
     ) -> Result<&'a mut SvcAgStd, InfErr> {
         let Self {
             spl_lsts,
+            lst_calc_backing_list_data,
             lst_calcs,
+            check_svc_upgrades,
+            stale_svc_policy,
             ..
         } = self;
-        Self::try_get_or_init_lst_svc_static(lst_calcs, spl_lsts, lst_state)
+        let lst_calc_backing_list = LstCalcBackingList::of_acc_data(lst_calc_backing_list_data)
+            .ok_or(InfErr::AccDeser {
+                pk: LST_CALC_BACKING_LIST_ID,
+            })?
+            .0;
+        let svc = Self::try_get_or_init_lst_svc_static_with_backing(
+            lst_calcs,
+            spl_lsts,
+            lst_calc_backing_list,
+            lst_state,
+        )?;
+        svc.set_upgrade_check_enabled(*check_svc_upgrades);
+        svc.set_spl_est_enabled(*stale_svc_policy == StaleSvcPolicy::Estimate);
+        Ok(svc)
     }
 
     // Associated fn format like this so that it can be used by external crates
     // (jup-interface)
+    /// Stake pool accounts of SPL LSTs are looked up in `spl_lsts` only,
+    /// see [`Self::try_get_or_init_lst_svc_static_with_backing`]
     #[inline]
     pub fn try_get_or_init_lst_svc_static<'a>(
         lst_calcs: &'a mut HashMap<[u8; 32], SvcAgStd>,
         spl_lsts: &HashMap<[u8; 32], [u8; 32]>,
+        lst_state: &LstState,
+    ) -> Result<&'a mut SvcAgStd, InfErr> {
+        Self::try_get_or_init_lst_svc_static_with_backing(lst_calcs, spl_lsts, &[], lst_state)
+    }
+
+    /// Stake pool accounts of SPL LSTs are looked up in `lst_calc_backing_list` first,
+    /// falling back to `spl_lsts`
+    #[inline]
+    pub fn try_get_or_init_lst_svc_static_with_backing<'a>(
+        lst_calcs: &'a mut HashMap<[u8; 32], SvcAgStd>,
+        spl_lsts: &HashMap<[u8; 32], [u8; 32]>,
+        lst_calc_backing_list: &[LstCalcBacking],
         LstState {
             mint,
             sol_value_calculator,
@@ -176,6 +281,15 @@ impl<F, C> Inf<F, C> {
                 svc_prog_id: *sol_value_calculator,
             })?;
 
+        let stake_pool_addr = || {
+            lst_calc_backing_list
+                .iter()
+                .find(|b| b.mint == *mint)
+                .map(|b| &b.backing)
+                .or_else(|| spl_lsts.get(mint))
+                .ok_or(InfErr::MissingSplData { mint: *mint })
+        };
+
         // Make closure to reuse code below.
         // Below structure uses entry api to work around simultaneous mutable borrow issues
         let init_data_fn = || {
@@ -183,24 +297,9 @@ impl<F, C> Inf<F, C> {
                 SvcAg::Inf(_) => SvcAg::Inf(()),
                 SvcAgTy::Lido(_) => SvcAg::Lido(()),
                 SvcAgTy::Marinade(_) => SvcAg::Marinade(()),
-                SvcAgTy::SanctumSpl(_) => {
-                    let stake_pool_addr = spl_lsts
-                        .get(mint)
-                        .ok_or(InfErr::MissingSplData { mint: *mint })?;
-                    SvcAg::SanctumSpl(*stake_pool_addr)
-                }
-                SvcAgTy::SanctumSplMulti(_) => {
-                    let stake_pool_addr = spl_lsts
-                        .get(mint)
-                        .ok_or(InfErr::MissingSplData { mint: *mint })?;
-                    SvcAg::SanctumSplMulti(*stake_pool_addr)
-                }
-                SvcAgTy::Spl(_) => {
-                    let stake_pool_addr = spl_lsts
-                        .get(mint)
-                        .ok_or(InfErr::MissingSplData { mint: *mint })?;
-                    SvcAg::Spl(*stake_pool_addr)
-                }
+                SvcAgTy::SanctumSpl(_) => SvcAg::SanctumSpl(*stake_pool_addr()?),
+                SvcAgTy::SanctumSplMulti(_) => SvcAg::SanctumSplMulti(*stake_pool_addr()?),
+                SvcAgTy::Spl(_) => SvcAg::Spl(*stake_pool_addr()?),
                 SvcAgTy::Wsol(_) => SvcAg::Wsol(()),
             })
         };
@@ -268,15 +367,15 @@ impl<F, C> Inf<F, C> {
 pub(crate) type LstVarsTup = (u32, LstState, SvcCalcAccsAg, [u8; 32]);
 
 impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
+    pub(crate) fn reserves_checked(&self, lst_state: &LstState) -> Result<&Reserves, InfErr> {
+        self.lst_reserves.get(&lst_state.mint).ok_or_else(|| {
+            self.create_pool_reserves_ata(&lst_state.mint, lst_state.pool_reserves_bump)
+                .map_or_else(|| InfErr::NoValidPda, |pk| InfErr::MissingAcc { pk })
+        })
+    }
+
     pub(crate) fn reserves_balance_checked(&self, lst_state: &LstState) -> Result<u64, InfErr> {
-        Ok(self
-            .lst_reserves
-            .get(&lst_state.mint)
-            .ok_or_else(|| {
-                self.create_pool_reserves_ata(&lst_state.mint, lst_state.pool_reserves_bump)
-                    .map_or_else(|| InfErr::NoValidPda, |pk| InfErr::MissingAcc { pk })
-            })?
-            .balance)
+        self.reserves_checked(lst_state).map(|r| r.balance)
     }
 
     pub(crate) fn lst_vars(&self, mint: &[u8; 32]) -> Result<LstVarsTup, InfErr> {
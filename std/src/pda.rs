@@ -23,6 +23,16 @@ pub fn find_protocol_fee_accumulator_ata(
     find_pda(&[s1, s2, s3], &ASSOCIATED_TOKEN_PROGRAM)
 }
 
+/// Associated token account of `owner` for a `mint` of the token program
+#[inline]
+pub fn find_ata(
+    find_pda: impl FnOnce(&[&[u8]], &[u8; 32]) -> Option<([u8; 32], u8)>,
+    owner: &[u8; 32],
+    mint: &[u8; 32],
+) -> Option<([u8; 32], u8)> {
+    find_pda(&[owner, &TOKEN_PROGRAM, mint], &ASSOCIATED_TOKEN_PROGRAM)
+}
+
 #[inline]
 pub fn create_pool_reserves_ata(
     create_pda: impl FnOnce(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>,
@@ -54,6 +64,11 @@ impl<F: Fn(&[&[u8]], &[u8; 32]) -> Option<([u8; 32], u8)>, C> Inf<F, C> {
     pub fn find_protocol_fee_accumulator_ata(&self, mint: &[u8; 32]) -> Option<([u8; 32], u8)> {
         find_protocol_fee_accumulator_ata(&self.find_pda, mint)
     }
+
+    #[inline]
+    pub fn find_ata(&self, owner: &[u8; 32], mint: &[u8; 32]) -> Option<([u8; 32], u8)> {
+        find_ata(&self.find_pda, owner, mint)
+    }
 }
 
 impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
@@ -0,0 +1,86 @@
+use inf1_core::inf1_ctl_core::{
+    instructions::protocol_fee::withdraw_protocol_fees::sweep::{
+        NewWithdrawProtocolFeesSweepIxAccsBuilder, WithdrawProtocolFeesSweepIxKeysOwned,
+    },
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_ID, TOKENKEG_ID},
+    typedefs::lst_state::LstState,
+};
+
+use crate::{
+    err::InfErr,
+    update::{Account, UpdateMap},
+    Inf,
+};
+
+impl<
+        F: Fn(&[&[u8]], &[u8; 32]) -> Option<([u8; 32], u8)>,
+        C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>,
+    > Inf<F, C>
+{
+    /// Legacy protocol fee accumulators of every LST in the pool,
+    /// in `LstStateList` order.
+    ///
+    /// Fetch these and pass them to [`Self::withdraw_protocol_fees_sweep_ixs`]
+    #[inline]
+    pub fn accounts_to_withdraw_protocol_fees_sweep(&self) -> Result<Vec<[u8; 32]>, InfErr> {
+        self.try_lst_state_list()?
+            .iter()
+            .map(|lst_state| self.protocol_fee_accumulator(&lst_state.into_lst_state()))
+            .collect()
+    }
+
+    /// Keys of a `WithdrawProtocolFeesSweep` instruction for every LST in the pool
+    /// whose legacy protocol fee accumulator has not yet been closed, in `LstStateList` order.
+    ///
+    /// An accumulator is considered closed if it is missing from `fetched`
+    /// or has no data, so `fetched` should contain the accounts returned by
+    /// [`Self::accounts_to_withdraw_protocol_fees_sweep`].
+    ///
+    /// Each LST's accumulator is swept to the protocol fee beneficiary's
+    /// ATA of the LST, which must already exist, with rent refunded to `refund_rent_to`.
+    #[inline]
+    pub fn withdraw_protocol_fees_sweep_ixs(
+        &self,
+        refund_rent_to: &[u8; 32],
+        fetched: impl UpdateMap,
+    ) -> Result<Vec<WithdrawProtocolFeesSweepIxKeysOwned>, InfErr> {
+        let beneficiary = self.pool.protocol_fee_beneficiary();
+        self.try_lst_state_list()?
+            .iter()
+            .filter_map(|lst_state| {
+                let lst_state = lst_state.into_lst_state();
+                let protocol_fee_accumulator = match self.protocol_fee_accumulator(&lst_state) {
+                    Ok(a) => a,
+                    Err(e) => return Some(Err(e)),
+                };
+                match fetched.get_account(&protocol_fee_accumulator) {
+                    Some(acc) if !acc.data().is_empty() => (),
+                    _ => return None,
+                }
+                let withdraw_to = match self.find_ata(beneficiary, &lst_state.mint) {
+                    Some((a, _)) => a,
+                    None => return Some(Err(InfErr::NoValidPda)),
+                };
+                Some(Ok(NewWithdrawProtocolFeesSweepIxAccsBuilder::start()
+                    .with_beneficiary(*beneficiary)
+                    .with_withdraw_to(withdraw_to)
+                    .with_protocol_fee_accumulator(protocol_fee_accumulator)
+                    .with_protocol_fee_accumulator_auth(PROTOCOL_FEE_ID)
+                    .with_token_program(TOKENKEG_ID)
+                    .with_pool_state(POOL_STATE_ID)
+                    .with_lst_mint(lst_state.mint)
+                    .with_refund_rent_to(*refund_rent_to)
+                    .build()))
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn protocol_fee_accumulator(&self, lst_state: &LstState) -> Result<[u8; 32], InfErr> {
+        self.create_protocol_fee_accumulator_ata(
+            &lst_state.mint,
+            lst_state.protocol_fee_accumulator_bump,
+        )
+        .ok_or(InfErr::NoValidPda)
+    }
+}
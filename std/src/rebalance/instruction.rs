@@ -4,7 +4,10 @@ use inf1_core::{
             end::EndRebalanceIxPreKeysOwned,
             start::{NewStartRebalanceIxPreAccsBuilder, StartRebalanceIxPreKeysOwned},
         },
-        keys::{INSTRUCTIONS_SYSVAR_ID, LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID},
+        keys::{
+            INSTRUCTIONS_SYSVAR_ID, LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID,
+            REBALANCE_RECORD_ID,
+        },
     },
     instructions::rebalance::{
         end::EndRebalanceIxAccs,
@@ -66,6 +69,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
                     .with_out_lst_token_program(TOKEN_PROGRAM)
                     .with_out_pool_reserves(out_reserves)
                     .with_pool_state(POOL_STATE_ID)
+                    .with_pool_config(POOL_CONFIG_ID)
                     .with_rebalance_auth(*self.pool.rebalance_authority())
                     .with_rebalance_record(REBALANCE_RECORD_ID)
                     .with_system_program(SYSTEM_PROGRAM)
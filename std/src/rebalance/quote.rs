@@ -1,4 +1,6 @@
-use inf1_core::quote::rebalance::{quote_rebalance_exact_out, RebalanceQuote, RebalanceQuoteArgs};
+use inf1_core::quote::rebalance::{
+    quote_rebalance_exact_out, verify_rebalance_not_disabled, RebalanceQuote, RebalanceQuoteArgs,
+};
 use inf1_pp_ag_std::update::all::Pair;
 
 use crate::{err::InfErr, Inf};
@@ -10,6 +12,10 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
         pair: &Pair<&[u8; 32]>,
         amt: u64,
     ) -> Result<RebalanceQuote, InfErr> {
+        verify_rebalance_not_disabled(self.pool.disable_modes()).map_err(InfErr::RebalanceQuote)?;
+        let max_loss = self
+            .pool
+            .rebalance_max_loss(self.pool_config.rebalance_max_loss_lamports);
         let Pair {
             inp: (inp_calc, inp_reserves),
             out: (out_calc, out_reserves),
@@ -26,6 +32,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
             out_mint: *pair.out,
             inp_calc,
             out_calc,
+            max_loss,
         })
         .map_err(InfErr::RebalanceQuote)
     }
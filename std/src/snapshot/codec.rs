@@ -0,0 +1,151 @@
+//! Primitive little-endian encoding used by snapshots.
+//!
+//! - integers are fixed-width little-endian
+//! - `bool`s and `Option` discriminants are a single `0`/`1` byte
+//! - variable-length byte slices and collections are prefixed with a `u32` len
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct SnapshotWriter(pub Vec<u8>);
+
+impl SnapshotWriter {
+    #[inline]
+    pub fn u8(&mut self, v: u8) {
+        self.0.push(v);
+    }
+
+    #[inline]
+    pub fn bool(&mut self, v: bool) {
+        self.u8(v.into());
+    }
+
+    #[inline]
+    pub fn u16(&mut self, v: u16) {
+        self.0.extend(v.to_le_bytes());
+    }
+
+    #[inline]
+    pub fn i16(&mut self, v: i16) {
+        self.0.extend(v.to_le_bytes());
+    }
+
+    #[inline]
+    pub fn u32(&mut self, v: u32) {
+        self.0.extend(v.to_le_bytes());
+    }
+
+    #[inline]
+    pub fn u64(&mut self, v: u64) {
+        self.0.extend(v.to_le_bytes());
+    }
+
+    #[inline]
+    pub fn pk(&mut self, v: &[u8; 32]) {
+        self.0.extend(v);
+    }
+
+    /// # Panics
+    /// - if `v.len() > u32::MAX`
+    #[inline]
+    pub fn seq_len(&mut self, v: usize) {
+        self.u32(v.try_into().unwrap());
+    }
+
+    #[inline]
+    pub fn bytes(&mut self, v: &[u8]) {
+        self.seq_len(v.len());
+        self.0.extend(v);
+    }
+
+    #[inline]
+    pub fn opt<T>(&mut self, v: Option<T>, f: impl FnOnce(&mut Self, T)) {
+        match v {
+            None => self.bool(false),
+            Some(v) => {
+                self.bool(true);
+                f(self, v);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SnapshotReader<'a>(pub &'a [u8]);
+
+impl<'a> SnapshotReader<'a> {
+    #[inline]
+    pub fn chunk<const N: usize>(&mut self) -> Option<&'a [u8; N]> {
+        let (c, rem) = self.0.split_first_chunk()?;
+        self.0 = rem;
+        Some(c)
+    }
+
+    #[inline]
+    pub fn u8(&mut self) -> Option<u8> {
+        self.chunk().map(|[b]: &[u8; 1]| *b)
+    }
+
+    #[inline]
+    pub fn bool(&mut self) -> Option<bool> {
+        match self.u8()? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn u16(&mut self) -> Option<u16> {
+        self.chunk().map(|c| u16::from_le_bytes(*c))
+    }
+
+    #[inline]
+    pub fn i16(&mut self) -> Option<i16> {
+        self.chunk().map(|c| i16::from_le_bytes(*c))
+    }
+
+    #[inline]
+    pub fn u32(&mut self) -> Option<u32> {
+        self.chunk().map(|c| u32::from_le_bytes(*c))
+    }
+
+    #[inline]
+    pub fn u64(&mut self) -> Option<u64> {
+        self.chunk().map(|c| u64::from_le_bytes(*c))
+    }
+
+    #[inline]
+    pub fn pk(&mut self) -> Option<[u8; 32]> {
+        self.chunk().copied()
+    }
+
+    #[inline]
+    pub fn seq_len(&mut self) -> Option<usize> {
+        self.u32().and_then(|l| l.try_into().ok())
+    }
+
+    #[inline]
+    pub fn bytes(&mut self) -> Option<&'a [u8]> {
+        let len = self.seq_len()?;
+        let (b, rem) = self.0.split_at_checked(len)?;
+        self.0 = rem;
+        Some(b)
+    }
+
+    #[inline]
+    pub fn opt<T>(&mut self, f: impl FnOnce(&mut Self) -> Option<T>) -> Option<Option<T>> {
+        match self.bool()? {
+            false => Some(None),
+            true => f(self).map(Some),
+        }
+    }
+
+    /// Reads a `u32` len followed by that many elements
+    #[inline]
+    pub fn collect<T, B: FromIterator<T>>(
+        &mut self,
+        mut f: impl FnMut(&mut Self) -> Option<T>,
+    ) -> Option<B> {
+        let len = self.seq_len()?;
+        (0..len).map(|_| f(self)).collect()
+    }
+}
@@ -0,0 +1,179 @@
+//! Compact binary snapshots of [`Inf`] for warm-starting without
+//! refetching every account.
+//!
+//! Format is versioned by a leading [`SNAPSHOT_VERSION`] byte.
+//! Snapshots of other versions are rejected with [`InfErr::SnapshotDeser`].
+//!
+//! PDA functions are not part of the snapshot and must be re-supplied on restore.
+
+use std::collections::HashMap;
+
+use inf1_core::inf1_ctl_core::accounts::{
+    lst_calc_backing_list::LstCalcBackingList, pool_config::PoolConfigPacked,
+    pool_state::VerPoolState,
+};
+
+use crate::{err::InfErr, trade::quote::StaleSvcPolicy, Inf, InfUpdateSlots, Reserves};
+
+use codec::{SnapshotReader, SnapshotWriter};
+use pp::{read_pp, write_pp};
+use svc::{read_slot, read_svc, write_slot, write_svc};
+
+mod codec;
+mod pp;
+mod svc;
+
+pub const SNAPSHOT_VERSION: u8 = 4;
+
+impl<F, C> Inf<F, C> {
+    /// Map entries are written in order of mint so that
+    /// identical states produce identical snapshots.
+    #[inline]
+    pub fn to_snapshot_bytes(&self) -> Vec<u8> {
+        let Self {
+            pool,
+            lst_state_list_data,
+            lst_calc_backing_list_data,
+            pool_config,
+            lp_token_supply,
+            pricing,
+            lst_reserves,
+            lst_calcs,
+            spl_lsts,
+            stale_svc_policy,
+            check_svc_upgrades,
+            slots:
+                InfUpdateSlots {
+                    pool: pool_slot,
+                    lst_state_list: lst_state_list_slot,
+                    lst_calc_backing_list: lst_calc_backing_list_slot,
+                    pool_config: pool_config_slot,
+                    lp_token_supply: lp_token_supply_slot,
+                    lst_reserves: lst_reserves_slots,
+                },
+            find_pda: _,
+            create_pda: _,
+        } = self;
+
+        let mut w = SnapshotWriter::default();
+        w.u8(SNAPSHOT_VERSION);
+
+        w.bytes(pool.as_acc_data_arr());
+        w.bytes(lst_state_list_data);
+        w.bytes(lst_calc_backing_list_data);
+        w.bytes(pool_config.as_acc_data_arr());
+        w.opt(*lp_token_supply, SnapshotWriter::u64);
+        [
+            pool_slot,
+            lst_state_list_slot,
+            lst_calc_backing_list_slot,
+            pool_config_slot,
+            lp_token_supply_slot,
+        ]
+        .into_iter()
+        .for_each(|s| write_slot(&mut w, *s));
+        write_pp(&mut w, pricing);
+
+        write_sorted(&mut w, lst_reserves, |w, Reserves { balance }| {
+            w.u64(*balance)
+        });
+        write_sorted(&mut w, lst_reserves_slots, |w, slot| write_slot(w, *slot));
+        write_sorted(&mut w, lst_calcs, write_svc);
+        write_sorted(&mut w, spl_lsts, |w, pool| w.pk(pool));
+
+        w.u8(match stale_svc_policy {
+            StaleSvcPolicy::Allow => 0,
+            StaleSvcPolicy::Reject => 1,
+            StaleSvcPolicy::Estimate => 2,
+        });
+        w.bool(*check_svc_upgrades);
+
+        w.0
+    }
+}
+
+impl<
+        F: Fn(&[&[u8]], &[u8; 32]) -> Option<([u8; 32], u8)> + Clone,
+        C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]> + Clone,
+    > Inf<F, C>
+{
+    /// Restore from bytes returned by [`Self::to_snapshot_bytes`]
+    #[inline]
+    pub fn from_snapshot_bytes(bytes: &[u8], find_pda: F, create_pda: C) -> Result<Self, InfErr> {
+        Self::read_snapshot(&mut SnapshotReader(bytes), find_pda, create_pda)
+            .ok_or(InfErr::SnapshotDeser)
+    }
+
+    fn read_snapshot(r: &mut SnapshotReader, find_pda: F, create_pda: C) -> Option<Self> {
+        if r.u8()? != SNAPSHOT_VERSION {
+            return None;
+        }
+
+        let pool = VerPoolState::try_from_acc_data(r.bytes()?)?;
+        let lst_state_list_data = r.bytes()?.into();
+        let lst_calc_backing_list_data: Box<[u8]> = r.bytes()?.into();
+        LstCalcBackingList::of_acc_data(&lst_calc_backing_list_data)?;
+        let pool_config = PoolConfigPacked::of_acc_data(r.bytes()?)?.into_pool_config();
+        let lp_token_supply = r.opt(SnapshotReader::u64)?;
+        let mut slots = InfUpdateSlots {
+            pool: read_slot(r)?,
+            lst_state_list: read_slot(r)?,
+            lst_calc_backing_list: read_slot(r)?,
+            pool_config: read_slot(r)?,
+            lp_token_supply: read_slot(r)?,
+            lst_reserves: HashMap::new(),
+        };
+        let pricing = read_pp(r, find_pda.clone(), create_pda.clone())?;
+
+        let lst_reserves: HashMap<_, _> =
+            r.collect(|r| Some((r.pk()?, Reserves { balance: r.u64()? })))?;
+        slots.lst_reserves = r.collect(|r| Some((r.pk()?, read_slot(r)?)))?;
+        let lst_calcs: HashMap<_, _> = r.collect(|r| Some((r.pk()?, read_svc(r)?)))?;
+        let spl_lsts: HashMap<_, _> = r.collect(|r| Some((r.pk()?, r.pk()?)))?;
+
+        let stale_svc_policy = match r.u8()? {
+            0 => StaleSvcPolicy::Allow,
+            1 => StaleSvcPolicy::Reject,
+            2 => StaleSvcPolicy::Estimate,
+            _ => return None,
+        };
+        let check_svc_upgrades = r.bool()?;
+
+        if !r.0.is_empty() {
+            return None;
+        }
+
+        let mut res = Self::new(
+            pool,
+            lst_state_list_data,
+            lp_token_supply,
+            Some(pricing),
+            lst_reserves,
+            lst_calcs,
+            spl_lsts,
+            find_pda,
+            create_pda,
+        )
+        .ok()?;
+        res.lst_calc_backing_list_data = lst_calc_backing_list_data;
+        res.pool_config = pool_config;
+        res.stale_svc_policy = stale_svc_policy;
+        res.check_svc_upgrades = check_svc_upgrades;
+        res.slots = slots;
+        Some(res)
+    }
+}
+
+fn write_sorted<V>(
+    w: &mut SnapshotWriter,
+    map: &HashMap<[u8; 32], V>,
+    mut f: impl FnMut(&mut SnapshotWriter, &V),
+) {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_unstable_by_key(|(mint, _)| *mint);
+    w.seq_len(entries.len());
+    entries.into_iter().for_each(|(mint, v)| {
+        w.pk(mint);
+        f(w, v);
+    });
+}
@@ -0,0 +1,103 @@
+use inf1_pp_ag_std::{
+    inf1_pp_flatfee_std::{accounts::fee::FeeAccount, FlatFeePricing},
+    inf1_pp_flatslab_std::FlatSlabPricing,
+    update::UpdatePricingProg,
+    PricingAg, PricingProgAg,
+};
+
+use super::{
+    codec::{SnapshotReader, SnapshotWriter},
+    svc::{read_slot, write_slot},
+};
+use crate::update::LastUpdateSlot;
+
+pub(crate) fn write_pp<F, C>(w: &mut SnapshotWriter, PricingProgAg(pp): &PricingProgAg<F, C>) {
+    match pp {
+        PricingAg::FlatFee(p) => {
+            w.u8(0);
+            write_flatfee(w, p);
+        }
+        PricingAg::FlatSlab(p) => {
+            w.u8(1);
+            w.bytes(p.slab_acc_data());
+            write_slot(w, LastUpdateSlot(p.last_update_slot()));
+        }
+    }
+}
+
+pub(crate) fn read_pp<
+    F: Fn(&[&[u8]], &[u8; 32]) -> Option<([u8; 32], u8)>,
+    C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>,
+>(
+    r: &mut SnapshotReader,
+    find_pda: F,
+    create_pda: C,
+) -> Option<PricingProgAg<F, C>> {
+    Some(PricingProgAg(match r.u8()? {
+        0 => PricingAg::FlatFee(read_flatfee(r, find_pda, create_pda)?),
+        1 => PricingAg::FlatSlab(
+            FlatSlabPricing::new(r.bytes()?.into()).with_last_update_slot(read_slot(r)?),
+        ),
+        _ => return None,
+    }))
+}
+
+/// Fee accounts are written in order of mint for determinism
+fn write_flatfee<F, C>(w: &mut SnapshotWriter, p: &FlatFeePricing<F, C>) {
+    w.opt(p.lp_withdrawal_fee_bps(), SnapshotWriter::u16);
+
+    let mut lsts: Vec<_> = p.fee_accounts().iter().collect();
+    lsts.sort_unstable_by_key(|(mint, _)| *mint);
+    w.seq_len(lsts.len());
+    lsts.into_iter().for_each(
+        |(
+            mint,
+            FeeAccount {
+                bump,
+                padding: _,
+                input_fee_bps,
+                output_fee_bps,
+            },
+        )| {
+            w.pk(mint);
+            w.u8(*bump);
+            w.i16(*input_fee_bps);
+            w.i16(*output_fee_bps);
+        },
+    );
+
+    write_slot(w, p.program_state_slot());
+    w.seq_len(p.lst_slots().len());
+    p.lst_slots().iter().for_each(|(mint, slot)| {
+        w.pk(mint);
+        write_slot(w, *slot);
+    });
+}
+
+fn read_flatfee<
+    F: Fn(&[&[u8]], &[u8; 32]) -> Option<([u8; 32], u8)>,
+    C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>,
+>(
+    r: &mut SnapshotReader,
+    find_pda: F,
+    create_pda: C,
+) -> Option<FlatFeePricing<F, C>> {
+    let lp_withdrawal_fee_bps = r.opt(SnapshotReader::u16)?;
+    let lsts = r.collect(|r| {
+        Some((
+            r.pk()?,
+            FeeAccount {
+                bump: r.u8()?,
+                padding: 0,
+                input_fee_bps: r.i16()?,
+                output_fee_bps: r.i16()?,
+            },
+        ))
+    })?;
+    let program_state_slot = read_slot(r)?;
+    let lst_slots = r.collect(|r| Some((r.pk()?, read_slot(r)?)))?;
+    Some(
+        FlatFeePricing::new(lp_withdrawal_fee_bps, lsts, find_pda, create_pda)
+            .with_update_slots(program_state_slot, lst_slots),
+    )
+}
@@ -0,0 +1,398 @@
+use inf1_core::inf1_ctl_core::{svc::InfCalc, typedefs::pool_sv::PoolSv};
+use inf1_svc_ag_std::{
+    inf1_svc_generic::upgrade::{LastUpgradeSlots, UpgradeCheck},
+    inf1_svc_inf_std::InfSvcStd,
+    inf1_svc_lido_core::{calc::LidoCalc, solido_legacy_core::ExchangeRate},
+    inf1_svc_lido_std::LidoSvcStd,
+    inf1_svc_marinade_core::calc::MarinadeCalc,
+    inf1_svc_marinade_std::MarinadeSvcStd,
+    inf1_svc_spl_core::{
+        calc::{SplCalc, SplEstCalc, SplFeeMode},
+        instructions::sol_val_calc::{SanctumSplCalcAccs, SanctumSplMultiCalcAccs, SplCalcAccs},
+        sanctum_spl_stake_pool_core::Fee,
+    },
+    inf1_svc_spl_std::{GenSplSvcStd, SplEstState},
+    inf1_svc_wsol_std::WsolSvcStd,
+    update::{LastUpdateSlot, UpdateSvc},
+    SvcAg, SvcAgStd,
+};
+
+use super::codec::{SnapshotReader, SnapshotWriter};
+
+pub(crate) fn write_svc(w: &mut SnapshotWriter, SvcAgStd(svc): &SvcAgStd) {
+    match svc {
+        SvcAg::Inf(s) => {
+            w.u8(0);
+            write_inf(w, s);
+        }
+        SvcAg::Lido(s) => {
+            w.u8(1);
+            write_lido(w, s);
+        }
+        SvcAg::Marinade(s) => {
+            w.u8(2);
+            write_marinade(w, s);
+        }
+        SvcAg::SanctumSpl(s) => {
+            w.u8(3);
+            write_spl(w, s, s.as_accs().stake_pool_addr);
+        }
+        SvcAg::SanctumSplMulti(s) => {
+            w.u8(4);
+            write_spl(w, s, s.as_accs().stake_pool_addr);
+        }
+        SvcAg::Spl(s) => {
+            w.u8(5);
+            write_spl(w, s, s.as_accs().stake_pool_addr);
+        }
+        SvcAg::Wsol(WsolSvcStd) => w.u8(6),
+    }
+}
+
+pub(crate) fn read_svc(r: &mut SnapshotReader) -> Option<SvcAgStd> {
+    Some(SvcAgStd(match r.u8()? {
+        0 => SvcAg::Inf(read_inf(r)?),
+        1 => SvcAg::Lido(read_lido(r)?),
+        2 => SvcAg::Marinade(read_marinade(r)?),
+        3 => SvcAg::SanctumSpl(read_spl(r, |stake_pool_addr| SanctumSplCalcAccs {
+            stake_pool_addr,
+        })?),
+        4 => SvcAg::SanctumSplMulti(read_spl(r, |stake_pool_addr| SanctumSplMultiCalcAccs {
+            stake_pool_addr,
+        })?),
+        5 => SvcAg::Spl(read_spl(r, |stake_pool_addr| SplCalcAccs {
+            stake_pool_addr,
+        })?),
+        6 => SvcAg::Wsol(WsolSvcStd),
+        _ => return None,
+    }))
+}
+
+// Inf
+
+fn write_inf(
+    w: &mut SnapshotWriter,
+    InfSvcStd {
+        calc:
+            InfCalc {
+                pool_lamports: PoolSv(pool_lamports),
+                mint_supply,
+            },
+        mint_addr,
+        last_update_slot,
+    }: &InfSvcStd,
+) {
+    pool_lamports.iter().for_each(|l| w.u64(*l));
+    w.u64(*mint_supply);
+    w.pk(mint_addr);
+    write_slot(w, *last_update_slot);
+}
+
+fn read_inf(r: &mut SnapshotReader) -> Option<InfSvcStd> {
+    let mut pool_lamports = [0; 3];
+    pool_lamports
+        .iter_mut()
+        .try_for_each(|l| r.u64().map(|v| *l = v))?;
+    Some(InfSvcStd {
+        calc: InfCalc {
+            pool_lamports: PoolSv(pool_lamports),
+            mint_supply: r.u64()?,
+        },
+        mint_addr: r.pk()?,
+        last_update_slot: read_slot(r)?,
+    })
+}
+
+// Lido
+
+fn write_lido(
+    w: &mut SnapshotWriter,
+    LidoSvcStd {
+        calc,
+        upgrade_check,
+        last_update_slot,
+    }: &LidoSvcStd,
+) {
+    w.opt(
+        calc.as_ref(),
+        |w,
+         LidoCalc {
+             exchange_rate:
+                 ExchangeRate {
+                     computed_in_epoch,
+                     st_sol_supply,
+                     sol_balance,
+                 },
+             current_epoch,
+         }| {
+            w.u64(*computed_in_epoch);
+            w.u64(*st_sol_supply);
+            w.u64(*sol_balance);
+            w.u64(*current_epoch);
+        },
+    );
+    write_upgrade_check(w, upgrade_check.as_ref());
+    write_slot(w, *last_update_slot);
+}
+
+fn read_lido(r: &mut SnapshotReader) -> Option<LidoSvcStd> {
+    Some(LidoSvcStd {
+        calc: r.opt(|r| {
+            Some(LidoCalc {
+                exchange_rate: ExchangeRate {
+                    computed_in_epoch: r.u64()?,
+                    st_sol_supply: r.u64()?,
+                    sol_balance: r.u64()?,
+                },
+                current_epoch: r.u64()?,
+            })
+        })?,
+        upgrade_check: read_upgrade_check(r)?,
+        last_update_slot: read_slot(r)?,
+    })
+}
+
+// Marinade
+
+fn write_marinade(w: &mut SnapshotWriter, s: &MarinadeSvcStd) {
+    w.opt(
+        s.as_calc(),
+        |w,
+         MarinadeCalc {
+             available_reserve_balance,
+             circulating_ticket_balance,
+             delayed_unstake_cooling_down,
+             emergency_cooling_down,
+             msol_supply,
+             total_active_balance,
+             withdraw_stake_account_fee_cents,
+             withdraw_stake_account_enabled,
+             paused,
+         }| {
+            [
+                available_reserve_balance,
+                circulating_ticket_balance,
+                delayed_unstake_cooling_down,
+                emergency_cooling_down,
+                msol_supply,
+                total_active_balance,
+            ]
+            .into_iter()
+            .for_each(|v| w.u64(*v));
+            w.u32(*withdraw_stake_account_fee_cents);
+            w.bool(*withdraw_stake_account_enabled);
+            w.bool(*paused);
+        },
+    );
+    write_upgrade_check(w, s.as_upgrade_check());
+    write_slot(w, LastUpdateSlot(s.last_update_slot()));
+}
+
+fn read_marinade(r: &mut SnapshotReader) -> Option<MarinadeSvcStd> {
+    let calc = r.opt(|r| {
+        Some(MarinadeCalc {
+            available_reserve_balance: r.u64()?,
+            circulating_ticket_balance: r.u64()?,
+            delayed_unstake_cooling_down: r.u64()?,
+            emergency_cooling_down: r.u64()?,
+            msol_supply: r.u64()?,
+            total_active_balance: r.u64()?,
+            withdraw_stake_account_fee_cents: r.u32()?,
+            withdraw_stake_account_enabled: r.bool()?,
+            paused: r.bool()?,
+        })
+    })?;
+    Some(MarinadeSvcStd::from_parts(
+        calc,
+        read_upgrade_check(r)?,
+        read_slot(r)?,
+    ))
+}
+
+// SPL
+
+fn write_spl<A>(w: &mut SnapshotWriter, s: &GenSplSvcStd<A>, stake_pool_addr: [u8; 32])
+where
+    GenSplSvcStd<A>: UpdateSvc,
+{
+    w.pk(&stake_pool_addr);
+    w.opt(s.as_calc(), write_spl_calc);
+    w.opt(
+        s.as_est(),
+        |w,
+         SplEstState {
+             validator_list_addr,
+             calc,
+         }| {
+            w.opt(validator_list_addr.as_ref(), |w, a| w.pk(a));
+            w.opt(
+                calc.as_ref(),
+                |w,
+                 SplEstCalc {
+                     calc,
+                     last_epoch_total_lamports,
+                     last_epoch_pool_token_supply,
+                     active_stake_lamports,
+                 }| {
+                    write_spl_calc(w, calc);
+                    w.u64(*last_epoch_total_lamports);
+                    w.u64(*last_epoch_pool_token_supply);
+                    w.u64(*active_stake_lamports);
+                },
+            );
+        },
+    );
+    w.u8(fee_mode_discm(s.fee_mode()));
+    write_upgrade_check(w, s.as_upgrade_check());
+    write_slot(w, LastUpdateSlot(s.last_update_slot()));
+}
+
+fn read_spl<A>(
+    r: &mut SnapshotReader,
+    accs: impl FnOnce([u8; 32]) -> A,
+) -> Option<GenSplSvcStd<A>> {
+    let accs = accs(r.pk()?);
+    let calc = r.opt(read_spl_calc)?;
+    let est = r.opt(|r| {
+        Some(SplEstState {
+            validator_list_addr: r.opt(|r| r.pk())?,
+            calc: r.opt(|r| {
+                Some(SplEstCalc {
+                    calc: read_spl_calc(r)?,
+                    last_epoch_total_lamports: r.u64()?,
+                    last_epoch_pool_token_supply: r.u64()?,
+                    active_stake_lamports: r.u64()?,
+                })
+            })?,
+        })
+    })?;
+    let fee_mode = fee_mode_of_discm(r.u8()?)?;
+    Some(GenSplSvcStd::from_parts(
+        accs,
+        calc,
+        est,
+        fee_mode,
+        read_upgrade_check(r)?,
+        read_slot(r)?,
+    ))
+}
+
+fn write_spl_calc(
+    w: &mut SnapshotWriter,
+    SplCalc {
+        last_update_epoch,
+        total_lamports,
+        pool_token_supply,
+        stake_withdrawal_fee,
+        sol_withdrawal_fee,
+        stake_deposit_fee,
+        sol_deposit_fee,
+        current_epoch,
+        fee_mode,
+    }: &SplCalc,
+) {
+    w.u64(*last_update_epoch);
+    w.u64(*total_lamports);
+    w.u64(*pool_token_supply);
+    [
+        stake_withdrawal_fee,
+        sol_withdrawal_fee,
+        stake_deposit_fee,
+        sol_deposit_fee,
+    ]
+    .into_iter()
+    .for_each(
+        |Fee {
+             denominator,
+             numerator,
+         }| {
+            w.u64(*denominator);
+            w.u64(*numerator);
+        },
+    );
+    w.u64(*current_epoch);
+    w.u8(fee_mode_discm(*fee_mode));
+}
+
+fn read_spl_calc(r: &mut SnapshotReader) -> Option<SplCalc> {
+    let [last_update_epoch, total_lamports, pool_token_supply] = [r.u64()?, r.u64()?, r.u64()?];
+    let mut fee = || {
+        Some(Fee {
+            denominator: r.u64()?,
+            numerator: r.u64()?,
+        })
+    };
+    let [stake_withdrawal_fee, sol_withdrawal_fee, stake_deposit_fee, sol_deposit_fee] =
+        [fee()?, fee()?, fee()?, fee()?];
+    Some(SplCalc {
+        last_update_epoch,
+        total_lamports,
+        pool_token_supply,
+        stake_withdrawal_fee,
+        sol_withdrawal_fee,
+        stake_deposit_fee,
+        sol_deposit_fee,
+        current_epoch: r.u64()?,
+        fee_mode: fee_mode_of_discm(r.u8()?)?,
+    })
+}
+
+const fn fee_mode_discm(fee_mode: SplFeeMode) -> u8 {
+    match fee_mode {
+        SplFeeMode::WithdrawStake => 0,
+        SplFeeMode::WithdrawStakeValidatorRemoval => 1,
+        SplFeeMode::WithdrawSol => 2,
+        SplFeeMode::DepositStake => 3,
+        SplFeeMode::DepositSol => 4,
+    }
+}
+
+const fn fee_mode_of_discm(discm: u8) -> Option<SplFeeMode> {
+    Some(match discm {
+        0 => SplFeeMode::WithdrawStake,
+        1 => SplFeeMode::WithdrawStakeValidatorRemoval,
+        2 => SplFeeMode::WithdrawSol,
+        3 => SplFeeMode::DepositStake,
+        4 => SplFeeMode::DepositSol,
+        _ => return None,
+    })
+}
+
+// Common
+
+fn write_upgrade_check(w: &mut SnapshotWriter, upgrade_check: Option<&UpgradeCheck>) {
+    w.opt(upgrade_check, |w, UpgradeCheck { slots }| {
+        w.opt(
+            slots.as_ref(),
+            |w,
+             LastUpgradeSlots {
+                 state,
+                 pool_progdata,
+             }| {
+                w.u64(*state);
+                w.u64(*pool_progdata);
+            },
+        );
+    });
+}
+
+fn read_upgrade_check(r: &mut SnapshotReader) -> Option<Option<UpgradeCheck>> {
+    r.opt(|r| {
+        Some(UpgradeCheck {
+            slots: r.opt(|r| {
+                Some(LastUpgradeSlots {
+                    state: r.u64()?,
+                    pool_progdata: r.u64()?,
+                })
+            })?,
+        })
+    })
+}
+
+pub(crate) fn write_slot(w: &mut SnapshotWriter, LastUpdateSlot(slot): LastUpdateSlot) {
+    w.opt(slot, SnapshotWriter::u64);
+}
+
+pub(crate) fn read_slot(r: &mut SnapshotReader) -> Option<LastUpdateSlot> {
+    r.opt(SnapshotReader::u64).map(LastUpdateSlot)
+}
@@ -1,9 +1,19 @@
 use inf1_core::{
     inf1_ctl_core::{
-        instructions::swap::v2::{
-            IxPreAccs as SwapV2IxPreAccs, NewIxPreAccsBuilder as NewSwapV2IxPreAccsBuilder,
+        instructions::swap::{
+            v2::{
+                referral::{
+                    IxDataWithReferral, NewReferralSufAccsBuilder, ReferralSufKeysOwned,
+                    REFERRAL_SUF_IS_SIGNER, REFERRAL_SUF_IS_WRITER,
+                },
+                IxPreAccs as SwapV2IxPreAccs, NewIxPreAccsBuilder as NewSwapV2IxPreAccsBuilder,
+            },
+            IxData,
+        },
+        keys::{
+            ATOKEN_ID, LST_STATE_LIST_ID, POOL_STATE_ID, REFERRAL_CONFIG_ID, SYS_PROG_ID,
+            TOKENKEG_ID,
         },
-        keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
         svc::InfDummyCalcAccs,
     },
     inf1_pp_core::{
@@ -16,10 +26,13 @@ use inf1_pp_ag_std::{
     inf1_pp_flatfee_std::instructions::pricing::price::FlatFeePriceAccs,
     inf1_pp_flatslab_std::instructions::pricing::FlatSlabPpAccs, PricingAg,
 };
-use inf1_svc_ag_std::{
-    inf1_svc_marinade_core::sanctum_marinade_liquid_staking_core::TOKEN_PROGRAM,
-    instructions::SvcCalcAccsAg,
+use inf1_svc_ag_std::instructions::SvcCalcAccsAg;
+use sanctum_ata_core::instructions::create::{CreateIdempotentIxData, NewCreateIxAccsBuilder};
+use sanctum_spl_token_core::instructions::{
+    close_account::{CloseAccountIxData, NewCloseAccountIxAccsBuilder},
+    sync_native::SyncNativeIxData,
 };
+use sanctum_system_core::instructions::transfer::{NewTransferIxAccsBuilder, TransferIxData};
 
 use crate::{
     err::InfErr,
@@ -35,7 +48,45 @@ pub type SwapIxArgsStd = SwapIxArgs<
     PricingAg<FlatFeePriceAccs, FlatSlabPpAccs>,
 >;
 
-pub type TradeIxArgsStd = Trade<SwapIxArgsStd, SwapIxArgsStd>;
+/// A V2 swap instruction with its optional referral accounts suffix
+#[derive(Debug, Clone, Copy)]
+pub struct SwapV2IxArgsStd {
+    pub swap: SwapIxArgsStd,
+    pub referral: Option<ReferralSufKeysOwned>,
+}
+
+impl SwapV2IxArgsStd {
+    /// Chain this after the swap instruction's keys
+    #[inline]
+    pub fn referral_suf_keys_owned(&self) -> impl Iterator<Item = &[u8; 32]> {
+        self.referral.iter().flat_map(|r| r.0.iter())
+    }
+
+    /// Chain this after the swap instruction's signer flags
+    #[inline]
+    pub fn referral_suf_is_signer(&self) -> impl Iterator<Item = &bool> {
+        self.referral.iter().flat_map(|_| &REFERRAL_SUF_IS_SIGNER.0)
+    }
+
+    /// Chain this after the swap instruction's writable flags
+    #[inline]
+    pub fn referral_suf_is_writer(&self) -> impl Iterator<Item = &bool> {
+        self.referral.iter().flat_map(|_| &REFERRAL_SUF_IS_WRITER.0)
+    }
+
+    /// Serialized instruction data, with the trailing referral flag if
+    /// [`Self::referral`] is set
+    #[inline]
+    pub fn to_ix_data<const DISCM: u8>(&self) -> Vec<u8> {
+        let args = self.swap.to_full();
+        match self.referral {
+            None => IxData::<DISCM>::new(&args).as_buf().to_vec(),
+            Some(_) => IxDataWithReferral::<DISCM>::new(&args).as_buf().to_vec(),
+        }
+    }
+}
+
+pub type TradeIxArgsStd = Trade<SwapV2IxArgsStd, SwapV2IxArgsStd>;
 
 #[derive(Debug, Clone, Copy)]
 pub struct TradeIxArgs<'a> {
@@ -48,6 +99,119 @@ pub struct TradeIxArgs<'a> {
 
     pub signer: &'a [u8; 32],
     pub token_accs: &'a Pair<&'a [u8; 32]>,
+
+    /// The referrer's token account of the input mint.
+    /// If set, the referral accounts suffix is appended to the instruction.
+    pub referrer: Option<&'a [u8; 32]>,
+}
+
+/// Instructions to wrap or unwrap native SOL around a trade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WsolIx {
+    /// Idempotently create `owner`'s wSOL associated token account `ata`,
+    /// with rent paid by `owner`
+    CreateAta {
+        owner: [u8; 32],
+        ata: [u8; 32],
+        mint: [u8; 32],
+    },
+
+    /// Transfer `lamports` from `owner` to `ata`
+    Transfer {
+        owner: [u8; 32],
+        ata: [u8; 32],
+        lamports: u64,
+    },
+
+    /// Sync `ata`'s token balance with its lamports balance
+    SyncNative { ata: [u8; 32] },
+
+    /// Close `ata`, returning all its lamports to `owner`
+    CloseAta { owner: [u8; 32], ata: [u8; 32] },
+}
+
+impl WsolIx {
+    #[inline]
+    pub const fn program_id(&self) -> &[u8; 32] {
+        match self {
+            Self::CreateAta { .. } => &ATOKEN_ID,
+            Self::Transfer { .. } => &SYS_PROG_ID,
+            Self::SyncNative { .. } | Self::CloseAta { .. } => &TOKENKEG_ID,
+        }
+    }
+
+    #[inline]
+    pub fn keys_owned(&self) -> Vec<[u8; 32]> {
+        match *self {
+            Self::CreateAta { owner, ata, mint } => NewCreateIxAccsBuilder::start()
+                .with_funding(owner)
+                .with_ata(ata)
+                .with_wallet(owner)
+                .with_mint(mint)
+                .with_sys_prog(SYS_PROG_ID)
+                .with_token_prog(TOKENKEG_ID)
+                .build()
+                .0
+                .to_vec(),
+            Self::Transfer { owner, ata, .. } => NewTransferIxAccsBuilder::start()
+                .with_from(owner)
+                .with_to(ata)
+                .build()
+                .0
+                .to_vec(),
+            Self::SyncNative { ata } => vec![ata],
+            Self::CloseAta { owner, ata } => NewCloseAccountIxAccsBuilder::start()
+                .with_close(ata)
+                .with_dst(owner)
+                .with_auth(owner)
+                .build()
+                .0
+                .to_vec(),
+        }
+    }
+
+    #[inline]
+    pub const fn is_signer(&self) -> &'static [bool] {
+        match self {
+            Self::CreateAta { .. } => &[true, false, false, false, false, false],
+            Self::Transfer { .. } => &[true, false],
+            Self::SyncNative { .. } => &[false],
+            Self::CloseAta { .. } => &[false, false, true],
+        }
+    }
+
+    #[inline]
+    pub const fn is_writer(&self) -> &'static [bool] {
+        match self {
+            Self::CreateAta { .. } => &[true, true, false, false, false, false],
+            Self::Transfer { .. } => &[true, true],
+            Self::SyncNative { .. } => &[true],
+            Self::CloseAta { .. } => &[true, true, false],
+        }
+    }
+
+    #[inline]
+    pub fn data(&self) -> Vec<u8> {
+        match self {
+            Self::CreateAta { .. } => CreateIdempotentIxData::as_buf().to_vec(),
+            Self::Transfer { lamports, .. } => TransferIxData::new(*lamports).as_buf().to_vec(),
+            Self::SyncNative { .. } => SyncNativeIxData::as_buf().to_vec(),
+            Self::CloseAta { .. } => CloseAccountIxData::as_buf().to_vec(),
+        }
+    }
+}
+
+/// A trade instruction together with the instructions required
+/// to wrap native SOL before it and unwrap native SOL after it
+#[derive(Debug, Clone)]
+pub struct TradeIxBundleStd {
+    /// To be executed in order before `trade`
+    pub pre: Vec<WsolIx>,
+
+    pub trade: TradeIxArgsStd,
+
+    /// To be executed in order after `trade`
+    pub post: Vec<WsolIx>,
 }
 
 enum TokenVars {
@@ -58,6 +222,11 @@ enum TokenVars {
     ),
 }
 
+#[inline]
+const fn is_wsol_lst((_, _, calc, _): &LstVarsTup) -> bool {
+    matches!(calc, SvcCalcAccsAg::Wsol(_))
+}
+
 impl TokenVars {
     #[inline]
     pub const fn lst_index(&self) -> u32 {
@@ -120,6 +289,132 @@ impl<
         }
     }
 
+    // native SOL bundle
+
+    /// [`Self::trade_ix_mut`], but with wrapping and unwrapping of native SOL
+    /// if either of `args.mints` is the wSOL mint.
+    ///
+    /// The wSOL entry of `args.token_accs` is ignored and replaced with the signer's
+    /// wSOL associated token account, which is created before and closed after the trade.
+    /// Any wSOL already in that account is unwrapped together with the trade's.
+    ///
+    /// For [`TradeLimitTy::ExactOut`] with wSOL input, only the currently quoted
+    /// input amount is wrapped, not `args.limit`, so the trade fails if it
+    /// requires more input at execution time than quoted.
+    #[inline]
+    pub fn trade_ix_bundle_mut(
+        &mut self,
+        args: &TradeIxArgs,
+        limit_ty: TradeLimitTy,
+    ) -> Result<TradeIxBundleStd, InfErr> {
+        let is_wsol = args.mints.try_map(|mint| {
+            if mint == self.pool.lp_token_mint() {
+                Ok(false)
+            } else {
+                self.lst_vars_mut(mint).map(|vars| is_wsol_lst(&vars))
+            }
+        })?;
+        let wrap_lamports = match (is_wsol.inp, limit_ty) {
+            (false, _) => 0,
+            (true, TradeLimitTy::ExactIn(_)) => args.amt,
+            (true, TradeLimitTy::ExactOut(_)) => {
+                self.quote_exact_out_mut(args.mints, args.amt, 0)?.inp
+            }
+        };
+        let (token_accs, pre, post) = self.native_sol_wsol_ixs(args, wrap_lamports, &is_wsol)?;
+        let trade = self.trade_ix_mut(
+            &TradeIxArgs {
+                token_accs: &Pair {
+                    inp: &token_accs.inp,
+                    out: &token_accs.out,
+                },
+                ..*args
+            },
+            limit_ty,
+        )?;
+        Ok(TradeIxBundleStd { pre, trade, post })
+    }
+
+    /// [`Self::trade_ix`], but with wrapping and unwrapping of native SOL.
+    /// See [`Self::trade_ix_bundle_mut`]
+    #[inline]
+    pub fn trade_ix_bundle(
+        &self,
+        args: &TradeIxArgs,
+        limit_ty: TradeLimitTy,
+    ) -> Result<TradeIxBundleStd, InfErr> {
+        let is_wsol = args.mints.try_map(|mint| {
+            if mint == self.pool.lp_token_mint() {
+                Ok(false)
+            } else {
+                self.lst_vars(mint).map(|vars| is_wsol_lst(&vars))
+            }
+        })?;
+        let wrap_lamports = match (is_wsol.inp, limit_ty) {
+            (false, _) => 0,
+            (true, TradeLimitTy::ExactIn(_)) => args.amt,
+            (true, TradeLimitTy::ExactOut(_)) => self.quote_exact_out(args.mints, args.amt, 0)?.inp,
+        };
+        let (token_accs, pre, post) = self.native_sol_wsol_ixs(args, wrap_lamports, &is_wsol)?;
+        let trade = self.trade_ix(
+            &TradeIxArgs {
+                token_accs: &Pair {
+                    inp: &token_accs.inp,
+                    out: &token_accs.out,
+                },
+                ..*args
+            },
+            limit_ty,
+        )?;
+        Ok(TradeIxBundleStd { pre, trade, post })
+    }
+
+    /// `wrap_lamports` is ignored if input is not wSOL
+    ///
+    /// # Returns
+    /// `(token accounts to trade with, pre-trade instructions, post-trade instructions)`
+    #[inline]
+    fn native_sol_wsol_ixs(
+        &self,
+        TradeIxArgs {
+            mints,
+            signer,
+            token_accs,
+            ..
+        }: &TradeIxArgs,
+        wrap_lamports: u64,
+        is_wsol: &Pair<bool>,
+    ) -> Result<(Pair<[u8; 32]>, Vec<WsolIx>, Vec<WsolIx>), InfErr> {
+        let mut res_token_accs = token_accs.map(|a| *a);
+        let mut pre = Vec::new();
+        let mut post = Vec::new();
+
+        let wsol = match (is_wsol.inp, is_wsol.out) {
+            (true, _) => Some((mints.inp, &mut res_token_accs.inp)),
+            (_, true) => Some((mints.out, &mut res_token_accs.out)),
+            _ => None,
+        };
+        if let Some((mint, token_acc)) = wsol {
+            let (ata, _bump) = self.find_ata(signer, mint).ok_or(InfErr::NoValidPda)?;
+            *token_acc = ata;
+            let [owner, mint] = [**signer, *mint];
+            pre.push(WsolIx::CreateAta { owner, ata, mint });
+            if is_wsol.inp {
+                pre.extend([
+                    WsolIx::Transfer {
+                        owner,
+                        ata,
+                        lamports: wrap_lamports,
+                    },
+                    WsolIx::SyncNative { ata },
+                ]);
+            }
+            post.push(WsolIx::CloseAta { owner, ata });
+        }
+
+        Ok((res_token_accs, pre, post))
+    }
+
     // swap common
 
     #[inline]
@@ -153,13 +448,26 @@ impl<
             .with_inp_mint(**inp_mint)
             .with_out_mint(**out_mint)
             // TODO: token-22 support
-            .with_inp_token_program(TOKEN_PROGRAM)
-            .with_out_token_program(TOKEN_PROGRAM)
+            .with_inp_token_program(TOKENKEG_ID)
+            .with_out_token_program(TOKENKEG_ID)
             .with_lst_state_list(LST_STATE_LIST_ID)
             .with_pool_state(POOL_STATE_ID)
             .build()
     }
 
+    #[inline]
+    fn swap_v2_ix_args(swap: SwapIxArgsStd, args: &TradeIxArgs) -> SwapV2IxArgsStd {
+        SwapV2IxArgsStd {
+            swap,
+            referral: args.referrer.map(|referrer_acc| {
+                NewReferralSufAccsBuilder::start()
+                    .with_referral_config(REFERRAL_CONFIG_ID)
+                    .with_referrer_acc(*referrer_acc)
+                    .build()
+            }),
+        }
+    }
+
     // SwapExactIn
 
     #[inline]
@@ -167,7 +475,7 @@ impl<
         &self,
         args: &TradeIxArgs,
         vars: &Pair<TokenVars>,
-    ) -> Result<SwapIxArgsStd, InfErr> {
+    ) -> Result<SwapV2IxArgsStd, InfErr> {
         let Pair {
             inp: inp_vars,
             out: out_vars,
@@ -185,17 +493,20 @@ impl<
             out_calc_prog: *out_vars.svc_prog_id(),
             out_calc: out_vars.svc_calc_accs_ag(),
         };
-        Ok(SwapIxArgs {
-            amount: args.amt,
-            limit: args.limit,
-            accs,
-            inp_lst_index: inp_vars.lst_index(),
-            out_lst_index: out_vars.lst_index(),
-        })
+        Ok(Self::swap_v2_ix_args(
+            SwapIxArgs {
+                amount: args.amt,
+                limit: args.limit,
+                accs,
+                inp_lst_index: inp_vars.lst_index(),
+                out_lst_index: out_vars.lst_index(),
+            },
+            args,
+        ))
     }
 
     #[inline]
-    pub fn swap_exact_in_ix(&self, args: &TradeIxArgs) -> Result<SwapIxArgsStd, InfErr> {
+    pub fn swap_exact_in_ix(&self, args: &TradeIxArgs) -> Result<SwapV2IxArgsStd, InfErr> {
         let vars = args.mints.try_map(|mint| {
             if mint == self.pool.lp_token_mint() {
                 Ok(TokenVars::Lp(*self.pool.lp_token_mint()))
@@ -207,7 +518,7 @@ impl<
     }
 
     #[inline]
-    pub fn swap_exact_in_ix_mut(&mut self, args: &TradeIxArgs) -> Result<SwapIxArgsStd, InfErr> {
+    pub fn swap_exact_in_ix_mut(&mut self, args: &TradeIxArgs) -> Result<SwapV2IxArgsStd, InfErr> {
         let vars = args.mints.try_map(|mint| {
             if mint == self.pool.lp_token_mint() {
                 Ok(TokenVars::Lp(*self.pool.lp_token_mint()))
@@ -225,7 +536,7 @@ impl<
         &self,
         args: &TradeIxArgs,
         vars: &Pair<TokenVars>,
-    ) -> Result<SwapIxArgsStd, InfErr> {
+    ) -> Result<SwapV2IxArgsStd, InfErr> {
         let Pair {
             inp: inp_vars,
             out: out_vars,
@@ -243,17 +554,20 @@ impl<
             out_calc_prog: *out_vars.svc_prog_id(),
             out_calc: out_vars.svc_calc_accs_ag(),
         };
-        Ok(SwapIxArgs {
-            amount: args.amt,
-            limit: args.limit,
-            accs,
-            inp_lst_index: inp_vars.lst_index(),
-            out_lst_index: out_vars.lst_index(),
-        })
+        Ok(Self::swap_v2_ix_args(
+            SwapIxArgs {
+                amount: args.amt,
+                limit: args.limit,
+                accs,
+                inp_lst_index: inp_vars.lst_index(),
+                out_lst_index: out_vars.lst_index(),
+            },
+            args,
+        ))
     }
 
     #[inline]
-    pub fn swap_exact_out_ix(&self, args: &TradeIxArgs) -> Result<SwapIxArgsStd, InfErr> {
+    pub fn swap_exact_out_ix(&self, args: &TradeIxArgs) -> Result<SwapV2IxArgsStd, InfErr> {
         let vars = args.mints.try_map(|mint| {
             if mint == self.pool.lp_token_mint() {
                 Ok(TokenVars::Lp(*self.pool.lp_token_mint()))
@@ -265,7 +579,7 @@ impl<
     }
 
     #[inline]
-    pub fn swap_exact_out_ix_mut(&mut self, args: &TradeIxArgs) -> Result<SwapIxArgsStd, InfErr> {
+    pub fn swap_exact_out_ix_mut(&mut self, args: &TradeIxArgs) -> Result<SwapV2IxArgsStd, InfErr> {
         let vars = args.mints.try_map(|mint| {
             if mint == self.pool.lp_token_mint() {
                 Ok(TokenVars::Lp(*self.pool.lp_token_mint()))
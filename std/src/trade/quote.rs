@@ -1,17 +1,53 @@
 use inf1_core::{
-    inf1_ctl_core::typedefs::u8bool::U8Bool,
+    inf1_ctl_core::{
+        err::{Inf1CtlErr, InvalidPoolStateDataErrV2},
+        typedefs::{fee_nanos::FeeNanos, u8bool::U8Bool},
+    },
     inf1_pp_core::{
         pair::{Pair, PairMbr},
         traits::collection::{PriceExactInCol, PriceExactOutCol},
     },
     quote::{
-        swap::{err::QuoteErr, exact_in::quote_exact_in, exact_out::quote_exact_out, QuoteArgs},
+        swap::{
+            err::QuoteErr, exact_in::quote_exact_in, exact_out::quote_exact_out,
+            verify_swap_not_disabled, QuoteArgs,
+        },
         Quote,
     },
 };
-use inf1_svc_ag_std::{calc::SvcCalcAg, SvcAg};
+use inf1_pp_ag_std::update::UpdatePricingProg;
+use inf1_svc_ag_std::{
+    calc::{SvcCalcAg, SvcEstCalcAg},
+    inf1_svc_core::traits::Staleness,
+    update::UpdateSvc,
+    SvcAg,
+};
+
+use crate::{
+    err::InfErr, trade::TradeLimitTy, update::oldest_slot, utils::try_find_lst_state, Inf,
+};
 
-use crate::{err::InfErr, trade::TradeLimitTy, Inf};
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StaleSvcPolicy {
+    /// Quote with stale SOL value calculator data.
+    ///
+    /// Use [`Inf::trade_staleness`] to flag such quotes.
+    #[default]
+    Allow,
+
+    /// Fail quotes that involve a stale SOL value calculator
+    /// with [`InfErr::StaleSvc`]
+    Reject,
+
+    /// Quote SPL LSTs with stale SOL value calculator data using
+    /// [`inf1_svc_ag_std::inf1_svc_spl_std::calc::SplEstCalc`]s, whose returned ranges span
+    /// the unprojected to projected post-epoch-boundary values, so such quotes are indicative only.
+    ///
+    /// Opts each LST's [`inf1_svc_ag_std::SvcAgStd`] in to estimates on [`Inf::try_get_or_init_lst_svc`].
+    /// Falls back to [`Self::Allow`] until the accounts required for the estimator
+    /// have been fetched, and for other calculators.
+    Estimate,
+}
 
 impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
     #[inline]
@@ -42,12 +78,100 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
         }
     }
 
+    /// Returns whether the SOL value calculators of
+    /// `pair.inp` and `pair.out` respectively are stale.
+    ///
+    /// INF is never stale.
+    #[inline]
+    pub fn trade_staleness(&self, pair: &Pair<&[u8; 32]>) -> Result<Pair<bool>, InfErr> {
+        pair.try_map(|mint| {
+            if mint == self.pool.lp_token_mint() {
+                Ok(false)
+            } else {
+                self.lst_state_and_calc(mint).map(|(_, calc)| calc.is_stale())
+            }
+        })
+    }
+
+    /// Oldest context slot out of all the state that quoting a trade of
+    /// `pair` depends on, as tracked by [`crate::update::LastUpdateSlot`]s.
+    ///
+    /// `None` if all such state was updated with accounts that did not carry a context slot.
+    #[inline]
+    pub fn trade_oldest_slot(&self, pair: &Pair<&[u8; 32]>) -> Result<Option<u64>, InfErr> {
+        let Pair { inp, out } = pair.try_map(|mint| {
+            if mint == self.pool.lp_token_mint() {
+                Ok([self.slots.lp_token_supply.0, None])
+            } else {
+                let (_i, lst_state) = try_find_lst_state(self.try_lst_state_list()?, mint)?;
+                self.reserves_checked(&lst_state)?;
+                Ok([
+                    self.slots.lst_reserves.get(mint).and_then(|s| s.0),
+                    self.try_get_lst_svc(mint)?.last_update_slot(),
+                ])
+            }
+        })?;
+        Ok(oldest_slot(
+            [
+                self.slots.pool.0,
+                self.slots.lst_state_list.0,
+                self.pricing.last_update_slot(),
+            ]
+            .into_iter()
+            .chain(inp)
+            .chain(out),
+        ))
+    }
+
+    /// Applies [`Inf::stale_svc_policy`] to `calc`
+    #[inline]
+    fn quote_calc(&self, mint: &[u8; 32], calc: SvcCalcAg) -> Result<SvcEstCalcAg, InfErr> {
+        match self.stale_svc_policy {
+            StaleSvcPolicy::Reject if calc.is_stale() => Err(InfErr::StaleSvc { mint: *mint }),
+            StaleSvcPolicy::Estimate if calc.is_stale() => Ok(self
+                .try_get_lst_svc(mint)?
+                .as_sol_val_calc_est()
+                .map_or_else(|| calc.into_est(), |est| est.to_owned_copy())),
+            _ => Ok(calc.into_est()),
+        }
+    }
+
+    /// No-op unless opted-in via [`Inf::check_svc_upgrades`]
+    /// and required accounts have been fetched
+    #[inline]
+    fn check_pool_prog_upgrade(&self, mint: &[u8; 32]) -> Result<(), InfErr> {
+        match self.try_get_lst_svc(mint)?.as_last_upgrade_slots() {
+            Some(s) if s.is_pool_prog_upgraded() => Err(InfErr::PoolProgUpgraded { mint: *mint }),
+            _ => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn check_swap_not_disabled(&self, pair: &Pair<&[u8; 32]>) -> Result<(), InfErr> {
+        verify_swap_not_disabled(
+            self.pool.disable_modes(),
+            self.pool.lp_token_mint(),
+            pair.inp,
+            pair.out,
+        )
+        .map_err(InfErr::SwapQuote)
+    }
+
+    #[inline]
+    fn min_fee_nanos(&self) -> Result<FeeNanos, InfErr> {
+        self.pool.min_fee_nanos_checked().map_err(|e| {
+            InfErr::Ctl(Inf1CtlErr::InvalidPoolStateDataV2(
+                InvalidPoolStateDataErrV2::MinFeeNanos(e),
+            ))
+        })
+    }
+
     #[inline]
     fn reserves_and_calc(
         &self,
         mint: PairMbr<&[u8; 32]>,
         slot_lookahead: u64,
-    ) -> Result<(u64, SvcCalcAg), InfErr> {
+    ) -> Result<(u64, SvcEstCalcAg), InfErr> {
         let m = mint.as_ref_t();
         Ok(if *m == self.pool.lp_token_mint() {
             let calc = self.inf_calc(slot_lookahead)?;
@@ -57,6 +181,8 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
             if matches!(mint, PairMbr::Inp(_)) && U8Bool(&lst_state.is_input_disabled).to_bool() {
                 return Err(InfErr::SwapQuote(QuoteErr::InpDisabled));
             }
+            let calc = self.quote_calc(m, calc)?;
+            self.check_pool_prog_upgrade(m)?;
             let reserves = self.reserves_balance_checked(&lst_state)?;
             (reserves, calc)
         })
@@ -67,7 +193,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
         &mut self,
         mint: PairMbr<&[u8; 32]>,
         slot_lookahead: u64,
-    ) -> Result<(u64, SvcCalcAg), InfErr> {
+    ) -> Result<(u64, SvcEstCalcAg), InfErr> {
         let m = mint.as_ref_t();
         Ok(if *m == self.pool.lp_token_mint() {
             let calc = self.inf_calc(slot_lookahead)?;
@@ -77,6 +203,8 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
             if matches!(mint, PairMbr::Inp(_)) && U8Bool(&lst_state.is_input_disabled).to_bool() {
                 return Err(InfErr::SwapQuote(QuoteErr::InpDisabled));
             }
+            let calc = self.quote_calc(m, calc)?;
+            self.check_pool_prog_upgrade(m)?;
             let reserves = self.reserves_balance_checked(&lst_state)?;
             (reserves, calc)
         })
@@ -89,6 +217,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
         amt: u64,
         slot_lookahead: u64,
     ) -> Result<Quote, InfErr> {
+        self.check_swap_not_disabled(pair)?;
         let Pair {
             inp: (_, inp_calc),
             out: (out_reserves, out_calc),
@@ -105,6 +234,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
             out_calc,
             pricing,
             out_reserves,
+            min_fee_nanos: self.min_fee_nanos()?,
         })
         .map_err(InfErr::SwapQuote)
     }
@@ -116,6 +246,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
         amt: u64,
         slot_lookahead: u64,
     ) -> Result<Quote, InfErr> {
+        self.check_swap_not_disabled(pair)?;
         let Pair {
             inp: (_, inp_calc),
             out: (out_reserves, out_calc),
@@ -132,6 +263,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
             out_calc,
             pricing,
             out_reserves,
+            min_fee_nanos: self.min_fee_nanos()?,
         })
         .map_err(InfErr::SwapQuote)
     }
@@ -143,6 +275,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
         amt: u64,
         slot_lookahead: u64,
     ) -> Result<Quote, InfErr> {
+        self.check_swap_not_disabled(pair)?;
         let Pair {
             inp: (_, inp_calc),
             out: (out_reserves, out_calc),
@@ -159,6 +292,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
             out_calc,
             pricing,
             out_reserves,
+            min_fee_nanos: self.min_fee_nanos()?,
         })
         .map_err(InfErr::SwapQuote)
     }
@@ -170,6 +304,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
         amt: u64,
         slot_lookahead: u64,
     ) -> Result<Quote, InfErr> {
+        self.check_swap_not_disabled(pair)?;
         let Pair {
             inp: (_, inp_calc),
             out: (out_reserves, out_calc),
@@ -186,6 +321,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
             out_calc,
             pricing,
             out_reserves,
+            min_fee_nanos: self.min_fee_nanos()?,
         })
         .map_err(InfErr::SwapQuote)
     }
@@ -5,7 +5,7 @@ use std::{
 
 use inf1_core::{
     inf1_ctl_core::{
-        keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
+        keys::{LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID, POOL_STATE_ID},
         svc::InfCalc,
         typedefs::lst_state::LstState,
     },
@@ -25,7 +25,7 @@ use inf1_svc_ag_std::{
 use crate::{
     err::InfErr,
     trade::{Trade, TradeLimitTy},
-    update::UpdateLstPkIter,
+    update::{UpdateChanges, UpdateLstPkIter},
     utils::try_find_lst_state,
     Inf,
 };
@@ -121,7 +121,7 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
 }
 
 pub type UpdateSwapCommonPkIter =
-    Chain<Chain<array::IntoIter<[u8; 32], 2>, UpdateLstPkIter>, UpdateLstPkIter>;
+    Chain<Chain<array::IntoIter<[u8; 32], 3>, UpdateLstPkIter>, UpdateLstPkIter>;
 
 pub type UpdateSwapExactInPkIter =
     Chain<UpdateSwapCommonPkIter, inf1_pp_ag_std::update::price_exact_in::PkIter>;
@@ -135,12 +135,13 @@ impl<
     > Inf<F, C>
 {
     #[inline]
-    fn inf_svc_pks(&self) -> UpdateLstPkIter {
+    pub(crate) fn inf_svc_pks(&self) -> UpdateLstPkIter {
         // dont care abt calc quoting for getting pks
         let calc = self.inf_calc(0).unwrap_or(InfCalc::DEFAULT);
         SvcAgStd(SvcAg::Inf(InfSvcStd {
             calc,
             mint_addr: *self.pool.lp_token_mint(),
+            ..InfSvcStd::DEFAULT
         }))
         .accounts_to_update_svc()
         // TODO: currently a happy coincidence that
@@ -163,7 +164,7 @@ impl<
                 self.accounts_to_update_lst_by_mint(m)
             }
         })?;
-        Ok([POOL_STATE_ID, LST_STATE_LIST_ID]
+        Ok([POOL_STATE_ID, LST_STATE_LIST_ID, LST_CALC_BACKING_LIST_ID]
             .into_iter()
             .chain(inp)
             .chain(out))
@@ -199,7 +200,7 @@ impl<
                 self.accounts_to_update_lst_by_mint_mut(m)
             }
         })?;
-        Ok([POOL_STATE_ID, LST_STATE_LIST_ID]
+        Ok([POOL_STATE_ID, LST_STATE_LIST_ID, LST_CALC_BACKING_LIST_ID]
             .into_iter()
             .chain(inp)
             .chain(out))
@@ -235,7 +236,7 @@ impl<
         pair: &Pair<&[u8; 32]>,
         limit_ty: TradeLimitTy,
         fetched: impl UpdateMap,
-    ) -> Result<(), UpdateErr<InfErr>> {
+    ) -> Result<UpdateChanges, UpdateErr<InfErr>> {
         match limit_ty {
             TradeLimitTy::ExactOut(_) => self.update_swap_exact_out(pair, fetched),
             TradeLimitTy::ExactIn(_) => self.update_swap_exact_in(pair, fetched),
@@ -246,9 +247,9 @@ impl<
         &mut self,
         pair: &Pair<&[u8; 32]>,
         fetched: impl UpdateMap,
-    ) -> Result<(), UpdateErr<InfErr>> {
-        self.update_pool(&fetched)?;
-        self.update_lst_state_list(&fetched)?;
+    ) -> Result<UpdateChanges, UpdateErr<InfErr>> {
+        let pool = self.update_pool(&fetched)?;
+        let lst_state_list = self.update_lst_state_list(&fetched)?;
 
         pair.try_map(|mint| {
             if mint == self.pool.lp_token_mint() {
@@ -261,7 +262,10 @@ impl<
             }
         })?;
 
-        Ok(())
+        Ok(UpdateChanges {
+            pool,
+            lst_state_list,
+        })
     }
 
     #[inline]
@@ -269,12 +273,12 @@ impl<
         &mut self,
         pair: &Pair<&[u8; 32]>,
         fetched: impl UpdateMap,
-    ) -> Result<(), UpdateErr<InfErr>> {
-        self.update_swap_common(pair, &fetched)?;
+    ) -> Result<UpdateChanges, UpdateErr<InfErr>> {
+        let changes = self.update_swap_common(pair, &fetched)?;
         self.pricing
             .update_price_exact_in(pair, fetched)
             .map_err(|e| e.map_inner(InfErr::UpdatePp))?;
-        Ok(())
+        Ok(changes)
     }
 
     #[inline]
@@ -282,11 +286,11 @@ impl<
         &mut self,
         pair: &Pair<&[u8; 32]>,
         fetched: impl UpdateMap,
-    ) -> Result<(), UpdateErr<InfErr>> {
-        self.update_swap_common(pair, &fetched)?;
+    ) -> Result<UpdateChanges, UpdateErr<InfErr>> {
+        let changes = self.update_swap_common(pair, &fetched)?;
         self.pricing
             .update_price_exact_out(pair, fetched)
             .map_err(|e| e.map_inner(InfErr::UpdatePp))?;
-        Ok(())
+        Ok(changes)
     }
 }
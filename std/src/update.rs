@@ -4,20 +4,29 @@
 //! (e.g. update for trade is in update folder)
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     iter::{Chain, Once},
 };
 
 use inf1_core::inf1_ctl_core::{
-    accounts::{lst_state_list::LstStatePackedList, pool_state::VerPoolState},
-    keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
-    typedefs::lst_state::LstState,
+    accounts::{
+        lst_calc_backing_list::LstCalcBackingList, lst_state_list::LstStatePackedList,
+        pool_config::PoolConfigPacked, pool_state::VerPoolState,
+    },
+    keys::{LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID},
+    typedefs::{
+        lst_state::{LstState, LstStatePacked},
+        u8bool::U8Bool,
+    },
+};
+use inf1_pp_ag_std::{
+    update::{all::AccountsToUpdateAll, UpdatePricingProg},
+    PricingProgAg,
 };
-use inf1_pp_ag_std::PricingProgAg;
 use inf1_svc_ag_std::update::{SvcPkIterAg, UpdateSvc};
 
 // Re-exports
-pub use inf1_svc_ag_std::update::{Account, UpdateErr, UpdateMap};
+pub use inf1_svc_ag_std::update::{oldest_slot, Account, LastUpdateSlot, UpdateErr, UpdateMap};
 
 use crate::{
     err::InfErr,
@@ -29,31 +38,254 @@ use crate::{
     Inf, Reserves,
 };
 
+/// Changes to the pool state found by [`Inf::update_pool`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PoolChanges {
+    /// `true` if the pricing program was swapped out
+    pub pricing_prog: bool,
+
+    /// `Some(is_disabled)` if the pool was disabled or enabled
+    pub is_disabled: Option<bool>,
+
+    /// `Some(is_rebalancing)` if a rebalance was started or ended
+    pub is_rebalancing: Option<bool>,
+}
+
+impl PoolChanges {
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        !self.pricing_prog && self.is_disabled.is_none() && self.is_rebalancing.is_none()
+    }
+}
+
+/// Changes to the LST state list found by [`Inf::update_lst_state_list`].
+///
+/// Each field is a list of LST mints.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct LstStateListChanges {
+    pub added: Vec<[u8; 32]>,
+
+    /// Data of removed LSTs has been pruned from
+    /// `lst_reserves`, `lst_calcs` and `spl_lsts`
+    pub removed: Vec<[u8; 32]>,
+
+    /// LSTs whose SOL value calculator program was swapped out.
+    ///
+    /// Their stale SOL value calculator data has been evicted from `lst_calcs`
+    pub svc_prog: Vec<[u8; 32]>,
+
+    pub input_disabled: Vec<[u8; 32]>,
+
+    pub input_enabled: Vec<[u8; 32]>,
+}
+
+impl LstStateListChanges {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        let Self {
+            added,
+            removed,
+            svc_prog,
+            input_disabled,
+            input_enabled,
+        } = self;
+        [added, removed, svc_prog, input_disabled, input_enabled]
+            .iter()
+            .all(|v| v.is_empty())
+    }
+}
+
+/// Changes found by a compound update procedure
+/// e.g. [`Inf::update_trade`]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct UpdateChanges {
+    pub pool: PoolChanges,
+    pub lst_state_list: LstStateListChanges,
+}
+
+impl UpdateChanges {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pool.is_empty() && self.lst_state_list.is_empty()
+    }
+}
+
+/// Max number of pubkeys accepted by a single `getMultipleAccounts` RPC call
+pub const GET_MULTIPLE_ACCOUNTS_MAX_PKS: usize = 100;
+
+/// Accounts to fetch for [`Inf::update_all`]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct AllUpdatePks {
+    /// Deduplicated, in order of first appearance
+    pub pks: Vec<[u8; 32]>,
+
+    /// `(mint, err)` of LSTs whose accounts could not be determined.
+    ///
+    /// These LSTs' accounts are excluded from `pks`.
+    pub lst_errs: Vec<([u8; 32], InfErr)>,
+}
+
+impl AllUpdatePks {
+    /// `pks` split into batches that each fit into a single `getMultipleAccounts` call
+    #[inline]
+    pub fn batches(&self) -> core::slice::Chunks<'_, [u8; 32]> {
+        self.pks.chunks(GET_MULTIPLE_ACCOUNTS_MAX_PKS)
+    }
+}
+
+/// Result of [`Inf::update_all`]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct UpdateAllReport {
+    pub changes: UpdateChanges,
+
+    /// `(mint, err)` of LSTs that failed to update.
+    ///
+    /// These LSTs retain their old data.
+    pub lst_errs: Vec<([u8; 32], UpdateErr<InfErr>)>,
+
+    /// Error updating the pricing program, if any.
+    ///
+    /// The pricing program may have been partially updated.
+    pub pricing_err: Option<UpdateErr<InfErr>>,
+}
+
 impl<
         F: Fn(&[&[u8]], &[u8; 32]) -> Option<([u8; 32], u8)> + Clone,
         C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]> + Clone,
     > Inf<F, C>
 {
+    /// Accounts required to update the pool, the INF mint, the pricing program and
+    /// every LST in the current LST state list.
+    ///
+    /// Lazily initializes SOL value calculators of LSTs if able to.
+    /// LSTs that fail to initialize are reported in [`AllUpdatePks::lst_errs`]
+    /// instead of failing the entire procedure.
+    ///
+    /// Call [`Self::update_lst_state_list`] beforehand if LSTs may have been added to the pool
+    /// since the last update.
+    #[inline]
+    pub fn accounts_to_update_all_mut(&mut self) -> Result<AllUpdatePks, InfErr> {
+        let lsts = self.lst_states_owned()?;
+        let mut res = AllUpdatePks::default();
+        let mut seen = HashSet::new();
+        let mut extend = |pks: &mut Vec<[u8; 32]>, iter: &mut dyn Iterator<Item = [u8; 32]>| {
+            pks.extend(iter.filter(|pk| seen.insert(*pk)))
+        };
+
+        extend(
+            &mut res.pks,
+            &mut [
+                POOL_STATE_ID,
+                LST_STATE_LIST_ID,
+                LST_CALC_BACKING_LIST_ID,
+                POOL_CONFIG_ID,
+                *self.pool.lp_token_mint(),
+            ]
+            .into_iter(),
+        );
+        extend(&mut res.pks, &mut self.inf_svc_pks());
+        lsts.iter().for_each(
+            |lst_state| match self.accounts_to_update_lst_mut(lst_state) {
+                Ok(mut iter) => extend(&mut res.pks, &mut iter),
+                Err(e) => res.lst_errs.push((lst_state.mint, e)),
+            },
+        );
+        extend(
+            &mut res.pks,
+            &mut self
+                .pricing
+                .accounts_to_update_all(lsts.iter().map(|s| s.mint)),
+        );
+
+        Ok(res)
+    }
+
+    /// Update the pool, the INF mint, the pricing program and
+    /// every LST in the LST state list with accounts returned by [`Self::accounts_to_update_all_mut`].
+    ///
+    /// Failure to update any individual LST or the pricing program is reported in
+    /// [`UpdateAllReport::lst_errs`] and [`UpdateAllReport::pricing_err`]
+    /// instead of failing the entire procedure.
+    #[inline]
+    pub fn update_all(
+        &mut self,
+        fetched: impl UpdateMap,
+    ) -> Result<UpdateAllReport, UpdateErr<InfErr>> {
+        let pool = self.update_pool(&fetched)?;
+        self.update_pool_config(&fetched)?;
+        let lst_state_list = self.update_lst_state_list(&fetched)?;
+        self.update_lp_token_supply(&fetched)?;
+
+        let lsts = self.lst_states_owned().map_err(UpdateErr::Inner)?;
+        let lst_errs = lsts
+            .iter()
+            .filter_map(|lst_state| {
+                self.update_lst(lst_state, &fetched)
+                    .err()
+                    .map(|e| (lst_state.mint, e))
+            })
+            .collect();
+
+        let pricing_err = self
+            .pricing
+            .update_all(lsts.iter().map(|s| s.mint), &fetched)
+            .err()
+            .map(|e| e.map_inner(InfErr::UpdatePp));
+
+        Ok(UpdateAllReport {
+            changes: UpdateChanges {
+                pool,
+                lst_state_list,
+            },
+            lst_errs,
+            pricing_err,
+        })
+    }
+
+    pub(crate) fn lst_states_owned(&self) -> Result<Vec<LstState>, InfErr> {
+        Ok(self
+            .try_lst_state_list()?
+            .iter()
+            .map(|s| s.into_lst_state())
+            .collect())
+    }
+
     /// Also replaces the pricing program data with fresh default if the pricing program was
     /// found to have changed.
     #[inline]
-    pub fn update_pool(&mut self, fetched: impl UpdateMap) -> Result<(), UpdateErr<InfErr>> {
+    pub fn update_pool(
+        &mut self,
+        fetched: impl UpdateMap,
+    ) -> Result<PoolChanges, UpdateErr<InfErr>> {
         let pool_state_acc = fetched.get_account_checked(&POOL_STATE_ID)?;
+        if self.slots.pool.is_regression(pool_state_acc.slot()) {
+            return Ok(PoolChanges::default());
+        }
 
         let pool = VerPoolState::try_from_acc_data(pool_state_acc.data())
             .ok_or(UpdateErr::Inner(InfErr::AccDeser { pk: POOL_STATE_ID }))?;
 
-        if self.pricing.0.ty().program_id() != pool.pricing_program() {
+        let pricing_prog = self.pricing.0.ty().program_id() != pool.pricing_program();
+        if pricing_prog {
             self.pricing = self
                 .try_default_pricing_prog_from_program_id(pool.pricing_program())
                 .map_err(UpdateErr::Inner)?;
         }
 
-        self.pool = pool;
+        let toggled = |old: &u8, new: &u8| {
+            let new = U8Bool(new).to_bool();
+            (U8Bool(old).to_bool() != new).then_some(new)
+        };
+        let changes = PoolChanges {
+            pricing_prog,
+            is_disabled: toggled(self.pool.is_disabled(), pool.is_disabled()),
+            is_rebalancing: toggled(self.pool.is_rebalancing(), pool.is_rebalancing()),
+        };
 
-        // TODO: maybe cleanup removed LSTs from self.lst_reserves and self.lst_calc?
+        self.pool = pool;
+        self.slots.pool = LastUpdateSlot(pool_state_acc.slot());
 
-        Ok(())
+        Ok(changes)
     }
 
     #[inline]
@@ -86,26 +318,104 @@ impl<F, C: Fn(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>> Inf<F, C> {
         calc.update_svc(&fetched)
             .map_err(|e| e.map_inner(InfErr::UpdateSvc))?;
 
-        Self::update_lst_reserves(&mut self.lst_reserves, &self.create_pda, lst_state, fetched)?;
+        Self::update_lst_reserves_slotted(
+            &mut self.lst_reserves,
+            &mut self.slots.lst_reserves,
+            &self.create_pda,
+            lst_state,
+            fetched,
+        )?;
 
         Ok(())
     }
 }
 
 impl<F, C> Inf<F, C> {
+    /// Also prunes data of LSTs that were removed from the pool.
+    ///
+    /// Also calls [`Self::update_lst_calc_backing_list`] so that
+    /// SPL LSTs newly added to the pool can be initialized without `spl_lsts`.
     #[inline]
     pub fn update_lst_state_list(
         &mut self,
         fetched: impl UpdateMap,
-    ) -> Result<(), UpdateErr<InfErr>> {
+    ) -> Result<LstStateListChanges, UpdateErr<InfErr>> {
+        self.update_lst_calc_backing_list(&fetched)?;
+
         let lst_state_list_acc = fetched.get_account_checked(&LST_STATE_LIST_ID)?;
-        if LstStatePackedList::of_acc_data(lst_state_list_acc.data()).is_none() {
-            return Err(UpdateErr::Inner(InfErr::AccDeser {
+        if self
+            .slots
+            .lst_state_list
+            .is_regression(lst_state_list_acc.slot())
+        {
+            return Ok(LstStateListChanges::default());
+        }
+        let new = LstStatePackedList::of_acc_data(lst_state_list_acc.data())
+            .ok_or(UpdateErr::Inner(InfErr::AccDeser {
                 pk: inf1_core::inf1_ctl_core::keys::LST_STATE_LIST_ID,
+            }))?
+            .0;
+        let changes = lst_state_list_changes(self.try_lst_state_list().unwrap_or_default(), new);
+        self.lst_state_list_data = lst_state_list_acc.data().into();
+        self.slots.lst_state_list = LastUpdateSlot(lst_state_list_acc.slot());
+
+        changes.removed.iter().for_each(|mint| {
+            self.lst_reserves.remove(mint);
+            self.slots.lst_reserves.remove(mint);
+            self.lst_calcs.remove(mint);
+            self.spl_lsts.remove(mint);
+        });
+        changes.svc_prog.iter().for_each(|mint| {
+            self.lst_calcs.remove(mint);
+        });
+
+        Ok(changes)
+    }
+
+    /// No-op if [`LST_CALC_BACKING_LIST_ID`] is not in `fetched`,
+    /// since the account may not exist onchain yet
+    #[inline]
+    pub fn update_lst_calc_backing_list(
+        &mut self,
+        fetched: impl UpdateMap,
+    ) -> Result<(), UpdateErr<InfErr>> {
+        let acc = match fetched.get_account(&LST_CALC_BACKING_LIST_ID) {
+            None => return Ok(()),
+            Some(a) => a,
+        };
+        if self.slots.lst_calc_backing_list.is_regression(acc.slot()) {
+            return Ok(());
+        }
+        if LstCalcBackingList::of_acc_data(acc.data()).is_none() {
+            return Err(UpdateErr::Inner(InfErr::AccDeser {
+                pk: LST_CALC_BACKING_LIST_ID,
             }));
         }
-        self.lst_state_list_data = lst_state_list_acc.data().into();
+        self.lst_calc_backing_list_data = acc.data().into();
+        self.slots.lst_calc_backing_list = LastUpdateSlot(acc.slot());
+        Ok(())
+    }
 
+    /// No-op if [`POOL_CONFIG_ID`] is not in `fetched`,
+    /// since the account may not exist onchain yet
+    #[inline]
+    pub fn update_pool_config(&mut self, fetched: impl UpdateMap) -> Result<(), UpdateErr<InfErr>> {
+        let acc = match fetched.get_account(&POOL_CONFIG_ID) {
+            None => return Ok(()),
+            Some(a) => a,
+        };
+        if self.slots.pool_config.is_regression(acc.slot()) {
+            return Ok(());
+        }
+        let pool_config = match acc.data() {
+            // not yet created
+            [] => Default::default(),
+            data => PoolConfigPacked::of_acc_data(data)
+                .ok_or(UpdateErr::Inner(InfErr::AccDeser { pk: POOL_CONFIG_ID }))?
+                .into_pool_config(),
+        };
+        self.pool_config = pool_config;
+        self.slots.pool_config = LastUpdateSlot(acc.slot());
         Ok(())
     }
 
@@ -117,6 +427,9 @@ impl<F, C> Inf<F, C> {
         fetched: impl UpdateMap,
     ) -> Result<(), UpdateErr<InfErr>> {
         let lp_mint_acc = fetched.get_account_checked(self.pool.lp_token_mint())?;
+        if self.slots.lp_token_supply.is_regression(lp_mint_acc.slot()) {
+            return Ok(());
+        }
         let lp_token_supply = token_supply_from_mint_data(lp_mint_acc.data()).ok_or(
             UpdateErr::Inner(InfErr::AccDeser {
                 pk: *self.pool.lp_token_mint(),
@@ -124,26 +437,98 @@ impl<F, C> Inf<F, C> {
         )?;
 
         self.lp_token_supply = Some(lp_token_supply);
+        self.slots.lp_token_supply = LastUpdateSlot(lp_mint_acc.slot());
 
         Ok(())
     }
 
     // Associated fn format like this so that it can be used by external crates
     // (jup-interface)
+    /// Does not track context slots, see [`Self::update_lst_reserves_slotted`]
     #[inline]
     pub fn update_lst_reserves(
         lst_reserves: &mut HashMap<[u8; 32], Reserves>,
         create_pda: impl FnOnce(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>,
         lst_state: &LstState,
         fetched: impl UpdateMap,
+    ) -> Result<(), UpdateErr<InfErr>> {
+        Self::update_lst_reserves_slotted(
+            lst_reserves,
+            &mut HashMap::new(),
+            create_pda,
+            lst_state,
+            fetched,
+        )
+    }
+
+    /// [`Self::update_lst_reserves`], but refuses to overwrite reserves with data
+    /// older than the slot recorded in `lst_reserves_slots`, recording the new slot on success.
+    #[inline]
+    pub fn update_lst_reserves_slotted(
+        lst_reserves: &mut HashMap<[u8; 32], Reserves>,
+        lst_reserves_slots: &mut HashMap<[u8; 32], LastUpdateSlot>,
+        create_pda: impl FnOnce(&[&[u8]], &[u8; 32]) -> Option<[u8; 32]>,
+        lst_state: &LstState,
+        fetched: impl UpdateMap,
     ) -> Result<(), UpdateErr<InfErr>> {
         let reserves_addr =
             create_pool_reserves_ata(create_pda, &lst_state.mint, lst_state.pool_reserves_bump)
                 .ok_or(UpdateErr::Inner(InfErr::NoValidPda))?;
         let token_acc = fetched.get_account_checked(&reserves_addr)?;
+        let slot = token_acc.slot();
+        if lst_reserves.contains_key(&lst_state.mint)
+            && lst_reserves_slots
+                .get(&lst_state.mint)
+                .is_some_and(|s| s.is_regression(slot))
+        {
+            return Ok(());
+        }
         let balance = balance_from_token_acc_data(token_acc.data())
             .ok_or(UpdateErr::Inner(InfErr::AccDeser { pk: reserves_addr }))?;
         lst_reserves.insert(lst_state.mint, Reserves { balance });
+        lst_reserves_slots.insert(lst_state.mint, LastUpdateSlot(slot));
         Ok(())
     }
 }
+
+fn lst_state_list_changes(old: &[LstStatePacked], new: &[LstStatePacked]) -> LstStateListChanges {
+    let old_by_mint: HashMap<[u8; 32], LstState> = old
+        .iter()
+        .map(|s| {
+            let s = s.into_lst_state();
+            (s.mint, s)
+        })
+        .collect();
+    let mut new_mints = HashSet::with_capacity(new.len());
+    let mut changes = LstStateListChanges::default();
+
+    new.iter().for_each(|n| {
+        let n = n.into_lst_state();
+        new_mints.insert(n.mint);
+        let o = match old_by_mint.get(&n.mint) {
+            None => {
+                changes.added.push(n.mint);
+                return;
+            }
+            Some(o) => o,
+        };
+        if o.sol_value_calculator != n.sol_value_calculator {
+            changes.svc_prog.push(n.mint);
+        }
+        match (
+            U8Bool(&o.is_input_disabled).to_bool(),
+            U8Bool(&n.is_input_disabled).to_bool(),
+        ) {
+            (false, true) => changes.input_disabled.push(n.mint),
+            (true, false) => changes.input_enabled.push(n.mint),
+            _ => (),
+        }
+    });
+    changes.removed = old
+        .iter()
+        .map(|s| s.into_lst_state().mint)
+        .filter(|mint| !new_mints.contains(mint))
+        .collect();
+
+    changes
+}
@@ -1,5 +1,7 @@
 mod fixtures;
 mod pda;
+mod update_map;
 
 pub use fixtures::*;
 pub use pda::*;
+pub use update_map::*;
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use inf1_std::update::{Account, UpdateMap};
+
+pub struct AccData<'a>(pub &'a [u8]);
+
+impl Account for AccData<'_> {
+    fn data(&self) -> &[u8] {
+        self.0
+    }
+}
+
+pub struct SlottedAccData<'a>(pub &'a [u8], pub u64);
+
+impl Account for SlottedAccData<'_> {
+    fn data(&self) -> &[u8] {
+        self.0
+    }
+
+    fn slot(&self) -> Option<u64> {
+        Some(self.1)
+    }
+}
+
+/// Map of `pubkey: account data`
+#[derive(Debug, Default, Clone)]
+pub struct UpdateMapFixture(pub HashMap<[u8; 32], Vec<u8>>);
+
+impl UpdateMap for UpdateMapFixture {
+    type Account<'a> = AccData<'a>;
+
+    fn get_account(&self, pk: &[u8; 32]) -> Option<Self::Account<'_>> {
+        self.0.get(pk).map(|d| AccData(d))
+    }
+}
+
+/// Map of `pubkey: (account data, context slot)`
+#[derive(Debug, Default, Clone)]
+pub struct SlottedUpdateMapFixture(pub HashMap<[u8; 32], (Vec<u8>, u64)>);
+
+impl UpdateMap for SlottedUpdateMapFixture {
+    type Account<'a> = SlottedAccData<'a>;
+
+    fn get_account(&self, pk: &[u8; 32]) -> Option<Self::Account<'_>> {
+        self.0.get(pk).map(|(d, s)| SlottedAccData(d, *s))
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use inf1_std::{
+    alt::ALT_MAX_ADDRESSES,
+    inf1_ctl_core,
+    inf1_pp_core::pair::Pair,
+    instructions::swap::v2::exact_in::swap_exact_in_v2_ix_keys_owned,
+    trade::{instruction::TradeIxArgs, Trade},
+    InfStd,
+};
+
+use crate::common::{create_pda, find_pda, lst_state_list_fixture, pool_state_fixture};
+
+#[test]
+fn alt_plan_covers_swap_fixture() {
+    let mut inf = InfStd::new(
+        pool_state_fixture(),
+        lst_state_list_fixture()
+            .iter()
+            .flat_map(|s| *s.as_acc_data_arr())
+            .collect(),
+        None,
+        None,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap();
+    let plan = inf.alt_plan_mut().unwrap();
+
+    assert_eq!(plan.tables[0][0], inf1_ctl_core::ID);
+    assert!(plan.tables.iter().all(|t| t.len() <= ALT_MAX_ADDRESSES));
+    let total = plan.tables.iter().map(Vec::len).sum::<usize>();
+    assert_eq!(
+        plan.tables.iter().flatten().collect::<HashSet<_>>().len(),
+        total
+    );
+
+    let [inp, out] = {
+        let mut mints = lst_state_list_fixture()
+            .into_iter()
+            .map(|s| s.mint)
+            .filter(|m| plan.lst_errs.iter().all(|(e, _)| e != m));
+        [mints.next().unwrap(), mints.next().unwrap()]
+    };
+    let signer = [1; 32];
+    let token_accs = [[2; 32], [3; 32]];
+    let ix = inf
+        .trade_ix_mut(
+            &TradeIxArgs {
+                amt: 1_000_000,
+                limit: 0,
+                mints: &Pair {
+                    inp: &inp,
+                    out: &out,
+                },
+                signer: &signer,
+                token_accs: &Pair {
+                    inp: &token_accs[0],
+                    out: &token_accs[1],
+                },
+                referrer: None,
+            },
+            Trade::ExactIn(()),
+        )
+        .unwrap();
+    let Trade::ExactIn(swap) = &ix else {
+        unreachable!()
+    };
+    let keys: Vec<_> = swap_exact_in_v2_ix_keys_owned(&swap.swap.accs)
+        .seq()
+        .copied()
+        .collect();
+
+    let covers = plan.cover_trade_ix(&ix);
+    let covered: Vec<_> = covers.iter().flat_map(|c| c.ix_accs.iter()).collect();
+    assert_eq!(covered.iter().collect::<HashSet<_>>().len(), covered.len());
+    covers.iter().for_each(|c| {
+        c.ix_accs
+            .iter()
+            .for_each(|i| assert!(plan.tables[c.table].contains(&keys[*i])))
+    });
+
+    // everything but the signer and its token accounts should be covered
+    keys.iter().enumerate().for_each(|(i, pk)| {
+        let is_user = *pk == signer || token_accs.contains(pk);
+        assert_eq!(covered.contains(&&i), !is_user, "{i}");
+    });
+}
@@ -0,0 +1,67 @@
+use inf1_std::{
+    cu::{estimate_cus, CuIxKind, CuKey, CU_TABLE},
+    inf1_pp_core::pair::Pair,
+    inf1_svc_ag_std::SvcAgTy,
+    trade::TradeLimitTy,
+    InfStd,
+};
+use inf1_test_utils::{JUPSOL_FIXTURE_LST_IDX, WSOL_FIXTURE_LST_IDX};
+
+use crate::common::{create_pda, find_pda, lst_state_list_fixture, pool_state_fixture};
+
+#[test]
+fn estimate_cus_table_entries() {
+    CU_TABLE
+        .iter()
+        .for_each(|(key, cus)| assert_eq!(estimate_cus(key), *cus));
+}
+
+#[test]
+fn estimate_cus_fixture() {
+    let lsts = lst_state_list_fixture();
+    let inf = InfStd::new(
+        pool_state_fixture(),
+        lsts.iter().flat_map(|s| *s.as_acc_data_arr()).collect(),
+        None,
+        None,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap();
+    let [inp, out] = [JUPSOL_FIXTURE_LST_IDX, WSOL_FIXTURE_LST_IDX].map(|i| &lsts[i].mint);
+    let mints = Pair { inp, out };
+
+    [
+        (TradeLimitTy::ExactIn(()), CuIxKind::SwapExactIn),
+        (TradeLimitTy::ExactOut(()), CuIxKind::SwapExactOut),
+    ]
+    .into_iter()
+    .for_each(|(limit_ty, kind)| {
+        let est = inf.estimate_cus(&mints, limit_ty).unwrap();
+        let expected = estimate_cus(&CuKey {
+            inp: SvcAgTy::SanctumSplMulti(()),
+            out: SvcAgTy::Wsol(()),
+            pricing: inf.pricing.0.ty(),
+            kind,
+        });
+        assert_eq!(est, expected);
+    });
+
+    // LP token mint is keyed by the INF calculator
+    let lp = *inf.pool.lp_token_mint();
+    let est = inf
+        .estimate_cus(&Pair { inp, out: &lp }, TradeLimitTy::ExactIn(()))
+        .unwrap();
+    assert_eq!(
+        est,
+        estimate_cus(&CuKey {
+            inp: SvcAgTy::SanctumSplMulti(()),
+            out: SvcAgTy::Inf(()),
+            pricing: inf.pricing.0.ty(),
+            kind: CuIxKind::SwapExactIn,
+        })
+    );
+}
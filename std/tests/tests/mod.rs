@@ -1 +1,6 @@
+mod alt;
+mod cu;
+mod protocol_fee;
 mod quote;
+mod snapshot;
+mod update;
@@ -0,0 +1,51 @@
+use inf1_std::InfStd;
+
+use crate::common::{
+    create_pda, find_pda, lst_state_list_fixture, pool_state_fixture, UpdateMapFixture,
+};
+
+#[test]
+fn withdraw_protocol_fees_sweep_skips_closed_accumulators_fixture() {
+    let inf = InfStd::new(
+        pool_state_fixture(),
+        lst_state_list_fixture()
+            .iter()
+            .flat_map(|s| *s.as_acc_data_arr())
+            .collect(),
+        None,
+        None,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap();
+    let accumulators = inf.accounts_to_withdraw_protocol_fees_sweep().unwrap();
+    assert_eq!(accumulators.len(), lst_state_list_fixture().len());
+
+    let refund_rent_to = [1; 32];
+    // every other accumulator open, the rest either closed
+    // (fetched with no data) or not returned by the RPC at all
+    let open: Vec<_> = accumulators.iter().step_by(2).copied().collect();
+    let fetched = UpdateMapFixture(
+        accumulators
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i % 4 != 3)
+            .map(|(i, pk)| (*pk, if i % 2 == 0 { vec![1; 165] } else { vec![] }))
+            .collect(),
+    );
+
+    let ixs = inf
+        .withdraw_protocol_fees_sweep_ixs(&refund_rent_to, &fetched)
+        .unwrap();
+
+    assert_eq!(
+        ixs.iter()
+            .map(|ix| *ix.protocol_fee_accumulator())
+            .collect::<Vec<_>>(),
+        open
+    );
+    assert!(ixs.iter().all(|ix| *ix.refund_rent_to() == refund_rent_to));
+}
@@ -0,0 +1,76 @@
+use inf1_pp_ag_std::update::all::Pair;
+use inf1_std::{
+    err::InfErr,
+    inf1_ctl_core::{keys::LST_CALC_BACKING_LIST_ID, typedefs::lst_calc_backing::LstCalcBacking},
+    InfStd,
+};
+use inf1_test_utils::{ALL_FIXTURES, JUPSOL_MINT, JUPSOL_POOL_ID, WSOL_MINT};
+
+use crate::common::{
+    create_pda, find_pda, lst_state_list_fixture, pool_state_fixture, UpdateMapFixture,
+};
+
+const DUMMY_AMT: u64 = 1_000_000_000;
+
+/// All fixture accounts, with a `LstCalcBackingList` that records jupsol's stake pool
+fn fetched_with_backing() -> UpdateMapFixture {
+    UpdateMapFixture(
+        ALL_FIXTURES
+            .iter()
+            .map(|(pk, acc)| (pk.to_bytes(), acc.data.clone()))
+            .chain([(
+                LST_CALC_BACKING_LIST_ID,
+                LstCalcBacking {
+                    mint: JUPSOL_MINT.to_bytes(),
+                    backing: JUPSOL_POOL_ID.to_bytes(),
+                }
+                .as_acc_data_arr()
+                .to_vec(),
+            )])
+            .collect(),
+    )
+}
+
+#[test]
+fn quote_spl_lst_missing_from_spl_lsts_fixture() {
+    let pair = Pair {
+        inp: WSOL_MINT.as_array(),
+        out: JUPSOL_MINT.as_array(),
+    };
+    // no `spl_lsts`
+    let mut inf = InfStd::new(
+        pool_state_fixture(),
+        lst_state_list_fixture()
+            .iter()
+            .flat_map(|s| *s.as_acc_data_arr())
+            .collect(),
+        None,
+        None,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap();
+
+    assert_eq!(
+        inf.accounts_to_update_swap_exact_in_mut(&pair).err(),
+        Some(InfErr::MissingSplData {
+            mint: JUPSOL_MINT.to_bytes()
+        })
+    );
+
+    inf.update_swap_exact_in(&pair, fetched_with_backing())
+        .unwrap();
+
+    let pks: Vec<_> = inf
+        .accounts_to_update_swap_exact_in_mut(&pair)
+        .unwrap()
+        .collect();
+    assert!(pks.contains(&LST_CALC_BACKING_LIST_ID));
+    assert!(pks.contains(&JUPSOL_POOL_ID.to_bytes()));
+
+    let quote = inf.quote_exact_in(&pair, DUMMY_AMT, 0).unwrap();
+    assert!(quote.out > 0);
+}
@@ -3,7 +3,13 @@ use std::collections::HashMap;
 use inf1_pp_ag_std::update::all::Pair;
 use inf1_std::{
     err::InfErr,
-    inf1_ctl_core::{accounts::pool_state::VerPoolState, typedefs::lst_state::LstState},
+    inf1_ctl_core::{
+        accounts::pool_state::{PoolStateV2, VerPoolState},
+        typedefs::{
+            disable_modes::{DisableMode, DisableModes},
+            lst_state::LstState,
+        },
+    },
     quote::swap::err::QuoteErr,
     InfStd,
 };
@@ -37,6 +43,7 @@ fn svcs_for_test() -> HashMap<[u8; 32], SvcAgStd> {
             DISABLED_MINT,
             SvcAgStd(SvcAg::Lido(LidoSvcStd {
                 calc: Some(Default::default()),
+                ..LidoSvcStd::DEFAULT
             })),
         ),
         (WSOL_MINT.to_bytes(), SvcAgStd(SvcAg::Wsol(WsolSvcStd))),
@@ -98,3 +105,27 @@ fn quote_exact_out_inp_disabled_fixture() {
     assert_eq!(e, EXPECTED_ERR);
     assert_eq!(em, EXPECTED_ERR);
 }
+
+fn swap_disabled_setup() -> InfStd {
+    let pool = VerPoolState::V2(PoolStateV2 {
+        disable_modes: DisableModes::NONE.with(DisableMode::Swap).get(),
+        ..pool_state_fixture().migrated(0)
+    });
+    inf_for_test(pool, &lst_state_list_fixture())
+}
+
+#[test]
+fn quote_swap_disabled_fixture() {
+    const EXPECTED_ERR: InfErr = InfErr::SwapQuote(QuoteErr::Disabled(DisableMode::Swap));
+
+    let mut inf = swap_disabled_setup();
+
+    let es = [
+        inf.quote_exact_in(&DISABLED_INP_PAIR, DUMMY_AMT, DUMMY_SLOT_LOOKAHEAD),
+        inf.quote_exact_out(&DISABLED_INP_PAIR, DUMMY_AMT, DUMMY_SLOT_LOOKAHEAD),
+        inf.quote_exact_in_mut(&DISABLED_INP_PAIR, DUMMY_AMT, DUMMY_SLOT_LOOKAHEAD),
+        inf.quote_exact_out_mut(&DISABLED_INP_PAIR, DUMMY_AMT, DUMMY_SLOT_LOOKAHEAD),
+    ];
+    es.into_iter()
+        .for_each(|e| assert_eq!(e.unwrap_err(), EXPECTED_ERR));
+}
@@ -0,0 +1,165 @@
+use inf1_std::{trade::quote::StaleSvcPolicy, InfStd};
+use inf1_svc_ag_std::{
+    inf1_svc_spl_std::{
+        sanctum_spl_stake_pool_core::{StakePool, SYSVAR_CLOCK},
+        SanctumSplMultiSvcStd,
+    },
+    update::UpdateSvc,
+    SvcAg, SvcAgStd,
+};
+use inf1_test_utils::{KeyedUiAccount, JUPSOL_MINT, JUPSOL_POOL_ID};
+
+use crate::common::{
+    create_pda, find_pda, lst_state_list_fixture, pool_state_fixture, UpdateMapFixture,
+};
+
+const DUMMY_AMT: u64 = 1_000_000_000;
+
+/// `[account_type, max_validators, vec len]`
+const VALIDATOR_LIST_HEADER_LEN: usize = 9;
+const VALIDATOR_STAKE_INFO_LEN: usize = 73;
+
+fn validator_list_data(active_stake_lamports: &[u64]) -> Vec<u8> {
+    let mut data = vec![0u8; VALIDATOR_LIST_HEADER_LEN];
+    // AccountType::ValidatorList
+    data[0] = 2;
+    data[1..5].copy_from_slice(&(active_stake_lamports.len() as u32).to_le_bytes());
+    data[5..9].copy_from_slice(&(active_stake_lamports.len() as u32).to_le_bytes());
+    active_stake_lamports.iter().for_each(|a| {
+        let mut entry = [0u8; VALIDATOR_STAKE_INFO_LEN];
+        entry[..8].copy_from_slice(&a.to_le_bytes());
+        data.extend(entry);
+    });
+    data
+}
+
+fn clock_data(epoch: u64) -> Vec<u8> {
+    let mut data = vec![0u8; 40];
+    data[16..24].copy_from_slice(&epoch.to_le_bytes());
+    data
+}
+
+/// jupsol stake pool fixture, in an epoch after its `last_update_epoch`,
+/// with a validator list whose active stake sums to `active_stake_lamports`
+fn stale_jupsol_update_map(active_stake_lamports: &[u64]) -> ([u8; 32], UpdateMapFixture) {
+    let (pool_addr, pool_acc) =
+        KeyedUiAccount::from_test_fixtures_json("jupsol-pool").into_keyed_account();
+    let pool = StakePool::borsh_de(pool_acc.data.as_slice()).unwrap();
+    (
+        pool_addr.to_bytes(),
+        UpdateMapFixture(
+            [
+                (pool_addr.to_bytes(), pool_acc.data),
+                (SYSVAR_CLOCK, clock_data(pool.last_update_epoch + 1)),
+                (
+                    pool.validator_list,
+                    validator_list_data(active_stake_lamports),
+                ),
+            ]
+            .into(),
+        ),
+    )
+}
+
+#[test]
+fn spl_est_projects_stale_jupsol_fixture() {
+    let (pool_addr, map) = stale_jupsol_update_map(&[1_000_000_000_000_000, 2_000_000_000_000_000]);
+    let mut svc = SvcAgStd(SvcAg::SanctumSplMulti(SanctumSplMultiSvcStd::new(
+        pool_addr,
+    )));
+    svc.set_spl_est_enabled(true);
+    svc.update_svc(&map).unwrap();
+
+    // unestimated calc refuses to quote through the epoch boundary
+    assert!(svc
+        .as_sol_val_calc()
+        .unwrap()
+        .svc_lst_to_sol(DUMMY_AMT)
+        .is_err());
+
+    let est = match svc.as_sol_val_calc_est().unwrap() {
+        SvcAg::SanctumSplMulti(c) => *c,
+        _ => unreachable!(),
+    };
+    assert_eq!(est.active_stake_lamports, 3_000_000_000_000_000);
+    assert!(est.est_epoch_rewards().unwrap() > 0);
+
+    let sol = est.svc_lst_to_sol(DUMMY_AMT).unwrap();
+    assert_eq!(
+        sol.start(),
+        est.unprojected_calc()
+            .svc_lst_to_sol(DUMMY_AMT)
+            .unwrap()
+            .start()
+    );
+    assert!(sol.start() < sol.end(), "{sol:?}");
+}
+
+#[test]
+fn spl_est_unavailable_without_validator_list_fixture() {
+    let (pool_addr, mut map) = stale_jupsol_update_map(&[]);
+    map.0
+        .retain(|pk, _| *pk == pool_addr || *pk == SYSVAR_CLOCK);
+    let mut svc = SvcAgStd(SvcAg::SanctumSplMulti(SanctumSplMultiSvcStd::new(
+        pool_addr,
+    )));
+    svc.set_spl_est_enabled(true);
+    svc.update_svc(&map).unwrap();
+
+    assert!(svc.as_sol_val_calc().is_some());
+    assert!(svc.as_sol_val_calc_est().is_none());
+}
+
+#[test]
+fn estimate_policy_opts_in_spl_svcs_fixture() {
+    let jupsol = lst_state_list_fixture()
+        .into_iter()
+        .find(|s| s.mint == JUPSOL_MINT.to_bytes())
+        .unwrap();
+    [
+        (StaleSvcPolicy::Allow, false),
+        (StaleSvcPolicy::Reject, false),
+        (StaleSvcPolicy::Estimate, true),
+    ]
+    .into_iter()
+    .for_each(|(policy, expected)| {
+        let mut inf = InfStd::new(
+            pool_state_fixture(),
+            lst_state_list_fixture()
+                .iter()
+                .flat_map(|s| *s.as_acc_data_arr())
+                .collect(),
+            None,
+            None,
+            Default::default(),
+            Default::default(),
+            [(JUPSOL_MINT.to_bytes(), JUPSOL_POOL_ID.to_bytes())].into(),
+            find_pda,
+            create_pda,
+        )
+        .unwrap();
+        inf.stale_svc_policy = policy;
+        let is_est_enabled = match &inf.try_get_or_init_lst_svc(&jupsol).unwrap().0 {
+            SvcAg::SanctumSplMulti(c) => c.is_est_enabled(),
+            _ => unreachable!(),
+        };
+        assert_eq!(is_est_enabled, expected, "{policy:?}");
+    });
+}
+
+#[test]
+fn spl_est_truncated_validator_list_fixture() {
+    let (pool_addr, mut map) = stale_jupsol_update_map(&[1_000_000_000, 2_000_000_000]);
+    map.0
+        .iter_mut()
+        .filter(|(pk, _)| **pk != pool_addr && **pk != SYSVAR_CLOCK)
+        .for_each(|(_, validator_list_data)| {
+            validator_list_data.truncate(validator_list_data.len() - 1)
+        });
+    let mut svc = SvcAgStd(SvcAg::SanctumSplMulti(SanctumSplMultiSvcStd::new(
+        pool_addr,
+    )));
+    svc.set_spl_est_enabled(true);
+
+    assert!(svc.update_svc(&map).is_err());
+}
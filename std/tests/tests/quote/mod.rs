@@ -1 +1,5 @@
+mod backing;
 mod errs;
+mod est;
+mod stale;
+mod upgrade;
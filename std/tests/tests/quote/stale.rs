@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use inf1_pp_ag_std::update::all::Pair;
+use inf1_std::{err::InfErr, trade::quote::StaleSvcPolicy, InfStd};
+use inf1_svc_ag_std::{
+    inf1_svc_lido_core::calc::LidoCalc,
+    inf1_svc_lido_std::{solido_legacy_core::STSOL_MINT_ADDR, LidoSvcStd},
+    inf1_svc_wsol_std::WsolSvcStd,
+    SvcAg, SvcAgStd,
+};
+use inf1_test_utils::WSOL_MINT;
+
+use crate::common::{create_pda, find_pda, lst_state_list_fixture, pool_state_fixture};
+
+const STALE_MINT: [u8; 32] = STSOL_MINT_ADDR;
+const DUMMY_AMT: u64 = 1_000_000_000;
+const DUMMY_SLOT_LOOKAHEAD: u64 = 0;
+const STALE_INP_PAIR: Pair<&[u8; 32]> = Pair {
+    inp: &STALE_MINT,
+    out: WSOL_MINT.as_array(),
+};
+
+fn svcs_for_test() -> HashMap<[u8; 32], SvcAgStd> {
+    [
+        (
+            STALE_MINT,
+            SvcAgStd(SvcAg::Lido(LidoSvcStd {
+                // exchange rate last computed in epoch 0
+                calc: Some(LidoCalc {
+                    exchange_rate: Default::default(),
+                    current_epoch: 1,
+                }),
+                ..LidoSvcStd::DEFAULT
+            })),
+        ),
+        (WSOL_MINT.to_bytes(), SvcAgStd(SvcAg::Wsol(WsolSvcStd))),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn stale_setup(stale_svc_policy: StaleSvcPolicy) -> InfStd {
+    let mut inf = InfStd::new(
+        pool_state_fixture(),
+        lst_state_list_fixture()
+            .iter()
+            .flat_map(|s| *s.as_acc_data_arr())
+            .collect(),
+        Some(1_000_000_000),
+        None,
+        Default::default(),
+        svcs_for_test(),
+        Default::default(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap();
+    inf.stale_svc_policy = stale_svc_policy;
+    inf
+}
+
+#[test]
+fn trade_staleness_fixture() {
+    let inf = stale_setup(StaleSvcPolicy::Allow);
+    assert_eq!(
+        inf.trade_staleness(&STALE_INP_PAIR).unwrap(),
+        Pair {
+            inp: true,
+            out: false
+        }
+    );
+}
+
+#[test]
+fn quote_stale_rejected_fixture() {
+    const EXPECTED_ERR: InfErr = InfErr::StaleSvc { mint: STALE_MINT };
+
+    let mut inf = stale_setup(StaleSvcPolicy::Reject);
+
+    let ei = inf
+        .quote_exact_in(&STALE_INP_PAIR, DUMMY_AMT, DUMMY_SLOT_LOOKAHEAD)
+        .unwrap_err();
+    let eim = inf
+        .quote_exact_in_mut(&STALE_INP_PAIR, DUMMY_AMT, DUMMY_SLOT_LOOKAHEAD)
+        .unwrap_err();
+    let eo = inf
+        .quote_exact_out(&STALE_INP_PAIR, DUMMY_AMT, DUMMY_SLOT_LOOKAHEAD)
+        .unwrap_err();
+    let eom = inf
+        .quote_exact_out_mut(&STALE_INP_PAIR, DUMMY_AMT, DUMMY_SLOT_LOOKAHEAD)
+        .unwrap_err();
+    [ei, eim, eo, eom]
+        .into_iter()
+        .for_each(|e| assert_eq!(e, EXPECTED_ERR));
+}
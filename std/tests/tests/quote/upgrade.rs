@@ -0,0 +1,63 @@
+use inf1_pp_ag_std::update::all::Pair;
+use inf1_std::{err::InfErr, InfStd};
+use inf1_svc_ag_std::{
+    inf1_svc_generic::upgrade::{LastUpgradeSlots, UpgradeCheck},
+    inf1_svc_lido_std::{solido_legacy_core::STSOL_MINT_ADDR, LidoSvcStd},
+    inf1_svc_wsol_std::WsolSvcStd,
+    SvcAg, SvcAgStd,
+};
+use inf1_test_utils::WSOL_MINT;
+
+use crate::common::{create_pda, find_pda, lst_state_list_fixture, pool_state_fixture};
+
+const UPGRADED_MINT: [u8; 32] = STSOL_MINT_ADDR;
+const UPGRADED_INP_PAIR: Pair<&[u8; 32]> = Pair {
+    inp: &UPGRADED_MINT,
+    out: WSOL_MINT.as_array(),
+};
+
+#[test]
+fn quote_pool_prog_upgraded_fixture() {
+    const EXPECTED_ERR: InfErr = InfErr::PoolProgUpgraded {
+        mint: UPGRADED_MINT,
+    };
+
+    let inf = InfStd::new(
+        pool_state_fixture(),
+        lst_state_list_fixture()
+            .iter()
+            .flat_map(|s| *s.as_acc_data_arr())
+            .collect(),
+        None,
+        None,
+        Default::default(),
+        [
+            (
+                UPGRADED_MINT,
+                SvcAgStd(SvcAg::Lido(LidoSvcStd {
+                    calc: Some(Default::default()),
+                    // stake pool program upgraded after calculator program state was updated
+                    upgrade_check: Some(UpgradeCheck {
+                        slots: Some(LastUpgradeSlots {
+                            state: 1,
+                            pool_progdata: 2,
+                        }),
+                    }),
+                    ..LidoSvcStd::DEFAULT
+                })),
+            ),
+            (WSOL_MINT.to_bytes(), SvcAgStd(SvcAg::Wsol(WsolSvcStd))),
+        ]
+        .into(),
+        Default::default(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap();
+
+    let ei = inf.quote_exact_in(&UPGRADED_INP_PAIR, 1, 0).unwrap_err();
+    let eo = inf.quote_exact_out(&UPGRADED_INP_PAIR, 1, 0).unwrap_err();
+    [ei, eo]
+        .into_iter()
+        .for_each(|e| assert_eq!(e, EXPECTED_ERR));
+}
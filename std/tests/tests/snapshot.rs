@@ -0,0 +1,112 @@
+use inf1_std::{
+    err::InfErr,
+    inf1_ctl_core::{
+        accounts::pool_config::PoolConfig, typedefs::lst_calc_backing::LstCalcBacking,
+    },
+    snapshot::SNAPSHOT_VERSION,
+    trade::quote::StaleSvcPolicy,
+    update::LastUpdateSlot,
+    InfStd, Reserves,
+};
+use inf1_svc_ag_std::{
+    inf1_svc_inf_std::InfSvcStd,
+    inf1_svc_lido_std::LidoSvcStd,
+    inf1_svc_marinade_std::MarinadeSvcStd,
+    inf1_svc_spl_std::{calc::SplFeeMode, SanctumSplMultiSvcStd, SanctumSplSvcStd, SplSvcStd},
+    inf1_svc_wsol_std::WsolSvcStd,
+    SvcAg, SvcAgStd,
+};
+
+use crate::common::{create_pda, find_pda, lst_state_list_fixture, pool_state_fixture};
+
+fn inf_for_test() -> InfStd {
+    let mut spl = SplSvcStd::new([5; 32]);
+    spl.set_est_enabled(true);
+    spl.set_upgrade_check_enabled(true);
+    spl.set_fee_mode(SplFeeMode::WithdrawSol);
+
+    let svcs = [
+        SvcAg::Inf(InfSvcStd::DEFAULT),
+        SvcAg::Lido(LidoSvcStd {
+            calc: Some(Default::default()),
+            last_update_slot: LastUpdateSlot(Some(7)),
+            ..LidoSvcStd::DEFAULT
+        }),
+        SvcAg::Marinade(MarinadeSvcStd::DEFAULT),
+        SvcAg::SanctumSpl(SanctumSplSvcStd::new([3; 32])),
+        SvcAg::SanctumSplMulti(SanctumSplMultiSvcStd::new([4; 32])),
+        SvcAg::Spl(spl),
+        SvcAg::Wsol(WsolSvcStd),
+    ];
+
+    let mut inf = InfStd::new(
+        pool_state_fixture(),
+        lst_state_list_fixture()
+            .iter()
+            .flat_map(|s| *s.as_acc_data_arr())
+            .collect(),
+        Some(1_000_000_000),
+        None,
+        (0u8..3)
+            .map(|i| ([i; 32], Reserves { balance: i.into() }))
+            .collect(),
+        svcs.into_iter()
+            .zip(0u8..)
+            .map(|(svc, i)| ([i; 32], SvcAgStd(svc)))
+            .collect(),
+        [([3; 32], [3; 32]), ([4; 32], [4; 32])].into(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap();
+    inf.stale_svc_policy = StaleSvcPolicy::Reject;
+    inf.check_svc_upgrades = true;
+    inf.slots.pool = LastUpdateSlot(Some(100));
+    inf.slots.lst_reserves = (0u8..3)
+        .map(|i| ([i; 32], LastUpdateSlot(Some(i.into()))))
+        .collect();
+    inf.lst_calc_backing_list_data = LstCalcBacking {
+        mint: [6; 32],
+        backing: [7; 32],
+    }
+    .as_acc_data_arr()
+    .as_slice()
+    .into();
+    inf.slots.lst_calc_backing_list = LastUpdateSlot(Some(90));
+    inf.pool_config = PoolConfig {
+        admin_action_delay_slots: 216_000,
+        rebalance_max_loss_lamports: 1_000_000,
+    };
+    inf.slots.pool_config = LastUpdateSlot(Some(80));
+    inf
+}
+
+#[test]
+fn snapshot_round_trip() {
+    let inf = inf_for_test();
+    let bytes = inf.to_snapshot_bytes();
+    assert_eq!(bytes[0], SNAPSHOT_VERSION);
+
+    let restored = InfStd::from_snapshot_bytes(&bytes, find_pda, create_pda).unwrap();
+    assert_eq!(restored, inf);
+
+    // deterministic
+    assert_eq!(restored.to_snapshot_bytes(), bytes);
+}
+
+#[test]
+fn snapshot_rejects_invalid() {
+    let mut bytes = inf_for_test().to_snapshot_bytes();
+
+    let res = InfStd::from_snapshot_bytes(&bytes[..bytes.len() - 1], find_pda, create_pda);
+    assert_eq!(res.unwrap_err(), InfErr::SnapshotDeser);
+
+    bytes.push(0);
+    let res = InfStd::from_snapshot_bytes(&bytes, find_pda, create_pda);
+    assert_eq!(res.unwrap_err(), InfErr::SnapshotDeser);
+
+    bytes.pop();
+    bytes[0] = SNAPSHOT_VERSION + 1;
+    let res = InfStd::from_snapshot_bytes(&bytes, find_pda, create_pda);
+    assert_eq!(res.unwrap_err(), InfErr::SnapshotDeser);
+}
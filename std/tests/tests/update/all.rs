@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use inf1_std::{
+    inf1_ctl_core::keys::{LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID, POOL_STATE_ID},
+    inf1_pp_ag_std::update::all::AccountsToUpdateAll,
+    update::{UpdateErr, GET_MULTIPLE_ACCOUNTS_MAX_PKS},
+    InfStd,
+};
+use inf1_test_utils::{ALL_FIXTURES, JUPSOL_MINT, JUPSOL_POOL_ID};
+
+use crate::common::{
+    create_pda, find_pda, lst_state_list_fixture, pool_state_fixture, UpdateMapFixture,
+};
+
+fn setup() -> InfStd {
+    InfStd::new(
+        pool_state_fixture(),
+        lst_state_list_fixture()
+            .iter()
+            .flat_map(|s| *s.as_acc_data_arr())
+            .collect(),
+        None,
+        None,
+        Default::default(),
+        Default::default(),
+        [(JUPSOL_MINT.to_bytes(), JUPSOL_POOL_ID.to_bytes())].into(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap()
+}
+
+fn all_fixtures_update_map() -> UpdateMapFixture {
+    UpdateMapFixture(
+        ALL_FIXTURES
+            .iter()
+            .map(|(pk, acc)| (pk.to_bytes(), acc.data.clone()))
+            .collect(),
+    )
+}
+
+#[test]
+fn accounts_to_update_all_dedup_fixture() {
+    let mut inf = setup();
+    let all = inf.accounts_to_update_all_mut().unwrap();
+
+    assert_eq!(
+        all.pks[..3],
+        [POOL_STATE_ID, LST_STATE_LIST_ID, LST_CALC_BACKING_LIST_ID]
+    );
+    assert!(all
+        .batches()
+        .all(|batch| batch.len() <= GET_MULTIPLE_ACCOUNTS_MAX_PKS));
+    assert_eq!(all.batches().map(<[_]>::len).sum::<usize>(), all.pks.len());
+
+    let lsts = lst_state_list_fixture();
+    let mut expected: HashSet<_> = [
+        POOL_STATE_ID,
+        LST_STATE_LIST_ID,
+        LST_CALC_BACKING_LIST_ID,
+        *inf.pool.lp_token_mint(),
+    ]
+    .into();
+    let mut expected_errs = HashSet::new();
+    lsts.iter().for_each(
+        |lst_state| match inf.accounts_to_update_lst_mut(lst_state) {
+            Ok(iter) => expected.extend(iter),
+            Err(_) => {
+                expected_errs.insert(lst_state.mint);
+            }
+        },
+    );
+    expected.extend(
+        inf.pricing
+            .accounts_to_update_all(lsts.iter().map(|s| s.mint)),
+    );
+
+    let actual: HashSet<_> = all.pks.iter().copied().collect();
+    assert_eq!(actual.len(), all.pks.len(), "duplicate pks");
+    assert_eq!(actual, expected);
+    assert_eq!(
+        all.lst_errs.iter().map(|(m, _)| *m).collect::<HashSet<_>>(),
+        expected_errs
+    );
+    assert!(!expected_errs.contains(&JUPSOL_MINT.to_bytes()));
+}
+
+#[test]
+fn update_all_fixture() {
+    let mut inf = setup();
+    let report = inf.update_all(all_fixtures_update_map()).unwrap();
+
+    assert!(inf.lp_token_supply.is_some());
+    let errs: HashSet<_> = report.lst_errs.iter().map(|(m, _)| *m).collect();
+    assert!(!errs.contains(&JUPSOL_MINT.to_bytes()), "{report:?}");
+    // every LST is either updated or reported as failing
+    lst_state_list_fixture().iter().for_each(|lst_state| {
+        assert_ne!(
+            inf.lst_reserves.contains_key(&lst_state.mint),
+            errs.contains(&lst_state.mint),
+            "{report:?}"
+        );
+    });
+}
+
+#[test]
+fn update_all_pricing_err_does_not_abort_fixture() {
+    let mut inf = setup();
+    let lsts = lst_state_list_fixture();
+    let pricing_pks: HashSet<_> = inf
+        .pricing
+        .accounts_to_update_all(lsts.iter().map(|s| s.mint))
+        .collect();
+    let mut fetched = all_fixtures_update_map();
+    fetched.0.retain(|pk, _| !pricing_pks.contains(pk));
+
+    let report = inf.update_all(fetched).unwrap();
+
+    assert!(
+        matches!(report.pricing_err, Some(UpdateErr::AccMissing { pk }) if pricing_pks.contains(&pk)),
+        "{report:?}"
+    );
+    // pool and LSTs still updated
+    assert!(inf.lp_token_supply.is_some());
+    assert!(inf.lst_reserves.contains_key(&JUPSOL_MINT.to_bytes()));
+}
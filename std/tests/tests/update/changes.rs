@@ -0,0 +1,115 @@
+use inf1_std::{
+    inf1_ctl_core::{
+        keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
+        typedefs::lst_state::LstState,
+    },
+    update::{LastUpdateSlot, LstStateListChanges, PoolChanges},
+    InfStd, Reserves,
+};
+use inf1_svc_ag_std::{inf1_svc_wsol_std::WsolSvcStd, SvcAg, SvcAgStd};
+use inf1_test_utils::bool_to_u8;
+
+use crate::common::{
+    create_pda, find_pda, lst_state_list_fixture, pool_state_fixture, UpdateMapFixture,
+};
+
+const ADDED_MINT: [u8; 32] = [7; 32];
+const NEW_SVC_PROG: [u8; 32] = [9; 32];
+
+fn lst_state_list_data(list: &[LstState]) -> Vec<u8> {
+    list.iter().flat_map(|s| *s.as_acc_data_arr()).collect()
+}
+
+fn setup(removed_mint: &[u8; 32], svc_changed_mint: &[u8; 32]) -> InfStd {
+    InfStd::new(
+        pool_state_fixture(),
+        lst_state_list_data(&lst_state_list_fixture()).into(),
+        Some(1_000_000_000),
+        None,
+        [(*removed_mint, Reserves { balance: 1 })].into(),
+        [
+            (*removed_mint, SvcAgStd(SvcAg::Wsol(WsolSvcStd))),
+            (*svc_changed_mint, SvcAgStd(SvcAg::Wsol(WsolSvcStd))),
+        ]
+        .into(),
+        [(*removed_mint, [1; 32])].into(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap()
+}
+
+#[test]
+fn update_lst_state_list_changes_fixture() {
+    let mut list = lst_state_list_fixture();
+    let removed = list.remove(0);
+    let was_input_disabled = list[0].is_input_disabled != 0;
+    list[0].is_input_disabled = bool_to_u8(!was_input_disabled);
+    list[1].sol_value_calculator = NEW_SVC_PROG;
+    let mut added = list[2];
+    added.mint = ADDED_MINT;
+    list.push(added);
+
+    let mut inf = setup(&removed.mint, &list[1].mint);
+    inf.slots
+        .lst_reserves
+        .insert(removed.mint, LastUpdateSlot(Some(1)));
+    let changes = inf
+        .update_lst_state_list(UpdateMapFixture(
+            [(LST_STATE_LIST_ID, lst_state_list_data(&list))].into(),
+        ))
+        .unwrap();
+
+    let (input_disabled, input_enabled) = if was_input_disabled {
+        (vec![], vec![list[0].mint])
+    } else {
+        (vec![list[0].mint], vec![])
+    };
+    assert_eq!(
+        changes,
+        LstStateListChanges {
+            added: vec![ADDED_MINT],
+            removed: vec![removed.mint],
+            svc_prog: vec![list[1].mint],
+            input_disabled,
+            input_enabled,
+        }
+    );
+    assert!(!inf.lst_reserves.contains_key(&removed.mint));
+    assert!(!inf.slots.lst_reserves.contains_key(&removed.mint));
+    assert!(!inf.lst_calcs.contains_key(&removed.mint));
+    assert!(!inf.spl_lsts.contains_key(&removed.mint));
+    // stale calculator data of LST whose calculator program changed is evicted
+    assert!(!inf.lst_calcs.contains_key(&list[1].mint));
+
+    // no-op update
+    let changes = inf
+        .update_lst_state_list(UpdateMapFixture(
+            [(LST_STATE_LIST_ID, lst_state_list_data(&list))].into(),
+        ))
+        .unwrap();
+    assert!(changes.is_empty());
+}
+
+#[test]
+fn update_pool_changes_fixture() {
+    let mut pool = pool_state_fixture();
+    let was_disabled = *pool.is_disabled() != 0;
+    *pool.is_disabled_mut() = bool_to_u8(!was_disabled);
+
+    let mut inf = setup(&[0; 32], &[1; 32]);
+    let fetched = UpdateMapFixture([(POOL_STATE_ID, pool.as_acc_data_arr().to_vec())].into());
+
+    let changes = inf.update_pool(&fetched).unwrap();
+    assert_eq!(
+        changes,
+        PoolChanges {
+            pricing_prog: false,
+            is_disabled: Some(!was_disabled),
+            is_rebalancing: None,
+        }
+    );
+
+    let changes = inf.update_pool(&fetched).unwrap();
+    assert!(changes.is_empty());
+}
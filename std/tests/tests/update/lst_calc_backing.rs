@@ -0,0 +1,103 @@
+use inf1_std::{
+    err::InfErr,
+    inf1_ctl_core::{
+        keys::{LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID},
+        typedefs::{lst_calc_backing::LstCalcBacking, lst_state::LstState},
+    },
+    InfStd,
+};
+use inf1_svc_ag_std::{SvcAg, SvcAgStd, SvcAgTy};
+
+use crate::common::{
+    create_pda, find_pda, lst_state_list_fixture, pool_state_fixture, UpdateMapFixture,
+};
+
+const SPL_MINT: [u8; 32] = [7; 32];
+const STAKE_POOL: [u8; 32] = [8; 32];
+const OFFCHAIN_STAKE_POOL: [u8; 32] = [9; 32];
+
+fn spl_lst_state() -> LstState {
+    LstState {
+        mint: SPL_MINT,
+        sol_value_calculator: *SvcAgTy::Spl(()).svc_program_id(),
+        ..lst_state_list_fixture()[0]
+    }
+}
+
+fn setup(spl_lsts: &[([u8; 32], [u8; 32])]) -> InfStd {
+    InfStd::new(
+        pool_state_fixture(),
+        (*spl_lst_state().as_acc_data_arr()).into(),
+        None,
+        None,
+        Default::default(),
+        Default::default(),
+        spl_lsts.iter().copied().collect(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap()
+}
+
+fn fetched_with_backing() -> UpdateMapFixture {
+    UpdateMapFixture(
+        [
+            (
+                LST_STATE_LIST_ID,
+                spl_lst_state().as_acc_data_arr().to_vec(),
+            ),
+            (
+                LST_CALC_BACKING_LIST_ID,
+                LstCalcBacking {
+                    mint: SPL_MINT,
+                    backing: STAKE_POOL,
+                }
+                .as_acc_data_arr()
+                .to_vec(),
+            ),
+        ]
+        .into(),
+    )
+}
+
+#[test]
+fn init_spl_svc_from_onchain_backing() {
+    let mut inf = setup(&[]);
+    assert_eq!(
+        inf.try_get_or_init_lst_svc(&spl_lst_state()).unwrap_err(),
+        InfErr::MissingSplData { mint: SPL_MINT }
+    );
+
+    inf.update_lst_state_list(fetched_with_backing()).unwrap();
+    assert_eq!(
+        *inf.try_get_or_init_lst_svc(&spl_lst_state()).unwrap(),
+        SvcAgStd::new(SvcAg::Spl(STAKE_POOL))
+    );
+}
+
+#[test]
+fn onchain_backing_takes_precedence_over_spl_lsts() {
+    let mut inf = setup(&[(SPL_MINT, OFFCHAIN_STAKE_POOL)]);
+    inf.update_lst_state_list(fetched_with_backing()).unwrap();
+    assert_eq!(
+        *inf.try_get_or_init_lst_svc(&spl_lst_state()).unwrap(),
+        SvcAgStd::new(SvcAg::Spl(STAKE_POOL))
+    );
+}
+
+#[test]
+fn spl_lsts_fallback_without_onchain_backing() {
+    let mut inf = setup(&[(SPL_MINT, OFFCHAIN_STAKE_POOL)]);
+    inf.update_lst_state_list(UpdateMapFixture(
+        [(
+            LST_STATE_LIST_ID,
+            spl_lst_state().as_acc_data_arr().to_vec(),
+        )]
+        .into(),
+    ))
+    .unwrap();
+    assert_eq!(
+        *inf.try_get_or_init_lst_svc(&spl_lst_state()).unwrap(),
+        SvcAgStd::new(SvcAg::Spl(OFFCHAIN_STAKE_POOL))
+    );
+}
@@ -0,0 +1,4 @@
+mod all;
+mod changes;
+mod lst_calc_backing;
+mod slot;
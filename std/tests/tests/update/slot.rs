@@ -0,0 +1,115 @@
+use inf1_std::{
+    inf1_ctl_core::{accounts::pool_state::VerPoolState, keys::POOL_STATE_ID},
+    inf1_pp_ag_std::update::UpdatePricingProg,
+    InfStd,
+};
+use inf1_svc_ag_std::{
+    inf1_svc_spl_std::SanctumSplMultiSvcStd, update::UpdateSvc, SvcAg, SvcAgStd,
+};
+use inf1_test_utils::{bool_to_u8, ALL_FIXTURES, JUPSOL_MINT, JUPSOL_POOL_ID};
+
+use crate::common::{
+    create_pda, find_pda, lst_state_list_fixture, pool_state_fixture, SlottedUpdateMapFixture,
+};
+
+fn setup() -> InfStd {
+    InfStd::new(
+        pool_state_fixture(),
+        lst_state_list_fixture()
+            .iter()
+            .flat_map(|s| *s.as_acc_data_arr())
+            .collect(),
+        None,
+        None,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        find_pda,
+        create_pda,
+    )
+    .unwrap()
+}
+
+#[test]
+fn update_pool_refuses_older_slot_fixture() {
+    let old = pool_state_fixture();
+    let mut new = pool_state_fixture();
+    *new.is_disabled_mut() = bool_to_u8(*old.is_disabled() == 0);
+    let fetched = |pool: &VerPoolState, slot| {
+        SlottedUpdateMapFixture([(POOL_STATE_ID, (pool.as_acc_data_arr().to_vec(), slot))].into())
+    };
+
+    let mut inf = setup();
+    assert!(!inf.update_pool(fetched(&new, 10)).unwrap().is_empty());
+    assert_eq!(inf.slots.pool.0, Some(10));
+
+    // older data is ignored
+    assert!(inf.update_pool(fetched(&old, 9)).unwrap().is_empty());
+    assert_eq!(inf.pool, new);
+    assert_eq!(inf.slots.pool.0, Some(10));
+
+    // same or newer data is applied
+    assert!(!inf.update_pool(fetched(&old, 10)).unwrap().is_empty());
+    assert_eq!(inf.pool, old);
+}
+
+/// All fixture accounts at `slot`.
+///
+/// If `corrupt`, all account data is emptied so that
+/// any update that reads them fails.
+fn all_fixtures_at(slot: u64, corrupt: bool) -> SlottedUpdateMapFixture {
+    SlottedUpdateMapFixture(
+        ALL_FIXTURES
+            .iter()
+            .map(|(pk, acc)| {
+                let data = if corrupt { vec![] } else { acc.data.clone() };
+                (pk.to_bytes(), (data, slot))
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn update_svc_refuses_older_slot_fixture() {
+    let mut svc = SvcAgStd(SvcAg::SanctumSplMulti(SanctumSplMultiSvcStd::new(
+        JUPSOL_POOL_ID.to_bytes(),
+    )));
+    svc.update_svc(all_fixtures_at(10, false)).unwrap();
+    assert_eq!(svc.last_update_slot(), Some(10));
+    let bef = svc;
+
+    // older data is ignored
+    svc.update_svc(all_fixtures_at(9, true)).unwrap();
+    assert_eq!(svc, bef);
+
+    // same or newer data is applied
+    assert!(svc.update_svc(all_fixtures_at(10, true)).is_err());
+    svc.update_svc(all_fixtures_at(11, false)).unwrap();
+    assert_eq!(svc.last_update_slot(), Some(11));
+}
+
+#[test]
+fn update_pricing_refuses_older_slot_fixture() {
+    let mut inf = setup();
+    let jupsol = JUPSOL_MINT.to_bytes();
+    inf.pricing
+        .update_redeem_lp(&jupsol, all_fixtures_at(10, false))
+        .unwrap();
+    assert_eq!(inf.pricing.last_update_slot(), Some(10));
+
+    // older data is ignored
+    inf.pricing
+        .update_redeem_lp(&jupsol, all_fixtures_at(9, true))
+        .unwrap();
+    assert_eq!(inf.pricing.last_update_slot(), Some(10));
+
+    // same or newer data is applied
+    assert!(inf
+        .pricing
+        .update_redeem_lp(&jupsol, all_fixtures_at(10, true))
+        .is_err());
+    inf.pricing
+        .update_redeem_lp(&jupsol, all_fixtures_at(11, false))
+        .unwrap();
+    assert_eq!(inf.pricing.last_update_slot(), Some(11));
+}
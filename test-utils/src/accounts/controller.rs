@@ -1,5 +1,13 @@
 use inf1_ctl_core::{
-    accounts::lst_state_list::LstStatePackedList, keys::SYS_PROG_ID, typedefs::lst_state::LstState,
+    accounts::{
+        lst_calc_backing_list::LstCalcBackingList, lst_state_list::LstStatePackedList,
+        protocol_fee_beneficiary_list::ProtocolFeeBeneficiaryList,
+    },
+    keys::SYS_PROG_ID,
+    typedefs::{
+        lst_calc_backing::LstCalcBacking, lst_state::LstState,
+        protocol_fee_beneficiary_share::ProtocolFeeBeneficiaryShare,
+    },
 };
 use jiminy_sysvar_rent::Rent;
 use solana_account::Account;
@@ -33,3 +41,29 @@ pub fn get_lst_state_list(lst_state_list_data: &[u8]) -> Vec<LstState> {
         .map(|s| s.into_lst_state())
         .collect()
 }
+
+pub fn lst_calc_backing_list_account(entries: &[LstCalcBacking]) -> Account {
+    // same rent + ownership rules as lst state list
+    lst_state_list_account(entries.iter().flat_map(|e| *e.as_acc_data_arr()).collect())
+}
+
+pub fn get_lst_calc_backing_list(lst_calc_backing_list_data: &[u8]) -> Vec<LstCalcBacking> {
+    LstCalcBackingList::of_acc_data(lst_calc_backing_list_data)
+        .unwrap()
+        .0
+        .to_vec()
+}
+
+pub fn protocol_fee_beneficiary_list_account(entries: &[ProtocolFeeBeneficiaryShare]) -> Account {
+    // same rent + ownership rules as lst state list
+    lst_state_list_account(entries.iter().flat_map(|e| *e.as_acc_data_arr()).collect())
+}
+
+pub fn get_protocol_fee_beneficiary_list(
+    protocol_fee_beneficiary_list_data: &[u8],
+) -> Vec<ProtocolFeeBeneficiaryShare> {
+    ProtocolFeeBeneficiaryList::of_acc_data(protocol_fee_beneficiary_list_data)
+        .unwrap()
+        .0
+        .to_vec()
+}
@@ -5,8 +5,15 @@ use inf1_ctl_core::{
 
 use crate::{gas_diff_zip_assert, u8_to_bool, Diff};
 
-pub type DiffsPoolStateV2 =
-    PoolStateV2Fta<Diff<[u8; 32]>, Diff<u64>, Diff<bool>, Diff<FeeNanos>, Diff<Rps>>;
+pub type DiffsPoolStateV2 = PoolStateV2Fta<
+    Diff<[u8; 32]>,
+    Diff<u64>,
+    Diff<bool>,
+    Diff<FeeNanos>,
+    Diff<Rps>,
+    Diff<u8>,
+    Diff<u32>,
+>;
 
 pub fn assert_diffs_pool_state_v2(
     DiffsPoolStateV2 {
@@ -15,6 +22,9 @@ pub fn assert_diffs_pool_state_v2(
         u8_bools,
         protocol_fee_nanos,
         rps,
+        disable_modes,
+        min_fee_nanos,
+        sol_value_circuit_breaker_nanos,
     }: &DiffsPoolStateV2,
     bef: &PoolStateV2,
     aft: &PoolStateV2,
@@ -25,12 +35,18 @@ pub fn assert_diffs_pool_state_v2(
         u8_bools: bef_u8_bools,
         protocol_fee_nanos: bef_protocol_fee_nanos,
         rps: bef_rps,
+        disable_modes: bef_disable_modes,
+        min_fee_nanos: bef_min_fee_nanos,
+        sol_value_circuit_breaker_nanos: bef_sol_value_circuit_breaker_nanos,
     }, PoolStateV2FtaVals {
         addrs: aft_addrs,
         u64s: aft_u64s,
         u8_bools: aft_u8_bools,
         protocol_fee_nanos: aft_protocol_fee_nanos,
         rps: aft_rps,
+        disable_modes: aft_disable_modes,
+        min_fee_nanos: aft_min_fee_nanos,
+        sol_value_circuit_breaker_nanos: aft_sol_value_circuit_breaker_nanos,
     }] = [bef, aft].map(|p| PoolStateV2FtaVals::try_from_pool_state_v2(*p).unwrap());
 
     let [bef_u8_bools, aft_u8_bools] =
@@ -41,4 +57,10 @@ pub fn assert_diffs_pool_state_v2(
     gas_diff_zip_assert!(u8_bools, bef_u8_bools, aft_u8_bools);
     protocol_fee_nanos.assert(&bef_protocol_fee_nanos, &aft_protocol_fee_nanos);
     rps.assert(&bef_rps, &aft_rps);
+    disable_modes.assert(&bef_disable_modes, &aft_disable_modes);
+    min_fee_nanos.assert(&bef_min_fee_nanos, &aft_min_fee_nanos);
+    sol_value_circuit_breaker_nanos.assert(
+        &bef_sol_value_circuit_breaker_nanos,
+        &aft_sol_value_circuit_breaker_nanos,
+    );
 }
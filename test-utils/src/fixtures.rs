@@ -7,8 +7,8 @@ use std::{
 
 use glob::glob;
 use inf1_ctl_core::keys::{
-    DISABLE_POOL_AUTHORITY_LIST_ID, LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID,
-    TOKEN_2022_ID,
+    DISABLE_POOL_AUTHORITY_LIST_ID, LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID, POOL_STATE_ID,
+    REBALANCE_RECORD_ID, TOKEN_2022_ID,
 };
 use inf1_svc_lido_core::solido_legacy_core::SYSVAR_CLOCK;
 use lazy_static::lazy_static;
@@ -161,6 +161,7 @@ lazy_static! {
         POOL_STATE_ID,
         LST_STATE_LIST_ID,
         DISABLE_POOL_AUTHORITY_LIST_ID,
+        LST_CALC_BACKING_LIST_ID,
         REBALANCE_RECORD_ID,
     ]
     .into_iter()
@@ -0,0 +1,24 @@
+use inf1_ctl_core::{accounts::inf_price_history::InfPriceHistory, keys::SYS_PROG_ID};
+use jiminy_sysvar_rent::Rent;
+use solana_account::Account;
+use solana_pubkey::Pubkey;
+
+/// `None` for an uncreated PDA owned by the system program
+pub fn inf_price_history_account(history: Option<&InfPriceHistory>) -> Account {
+    match history {
+        None => Account {
+            lamports: 0,
+            data: vec![],
+            owner: Pubkey::new_from_array(SYS_PROG_ID),
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+        Some(h) => Account {
+            lamports: Rent::DEFAULT.min_balance(h.as_acc_data_arr().len()),
+            data: h.as_acc_data_arr().into(),
+            owner: Pubkey::new_from_array(inf1_ctl_core::ID),
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    }
+}
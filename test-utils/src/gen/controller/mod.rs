@@ -1,7 +1,15 @@
+mod inf_price_history;
 mod packed_list;
+mod pending_admin_action;
+mod pool_config;
 mod pool_state;
+mod referral_config;
 mod typedefs;
 
+pub use inf_price_history::*;
 pub use packed_list::*;
+pub use pending_admin_action::*;
+pub use pool_config::*;
 pub use pool_state::*;
+pub use referral_config::*;
 pub use typedefs::*;
@@ -7,7 +7,10 @@ use std::{
 use generic_array_struct::generic_array_struct;
 use inf1_ctl_core::{
     accounts::lst_state_list::{LstStatePackedList, LstStatePackedListMut},
-    typedefs::lst_state::{LstState, LstStatePacked},
+    typedefs::{
+        compact_u64::CompactU64,
+        lst_state::{LstState, LstStatePacked},
+    },
 };
 use inf1_svc_lido_core::solido_legacy_core::TOKENKEG_PROGRAM;
 use proptest::{collection::vec, prelude::*};
@@ -72,7 +75,7 @@ pub fn gen_lst_state(
             is_input_disabled: bool_to_u8(is_input_disabled),
             pool_reserves_bump: *bumps.pool_reserves_bump(),
             protocol_fee_accumulator_bump: *bumps.protocol_fee_accumulator_bump(),
-            padding: [0u8; 5],
+            synced_reserves_balance: CompactU64::ZERO,
             sol_value,
             mint: *pks.mint(),
             sol_value_calculator: *pks.sol_value_calculator(),
@@ -255,7 +258,7 @@ fn lst_state_to_gen_args(
         sol_value,
         mint,
         sol_value_calculator,
-        padding: _,
+        synced_reserves_balance: _,
     }: &LstState,
 ) -> GenLstStateArgs {
     GenLstStateArgs {
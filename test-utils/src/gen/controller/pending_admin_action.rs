@@ -0,0 +1,24 @@
+use inf1_ctl_core::{accounts::pending_admin_action::PendingAdminAction, keys::SYS_PROG_ID};
+use jiminy_sysvar_rent::Rent;
+use solana_account::Account;
+use solana_pubkey::Pubkey;
+
+/// `None` for an uncreated PDA owned by the system program
+pub fn pending_admin_action_account(action: Option<&PendingAdminAction>) -> Account {
+    match action {
+        None => Account {
+            lamports: 0,
+            data: vec![],
+            owner: Pubkey::new_from_array(SYS_PROG_ID),
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+        Some(a) => Account {
+            lamports: Rent::DEFAULT.min_balance(a.as_acc_data_arr().len()),
+            data: a.as_acc_data_arr().into(),
+            owner: Pubkey::new_from_array(inf1_ctl_core::ID),
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    }
+}
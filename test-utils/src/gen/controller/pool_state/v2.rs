@@ -19,13 +19,17 @@ use crate::{
     u64_strat,
 };
 
-/// If `Option::None`, `any()` is used
+/// If `Option::None`, `any()` is used,
+/// except for `disable_modes`, which defaults to nothing disabled,
+/// and `min_fee_nanos` and `sol_value_circuit_breaker_nanos`, which default to 0
 pub type PoolStateV2FtaStrat = PoolStateV2Fta<
     Option<BoxedStrategy<[u8; 32]>>,
     Option<BoxedStrategy<u64>>,
     Option<BoxedStrategy<bool>>,
     Option<BoxedStrategy<FeeNanos>>,
     Option<BoxedStrategy<Rps>>,
+    Option<BoxedStrategy<u8>>,
+    Option<BoxedStrategy<u32>>,
 >;
 
 /// Not disabled, not rebalancing
@@ -84,6 +88,9 @@ pub fn any_pool_state_v2(
         u8_bools,
         protocol_fee_nanos,
         rps,
+        disable_modes,
+        min_fee_nanos,
+        sol_value_circuit_breaker_nanos,
     }: PoolStateV2FtaStrat,
 ) -> impl Strategy<Value = PoolStateV2> {
     let u64s = u64s.0.map(u64_strat);
@@ -92,18 +99,60 @@ pub fn any_pool_state_v2(
     let protocol_fee_nanos =
         protocol_fee_nanos.unwrap_or_else(|| any_ctl_fee_nanos_strat().boxed());
     let rps = rps.unwrap_or_else(|| any_rps_strat().boxed());
-    (u64s, bools, addrs, protocol_fee_nanos, rps).prop_map(
-        |(u64s, bools, addrs, protocol_fee_nanos, rps)| {
-            PoolStateV2FtaVals {
-                addrs: PoolStateV2Addrs(addrs),
-                u64s: PoolStateV2U64s(u64s),
-                u8_bools: PoolStateV2U8Bools(bools.map(bool_to_u8)),
+    let disable_modes = disable_modes.unwrap_or_else(|| Just(0).boxed());
+    let min_fee_nanos = min_fee_nanos.unwrap_or_else(|| Just(FeeNanos::ZERO).boxed());
+    let sol_value_circuit_breaker_nanos =
+        sol_value_circuit_breaker_nanos.unwrap_or_else(|| Just(0).boxed());
+    (
+        u64s,
+        bools,
+        addrs,
+        protocol_fee_nanos,
+        rps,
+        disable_modes,
+        min_fee_nanos,
+        sol_value_circuit_breaker_nanos,
+    )
+        .prop_map(
+            |(
+                u64s,
+                bools,
+                addrs,
                 protocol_fee_nanos,
                 rps,
-            }
-            .into_pool_state_v2()
-        },
-    )
+                disable_modes,
+                min_fee_nanos,
+                sol_value_circuit_breaker_nanos,
+            )| {
+                PoolStateV2FtaVals {
+                    addrs: PoolStateV2Addrs(addrs),
+                    u64s: PoolStateV2U64s(u64s),
+                    u8_bools: PoolStateV2U8Bools(bools.map(bool_to_u8)),
+                    protocol_fee_nanos,
+                    rps,
+                    disable_modes,
+                    min_fee_nanos,
+                    sol_value_circuit_breaker_nanos,
+                }
+                .into_pool_state_v2()
+            },
+        )
+}
+
+/// Not disabled, not rebalancing, no yield released since slot 0,
+/// and `withheld_lamports + protocol_fee_lamports <= total_sol_value`
+/// so that INF has a well-defined SOL value
+pub fn solvent_pool_state_v2_strat() -> impl Strategy<Value = PoolStateV2> {
+    any_pool_state_v2(PoolStateV2FtaStrat {
+        u8_bools: pool_state_v2_u8_bools_normal_strat(),
+        u64s: PoolStateV2U64s::default().with_last_release_slot(Some(Just(0).boxed())),
+        ..Default::default()
+    })
+    .prop_filter("LP solvent", |ps| {
+        ps.withheld_lamports
+            .checked_add(ps.protocol_fee_lamports)
+            .is_some_and(|x| x <= ps.total_sol_value)
+    })
 }
 
 pub fn pool_state_v2_account(data: PoolStateV2) -> Account {
@@ -1,6 +1,9 @@
 use inf1_std::inf1_ctl_core::{
-    accounts::{lst_state_list::LstStatePackedList, pool_state::VerPoolState},
-    keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    accounts::{
+        lst_calc_backing_list::LstCalcBackingList, lst_state_list::LstStatePackedList,
+        pool_state::VerPoolState,
+    },
+    keys::{LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID, POOL_STATE_ID},
     typedefs::versioned::V1_2,
 };
 use wasm_bindgen::prelude::*;
@@ -102,3 +105,23 @@ pub fn deser_lst_state_list(inf: &mut Inf, lst_state_list_data: Box<[u8]>) -> Re
 
     Ok(())
 }
+
+/// Set the data of the controller's `LstCalcBackingList` account,
+/// which records the stake pool account of SPL LSTs onchain.
+///
+/// @throws if `lst_calc_backing_list_data` is invalid
+#[wasm_bindgen(js_name = deserLstCalcBackingList)]
+pub fn deser_lst_calc_backing_list(
+    inf: &mut Inf,
+    lst_calc_backing_list_data: Box<[u8]>,
+) -> Result<(), InfError> {
+    LstCalcBackingList::of_acc_data(&lst_calc_backing_list_data).ok_or(
+        inf1_std::err::InfErr::AccDeser {
+            pk: LST_CALC_BACKING_LIST_ID,
+        },
+    )?;
+
+    inf.0.lst_calc_backing_list_data = lst_calc_backing_list_data;
+
+    Ok(())
+}
@@ -1,11 +1,19 @@
+use std::iter::repeat;
+
 use bs58_fixed_wasm::Bs58Array;
 use inf1_std::inf1_ctl_core::{
     self,
-    instructions::protocol_fee::withdraw_protocol_fees::v2::{
-        NewWithdrawProtocolFeesV2IxAccsBuilder, WithdrawProtocolFeesV2IxData,
-        WITHDRAW_PROTOCOL_FEES_V2_IX_IS_SIGNER, WITHDRAW_PROTOCOL_FEES_V2_IX_IS_WRITER,
+    instructions::protocol_fee::withdraw_protocol_fees::{
+        split::{
+            NewWithdrawProtocolFeesSplitIxAccsBuilder, WithdrawProtocolFeesSplitIxData,
+            WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_SIGNER, WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_WRITER,
+        },
+        v2::{
+            NewWithdrawProtocolFeesV2IxAccsBuilder, WithdrawProtocolFeesV2IxData,
+            WITHDRAW_PROTOCOL_FEES_V2_IX_IS_SIGNER, WITHDRAW_PROTOCOL_FEES_V2_IX_IS_WRITER,
+        },
     },
-    keys::POOL_STATE_ID,
+    keys::{POOL_STATE_ID, PROTOCOL_FEE_BENEFICIARY_LIST_ID},
 };
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
@@ -44,6 +52,7 @@ pub fn withdraw_protocol_fees_v2_ix_raw(
         .with_withdraw_to(*withdraw_to)
         .with_inf_mint(*inf_mint)
         .with_token_program(*token_program)
+        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
         .build();
 
     Ok(Instruction {
@@ -56,3 +65,50 @@ pub fn withdraw_protocol_fees_v2_ix_raw(
         program_address: B58PK::new(inf1_ctl_core::ID),
     })
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawProtocolFeesSplitArgs {
+    /// INF token accounts to mint each beneficiary's share to,
+    /// one per entry of the protocol fee beneficiary list, in list order
+    pub withdraw_tos: Box<[B58PK]>,
+    pub inf_mint: B58PK,
+    pub token_program: B58PK,
+}
+
+/// @throws
+#[wasm_bindgen(js_name = withdrawProtocolFeesSplitIxRaw)]
+pub fn withdraw_protocol_fees_split_ix_raw(
+    WithdrawProtocolFeesSplitArgs {
+        withdraw_tos,
+        inf_mint: Bs58Array(inf_mint),
+        token_program: Bs58Array(token_program),
+    }: &WithdrawProtocolFeesSplitArgs,
+) -> Result<Instruction, InfError> {
+    let keys = NewWithdrawProtocolFeesSplitIxAccsBuilder::start()
+        .with_pool_state(POOL_STATE_ID)
+        .with_protocol_fee_beneficiary_list(PROTOCOL_FEE_BENEFICIARY_LIST_ID)
+        .with_inf_mint(*inf_mint)
+        .with_token_program(*token_program)
+        .build();
+
+    Ok(Instruction {
+        data: ByteBuf::from(WithdrawProtocolFeesSplitIxData::as_buf()),
+        // suffix withdraw_tos are all writable non-signers
+        accounts: keys_signer_writable_to_metas(
+            keys.0
+                .iter()
+                .chain(withdraw_tos.iter().map(|Bs58Array(pk)| pk)),
+            WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_SIGNER
+                .0
+                .iter()
+                .chain(repeat(&false)),
+            WITHDRAW_PROTOCOL_FEES_SPLIT_IX_IS_WRITER
+                .0
+                .iter()
+                .chain(repeat(&true)),
+        ),
+        program_address: B58PK::new(inf1_ctl_core::ID),
+    })
+}
@@ -0,0 +1,55 @@
+//! Compute unit estimates
+
+use bs58_fixed_wasm::Bs58Array;
+use inf1_std::{inf1_pp_core::pair::Pair, trade::TradeLimitTy};
+use wasm_bindgen::prelude::*;
+
+use crate::{err::InfError, interface::PkPair, Inf};
+
+/// Estimated compute units consumed by the instruction returned by `tradeExactInIx`.
+///
+/// Does not include any headroom.
+///
+/// @throws
+#[wasm_bindgen(js_name = estimateTradeExactInCus)]
+pub fn estimate_trade_exact_in_cus(inf: &Inf, mints: &PkPair) -> Result<u32, InfError> {
+    estimate_trade_cus(inf, mints, TradeLimitTy::ExactIn(()))
+}
+
+/// Estimated compute units consumed by the instruction returned by `tradeExactOutIx`.
+///
+/// Does not include any headroom.
+///
+/// @throws
+#[wasm_bindgen(js_name = estimateTradeExactOutCus)]
+pub fn estimate_trade_exact_out_cus(inf: &Inf, mints: &PkPair) -> Result<u32, InfError> {
+    estimate_trade_cus(inf, mints, TradeLimitTy::ExactOut(()))
+}
+
+/// Estimated compute units consumed by the StartRebalance and EndRebalance instructions
+/// returned by `rebalanceIxs`, excluding any instructions in between.
+///
+/// Does not include any headroom.
+///
+/// @throws
+#[wasm_bindgen(js_name = estimateRebalanceCus)]
+pub fn estimate_rebalance_cus(
+    inf: &Inf,
+    PkPair {
+        inp: Bs58Array(inp),
+        out: Bs58Array(out),
+    }: &PkPair,
+) -> Result<u32, InfError> {
+    Ok(inf.0.estimate_rebalance_cus(&Pair { inp, out })?)
+}
+
+fn estimate_trade_cus(
+    inf: &Inf,
+    PkPair {
+        inp: Bs58Array(inp),
+        out: Bs58Array(out),
+    }: &PkPair,
+    limit_ty: TradeLimitTy,
+) -> Result<u32, InfError> {
+    Ok(inf.0.estimate_cus(&Pair { inp, out }, limit_ty)?)
+}
@@ -6,7 +6,7 @@ use std::convert::Infallible;
 use bs58_fixed::Bs58String;
 use inf1_std::{
     err::{InfErr as InfStdErr, NotEnoughLiquidityErr},
-    inf1_ctl_core::err::Inf1CtlErr,
+    inf1_ctl_core::{err::Inf1CtlErr, typedefs::disable_modes::DisableMode},
     inf1_pp_ag_std::{
         inf1_pp_flatfee_std::{
             pricing::err::FlatFeePricingErr, traits::FlatFeePricingColErr,
@@ -179,6 +179,20 @@ fn lst_input_disabled_err() -> InfError {
     }
 }
 
+fn pool_op_disabled_err(mode: DisableMode) -> InfError {
+    InfError {
+        code: InfErr::PoolErr,
+        cause: Some(format!("pool {} disabled", mode.as_str())),
+    }
+}
+
+pub(crate) fn snapshot_deser_err() -> InfError {
+    InfError {
+        code: InfErr::AccDeserErr,
+        cause: Some("snapshot not of expected format or version".to_owned()),
+    }
+}
+
 pub(crate) fn missing_acc_err(pk: &[u8; 32]) -> InfError {
     let pk = Bs58PkString::encode(pk);
     InfError {
@@ -203,6 +217,22 @@ pub(crate) fn missing_svc_data_err(mint: &[u8; 32]) -> InfError {
     }
 }
 
+pub(crate) fn stale_svc_err(mint: &[u8; 32]) -> InfError {
+    let mint = Bs58PkString::encode(mint);
+    InfError {
+        code: InfErr::PoolErr,
+        cause: Some(format!("sol value calculator data for mint {mint} is stale")),
+    }
+}
+
+pub(crate) fn pool_prog_upgraded_err(mint: &[u8; 32]) -> InfError {
+    let mint = Bs58PkString::encode(mint);
+    InfError {
+        code: InfErr::PoolErr,
+        cause: Some(format!("stake pool program of mint {mint} was upgraded")),
+    }
+}
+
 pub(crate) fn unknown_pp_err(program_id: &[u8; 32]) -> InfError {
     let program_id = Bs58PkString::encode(program_id);
     InfError {
@@ -248,6 +278,13 @@ pub fn overflow_err() -> InfError {
     }
 }
 
+fn fee_below_min_err() -> InfError {
+    InfError {
+        code: InfErr::PoolErr,
+        cause: Some("fee below pool minimum".to_owned()),
+    }
+}
+
 fn zero_value_err() -> InfError {
     InfError {
         code: InfErr::SizeTooSmallErr,
@@ -278,8 +315,11 @@ impl From<InfStdErr> for InfError {
             InfStdErr::MissingSplData { mint } => missing_spl_data_err(&mint),
             InfStdErr::MissingSvcData { mint } => missing_svc_data_err(&mint),
             InfStdErr::NoValidPda => no_valid_pda_err(),
+            InfStdErr::PoolProgUpgraded { mint } => pool_prog_upgraded_err(&mint),
             InfStdErr::PricingProg(e) => e.into(),
             InfStdErr::RebalanceQuote(e) => e.into(),
+            InfStdErr::SnapshotDeser => snapshot_deser_err(),
+            InfStdErr::StaleSvc { mint } => stale_svc_err(&mint),
             InfStdErr::SwapQuote(e) => e.into(),
             InfStdErr::UnknownPp { pp_prog_id } => unknown_pp_err(&pp_prog_id),
             InfStdErr::UnknownSvc { svc_prog_id } => unknown_svc_err(&svc_prog_id),
@@ -433,6 +473,7 @@ impl<E1: Into<InfError>, E2: Into<InfError>> From<RebalanceQuoteErr<E1, E2>> for
             RebalanceQuoteErr::OutCalc(e) => e.into(),
             RebalanceQuoteErr::NotEnoughLiquidity(e) => e.into(),
             RebalanceQuoteErr::Overflow => overflow_err(),
+            RebalanceQuoteErr::Disabled => pool_op_disabled_err(DisableMode::Rebalance),
         }
     }
 }
@@ -449,6 +490,8 @@ impl<E1: Into<InfError>, E2: Into<InfError>, E3: Into<InfError>> From<QuoteErr<E
             QuoteErr::Pricing(e) => e.into(),
             QuoteErr::ZeroValue => zero_value_err(),
             QuoteErr::InpDisabled => lst_input_disabled_err(),
+            QuoteErr::Disabled(m) => pool_op_disabled_err(m),
+            QuoteErr::FeeBelowMin => fee_below_min_err(),
         }
     }
 }
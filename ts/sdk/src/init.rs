@@ -1,8 +1,8 @@
 use bs58_fixed_wasm::Bs58Array;
 use inf1_std::{
     inf1_ctl_core::{
-        accounts::pool_state::VerPoolState,
-        keys::{LST_STATE_LIST_ID, POOL_STATE_ID},
+        accounts::{lst_calc_backing_list::LstCalcBackingList, pool_state::VerPoolState},
+        keys::{LST_CALC_BACKING_LIST_ID, LST_STATE_LIST_ID, POOL_STATE_ID},
         typedefs::versioned::V1_2,
     },
     InfStd,
@@ -20,16 +20,25 @@ use crate::{
 };
 
 /// Returns the pubkeys of the accounts that need to be fetched to initialize
-/// a new {@link Inf} object
+/// a new {@link Inf} object.
+///
+/// The controller's `LstCalcBackingList` account may not exist onchain yet,
+/// see {@link init}.
 #[wasm_bindgen(js_name = initPks)]
 pub fn init_pks() -> Box<[B58PK]> {
-    [POOL_STATE_ID, LST_STATE_LIST_ID].map(B58PK::new).into()
+    [POOL_STATE_ID, LST_STATE_LIST_ID, LST_CALC_BACKING_LIST_ID]
+        .map(B58PK::new)
+        .into()
 }
 
 /// Initialize a new {@link Inf} object.
 ///
 /// The returned object must be updated for a mint pair before it is ready to
-/// quote and operate for trades involving that pair
+/// quote and operate for trades involving that pair.
+///
+/// The controller's `LstCalcBackingList` account is optional in `fetched`
+/// since it may not exist onchain yet. If present, SPL LSTs recorded in it
+/// do not need to be included in `spl_lsts`.
 ///
 /// @throws
 #[wasm_bindgen(js_name = init)]
@@ -54,7 +63,7 @@ pub fn init(
         .map(|(Bs58Array(k), Bs58Array(v))| (k, v))
         .collect();
 
-    Ok(Inf(InfStd::new(
+    let mut inf = InfStd::new(
         pool,
         lst_state_list_data,
         None,
@@ -64,7 +73,15 @@ pub fn init(
         spl_lsts,
         find_pda,
         create_raw_pda_slice,
-    )?))
+    )?;
+    if let Some(backing_list) = fetched.remove(&B58PK::new(LST_CALC_BACKING_LIST_ID)) {
+        if LstCalcBackingList::of_acc_data(&backing_list.data).is_none() {
+            return Err(acc_deser_err(&LST_CALC_BACKING_LIST_ID));
+        }
+        inf.lst_calc_backing_list_data = backing_list.data.into_vec().into_boxed_slice();
+    }
+
+    Ok(Inf(inf))
 }
 
 /// Same as {@link init}, but instead of using accounts fetched from RPC,
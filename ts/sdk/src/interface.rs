@@ -3,7 +3,7 @@
 use std::collections::HashMap;
 
 use bs58_fixed_wasm::Bs58Array;
-use inf1_std::update::UpdateMap;
+use inf1_std::update::{LstStateListChanges, PoolChanges, UpdateMap};
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use tsify_next::{declare, Tsify};
@@ -29,6 +29,11 @@ impl inf1_std::update::Account for Account {
     fn data(&self) -> &[u8] {
         &self.data
     }
+
+    #[inline]
+    fn slot(&self) -> Option<u64> {
+        self.slot
+    }
 }
 
 impl UpdateMap for AccountMap {
@@ -43,8 +48,8 @@ impl UpdateMap for AccountMap {
 /// Map of `mint: stake pool account` for spl (all deploys) LSTs.
 ///
 /// This data is required to determine how to properly initialize the corresponding
-/// sol value calculator data since which stake pool account corresponds to which mint
-/// is not available onchain (yet)
+/// sol value calculator data for SPL LSTs whose stake pool account is not yet
+/// recorded onchain in the controller's `LstCalcBackingList` account
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 #[serde(rename_all = "camelCase")]
@@ -56,6 +61,13 @@ pub struct SplPoolAccounts(pub HashMap<B58PK, B58PK>);
 pub struct Account {
     pub data: ByteBuf,
     pub owner: B58PK,
+
+    /// Context slot that this account was fetched at, if known.
+    ///
+    /// Accounts older than data already applied to the pool are ignored.
+    #[tsify(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slot: Option<u64>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, Tsify)]
@@ -67,6 +79,7 @@ pub struct PoolStateV2 {
     pub version: u8,
     pub is_disabled: u8,
     pub is_rebalancing: u8,
+    pub disable_modes: u8,
     pub admin: B58PK,
     pub rebalance_authority: B58PK,
     pub protocol_fee_beneficiary: B58PK,
@@ -77,6 +90,8 @@ pub struct PoolStateV2 {
     pub withheld_lamports: u64,
     pub protocol_fee_lamports: u64,
     pub last_release_slot: u64,
+    pub min_fee_nanos: u32,
+    pub sol_value_circuit_breaker_nanos: u32,
 }
 
 pub const fn pool_state_v2_from_intf(
@@ -86,6 +101,7 @@ pub const fn pool_state_v2_from_intf(
         version,
         is_disabled,
         is_rebalancing,
+        disable_modes,
         admin: Bs58Array(admin),
         rebalance_authority: Bs58Array(rebalance_authority),
         protocol_fee_beneficiary: Bs58Array(protocol_fee_beneficiary),
@@ -96,6 +112,8 @@ pub const fn pool_state_v2_from_intf(
         withheld_lamports,
         protocol_fee_lamports,
         last_release_slot,
+        min_fee_nanos,
+        sol_value_circuit_breaker_nanos,
     }: PoolStateV2,
 ) -> inf1_std::inf1_ctl_core::accounts::pool_state::PoolStateV2 {
     inf1_std::inf1_ctl_core::accounts::pool_state::PoolStateV2 {
@@ -104,7 +122,7 @@ pub const fn pool_state_v2_from_intf(
         version,
         is_disabled,
         is_rebalancing,
-        padding: [0],
+        disable_modes,
         rps,
         withheld_lamports,
         protocol_fee_lamports,
@@ -115,6 +133,8 @@ pub const fn pool_state_v2_from_intf(
         pricing_program,
         lp_token_mint,
         rps_authority,
+        min_fee_nanos,
+        sol_value_circuit_breaker_nanos,
     }
 }
 
@@ -135,7 +155,9 @@ pub const fn pool_state_v2_into_intf(
         pricing_program,
         lp_token_mint,
         rps_authority,
-        padding: _,
+        disable_modes,
+        min_fee_nanos,
+        sol_value_circuit_breaker_nanos,
     }: inf1_std::inf1_ctl_core::accounts::pool_state::PoolStateV2,
 ) -> PoolStateV2 {
     PoolStateV2 {
@@ -143,6 +165,7 @@ pub const fn pool_state_v2_into_intf(
         admin: B58PK::new(admin),
         is_disabled,
         is_rebalancing,
+        disable_modes,
         lp_token_mint: B58PK::new(lp_token_mint),
         pricing_program: B58PK::new(pricing_program),
         protocol_fee_beneficiary: B58PK::new(protocol_fee_beneficiary),
@@ -154,6 +177,8 @@ pub const fn pool_state_v2_into_intf(
         withheld_lamports,
         protocol_fee_lamports,
         last_release_slot,
+        min_fee_nanos,
+        sol_value_circuit_breaker_nanos,
     }
 }
 
@@ -164,6 +189,7 @@ pub struct LstState {
     pub is_input_disabled: u8,
     pub pool_reserves_bump: u8,
     pub protocol_fee_accumulator_bump: u8,
+    pub synced_reserves_balance: u64,
     pub sol_value: u64,
     pub mint: B58PK,
     pub sol_value_calculator: B58PK,
@@ -175,6 +201,7 @@ pub const fn lst_state_from_intf(
         mint: Bs58Array(mint),
         pool_reserves_bump,
         protocol_fee_accumulator_bump,
+        synced_reserves_balance,
         sol_value,
         sol_value_calculator: Bs58Array(sol_value_calculator),
     }: LstState,
@@ -186,7 +213,9 @@ pub const fn lst_state_from_intf(
         protocol_fee_accumulator_bump,
         sol_value,
         sol_value_calculator,
-        padding: [0; 5],
+        synced_reserves_balance: inf1_std::inf1_ctl_core::typedefs::compact_u64::CompactU64::new(
+            synced_reserves_balance,
+        ),
     }
 }
 
@@ -198,7 +227,7 @@ pub const fn lst_state_into_intf(
         protocol_fee_accumulator_bump,
         sol_value,
         sol_value_calculator,
-        padding: _,
+        synced_reserves_balance,
     }: inf1_std::inf1_ctl_core::typedefs::lst_state::LstState,
 ) -> LstState {
     LstState {
@@ -206,6 +235,7 @@ pub const fn lst_state_into_intf(
         mint: B58PK::new(mint),
         pool_reserves_bump,
         protocol_fee_accumulator_bump,
+        synced_reserves_balance: synced_reserves_balance.get(),
         sol_value,
         sol_value_calculator: B58PK::new(sol_value_calculator),
     }
@@ -221,3 +251,69 @@ pub enum SlotLookahead {
     /// Lookahead relative, to `slot = pool.last_release_slot + this`
     Rel(u64),
 }
+
+/// Changes found by an update procedure.
+///
+/// LST lists are lists of LST mints.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateChanges {
+    /// `true` if the pool's pricing program was swapped out
+    pub pricing_prog: bool,
+
+    /// Set if the pool was disabled or enabled
+    #[tsify(optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_disabled: Option<bool>,
+
+    /// Set if a rebalance was started or ended
+    #[tsify(optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_rebalancing: Option<bool>,
+
+    pub lsts_added: Box<[B58PK]>,
+
+    pub lsts_removed: Box<[B58PK]>,
+
+    /// LSTs whose SOL value calculator program was swapped out
+    pub lsts_svc_prog: Box<[B58PK]>,
+
+    pub lsts_input_disabled: Box<[B58PK]>,
+
+    pub lsts_input_enabled: Box<[B58PK]>,
+}
+
+impl From<inf1_std::update::UpdateChanges> for UpdateChanges {
+    #[inline]
+    fn from(
+        inf1_std::update::UpdateChanges {
+            pool:
+                PoolChanges {
+                    pricing_prog,
+                    is_disabled,
+                    is_rebalancing,
+                },
+            lst_state_list:
+                LstStateListChanges {
+                    added,
+                    removed,
+                    svc_prog,
+                    input_disabled,
+                    input_enabled,
+                },
+        }: inf1_std::update::UpdateChanges,
+    ) -> Self {
+        let b58 = |v: Vec<[u8; 32]>| -> Box<[B58PK]> { v.into_iter().map(B58PK::new).collect() };
+        Self {
+            pricing_prog,
+            is_disabled,
+            is_rebalancing,
+            lsts_added: b58(added),
+            lsts_removed: b58(removed),
+            lsts_svc_prog: b58(svc_prog),
+            lsts_input_disabled: b58(input_disabled),
+            lsts_input_enabled: b58(input_enabled),
+        }
+    }
+}
@@ -3,12 +3,14 @@ use wasm_bindgen::prelude::*;
 
 mod accounts;
 mod controller;
+mod cu;
 mod err;
 mod init;
 mod instruction;
 mod interface;
 mod pda;
 mod rebalance;
+mod snapshot;
 mod spl;
 mod trade;
 
@@ -0,0 +1,28 @@
+use inf1_std::InfStd;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    err::InfError,
+    pda::{create_raw_pda_slice, find_pda},
+    Inf,
+};
+
+/// Serializes all data of the {@link Inf} object into a compact, versioned snapshot
+/// that can be persisted and restored with {@link fromBytes}
+/// to avoid having to refetch all accounts.
+#[wasm_bindgen(js_name = toBytes)]
+pub fn to_bytes(inf: &Inf) -> Box<[u8]> {
+    inf.0.to_snapshot_bytes().into()
+}
+
+/// Restores an {@link Inf} object from a snapshot returned by {@link toBytes}
+///
+/// @throws if `bytes` is not a valid snapshot of a supported version
+#[wasm_bindgen(js_name = fromBytes)]
+pub fn from_bytes(bytes: &[u8]) -> Result<Inf, InfError> {
+    Ok(Inf(InfStd::from_snapshot_bytes(
+        bytes,
+        find_pda,
+        create_raw_pda_slice,
+    )?))
+}
@@ -18,7 +18,8 @@ pub fn append_spl_lsts(inf: &mut Inf, SplPoolAccounts(spl_lsts): SplPoolAccounts
     );
 }
 
-/// Returns if the given SPL LST mints have their {@link SplPoolAccounts} present in the object.
+/// Returns if the given SPL LST mints have their {@link SplPoolAccounts} present in the object,
+/// either recorded onchain in the controller's `LstCalcBackingList` or added via {@link appendSplLsts}.
 ///
 /// Returns a byte array where ret[i] corresponds to the result for `mints[i]`.
 /// 0 - false, 1 - true.
@@ -33,8 +34,13 @@ pub fn has_spl_data(
     // Clippy complains, needed for wasm_bindgen
     #[allow(clippy::boxed_local)] mints: Box<[B58PK]>,
 ) -> Box<[u8]> {
+    let onchain = inf.0.try_lst_calc_backing_list().unwrap_or_default();
     mints
         .iter()
-        .map(|mint| u8::from(inf.0.spl_lsts.contains_key(&mint.0)))
+        .map(|mint| {
+            u8::from(
+                inf.0.spl_lsts.contains_key(&mint.0) || onchain.iter().any(|b| b.mint == mint.0),
+            )
+        })
         .collect()
 }
@@ -2,7 +2,9 @@ use bs58_fixed_wasm::Bs58Array;
 use inf1_std::{
     inf1_ctl_core::{
         self,
-        instructions::swap::v2::{exact_in::SwapExactInIxData, exact_out::SwapExactOutIxData},
+        instructions::swap::v2::{
+            exact_in::SWAP_EXACT_IN_V2_IX_DISCM, exact_out::SWAP_EXACT_OUT_V2_IX_DISCM,
+        },
     },
     instructions::swap::v2::exact_in::{
         swap_exact_in_v2_ix_is_signer, swap_exact_in_v2_ix_is_writer,
@@ -32,6 +34,12 @@ pub struct TradeArgs {
     pub mints: PkPair,
     pub signer: B58PK,
     pub token_accs: PkPair,
+
+    /// The referrer's token account of the input mint,
+    /// if a share of the trade's fees should be paid to a referrer
+    #[tsify(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referrer: Option<B58PK>,
 }
 
 /// @throws
@@ -52,6 +60,7 @@ pub fn trade_exact_in_ix(
                 inp: Bs58Array(inp_token_acc),
                 out: Bs58Array(out_token_acc),
             },
+        referrer,
     }: &TradeArgs,
 ) -> Result<Instruction, InfError> {
     let trade_ix_args = TradeIxArgs {
@@ -66,16 +75,23 @@ pub fn trade_exact_in_ix(
             inp: inp_token_acc,
             out: out_token_acc,
         },
+        referrer: referrer.as_ref().map(|Bs58Array(r)| r),
     };
     let ix = inf.0.swap_exact_in_ix_mut(&trade_ix_args)?;
     Ok(Instruction {
         accounts: keys_signer_writable_to_metas(
-            swap_exact_in_v2_ix_keys_owned(&ix.accs).seq(),
-            swap_exact_in_v2_ix_is_signer(&ix.accs).seq(),
-            swap_exact_in_v2_ix_is_writer(&ix.accs).seq(),
+            swap_exact_in_v2_ix_keys_owned(&ix.swap.accs)
+                .seq()
+                .chain(ix.referral_suf_keys_owned()),
+            swap_exact_in_v2_ix_is_signer(&ix.swap.accs)
+                .seq()
+                .chain(ix.referral_suf_is_signer()),
+            swap_exact_in_v2_ix_is_writer(&ix.swap.accs)
+                .seq()
+                .chain(ix.referral_suf_is_writer()),
         ),
         program_address: B58PK::new(inf1_ctl_core::ID),
-        data: ByteBuf::from(SwapExactInIxData::new(&ix.to_full()).as_buf()),
+        data: ByteBuf::from(ix.to_ix_data::<SWAP_EXACT_IN_V2_IX_DISCM>()),
     })
 }
 
@@ -97,6 +113,7 @@ pub fn trade_exact_out_ix(
                 inp: Bs58Array(inp_token_acc),
                 out: Bs58Array(out_token_acc),
             },
+        referrer,
     }: &TradeArgs,
 ) -> Result<Instruction, InfError> {
     let trade_ix_args = TradeIxArgs {
@@ -111,15 +128,22 @@ pub fn trade_exact_out_ix(
             inp: inp_token_acc,
             out: out_token_acc,
         },
+        referrer: referrer.as_ref().map(|Bs58Array(r)| r),
     };
     let ix = inf.0.swap_exact_out_ix_mut(&trade_ix_args)?;
     Ok(Instruction {
         accounts: keys_signer_writable_to_metas(
-            swap_exact_in_v2_ix_keys_owned(&ix.accs).seq(),
-            swap_exact_in_v2_ix_is_signer(&ix.accs).seq(),
-            swap_exact_in_v2_ix_is_writer(&ix.accs).seq(),
+            swap_exact_in_v2_ix_keys_owned(&ix.swap.accs)
+                .seq()
+                .chain(ix.referral_suf_keys_owned()),
+            swap_exact_in_v2_ix_is_signer(&ix.swap.accs)
+                .seq()
+                .chain(ix.referral_suf_is_signer()),
+            swap_exact_in_v2_ix_is_writer(&ix.swap.accs)
+                .seq()
+                .chain(ix.referral_suf_is_writer()),
         ),
         program_address: B58PK::new(inf1_ctl_core::ID),
-        data: ByteBuf::from(SwapExactOutIxData::new(&ix.to_full()).as_buf()),
+        data: ByteBuf::from(ix.to_ix_data::<SWAP_EXACT_OUT_V2_IX_DISCM>()),
     })
 }
@@ -49,6 +49,12 @@ pub struct Quote {
     pub fee: u64,
 
     pub mints: PkPair,
+
+    /// Oldest context slot out of all the accounts this quote was derived from,
+    /// if known.
+    #[tsify(optional)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oldest_slot: Option<u64>,
 }
 
 /// @throws
@@ -79,12 +85,17 @@ pub fn quote_trade_exact_in(
         *amt,
         *slot_lookahead,
     )?;
+    let oldest_slot = inf.0.trade_oldest_slot(&Pair {
+        inp: inp_mint,
+        out: out_mint,
+    })?;
     Ok(Quote {
         inp,
         inp_sol_val,
         out,
         fee,
         mints: *mints,
+        oldest_slot,
     })
 }
 
@@ -120,11 +131,16 @@ pub fn quote_trade_exact_out(
         *amt,
         *slot_lookahead,
     )?;
+    let oldest_slot = inf.0.trade_oldest_slot(&Pair {
+        inp: inp_mint,
+        out: out_mint,
+    })?;
     Ok(Quote {
         inp,
         inp_sol_val,
         out,
         fee,
         mints: *mints,
+        oldest_slot,
     })
 }
@@ -5,7 +5,7 @@ use wasm_bindgen::prelude::*;
 
 use crate::{
     err::InfError,
-    interface::{AccountMap, PkPair, B58PK},
+    interface::{AccountMap, PkPair, UpdateChanges, B58PK},
     trade::Pair,
     Inf,
 };
@@ -34,6 +34,8 @@ pub fn accounts_to_update_for_trade(
     Ok(res.into_boxed_slice())
 }
 
+/// Returns changes to the pool and its LSTs found while updating
+///
 /// @throws
 #[wasm_bindgen(js_name = updateForTrade)]
 pub fn update_for_trade(
@@ -43,8 +45,9 @@ pub fn update_for_trade(
         out: Bs58Array(out),
     }: &PkPair,
     account_map: &AccountMap,
-) -> Result<(), InfError> {
-    inf.0
+) -> Result<UpdateChanges, InfError> {
+    let changes = inf
+        .0
         .update_trade(&Pair { inp, out }, TradeLimitTy::ExactIn(()), account_map)?;
-    Ok(())
+    Ok(changes.into())
 }
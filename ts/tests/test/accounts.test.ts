@@ -37,10 +37,12 @@ describe("accounts test", () => {
     expect(pool).toMatchInlineSnapshot(`
       {
         "admin": "8VE2uJkoheDbJd9rCyKzfXmiMqAS4o1B3XGshEh86BGk",
+        "disableModes": 0,
         "isDisabled": 0,
         "isRebalancing": 0,
         "lastReleaseSlot": 0n,
         "lpTokenMint": "5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm",
+        "minFeeNanos": 0,
         "pricingProgram": "s1b6NRXj6ygNu1QMKXh2H9LUR2aPApAAm1UQ2DjdhNV",
         "protocolFeeBeneficiary": "EeQmNqm1RcQnee8LTyx6ccVG9FnR8TezQuw2JXq2LC1T",
         "protocolFeeLamports": 69n,
@@ -48,6 +50,7 @@ describe("accounts test", () => {
         "rebalanceAuthority": "GFHMc9BegxJXLdHJrABxNVoPRdnmVxXiNeoUCEpgXVHw",
         "rps": 39328803111936n,
         "rpsAuthority": "8VE2uJkoheDbJd9rCyKzfXmiMqAS4o1B3XGshEh86BGk",
+        "solValueCircuitBreakerNanos": 0,
         "totalSolValue": 111440393290220n,
         "version": 2,
         "withheldLamports": 999999999n,
@@ -62,10 +65,12 @@ describe("accounts test", () => {
     expect(pool).toMatchInlineSnapshot(`
       {
         "admin": "8VE2uJkoheDbJd9rCyKzfXmiMqAS4o1B3XGshEh86BGk",
+        "disableModes": 0,
         "isDisabled": 0,
         "isRebalancing": 0,
         "lastReleaseSlot": 10n,
         "lpTokenMint": "5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm",
+        "minFeeNanos": 0,
         "pricingProgram": "s1b6NRXj6ygNu1QMKXh2H9LUR2aPApAAm1UQ2DjdhNV",
         "protocolFeeBeneficiary": "EeQmNqm1RcQnee8LTyx6ccVG9FnR8TezQuw2JXq2LC1T",
         "protocolFeeLamports": 4333n,
@@ -73,6 +78,7 @@ describe("accounts test", () => {
         "rebalanceAuthority": "GFHMc9BegxJXLdHJrABxNVoPRdnmVxXiNeoUCEpgXVHw",
         "rps": 39328803111936n,
         "rpsAuthority": "8VE2uJkoheDbJd9rCyKzfXmiMqAS4o1B3XGshEh86BGk",
+        "solValueCircuitBreakerNanos": 0,
         "totalSolValue": 111440393290220n,
         "version": 2,
         "withheldLamports": 999957360n,
@@ -94,6 +100,7 @@ describe("accounts test", () => {
       admin: "8VE2uJkoheDbJd9rCyKzfXmiMqAS4o1B3XGshEh86BGk",
       isDisabled: 1,
       isRebalancing: 1,
+      disableModes: 5,
       lpTokenMint: "5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm",
       pricingProgram: "s1b6NRXj6ygNu1QMKXh2H9LUR2aPApAAm1UQ2DjdhNV",
       protocolFeeBeneficiary: "EeQmNqm1RcQnee8LTyx6ccVG9FnR8TezQuw2JXq2LC1T",
@@ -106,6 +113,8 @@ describe("accounts test", () => {
       rps: 12432n,
       withheldLamports: 354324231n,
       lastReleaseSlot: 32451325n,
+      minFeeNanos: 1_000_000,
+      solValueCircuitBreakerNanos: 50_000_000,
     };
 
     setPoolState(inf, pool);
@@ -121,6 +130,7 @@ describe("accounts test", () => {
       admin: "8VE2uJkoheDbJd9rCyKzfXmiMqAS4o1B3XGshEh86BGk",
       isDisabled: 1,
       isRebalancing: 1,
+      disableModes: 5,
       lpTokenMint: "5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm",
       pricingProgram: "s1b6NRXj6ygNu1QMKXh2H9LUR2aPApAAm1UQ2DjdhNV",
       protocolFeeBeneficiary: "EeQmNqm1RcQnee8LTyx6ccVG9FnR8TezQuw2JXq2LC1T",
@@ -133,6 +143,8 @@ describe("accounts test", () => {
       rps: 12432n,
       withheldLamports: 354324231n,
       lastReleaseSlot: 32451325n,
+      minFeeNanos: 1_000_000,
+      solValueCircuitBreakerNanos: 50_000_000,
     };
 
     setPoolState(inf, pool);
@@ -173,6 +185,7 @@ describe("accounts test", () => {
           "protocolFeeAccumulatorBump": 255,
           "solValue": 13414450670097n,
           "solValueCalculator": "wsoGmxQLSvwWpuaidCApxN5kEowLe2HLQLJhCQnj4bE",
+          "syncedReservesBalance": 0n,
         },
         {
           "isInputDisabled": 0,
@@ -181,6 +194,7 @@ describe("accounts test", () => {
           "protocolFeeAccumulatorBump": 253,
           "solValue": 30344n,
           "solValueCalculator": "1idUSy4MGGKyKhvjSnGZ6Zc7Q4eKQcibym4BkEEw9KR",
+          "syncedReservesBalance": 0n,
         },
         {
           "isInputDisabled": 0,
@@ -189,6 +203,7 @@ describe("accounts test", () => {
           "protocolFeeAccumulatorBump": 255,
           "solValue": 14651n,
           "solValueCalculator": "mare3SCyfZkAndpBRBeonETmkCCB3TJTTrz8ZN2dnhP",
+          "syncedReservesBalance": 0n,
         },
         {
           "isInputDisabled": 0,
@@ -197,6 +212,7 @@ describe("accounts test", () => {
           "protocolFeeAccumulatorBump": 249,
           "solValue": 98025942575128n,
           "solValueCalculator": "ssmbu3KZxgonUtjEMCKspZzxvUQCxAFnyh1rcHUeEDo",
+          "syncedReservesBalance": 0n,
         },
       ]
     `);
@@ -213,6 +229,7 @@ describe("accounts test", () => {
         protocolFeeAccumulatorBump: 252,
         solValue: 303444n,
         solValueCalculator: "1idUSy4MGGKyKhvjSnGZ6Zc7Q4eKQcibym4BkEEw9KR",
+        syncedReservesBalance: 1_000_000_000n,
       },
       {
         isInputDisabled: 1,
@@ -221,6 +238,7 @@ describe("accounts test", () => {
         protocolFeeAccumulatorBump: 251,
         solValue: 1341445067009n,
         solValueCalculator: "wsoGmxQLSvwWpuaidCApxN5kEowLe2HLQLJhCQnj4bE",
+        syncedReservesBalance: 1_000_000_000n,
       },
       {
         isInputDisabled: 1,
@@ -229,6 +247,7 @@ describe("accounts test", () => {
         protocolFeeAccumulatorBump: 255,
         solValue: 146510n,
         solValueCalculator: "mare3SCyfZkAndpBRBeonETmkCCB3TJTTrz8ZN2dnhP",
+        syncedReservesBalance: 1_000_000_000n,
       },
       {
         isInputDisabled: 1,
@@ -237,6 +256,7 @@ describe("accounts test", () => {
         protocolFeeAccumulatorBump: 240,
         solValue: 9802594257518n,
         solValueCalculator: "ssmbu3KZxgonUtjEMCKspZzxvUQCxAFnyh1rcHUeEDo",
+        syncedReservesBalance: 1_000_000_000n,
       },
     ];
 
@@ -258,6 +278,7 @@ describe("accounts test", () => {
         protocolFeeAccumulatorBump: 252,
         solValue: 303444n,
         solValueCalculator: "1idUSy4MGGKyKhvjSnGZ6Zc7Q4eKQcibym4BkEEw9KR",
+        syncedReservesBalance: 1_000_000_000n,
       },
       {
         isInputDisabled: 1,
@@ -266,6 +287,7 @@ describe("accounts test", () => {
         protocolFeeAccumulatorBump: 251,
         solValue: 1341445067009n,
         solValueCalculator: "wsoGmxQLSvwWpuaidCApxN5kEowLe2HLQLJhCQnj4bE",
+        syncedReservesBalance: 1_000_000_000n,
       },
       {
         isInputDisabled: 1,
@@ -274,6 +296,7 @@ describe("accounts test", () => {
         protocolFeeAccumulatorBump: 255,
         solValue: 146510n,
         solValueCalculator: "mare3SCyfZkAndpBRBeonETmkCCB3TJTTrz8ZN2dnhP",
+        syncedReservesBalance: 1_000_000_000n,
       },
       {
         isInputDisabled: 1,
@@ -282,6 +305,7 @@ describe("accounts test", () => {
         protocolFeeAccumulatorBump: 240,
         solValue: 9802594257518n,
         solValueCalculator: "ssmbu3KZxgonUtjEMCKspZzxvUQCxAFnyh1rcHUeEDo",
+        syncedReservesBalance: 1_000_000_000n,
       },
     ];
 
@@ -16,8 +16,8 @@ export function localRpc(): Rpc<SolanaRpcApi> {
  *
  * @param rpc
  * @param accounts
- * @returns
- * @throws if any of the account in `accounts` dont exist
+ * @returns map excluding accounts in `accounts` that dont exist.
+ * The SDK reports required accounts that are missing from the map.
  */
 export async function fetchAccountMap(
   rpc: Rpc<SolanaRpcApi>,
@@ -28,18 +28,19 @@ export async function fetchAccountMap(
   return {
     context,
     value: new Map(
-      zipped.map(([address, v]) => {
-        if (v == null) {
-          throw new Error(`missing account ${address}`);
-        }
-        return [
-          address,
-          {
-            data: new Uint8Array(getBase64Encoder().encode(v.data[0])),
-            owner: v.owner,
-          },
-        ];
-      }),
+      zipped.flatMap(([address, v]) =>
+        v == null
+          ? []
+          : [
+              [
+                address,
+                {
+                  data: new Uint8Array(getBase64Encoder().encode(v.data[0])),
+                  owner: v.owner,
+                },
+              ] as const,
+            ],
+      ),
     ),
   };
 }
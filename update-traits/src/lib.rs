@@ -4,6 +4,15 @@ use core::{error::Error, fmt::Display};
 
 pub trait Account {
     fn data(&self) -> &[u8];
+
+    /// Context slot that this account's data was observed at,
+    /// `None` if unknown.
+    ///
+    /// Used to refuse updates with data older than what has already been applied.
+    #[inline]
+    fn slot(&self) -> Option<u64> {
+        None
+    }
 }
 
 // cant generalize over for Deref<T> due to lifetime of &[u8]
@@ -13,6 +22,11 @@ impl<T: Account> Account for &T {
     fn data(&self) -> &[u8] {
         (*self).data()
     }
+
+    #[inline]
+    fn slot(&self) -> Option<u64> {
+        (*self).slot()
+    }
 }
 
 pub trait UpdateMap {
@@ -27,6 +41,17 @@ pub trait UpdateMap {
         self.get_account(pk)
             .ok_or(UpdateErr::AccMissing { pk: *pk })
     }
+
+    /// [`oldest_slot`] of the accounts in this map with the given pubkeys.
+    ///
+    /// Pubkeys missing from this map are ignored.
+    #[inline]
+    fn oldest_slot_of(&self, pks: impl IntoIterator<Item = [u8; 32]>) -> Option<u64> {
+        oldest_slot(
+            pks.into_iter()
+                .map(|pk| self.get_account(&pk).and_then(|a| a.slot())),
+        )
+    }
 }
 
 /// Blanket for refs
@@ -42,6 +67,40 @@ impl<T: UpdateMap> UpdateMap for &T {
     }
 }
 
+/// Context slot that a piece of state was last updated at.
+///
+/// `None` if unknown, e.g. not yet updated or updated with
+/// [`Account`]s that do not carry a context slot.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct LastUpdateSlot(pub Option<u64>);
+
+impl LastUpdateSlot {
+    pub const NONE: Self = Self(None);
+
+    /// Returns `true` if state observed at `slot` is older than the current state
+    /// and should not overwrite it.
+    ///
+    /// Always `false` if either slot is unknown.
+    #[inline]
+    pub const fn is_regression(&self, slot: Option<u64>) -> bool {
+        match (self.0, slot) {
+            (Some(curr), Some(new)) => new < curr,
+            _ => false,
+        }
+    }
+}
+
+/// Oldest known slot out of the given slots,
+/// `None` if all of them are unknown.
+///
+/// The state derived from multiple accounts is only as recent
+/// as the oldest account it was derived from.
+#[inline]
+pub fn oldest_slot(slots: impl IntoIterator<Item = Option<u64>>) -> Option<u64> {
+    slots.into_iter().flatten().min()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UpdateErr<E> {
     /// Account missing from `UpdateMap`